@@ -17,6 +17,30 @@ mod m20260109_000007_create_notes_table;
 mod m20260109_000008_create_documents_table;
 mod m20260109_000009_create_conditions_table;
 mod m20260112_000001_create_sync_tables;
+mod m20260201_000001_add_pi_overrides_to_benefit_to_borrower;
+mod m20260201_000002_add_savings_breakdown_to_savings_calculations;
+mod m20260202_000001_create_net_tangible_benefit_findings_table;
+mod m20260203_000001_add_mip_columns_to_new_loan_details;
+mod m20260204_000001_add_va_funding_fee_flags_to_new_loan_details;
+mod m20260205_000001_create_mi_rate_cards_table;
+mod m20260205_000002_add_pmi_columns_to_options_template;
+mod m20260206_000001_add_heloc_columns_to_new_loan_details;
+mod m20260207_000001_create_debt_to_income_calculations_table;
+mod m20260208_000001_create_residual_income_calculations_table;
+mod m20260209_000001_add_student_loan_status_to_consumer_debts;
+mod m20260210_000001_create_self_employment_tables;
+mod m20260211_000001_create_rental_properties_table;
+mod m20260212_000001_create_dscr_calculators_table;
+mod m20260213_000001_create_income_trend_analyses_table;
+mod m20260214_000001_add_custom_rule_set_to_settings;
+mod m20260215_000001_create_retirement_accounts_table;
+mod m20260216_000001_create_military_income_table;
+mod m20260217_000001_create_bank_statement_tables;
+mod m20260218_000001_create_investment_income_tables;
+mod m20260219_000001_create_accessory_income_tables;
+mod m20260220_000001_add_agency_to_loan_information;
+mod m20260220_000002_create_title_fees_and_pricing_options_tables;
+mod m20260220_000003_add_qualifying_monthly_to_income_trend_analyses;
 
 pub struct Migrator;
 
@@ -40,6 +64,30 @@ impl MigratorTrait for Migrator {
             Box::new(m20260109_000008_create_documents_table::Migration),
             Box::new(m20260109_000009_create_conditions_table::Migration),
             Box::new(m20260112_000001_create_sync_tables::Migration),
+            Box::new(m20260201_000001_add_pi_overrides_to_benefit_to_borrower::Migration),
+            Box::new(m20260201_000002_add_savings_breakdown_to_savings_calculations::Migration),
+            Box::new(m20260202_000001_create_net_tangible_benefit_findings_table::Migration),
+            Box::new(m20260203_000001_add_mip_columns_to_new_loan_details::Migration),
+            Box::new(m20260204_000001_add_va_funding_fee_flags_to_new_loan_details::Migration),
+            Box::new(m20260205_000001_create_mi_rate_cards_table::Migration),
+            Box::new(m20260205_000002_add_pmi_columns_to_options_template::Migration),
+            Box::new(m20260206_000001_add_heloc_columns_to_new_loan_details::Migration),
+            Box::new(m20260207_000001_create_debt_to_income_calculations_table::Migration),
+            Box::new(m20260208_000001_create_residual_income_calculations_table::Migration),
+            Box::new(m20260209_000001_add_student_loan_status_to_consumer_debts::Migration),
+            Box::new(m20260210_000001_create_self_employment_tables::Migration),
+            Box::new(m20260211_000001_create_rental_properties_table::Migration),
+            Box::new(m20260212_000001_create_dscr_calculators_table::Migration),
+            Box::new(m20260213_000001_create_income_trend_analyses_table::Migration),
+            Box::new(m20260214_000001_add_custom_rule_set_to_settings::Migration),
+            Box::new(m20260215_000001_create_retirement_accounts_table::Migration),
+            Box::new(m20260216_000001_create_military_income_table::Migration),
+            Box::new(m20260217_000001_create_bank_statement_tables::Migration),
+            Box::new(m20260218_000001_create_investment_income_tables::Migration),
+            Box::new(m20260219_000001_create_accessory_income_tables::Migration),
+            Box::new(m20260220_000001_add_agency_to_loan_information::Migration),
+            Box::new(m20260220_000002_create_title_fees_and_pricing_options_tables::Migration),
+            Box::new(m20260220_000003_add_qualifying_monthly_to_income_trend_analyses::Migration),
         ]
     }
 } 
\ No newline at end of file
@@ -0,0 +1,48 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Add P&I override flags one by one (SQLite limitation)
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("benefit_to_borrower"))
+                    .add_column(ColumnDef::new(Alias::new("existing_pi_override")).boolean().not_null().default(false))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("benefit_to_borrower"))
+                    .add_column(ColumnDef::new(Alias::new("proposed_pi_override")).boolean().not_null().default(false))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("benefit_to_borrower"))
+                    .drop_column(Alias::new("existing_pi_override"))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("benefit_to_borrower"))
+                    .drop_column(Alias::new("proposed_pi_override"))
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,40 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const COLUMNS: [&str; 3] = ["debt_paid", "payment_reduction", "recoup_period_months"];
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Add the derived savings breakdown one column at a time (SQLite limitation)
+        for column in COLUMNS {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Alias::new("savings_calculations"))
+                        .add_column(ColumnDef::new(Alias::new(column)).decimal_len(15, 2).not_null().default(0))
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in COLUMNS {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Alias::new("savings_calculations"))
+                        .drop_column(Alias::new(column))
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create net_tangible_benefit_findings table
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("net_tangible_benefit_findings"))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Alias::new("id"))
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Alias::new("borrower_id")).integer().not_null())
+                    .col(ColumnDef::new(Alias::new("program")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("rule")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("passed")).boolean().not_null().default(false))
+                    .col(ColumnDef::new(Alias::new("threshold")).decimal_len(10, 3).not_null())
+                    .col(ColumnDef::new(Alias::new("actual")).decimal_len(10, 3).not_null())
+                    .col(ColumnDef::new(Alias::new("detail")).text().not_null())
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_net_tangible_benefit_findings_borrower_id")
+                            .from(Alias::new("net_tangible_benefit_findings"), Alias::new("borrower_id"))
+                            .to(Alias::new("borrowers"), Alias::new("id"))
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Drop net_tangible_benefit_findings table
+        manager
+            .drop_table(Table::drop().table(Alias::new("net_tangible_benefit_findings")).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Add upfront premium and prior FHA loan columns one by one (SQLite limitation)
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("new_loan_details"))
+                    .add_column(ColumnDef::new(Alias::new("ff_umip_amount")).decimal_len(10, 2).not_null().default(0))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("new_loan_details"))
+                    .add_column(ColumnDef::new(Alias::new("prior_ufmip")).decimal_len(10, 2).not_null().default(0))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("new_loan_details"))
+                    .add_column(ColumnDef::new(Alias::new("months_since_endorsement")).integer().not_null().default(0))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in ["ff_umip_amount", "prior_ufmip", "months_since_endorsement"] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Alias::new("new_loan_details"))
+                        .drop_column(Alias::new(column))
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Add VA funding fee flags one by one (SQLite limitation)
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("new_loan_details"))
+                    .add_column(ColumnDef::new(Alias::new("va_subsequent_use")).boolean().not_null().default(false))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("new_loan_details"))
+                    .add_column(ColumnDef::new(Alias::new("va_funding_fee_exempt")).boolean().not_null().default(false))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("new_loan_details"))
+                    .drop_column(Alias::new("va_subsequent_use"))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("new_loan_details"))
+                    .drop_column(Alias::new("va_funding_fee_exempt"))
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,47 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create mi_rate_cards table
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("mi_rate_cards"))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Alias::new("id"))
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Alias::new("ltv_min")).double().not_null())
+                    .col(ColumnDef::new(Alias::new("ltv_max")).double().not_null())
+                    .col(ColumnDef::new(Alias::new("credit_score_min")).integer().not_null())
+                    .col(ColumnDef::new(Alias::new("credit_score_max")).integer().not_null())
+                    .col(ColumnDef::new(Alias::new("coverage")).double().not_null())
+                    .col(ColumnDef::new(Alias::new("term_min_months")).integer().not_null())
+                    .col(ColumnDef::new(Alias::new("term_max_months")).integer().not_null())
+                    .col(ColumnDef::new(Alias::new("annual_rate")).double().not_null())
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Drop mi_rate_cards table
+        manager
+            .drop_table(Table::drop().table(Alias::new("mi_rate_cards")).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
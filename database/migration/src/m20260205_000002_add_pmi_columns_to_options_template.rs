@@ -0,0 +1,71 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Credit score drives the MI rate card lookup
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("loan_information"))
+                    .add_column(ColumnDef::new(Alias::new("credit_score")).integer().not_null().default(0))
+                    .to_owned(),
+            )
+            .await?;
+
+        // Add MI results to new_loan_details one by one (SQLite limitation)
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("new_loan_details"))
+                    .add_column(ColumnDef::new(Alias::new("mi_coverage")).decimal_len(5, 2).not_null().default(0))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("new_loan_details"))
+                    .add_column(ColumnDef::new(Alias::new("mi_annual_rate")).decimal_len(5, 3).not_null().default(0))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("new_loan_details"))
+                    .add_column(ColumnDef::new(Alias::new("pmi_termination_month")).integer().not_null().default(0))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("loan_information"))
+                    .drop_column(Alias::new("credit_score"))
+                    .to_owned(),
+            )
+            .await?;
+
+        for column in ["mi_coverage", "mi_annual_rate", "pmi_termination_month"] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Alias::new("new_loan_details"))
+                        .drop_column(Alias::new(column))
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
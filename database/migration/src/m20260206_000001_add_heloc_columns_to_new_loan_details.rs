@@ -0,0 +1,43 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Add subordinate HELOC columns one by one (SQLite limitation)
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("new_loan_details"))
+                    .add_column(ColumnDef::new(Alias::new("heloc_balance")).decimal_len(12, 2).not_null().default(0))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("new_loan_details"))
+                    .add_column(ColumnDef::new(Alias::new("heloc_credit_limit")).decimal_len(12, 2).not_null().default(0))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in ["heloc_balance", "heloc_credit_limit"] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Alias::new("new_loan_details"))
+                        .drop_column(Alias::new(column))
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
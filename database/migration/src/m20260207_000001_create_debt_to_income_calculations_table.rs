@@ -0,0 +1,56 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create debt_to_income_calculations table
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("debt_to_income_calculations"))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Alias::new("id"))
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Alias::new("borrower_id")).integer().not_null())
+                    .col(ColumnDef::new(Alias::new("front_end_ratio")).decimal_len(7, 3).not_null())
+                    .col(ColumnDef::new(Alias::new("back_end_ratio")).decimal_len(7, 3).not_null())
+                    .col(ColumnDef::new(Alias::new("qualifying_income")).decimal_len(12, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("housing_payment")).decimal_len(10, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("monthly_debts")).decimal_len(10, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("compensating_factors")).boolean().not_null().default(false))
+                    .col(ColumnDef::new(Alias::new("max_front_end_ratio")).decimal_len(5, 2).null())
+                    .col(ColumnDef::new(Alias::new("max_back_end_ratio")).decimal_len(5, 2).null())
+                    .col(ColumnDef::new(Alias::new("within_limits")).boolean().not_null().default(false))
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_debt_to_income_calculations_borrower_id")
+                            .from(Alias::new("debt_to_income_calculations"), Alias::new("borrower_id"))
+                            .to(Alias::new("borrowers"), Alias::new("id"))
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Drop debt_to_income_calculations table
+        manager
+            .drop_table(Table::drop().table(Alias::new("debt_to_income_calculations")).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create residual_income_calculations table
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("residual_income_calculations"))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Alias::new("id"))
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Alias::new("borrower_id")).integer().not_null())
+                    .col(ColumnDef::new(Alias::new("state")).string().not_null().default(""))
+                    .col(ColumnDef::new(Alias::new("family_size")).integer().not_null().default(0))
+                    .col(ColumnDef::new(Alias::new("square_feet")).decimal_len(10, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("monthly_taxes")).decimal_len(10, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("region")).string().not_null().default(""))
+                    .col(ColumnDef::new(Alias::new("maintenance_utilities")).decimal_len(10, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("residual_income")).decimal_len(12, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("guideline_residual_income")).decimal_len(10, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("required_residual_income")).decimal_len(10, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("cushion_applied")).boolean().not_null().default(false))
+                    .col(ColumnDef::new(Alias::new("meets_guideline")).boolean().not_null().default(false))
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_residual_income_calculations_borrower_id")
+                            .from(Alias::new("residual_income_calculations"), Alias::new("borrower_id"))
+                            .to(Alias::new("borrowers"), Alias::new("id"))
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Drop residual_income_calculations table
+        manager
+            .drop_table(Table::drop().table(Alias::new("residual_income_calculations")).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
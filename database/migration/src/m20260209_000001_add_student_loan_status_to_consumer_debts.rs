@@ -0,0 +1,30 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Student loan repayment status drives the agency qualifying payment rules
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("consumer_debts"))
+                    .add_column(ColumnDef::new(Alias::new("student_loan_status")).string().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("consumer_debts"))
+                    .drop_column(Alias::new("student_loan_status"))
+                    .to_owned(),
+            )
+            .await
+    }
+}
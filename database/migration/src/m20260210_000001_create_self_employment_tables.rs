@@ -0,0 +1,96 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create self_employment_businesses table
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("self_employment_businesses"))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Alias::new("id"))
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Alias::new("borrower_id")).integer().not_null())
+                    .col(ColumnDef::new(Alias::new("business_name")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("business_type")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("ownership_percent")).decimal_len(5, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_self_employment_businesses_borrower_id")
+                            .from(Alias::new("self_employment_businesses"), Alias::new("borrower_id"))
+                            .to(Alias::new("borrowers"), Alias::new("id"))
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create self_employment_tax_years table, two rows per business
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("self_employment_tax_years"))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Alias::new("id"))
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Alias::new("business_id")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("borrower_id")).integer().not_null())
+                    .col(ColumnDef::new(Alias::new("is_recent_year")).boolean().not_null().default(false))
+                    .col(ColumnDef::new(Alias::new("tax_year")).integer().not_null())
+                    .col(ColumnDef::new(Alias::new("net_income")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("guaranteed_payments")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("w2_wages")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("nonrecurring_income")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("depreciation")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("depletion")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("amortization")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("business_use_of_home")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("notes_payable_short_term")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("meals_exclusion")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("total_tax")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_self_employment_tax_years_business_id")
+                            .from(Alias::new("self_employment_tax_years"), Alias::new("business_id"))
+                            .to(Alias::new("self_employment_businesses"), Alias::new("id"))
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Drop self_employment_tax_years table
+        manager
+            .drop_table(Table::drop().table(Alias::new("self_employment_tax_years")).to_owned())
+            .await?;
+
+        // Drop self_employment_businesses table
+        manager
+            .drop_table(Table::drop().table(Alias::new("self_employment_businesses")).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create rental_properties table, one row per property on the rental worksheet
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("rental_properties"))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Alias::new("id"))
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Alias::new("borrower_id")).integer().not_null())
+                    .col(ColumnDef::new(Alias::new("address")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("is_subject_property")).boolean().not_null().default(false))
+                    .col(ColumnDef::new(Alias::new("method")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("rents_received")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("total_expenses")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("depreciation")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("mortgage_interest")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("taxes")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("insurance")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("hoa_dues")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("months_in_service")).integer().not_null().default(12))
+                    .col(ColumnDef::new(Alias::new("gross_monthly_rent")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("monthly_pitia")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_rental_properties_borrower_id")
+                            .from(Alias::new("rental_properties"), Alias::new("borrower_id"))
+                            .to(Alias::new("borrowers"), Alias::new("id"))
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Alias::new("rental_properties")).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
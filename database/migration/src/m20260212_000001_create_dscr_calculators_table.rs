@@ -0,0 +1,65 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create dscr_calculators table for saved investor-loan scenarios
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("dscr_calculators"))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Alias::new("id"))
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Alias::new("property_address")).string().not_null().default(""))
+                    .col(ColumnDef::new(Alias::new("purchase_price")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("down_payment")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("rate")).decimal_len(6, 3).not_null())
+                    .col(ColumnDef::new(Alias::new("term_months")).integer().not_null().default(360))
+                    .col(ColumnDef::new(Alias::new("annual_property_tax")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("annual_insurance")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("monthly_hoa")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("expected_rent")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // Minimum ratio the calculator checks against
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("settings"))
+                    .add_column(ColumnDef::new(Alias::new("min_dscr")).double().not_null().default(1.0))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("settings"))
+                    .drop_column(Alias::new("min_dscr"))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Alias::new("dscr_calculators")).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create income_trend_analyses table, one row per worksheet section per borrower
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("income_trend_analyses"))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Alias::new("id"))
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Alias::new("borrower_id")).integer().not_null())
+                    .col(ColumnDef::new(Alias::new("section")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("ytd_monthly")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("prior_year_monthly")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("second_prior_year_monthly")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("prior_year_change_percent")).decimal_len(7, 2).null())
+                    .col(ColumnDef::new(Alias::new("ytd_change_percent")).decimal_len(7, 2).null())
+                    .col(ColumnDef::new(Alias::new("decline_threshold_percent")).decimal_len(5, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("declining")).boolean().not_null().default(false))
+                    .col(ColumnDef::new(Alias::new("significant_decline")).boolean().not_null().default(false))
+                    .col(ColumnDef::new(Alias::new("recommended_monthly")).decimal_len(15, 2).null())
+                    .col(ColumnDef::new(Alias::new("reasoning")).text().not_null())
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_income_trend_analyses_borrower_id")
+                            .from(Alias::new("income_trend_analyses"), Alias::new("borrower_id"))
+                            .to(Alias::new("borrowers"), Alias::new("id"))
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Decline threshold the trend analysis flags against
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("settings"))
+                    .add_column(ColumnDef::new(Alias::new("income_decline_threshold")).double().not_null().default(10.0))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("settings"))
+                    .drop_column(Alias::new("income_decline_threshold"))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Alias::new("income_trend_analyses")).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
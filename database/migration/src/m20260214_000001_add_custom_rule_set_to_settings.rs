@@ -0,0 +1,31 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Income rule set used by the custom profile; empty until configured, which
+        // loads as the Fannie Mae values
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("settings"))
+                    .add_column(ColumnDef::new(Alias::new("custom_rule_set")).json().not_null().default("{}"))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("settings"))
+                    .drop_column(Alias::new("custom_rule_set"))
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,53 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create retirement_accounts table, one row per account on the IRA worksheet
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("retirement_accounts"))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Alias::new("id"))
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Alias::new("borrower_id")).integer().not_null())
+                    .col(ColumnDef::new(Alias::new("description")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("account_type")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("balance")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("is_vested")).boolean().not_null().default(true))
+                    .col(ColumnDef::new(Alias::new("penalty_free")).boolean().not_null().default(false))
+                    .col(ColumnDef::new(Alias::new("distribution_amount")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("distribution_frequency")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_retirement_accounts_borrower_id")
+                            .from(Alias::new("retirement_accounts"), Alias::new("borrower_id"))
+                            .to(Alias::new("borrowers"), Alias::new("id"))
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Alias::new("retirement_accounts")).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
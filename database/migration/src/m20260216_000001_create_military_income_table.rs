@@ -0,0 +1,56 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create military_income table, one row per borrower from their Leave and Earnings Statement
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("military_income"))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Alias::new("id"))
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Alias::new("borrower_id")).integer().not_null().unique_key())
+                    .col(ColumnDef::new(Alias::new("branch")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("ets_date")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("base_pay")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("bah")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("bas")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("clothing_allowance")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("flight_pay")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("hazard_pay")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("other_allowances")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("other_allowances_taxable")).boolean().not_null().default(false))
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_military_income_borrower_id")
+                            .from(Alias::new("military_income"), Alias::new("borrower_id"))
+                            .to(Alias::new("borrowers"), Alias::new("id"))
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Alias::new("military_income")).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
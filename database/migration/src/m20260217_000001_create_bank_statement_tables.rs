@@ -0,0 +1,90 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create bank_statement_accounts table
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("bank_statement_accounts"))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Alias::new("id"))
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Alias::new("borrower_id")).integer().not_null())
+                    .col(ColumnDef::new(Alias::new("account_name")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("account_type")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("expense_method")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("expense_percent")).decimal_len(5, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("ownership_percent")).decimal_len(5, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_bank_statement_accounts_borrower_id")
+                            .from(Alias::new("bank_statement_accounts"), Alias::new("borrower_id"))
+                            .to(Alias::new("borrowers"), Alias::new("id"))
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create bank_statement_months table, one row per statement month per account
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("bank_statement_months"))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Alias::new("id"))
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Alias::new("account_id")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("borrower_id")).integer().not_null())
+                    .col(ColumnDef::new(Alias::new("month_index")).integer().not_null())
+                    .col(ColumnDef::new(Alias::new("month")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("total_deposits")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("excluded_deposits")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("nsf_count")).integer().not_null().default(0))
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_bank_statement_months_account_id")
+                            .from(Alias::new("bank_statement_months"), Alias::new("account_id"))
+                            .to(Alias::new("bank_statement_accounts"), Alias::new("id"))
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Drop bank_statement_months table
+        manager
+            .drop_table(Table::drop().table(Alias::new("bank_statement_months")).to_owned())
+            .await?;
+
+        // Drop bank_statement_accounts table
+        manager
+            .drop_table(Table::drop().table(Alias::new("bank_statement_accounts")).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
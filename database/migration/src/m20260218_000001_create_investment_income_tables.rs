@@ -0,0 +1,87 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create investment_income table, one row per borrower with the assets leaving at closing
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("investment_income"))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Alias::new("id"))
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Alias::new("borrower_id")).integer().not_null().unique_key())
+                    .col(ColumnDef::new(Alias::new("funds_to_close")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("reserves")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_investment_income_borrower_id")
+                            .from(Alias::new("investment_income"), Alias::new("borrower_id"))
+                            .to(Alias::new("borrowers"), Alias::new("id"))
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create investment_income_sources table, one row per 1099, Schedule D or K-1 source
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("investment_income_sources"))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Alias::new("id"))
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Alias::new("borrower_id")).integer().not_null())
+                    .col(ColumnDef::new(Alias::new("sort_order")).integer().not_null())
+                    .col(ColumnDef::new(Alias::new("name")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("source_type")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("prior_year_amount")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("recent_year_amount")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("asset_balance")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_investment_income_sources_borrower_id")
+                            .from(Alias::new("investment_income_sources"), Alias::new("borrower_id"))
+                            .to(Alias::new("borrowers"), Alias::new("id"))
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Drop investment_income_sources table
+        manager
+            .drop_table(Table::drop().table(Alias::new("investment_income_sources")).to_owned())
+            .await?;
+
+        // Drop investment_income table
+        manager
+            .drop_table(Table::drop().table(Alias::new("investment_income")).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
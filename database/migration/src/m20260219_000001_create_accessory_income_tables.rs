@@ -0,0 +1,86 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create accessory_income table, one row per borrower with the program and ADU lease
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("accessory_income"))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Alias::new("id"))
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Alias::new("borrower_id")).integer().not_null().unique_key())
+                    .col(ColumnDef::new(Alias::new("program")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("boarder_name")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("adu_monthly_rent")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("adu_lease_signed")).boolean().not_null().default(false))
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_accessory_income_borrower_id")
+                            .from(Alias::new("accessory_income"), Alias::new("borrower_id"))
+                            .to(Alias::new("borrowers"), Alias::new("id"))
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create boarder_rent_months table, one row per month of boarder rent history
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("boarder_rent_months"))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Alias::new("id"))
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Alias::new("borrower_id")).integer().not_null())
+                    .col(ColumnDef::new(Alias::new("month_index")).integer().not_null())
+                    .col(ColumnDef::new(Alias::new("month")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("amount")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_boarder_rent_months_borrower_id")
+                            .from(Alias::new("boarder_rent_months"), Alias::new("borrower_id"))
+                            .to(Alias::new("borrowers"), Alias::new("id"))
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Drop boarder_rent_months table
+        manager
+            .drop_table(Table::drop().table(Alias::new("boarder_rent_months")).to_owned())
+            .await?;
+
+        // Drop accessory_income table
+        manager
+            .drop_table(Table::drop().table(Alias::new("accessory_income")).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Agency picks the Fannie Mae or Freddie Mac income rules for conventional loans
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("loan_information"))
+                    .add_column(ColumnDef::new(Alias::new("agency")).string().not_null().default("fannie"))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("loan_information"))
+                    .drop_column(Alias::new("agency"))
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,129 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create title_fees table
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("title_fees"))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Alias::new("id"))
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Alias::new("borrower_id")).integer().not_null())
+                    .col(ColumnDef::new(Alias::new("title_insurance")).decimal_len(10, 2).not_null().default(0))
+                    .col(ColumnDef::new(Alias::new("title_search")).decimal_len(10, 2).not_null().default(0))
+                    .col(ColumnDef::new(Alias::new("title_examination")).decimal_len(10, 2).not_null().default(0))
+                    .col(ColumnDef::new(Alias::new("title_closing_fee")).decimal_len(10, 2).not_null().default(0))
+                    .col(ColumnDef::new(Alias::new("title_courier_fee")).decimal_len(10, 2).not_null().default(0))
+                    .col(ColumnDef::new(Alias::new("title_other_fees")).decimal_len(10, 2).not_null().default(0))
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_title_fees_borrower_id")
+                            .from(Alias::new("title_fees"), Alias::new("borrower_id"))
+                            .to(Alias::new("borrowers"), Alias::new("id"))
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // The original pricing_options table never matched the entity, so nothing could be
+        // saved to it. Recreate it with the options template's columns.
+        manager
+            .drop_table(Table::drop().table(Alias::new("pricing_options")).if_exists().to_owned())
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("pricing_options"))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Alias::new("id"))
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Alias::new("borrower_id")).integer().not_null())
+                    .col(ColumnDef::new(Alias::new("description")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("note_rate")).decimal_len(5, 3).not_null())
+                    .col(ColumnDef::new(Alias::new("ysp_percentage")).decimal_len(5, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("ysp_dollar")).decimal_len(8, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("bd_percentage")).decimal_len(5, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("bd_dollar")).decimal_len(8, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("is_selected")).boolean().not_null().default(false))
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_pricing_options_borrower_id")
+                            .from(Alias::new("pricing_options"), Alias::new("borrower_id"))
+                            .to(Alias::new("borrowers"), Alias::new("id"))
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Alias::new("title_fees")).to_owned())
+            .await?;
+
+        // Restore the original pricing_options table
+        manager
+            .drop_table(Table::drop().table(Alias::new("pricing_options")).to_owned())
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("pricing_options"))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Alias::new("id"))
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Alias::new("borrower_id")).integer().not_null())
+                    .col(ColumnDef::new(Alias::new("option_name")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("interest_rate")).decimal_len(5, 4).not_null())
+                    .col(ColumnDef::new(Alias::new("monthly_payment")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("total_cost")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("closing_costs")).decimal_len(15, 2).not_null())
+                    .col(ColumnDef::new(Alias::new("is_recommended")).boolean().not_null().default(false))
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_pricing_options_borrower_id")
+                            .from(Alias::new("pricing_options"), Alias::new("borrower_id"))
+                            .to(Alias::new("borrowers"), Alias::new("id"))
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Figure from the W-2 section's selected calculation method, which feeds DTI
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("income_trend_analyses"))
+                    .add_column(ColumnDef::new(Alias::new("qualifying_monthly")).decimal_len(15, 2).not_null().default(0))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("income_trend_analyses"))
+                    .drop_column(Alias::new("qualifying_monthly"))
+                    .to_owned(),
+            )
+            .await
+    }
+}
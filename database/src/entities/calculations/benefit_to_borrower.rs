@@ -53,6 +53,10 @@ pub struct Model {
     #[sea_orm(column_type = "Decimal(Some((10, 2)))")]
     pub proposed_total_obligations: Decimal,
 
+    // Manual P&I overrides
+    pub existing_pi_override: bool,
+    pub proposed_pi_override: bool,
+
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
 }
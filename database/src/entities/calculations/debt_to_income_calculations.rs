@@ -0,0 +1,33 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "debt_to_income_calculations")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: Uuid,
+    pub borrower_id: i32,
+    #[sea_orm(column_type = "Decimal(Some((7, 3)))")]
+    pub front_end_ratio: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((7, 3)))")]
+    pub back_end_ratio: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((12, 2)))")]
+    pub qualifying_income: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))")]
+    pub housing_payment: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))")]
+    pub monthly_debts: Decimal,
+    pub compensating_factors: bool,
+    #[sea_orm(column_type = "Decimal(Some((5, 2)))")]
+    pub max_front_end_ratio: Option<Decimal>,
+    #[sea_orm(column_type = "Decimal(Some((5, 2)))")]
+    pub max_back_end_ratio: Option<Decimal>,
+    pub within_limits: bool,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
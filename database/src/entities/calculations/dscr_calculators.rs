@@ -0,0 +1,32 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "dscr_calculators")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: Uuid,
+    pub property_address: String,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub purchase_price: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub down_payment: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((6, 3)))")]
+    pub rate: Decimal,
+    pub term_months: i32,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub annual_property_tax: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub annual_insurance: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub monthly_hoa: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub expected_rent: Decimal,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
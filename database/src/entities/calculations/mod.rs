@@ -1,5 +1,9 @@
 //! Financial calculation entities
 
 pub mod benefit_to_borrower;
+pub mod debt_to_income_calculations;
+pub mod dscr_calculators;
+pub mod net_tangible_benefit_findings;
 pub mod pricing_options;
+pub mod residual_income_calculations;
 pub mod savings_calculations;
@@ -0,0 +1,25 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "net_tangible_benefit_findings")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: Uuid,
+    pub borrower_id: i32,
+    pub program: String,
+    pub rule: String,
+    pub passed: bool,
+    #[sea_orm(column_type = "Decimal(Some((10, 3)))")]
+    pub threshold: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((10, 3)))")]
+    pub actual: Decimal,
+    pub detail: String,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
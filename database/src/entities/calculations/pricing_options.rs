@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 pub struct Model {
     #[sea_orm(primary_key)]
     pub id: Uuid,
+    pub borrower_id: i32,
     pub description: String,
     #[sea_orm(column_type = "Decimal(Some((5, 3)))")]
     pub note_rate: Decimal,
@@ -0,0 +1,34 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "residual_income_calculations")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: Uuid,
+    pub borrower_id: i32,
+    pub state: String,
+    pub family_size: i32,
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))")]
+    pub square_feet: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))")]
+    pub monthly_taxes: Decimal,
+    pub region: String,
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))")]
+    pub maintenance_utilities: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((12, 2)))")]
+    pub residual_income: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))")]
+    pub guideline_residual_income: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))")]
+    pub required_residual_income: Decimal,
+    pub cushion_applied: bool,
+    pub meets_guideline: bool,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,37 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "bank_statement_accounts")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: String,
+    pub borrower_id: i32,
+    pub account_name: String,
+    pub account_type: String,
+    pub expense_method: String,
+    #[sea_orm(column_type = "Decimal(Some((5, 2)))")]
+    pub expense_percent: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((5, 2)))")]
+    pub ownership_percent: Decimal,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "crate::entities::client::borrower::Entity",
+        from = "Column::BorrowerId",
+        to = "crate::entities::client::borrower::Column::Id"
+    )]
+    Borrower,
+}
+
+impl Related<crate::entities::client::borrower::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Borrower.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,38 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "bank_statement_months")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: Uuid,
+    pub account_id: String,
+    pub borrower_id: i32,
+    pub month_index: i32,
+    pub month: String,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub total_deposits: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub excluded_deposits: Decimal,
+    pub nsf_count: i32,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::bank_statement_accounts::Entity",
+        from = "Column::AccountId",
+        to = "super::bank_statement_accounts::Column::Id"
+    )]
+    Account,
+}
+
+impl Related<super::bank_statement_accounts::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Account.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,34 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "boarder_rent_months")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: Uuid,
+    pub borrower_id: i32,
+    pub month_index: i32,
+    pub month: String,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub amount: Decimal,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "crate::entities::client::borrower::Entity",
+        from = "Column::BorrowerId",
+        to = "crate::entities::client::borrower::Column::Id"
+    )]
+    Borrower,
+}
+
+impl Related<crate::entities::client::borrower::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Borrower.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
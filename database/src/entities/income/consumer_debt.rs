@@ -25,6 +25,7 @@ pub struct Model {
     pub interest_rate: Option<Decimal>,
     pub omit_from_dti: bool,
     pub pay_off_at_closing: bool,
+    pub student_loan_status: Option<String>,
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
 }
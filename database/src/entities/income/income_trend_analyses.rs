@@ -0,0 +1,51 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "income_trend_analyses")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: Uuid,
+    pub borrower_id: i32,
+    pub section: String,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub qualifying_monthly: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub ytd_monthly: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub prior_year_monthly: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub second_prior_year_monthly: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((7, 2)))", nullable)]
+    pub prior_year_change_percent: Option<Decimal>,
+    #[sea_orm(column_type = "Decimal(Some((7, 2)))", nullable)]
+    pub ytd_change_percent: Option<Decimal>,
+    #[sea_orm(column_type = "Decimal(Some((5, 2)))")]
+    pub decline_threshold_percent: Decimal,
+    pub declining: bool,
+    pub significant_decline: bool,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))", nullable)]
+    pub recommended_monthly: Option<Decimal>,
+    #[sea_orm(column_type = "Text")]
+    pub reasoning: String,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "crate::entities::client::borrower::Entity",
+        from = "Column::BorrowerId",
+        to = "crate::entities::client::borrower::Column::Id"
+    )]
+    Borrower,
+}
+
+impl Related<crate::entities::client::borrower::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Borrower.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
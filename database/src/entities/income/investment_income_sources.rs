@@ -0,0 +1,39 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "investment_income_sources")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: String,
+    pub borrower_id: i32,
+    pub sort_order: i32,
+    pub name: String,
+    pub source_type: String,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub prior_year_amount: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub recent_year_amount: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub asset_balance: Decimal,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "crate::entities::client::borrower::Entity",
+        from = "Column::BorrowerId",
+        to = "crate::entities::client::borrower::Column::Id"
+    )]
+    Borrower,
+}
+
+impl Related<crate::entities::client::borrower::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Borrower.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,47 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "military_income")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: Uuid,
+    pub borrower_id: i32,
+    pub branch: String,
+    pub ets_date: String,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub base_pay: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub bah: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub bas: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub clothing_allowance: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub flight_pay: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub hazard_pay: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub other_allowances: Decimal,
+    pub other_allowances_taxable: bool,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "crate::entities::client::borrower::Entity",
+        from = "Column::BorrowerId",
+        to = "crate::entities::client::borrower::Column::Id"
+    )]
+    Borrower,
+}
+
+impl Related<crate::entities::client::borrower::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Borrower.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -1,7 +1,19 @@
 //! Income and employment-related entities
 
+pub mod accessory_income;
+pub mod bank_statement_accounts;
+pub mod bank_statement_months;
+pub mod boarder_rent_months;
 pub mod consumer_debt;
 pub mod general_income_data;
 pub mod general_income_entries;
 pub mod income_information;
+pub mod income_trend_analyses;
+pub mod investment_income;
+pub mod investment_income_sources;
+pub mod military_income;
+pub mod rental_properties;
+pub mod retirement_accounts;
+pub mod self_employment_businesses;
+pub mod self_employment_tax_years;
 pub mod w2_jobs;
@@ -0,0 +1,52 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "rental_properties")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: String,
+    pub borrower_id: i32,
+    pub address: String,
+    pub is_subject_property: bool,
+    pub method: String,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub rents_received: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub total_expenses: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub depreciation: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub mortgage_interest: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub taxes: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub insurance: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub hoa_dues: Decimal,
+    pub months_in_service: i32,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub gross_monthly_rent: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub monthly_pitia: Decimal,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "crate::entities::client::borrower::Entity",
+        from = "Column::BorrowerId",
+        to = "crate::entities::client::borrower::Column::Id"
+    )]
+    Borrower,
+}
+
+impl Related<crate::entities::client::borrower::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Borrower.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,39 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "retirement_accounts")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: String,
+    pub borrower_id: i32,
+    pub description: String,
+    pub account_type: String,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub balance: Decimal,
+    pub is_vested: bool,
+    pub penalty_free: bool,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub distribution_amount: Decimal,
+    pub distribution_frequency: String,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "crate::entities::client::borrower::Entity",
+        from = "Column::BorrowerId",
+        to = "crate::entities::client::borrower::Column::Id"
+    )]
+    Borrower,
+}
+
+impl Related<crate::entities::client::borrower::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Borrower.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
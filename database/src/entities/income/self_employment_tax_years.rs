@@ -0,0 +1,55 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "self_employment_tax_years")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: Uuid,
+    pub business_id: String,
+    pub borrower_id: i32,
+    pub is_recent_year: bool,
+    pub tax_year: i32,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub net_income: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub guaranteed_payments: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub w2_wages: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub nonrecurring_income: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub depreciation: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub depletion: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub amortization: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub business_use_of_home: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub notes_payable_short_term: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub meals_exclusion: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
+    pub total_tax: Decimal,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::self_employment_businesses::Entity",
+        from = "Column::BusinessId",
+        to = "super::self_employment_businesses::Column::Id"
+    )]
+    Business,
+}
+
+impl Related<super::self_employment_businesses::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Business.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
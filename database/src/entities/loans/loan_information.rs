@@ -12,6 +12,8 @@ pub struct Model {
     pub new_term_months: u32,
     pub loan_purpose: String,
     pub appraisal_waiver: bool,
+    pub credit_score: i32,
+    pub agency: String,
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
 }
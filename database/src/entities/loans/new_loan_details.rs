@@ -16,10 +16,26 @@ pub struct Model {
     pub base_loan_amount: Decimal,
     #[sea_orm(column_type = "Decimal(Some((12, 2)))")]
     pub subordinated_amount: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((12, 2)))")]
+    pub heloc_balance: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((12, 2)))")]
+    pub heloc_credit_limit: Decimal,
     #[sea_orm(column_type = "Decimal(Some((5, 2)))")]
     pub ff_umip_percentage: Decimal,
     #[sea_orm(column_type = "Decimal(Some((10, 2)))")]
+    pub ff_umip_amount: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))")]
     pub umip_refund: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))")]
+    pub prior_ufmip: Decimal,
+    pub months_since_endorsement: i32,
+    pub va_subsequent_use: bool,
+    pub va_funding_fee_exempt: bool,
+    #[sea_orm(column_type = "Decimal(Some((5, 2)))")]
+    pub mi_coverage: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((5, 3)))")]
+    pub mi_annual_rate: Decimal,
+    pub pmi_termination_month: i32,
     #[sea_orm(column_type = "Decimal(Some((12, 2)))")]
     pub total_loan_amount: Decimal,
     #[sea_orm(column_type = "Decimal(Some((5, 3)))")]
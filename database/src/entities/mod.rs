@@ -9,6 +9,7 @@ pub mod loans;
 pub mod refinance;
 pub mod calculations;
 pub mod settings;
+pub mod mi_rate_cards;
 
 // Sync system entities
 pub mod sync_log;
@@ -17,7 +18,7 @@ pub mod sync_conflicts;
 
 // Re-export entities at the top level for backward compatibility
 pub use client::{borrower, timeline_events, outreach_templates, campaigns, ab_tests, note, document, condition};
-pub use income::{consumer_debt, general_income_data, general_income_entries, income_information, w2_jobs};
+pub use income::{accessory_income, bank_statement_accounts, bank_statement_months, boarder_rent_months, consumer_debt, general_income_data, general_income_entries, income_information, income_trend_analyses, investment_income, investment_income_sources, military_income, rental_properties, retirement_accounts, self_employment_businesses, self_employment_tax_years, w2_jobs};
 pub use loans::{existing_loans, loan_information, new_loan_details};
-pub use refinance::{mortgage_refinance, other_fees};
-pub use calculations::{benefit_to_borrower, pricing_options, savings_calculations};
+pub use refinance::{mortgage_refinance, other_fees, title_fees};
+pub use calculations::{benefit_to_borrower, debt_to_income_calculations, dscr_calculators, net_tangible_benefit_findings, pricing_options, residual_income_calculations, savings_calculations};
@@ -11,10 +11,22 @@ pub use super::client::document::{self as Document, Entity as DocumentEntity};
 pub use super::client::condition::{self as Condition, Entity as ConditionEntity};
 
 // Income entities
+pub use super::income::accessory_income::{self as AccessoryIncome, Entity as AccessoryIncomeEntity};
+pub use super::income::bank_statement_accounts::{self as BankStatementAccounts, Entity as BankStatementAccountsEntity};
+pub use super::income::bank_statement_months::{self as BankStatementMonths, Entity as BankStatementMonthsEntity};
+pub use super::income::boarder_rent_months::{self as BoarderRentMonths, Entity as BoarderRentMonthsEntity};
 pub use super::income::consumer_debt::{self as ConsumerDebt, Entity as ConsumerDebtEntity};
 pub use super::income::general_income_data::{self as GeneralIncomeData, Entity as GeneralIncomeDataEntity};
 pub use super::income::general_income_entries::{self as GeneralIncomeEntries, Entity as GeneralIncomeEntriesEntity};
+pub use super::income::rental_properties::{self as RentalProperties, Entity as RentalPropertiesEntity};
+pub use super::income::retirement_accounts::{self as RetirementAccounts, Entity as RetirementAccountsEntity};
+pub use super::income::self_employment_businesses::{self as SelfEmploymentBusinesses, Entity as SelfEmploymentBusinessesEntity};
+pub use super::income::self_employment_tax_years::{self as SelfEmploymentTaxYears, Entity as SelfEmploymentTaxYearsEntity};
 pub use super::income::income_information::{self as IncomeInformation, Entity as IncomeInformationEntity};
+pub use super::income::income_trend_analyses::{self as IncomeTrendAnalyses, Entity as IncomeTrendAnalysesEntity};
+pub use super::income::investment_income::{self as InvestmentIncome, Entity as InvestmentIncomeEntity};
+pub use super::income::investment_income_sources::{self as InvestmentIncomeSources, Entity as InvestmentIncomeSourcesEntity};
+pub use super::income::military_income::{self as MilitaryIncome, Entity as MilitaryIncomeEntity};
 pub use super::income::w2_jobs::{self as W2Jobs, Entity as W2JobsEntity};
 
 // Loan entities
@@ -25,9 +37,14 @@ pub use super::loans::new_loan_details::{self as NewLoanDetails, Entity as NewLo
 // Refinance entities
 pub use super::refinance::mortgage_refinance::{self as MortgageRefinance, Entity as MortgageRefinanceEntity};
 pub use super::refinance::other_fees::{self as OtherFees, Entity as OtherFeesEntity};
+pub use super::refinance::title_fees::{self as TitleFees, Entity as TitleFeesEntity};
 
 // Calculation entities
 pub use super::calculations::benefit_to_borrower::{self as BenefitToBorrower, Entity as BenefitToBorrowerEntity};
+pub use super::calculations::debt_to_income_calculations::{self as DebtToIncomeCalculations, Entity as DebtToIncomeCalculationsEntity};
+pub use super::calculations::dscr_calculators::{self as DscrCalculators, Entity as DscrCalculatorsEntity};
+pub use super::calculations::residual_income_calculations::{self as ResidualIncomeCalculations, Entity as ResidualIncomeCalculationsEntity};
+pub use super::calculations::net_tangible_benefit_findings::{self as NetTangibleBenefitFindings, Entity as NetTangibleBenefitFindingsEntity};
 pub use super::calculations::pricing_options::{self as PricingOptions, Entity as PricingOptionsEntity};
 pub use super::calculations::savings_calculations::{self as SavingsCalculations, Entity as SavingsCalculationsEntity};
 
@@ -38,3 +55,4 @@ pub use super::sync_conflicts::{self as SyncConflicts, Entity as SyncConflictsEn
 
 // Settings
 pub use super::settings::{self as Settings, Entity as SettingsEntity};
+pub use super::mi_rate_cards::{self as MiRateCards, Entity as MiRateCardsEntity};
@@ -2,3 +2,4 @@
 
 pub mod mortgage_refinance;
 pub mod other_fees;
+pub mod title_fees;
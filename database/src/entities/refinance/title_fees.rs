@@ -0,0 +1,29 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "title_fees")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: Uuid,
+    pub borrower_id: i32,
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))")]
+    pub title_insurance: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))")]
+    pub title_search: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))")]
+    pub title_examination: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))")]
+    pub title_closing_fee: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))")]
+    pub title_courier_fee: Decimal,
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))")]
+    pub title_other_fees: Decimal,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -10,6 +10,9 @@ pub struct Model {
     pub currency: String,
     pub default_loan_term: i32,
     pub dti_threshold: f64,
+    pub min_dscr: f64,
+    pub income_decline_threshold: f64,
+    pub custom_rule_set: Json,
     pub auto_backup: bool,
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
@@ -69,6 +69,15 @@ pub fn Navbar() -> Element {
                             }
                         }
 
+                        li {
+                            Link {
+                                to: Route::DscrCalculator {},
+                                class: "block py-2 px-3 text-theme-text-secondary rounded hover:bg-theme-bg-tertiary hover:text-theme-text-primary md:hover:bg-transparent md:border-0 md:p-0 transition-colors",
+                                onclick: move |_| menu_open.set(false),
+                                "DSCR"
+                            }
+                        }
+
                         li {
                             Link {
                                 to: Route::Settings {},
@@ -2,7 +2,7 @@
 use dioxus::prelude::*;
 
 use crate::views::{ Welcome, Help};
-use crate::views::dashboard::{MainDashboard, Analytics, Settings};
+use crate::views::dashboard::{MainDashboard, Analytics, Settings, DscrCalculator};
 use crate::views::dashboard::by_id::{Worksheet, ClientDetails, OptionsTemplate, Timeline, OutreachTemplates, UploadDocuments, ClientDocuments, ClientNotes};
 use crate::components::layout::AppLayout;
 
@@ -21,6 +21,8 @@ pub enum Route {
 
         #[route("/dashboard/settings")]
         Settings {},
+        #[route("/dashboard/dscr")]
+        DscrCalculator {},
         #[route("/:id/client")]
         ClientDetails { id: i32 },
         #[route("/:id/income-worksheet")]
@@ -265,6 +265,9 @@ pub fn GeneralIncome(borrower_id: i32) -> Element {
                                                 "Disability",
                                                 "Alimony",
                                                 "Child Support",
+                                                "Military",
+                                                "Boarder Income",
+                                                "ADU Rental Income",
                                                 "Other",
                                             ]
                                             {
@@ -0,0 +1,236 @@
+use dioxus::prelude::*;
+use shared::calc::accessory_income::{
+    ADU_RENT_FACTOR, AffordableProgram, PROGRAM_INCOME_SHARE_LIMIT, apply_to_general_income, calculate_accessory_income,
+    other_qualifying_income,
+};
+use shared::calc::rule_set::IncomeRuleSet;
+use shared::models::{AccessoryIncomeData, GeneralIncomeData};
+use crate::views::dashboard::by_id::income_worksheet::main::load_income_information;
+
+// Helper to format money
+fn format_money(amount: f64) -> String {
+    format!("${:.2}", amount)
+}
+
+const INPUT_CLASS: &str = "bg-white border-2 border-gray-300 text-gray-900 text-sm rounded-lg px-4 py-3";
+
+// Boarder rent and ADU income for HomeReady and Home Possible borrowers. Each save also
+// updates the boarder and ADU entries in general income, which feed qualifying income.
+#[component]
+pub fn AccessoryIncome(borrower_id: i32, rules: IncomeRuleSet) -> Element {
+    let client = use_context::<repository::Repository>();
+    let mut data = use_signal(AccessoryIncomeData::default);
+    let mut other_income = use_signal(|| 0.0);
+
+    let client_clone = client.clone();
+    use_effect(use_reactive!(|(rules,)| {
+        let client = client_clone.clone();
+        spawn(async move {
+            match client.get_accessory_income_data(borrower_id).await {
+                Ok(Some(saved)) => data.set(saved),
+                Ok(None) => {}
+                Err(e) => log::error!("Failed to load boarder and ADU income data: {:?}", e),
+            }
+            let income = load_income_information(&client, borrower_id).await;
+            other_income.set(other_qualifying_income(&income, &rules));
+        });
+    }));
+
+    let result = use_memo(move || calculate_accessory_income(&data(), other_income()));
+
+    // Callback so the handlers below stay `Copy` inside the loop
+    let save = Callback::new(move |_: ()| {
+        let client = client.clone();
+        spawn(async move {
+            let accessory = data();
+            if let Err(e) = client.save_accessory_income_data(borrower_id, accessory.clone()).await {
+                log::error!("Failed to save boarder and ADU income data: {:?}", e);
+                return;
+            }
+
+            let mut general = match client.get_general_income_data(borrower_id).await {
+                Ok(Some(general)) => general,
+                Ok(None) => GeneralIncomeData {
+                    entries: Vec::new(),
+                    ..Default::default()
+                },
+                Err(e) => {
+                    log::error!("Failed to load general income data: {:?}", e);
+                    return;
+                }
+            };
+            let result = calculate_accessory_income(&accessory, other_income());
+            apply_to_general_income(&mut general, &accessory, &result);
+            if let Err(e) = client.save_general_income_data(borrower_id, general).await {
+                log::error!("Failed to update general income data: {:?}", e);
+            }
+        });
+    });
+
+    let mut update_field = move |field: &str, value: String| {
+        {
+            let mut accessory = data.write();
+            match field {
+                "program" => accessory.program = value,
+                "boarder_name" => accessory.boarder_name = value,
+                "adu_monthly_rent" => accessory.adu_monthly_rent = value.parse().unwrap_or(0.0),
+                "adu_lease_signed" => accessory.adu_lease_signed = value == "true",
+                _ => {}
+            }
+        }
+        save.call(());
+    };
+
+    let mut update_month = move |index: usize, field: &str, value: String| {
+        if let Some(month) = data.write().boarder_months.get_mut(index) {
+            match field {
+                "month" => month.month = value,
+                "amount" => month.amount = value.parse().unwrap_or(0.0),
+                _ => {}
+            }
+        }
+        save.call(());
+    };
+
+    let program = AffordableProgram::from_key(&data().program);
+
+    rsx! {
+        div { class: "space-y-6",
+            div { class: "bg-gradient-to-br from-lime-50 to-green-50 p-6 rounded-xl shadow-md border-2 border-lime-200",
+                div { class: "flex flex-col mb-6 md:w-1/2",
+                    label { class: "block mb-2 text-sm font-semibold text-gray-900", "Program" }
+                    select {
+                        value: "{data().program}",
+                        class: "{INPUT_CLASS} font-semibold",
+                        onchange: move |e: Event<FormData>| update_field("program", e.value()),
+                        for option_program in AffordableProgram::ALL {
+                            option { value: option_program.as_key(), "{option_program.label()}" }
+                        }
+                    }
+                }
+
+                h3 { class: "text-lg font-semibold text-gray-900 mb-3", "Boarder Rent" }
+                div { class: "flex flex-col mb-4 md:w-1/2",
+                    label { class: "block mb-2 text-sm font-semibold text-gray-900", "Boarder" }
+                    input {
+                        r#type: "text",
+                        value: "{data().boarder_name}",
+                        class: INPUT_CLASS,
+                        onchange: move |e: Event<FormData>| update_field("boarder_name", e.value()),
+                    }
+                }
+                table { class: "min-w-full table-auto border-collapse border border-gray-300 text-sm bg-white",
+                    thead {
+                        tr { class: "bg-gray-50",
+                            for heading in ["Month", "Rent Received"] {
+                                th { class: "border border-gray-300 px-3 py-2 text-left text-xs font-medium text-gray-500 uppercase",
+                                    "{heading}"
+                                }
+                            }
+                        }
+                    }
+                    tbody {
+                        for (index, month) in data().boarder_months.into_iter().enumerate() {
+                            tr {
+                                td { class: "border border-gray-300 px-3 py-1",
+                                    input {
+                                        r#type: "month",
+                                        value: "{month.month}",
+                                        class: "w-full px-2 py-1 border rounded",
+                                        onchange: move |e: Event<FormData>| update_month(index, "month", e.value()),
+                                    }
+                                }
+                                td { class: "border border-gray-300 px-3 py-1",
+                                    input {
+                                        r#type: "number",
+                                        step: "any",
+                                        value: "{month.amount}",
+                                        class: "w-full px-2 py-1 border rounded",
+                                        onchange: move |e: Event<FormData>| update_month(index, "amount", e.value()),
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                div { class: "mt-3 space-y-1 text-sm",
+                    div { class: "flex justify-between",
+                        span { class: "text-gray-600",
+                            if let Some(required) = program.boarder_months_required() {
+                                "12-Month Average ({result().boarder_months_paid} months paid, {required} required)"
+                            } else {
+                                "12-Month Average ({result().boarder_months_paid} months paid)"
+                            }
+                        }
+                        span { class: "font-medium text-gray-900", "{format_money(result().boarder_average_monthly)}" }
+                    }
+                    div { class: "flex justify-between",
+                        span { class: "text-gray-600", "Qualifying Boarder Income" }
+                        span { class: "font-medium text-gray-900", "{format_money(result().boarder_qualifying)}" }
+                    }
+                }
+
+                h3 { class: "text-lg font-semibold text-gray-900 mt-6 mb-3", "Accessory Dwelling Unit" }
+                div { class: "grid grid-cols-1 md:grid-cols-2 gap-4",
+                    div { class: "flex flex-col",
+                        label { class: "block mb-2 text-sm font-semibold text-gray-900", "Monthly Lease Rent" }
+                        input {
+                            r#type: "number",
+                            step: "any",
+                            value: "{data().adu_monthly_rent}",
+                            class: INPUT_CLASS,
+                            onchange: move |e: Event<FormData>| update_field("adu_monthly_rent", e.value()),
+                        }
+                    }
+                    div { class: "flex items-center space-x-3 md:mt-8",
+                        input {
+                            r#type: "checkbox",
+                            id: "adu_lease_signed",
+                            class: "w-5 h-5 text-green-600 border-gray-400 rounded cursor-pointer",
+                            checked: data().adu_lease_signed,
+                            onchange: move |e: Event<FormData>| update_field("adu_lease_signed", e.checked().to_string()),
+                        }
+                        label {
+                            r#for: "adu_lease_signed",
+                            class: "text-sm font-semibold text-gray-900 cursor-pointer",
+                            "Signed lease on file"
+                        }
+                    }
+                }
+                div { class: "mt-3 space-y-1 text-sm",
+                    div { class: "flex justify-between",
+                        span { class: "text-gray-600", "Counted Rent ({ADU_RENT_FACTOR * 100.0:.0}% of lease)" }
+                        span { class: "font-medium text-gray-900", "{format_money(result().adu_counted_rent)}" }
+                    }
+                    div { class: "flex justify-between",
+                        span { class: "text-gray-600", "Qualifying ADU Income" }
+                        span { class: "font-medium text-gray-900", "{format_money(result().adu_qualifying)}" }
+                    }
+                }
+
+                div { class: "mt-6 space-y-1 text-sm",
+                    div { class: "flex justify-between",
+                        span { class: "text-gray-600", "Other Qualifying Income" }
+                        span { class: "font-medium text-gray-900", "{format_money(other_income())}" }
+                    }
+                    div { class: "flex justify-between",
+                        span { class: "text-gray-600", "Limit per Source ({PROGRAM_INCOME_SHARE_LIMIT * 100.0:.0}% of {format_money(result().total_qualifying_income)})" }
+                        span { class: "font-medium text-gray-900", "{format_money(result().income_limit)}" }
+                    }
+                }
+
+                for warning in result().warnings {
+                    div { class: "mt-4 p-4 bg-yellow-50 border-2 border-yellow-300 rounded-lg text-sm text-gray-900 font-medium",
+                        "{warning}"
+                    }
+                }
+
+                div { class: "flex justify-end mt-4",
+                    div { class: "px-4 py-3 bg-green-100 border-2 border-green-400 rounded-lg text-gray-900 font-bold text-lg",
+                        "Boarder / ADU Income: {format_money(result().qualifying_monthly)}/mo"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,259 @@
+use dioxus::prelude::*;
+use shared::calc::bank_statement::{BankAccountType, ExpenseMethod, analyze_account, total_monthly_income};
+use shared::models::{BankStatementAccount, BankStatementData, BankStatementMonth};
+
+// Helper to format money
+fn format_money(amount: f64) -> String {
+    format!("${:.2}", amount)
+}
+
+const INPUT_CLASS: &str = "bg-white border-2 border-gray-300 text-gray-900 text-sm rounded-lg px-4 py-3";
+
+// Bank-statement income for Non-QM borrowers. Saved like the self-employment worksheet,
+// because its monthly result feeds qualifying income on the options template.
+#[component]
+pub fn BankStatement(borrower_id: i32) -> Element {
+    let client = use_context::<repository::Repository>();
+    let mut data = use_signal(BankStatementData::default);
+
+    let client_clone = client.clone();
+    use_effect(move || {
+        let client = client_clone.clone();
+        spawn(async move {
+            match client.get_bank_statement_data(borrower_id).await {
+                Ok(Some(saved)) => data.set(saved),
+                Ok(None) => data.set(BankStatementData {
+                    accounts: vec![BankStatementAccount::default()],
+                }),
+                Err(e) => log::error!("Failed to load bank statement data: {:?}", e),
+            }
+        });
+    });
+
+    // Callback so the handlers below stay `Copy` inside the loops
+    let save = Callback::new(move |_: ()| {
+        let client = client.clone();
+        spawn(async move {
+            if let Err(e) = client.save_bank_statement_data(borrower_id, data()).await {
+                log::error!("Failed to save bank statement data: {:?}", e);
+            }
+        });
+    });
+
+    let mut update_account = move |index: usize, field: &str, value: String| {
+        if let Some(account) = data.write().accounts.get_mut(index) {
+            match field {
+                "account_name" => account.account_name = value,
+                "account_type" => account.account_type = value,
+                "expense_method" => account.expense_method = value,
+                "expense_percent" => account.expense_percent = value.parse().unwrap_or(0.0),
+                "ownership_percent" => account.ownership_percent = value.parse().unwrap_or(0.0),
+                "statement_months" => {
+                    let count = value.parse().unwrap_or(12);
+                    account.months.resize(count, BankStatementMonth::default());
+                }
+                _ => {}
+            }
+        }
+        save.call(());
+    };
+
+    let mut update_month = move |index: usize, month_index: usize, field: &str, value: String| {
+        if let Some(month) = data.write().accounts.get_mut(index).and_then(|account| account.months.get_mut(month_index)) {
+            match field {
+                "month" => month.month = value,
+                "total_deposits" => month.total_deposits = value.parse().unwrap_or(0.0),
+                "excluded_deposits" => month.excluded_deposits = value.parse().unwrap_or(0.0),
+                "nsf_count" => month.nsf_count = value.parse().unwrap_or(0),
+                _ => {}
+            }
+        }
+        save.call(());
+    };
+
+    let add_account = move |_| {
+        data.write().accounts.push(BankStatementAccount::default());
+        save.call(());
+    };
+
+    let mut remove_account = move |index: usize| {
+        data.write().accounts.remove(index);
+        save.call(());
+    };
+
+    rsx! {
+        div { class: "space-y-6",
+            for (index, account) in data().accounts.into_iter().enumerate() {
+                {
+                    let account_type = BankAccountType::from_key(&account.account_type);
+                    let result = analyze_account(&account);
+                    rsx! {
+                        div { key: "{account.id}", class: "bg-gradient-to-br from-amber-50 to-orange-50 p-6 rounded-xl shadow-md border-2 border-amber-200",
+                            div { class: "grid grid-cols-1 md:grid-cols-3 gap-4 mb-6",
+                                div { class: "flex flex-col",
+                                    label { class: "block mb-2 text-sm font-semibold text-gray-900", "Account" }
+                                    input {
+                                        r#type: "text",
+                                        value: "{account.account_name}",
+                                        class: INPUT_CLASS,
+                                        onchange: move |e: Event<FormData>| update_account(index, "account_name", e.value()),
+                                    }
+                                }
+                                div { class: "flex flex-col",
+                                    label { class: "block mb-2 text-sm font-semibold text-gray-900", "Account Type" }
+                                    select {
+                                        value: "{account.account_type}",
+                                        class: "{INPUT_CLASS} font-semibold",
+                                        onchange: move |e: Event<FormData>| update_account(index, "account_type", e.value()),
+                                        for option_type in BankAccountType::ALL {
+                                            option { value: option_type.as_key(), "{option_type.label()}" }
+                                        }
+                                    }
+                                }
+                                div { class: "flex flex-col",
+                                    label { class: "block mb-2 text-sm font-semibold text-gray-900", "Statements" }
+                                    select {
+                                        value: "{account.months.len()}",
+                                        class: "{INPUT_CLASS} font-semibold",
+                                        onchange: move |e: Event<FormData>| update_account(index, "statement_months", e.value()),
+                                        option { value: "12", "12 months" }
+                                        option { value: "24", "24 months" }
+                                    }
+                                }
+                                if account_type == BankAccountType::Business {
+                                    div { class: "flex flex-col",
+                                        label { class: "block mb-2 text-sm font-semibold text-gray-900", "Expense Factor" }
+                                        select {
+                                            value: "{account.expense_method}",
+                                            class: "{INPUT_CLASS} font-semibold",
+                                            onchange: move |e: Event<FormData>| update_account(index, "expense_method", e.value()),
+                                            for option_method in ExpenseMethod::ALL {
+                                                option { value: option_method.as_key(), "{option_method.label()}" }
+                                            }
+                                        }
+                                    }
+                                    div { class: "flex flex-col",
+                                        label { class: "block mb-2 text-sm font-semibold text-gray-900", "Expense %" }
+                                        input {
+                                            r#type: "number",
+                                            step: "any",
+                                            value: "{account.expense_percent}",
+                                            class: INPUT_CLASS,
+                                            onchange: move |e: Event<FormData>| update_account(index, "expense_percent", e.value()),
+                                        }
+                                    }
+                                    div { class: "flex flex-col",
+                                        label { class: "block mb-2 text-sm font-semibold text-gray-900", "Ownership %" }
+                                        input {
+                                            r#type: "number",
+                                            step: "any",
+                                            value: "{account.ownership_percent}",
+                                            class: INPUT_CLASS,
+                                            onchange: move |e: Event<FormData>| update_account(index, "ownership_percent", e.value()),
+                                        }
+                                    }
+                                }
+                            }
+
+                            table { class: "min-w-full table-auto border-collapse border border-gray-300 text-sm bg-white",
+                                thead {
+                                    tr { class: "bg-gray-50",
+                                        for heading in ["Month", "Total Deposits", "Excluded (transfers, non-business)", "NSFs", "Eligible"] {
+                                            th { class: "border border-gray-300 px-3 py-2 text-left text-xs font-medium text-gray-500 uppercase",
+                                                "{heading}"
+                                            }
+                                        }
+                                    }
+                                }
+                                tbody {
+                                    for (month_index, month) in account.months.iter().cloned().enumerate() {
+                                        tr {
+                                            class: if result.outlier_months.contains(&month_index) { "bg-yellow-50" } else if month.nsf_count > 0 { "bg-red-50" } else { "" },
+                                            td { class: "border border-gray-300 px-3 py-1",
+                                                input {
+                                                    r#type: "month",
+                                                    value: "{month.month}",
+                                                    class: "w-full px-2 py-1 border rounded",
+                                                    onchange: move |e: Event<FormData>| update_month(index, month_index, "month", e.value()),
+                                                }
+                                            }
+                                            for (field, value) in [("total_deposits", month.total_deposits), ("excluded_deposits", month.excluded_deposits)] {
+                                                td { class: "border border-gray-300 px-3 py-1",
+                                                    input {
+                                                        r#type: "number",
+                                                        step: "any",
+                                                        value: "{value}",
+                                                        class: "w-full px-2 py-1 border rounded",
+                                                        onchange: move |e: Event<FormData>| update_month(index, month_index, field, e.value()),
+                                                    }
+                                                }
+                                            }
+                                            td { class: "border border-gray-300 px-3 py-1",
+                                                input {
+                                                    r#type: "number",
+                                                    min: "0",
+                                                    value: "{month.nsf_count}",
+                                                    class: "w-20 px-2 py-1 border rounded",
+                                                    onchange: move |e: Event<FormData>| update_month(index, month_index, "nsf_count", e.value()),
+                                                }
+                                            }
+                                            td { class: "border border-gray-300 px-3 py-2 text-right font-medium",
+                                                "{format_money((month.total_deposits - month.excluded_deposits).max(0.0))}"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+
+                            div { class: "mt-4 space-y-1 text-sm",
+                                div { class: "flex justify-between",
+                                    span { class: "text-gray-600", "Average Eligible Deposits ({result.months_counted} months)" }
+                                    span { class: "font-medium text-gray-900", "{format_money(result.average_monthly_deposits)}" }
+                                }
+                                if account_type == BankAccountType::Business {
+                                    div { class: "flex justify-between",
+                                        span { class: "text-gray-600", "Less Expenses ({result.expense_percent:.1}%), at {account.ownership_percent:.1}% ownership" }
+                                        span { class: "font-medium text-gray-900", "{format_money(result.qualifying_monthly)}" }
+                                    }
+                                }
+                            }
+
+                            if result.nsf_count > 0 {
+                                div { class: "mt-4 p-4 bg-red-50 border-2 border-red-300 rounded-lg text-sm text-red-800 font-medium",
+                                    "{result.nsf_count} NSF or overdraft item(s) across {result.nsf_months.len()} statement(s). Most Non-QM programs limit NSFs and require a letter of explanation."
+                                }
+                            }
+                            if !result.outlier_months.is_empty() {
+                                div { class: "mt-4 p-4 bg-yellow-50 border-2 border-yellow-300 rounded-lg text-sm text-gray-900 font-medium",
+                                    "{result.outlier_months.len()} month(s) are well outside the average deposit. Source large deposits or exclude them if they are not business income."
+                                }
+                            }
+
+                            div { class: "flex items-center justify-between mt-4",
+                                button {
+                                    class: "text-red-600 hover:text-red-800 text-sm",
+                                    onclick: move |_| remove_account(index),
+                                    "Remove Account"
+                                }
+                                div { class: "px-4 py-3 bg-amber-200 border-2 border-amber-400 rounded-lg text-gray-900 font-bold text-lg",
+                                    "Qualifying Income: {format_money(result.qualifying_monthly)}/mo"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            div { class: "flex items-center justify-between",
+                button {
+                    class: "bg-blue-600 text-white px-4 py-2 rounded hover:bg-blue-700 text-sm",
+                    onclick: add_account,
+                    "Add Account"
+                }
+                div { class: "px-4 py-3 bg-green-100 border-2 border-green-400 rounded-lg text-gray-900 font-bold text-lg",
+                    "Bank Statement Income: {format_money(total_monthly_income(&data()))}/mo"
+                }
+            }
+        }
+    }
+}
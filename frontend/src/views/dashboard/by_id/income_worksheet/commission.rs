@@ -1,8 +1,13 @@
 use dioxus::prelude::*;
 use crate::components::Input;
+use shared::calc::income::{calculate_commission, parse_amount, parse_or, CommissionInput, CommissionPeriod, IncomeMethod};
+use shared::calc::pay_period::ytd_months;
+use crate::views::dashboard::by_id::income_worksheet::pay_stub::PayStubDates;
+use crate::views::dashboard::by_id::income_worksheet::trend::{save_trend, IncomeTrendPanel};
+use shared::calc::income_trend::IncomeTrend;
 
 #[component]
-pub fn Commission() -> Element {
+pub fn Commission(borrower_id: i32, decline_threshold: f64) -> Element {
     // State management for inputs
     let mut ytd_commission = use_signal(|| String::new());
     let mut ytd_expenses = use_signal(|| String::new());
@@ -10,74 +15,50 @@ pub fn Commission() -> Element {
     let mut past_year_expenses = use_signal(|| String::new());
     let mut additional_year_commission = use_signal(|| String::new());
     let mut additional_year_expenses = use_signal(|| String::new());
-    let mut ytd_months = use_signal(|| String::from("12"));
     let mut past_year_months = use_signal(|| String::from("12"));
     let mut additional_year_months = use_signal(|| String::from("12"));
     let mut selected_income = use_signal(|| String::from("ytd"));
 
-    // Calculations
-    let ytd_net_income = use_memo(move || {
-        let comm = ytd_commission().parse::<f64>().unwrap_or(0.0);
-        let exp = ytd_expenses().parse::<f64>().unwrap_or(0.0);
-        comm - exp
-    });
+    // YTD months come from the pay stub dates
+    let ytd_period_end = use_signal(|| String::new());
+    let ytd_pay_frequency = use_signal(|| String::from("monthly"));
+    let hire_date = use_signal(|| String::new());
+    // Zero until the period end is entered; the stub fields warn about it
+    let ytd_months_covered = move || ytd_months(&ytd_period_end(), &hire_date()).unwrap_or(0.0);
 
-    let ytd_monthly_income = use_memo(move || {
-        let months = ytd_months().parse::<f64>().unwrap_or(12.0);
-        if months > 0.0 {
-            ytd_net_income() / months
-        } else {
-            0.0
-        }
-    });
-
-    let past_year_net_income = use_memo(move || {
-        let comm = past_year_commission().parse::<f64>().unwrap_or(0.0);
-        let exp = past_year_expenses().parse::<f64>().unwrap_or(0.0);
-        comm - exp
-    });
-
-    let past_year_monthly_income = use_memo(move || {
-        let months = past_year_months().parse::<f64>().unwrap_or(12.0);
-        if months > 0.0 {
-            past_year_net_income() / months
-        } else {
-            0.0
-        }
-    });
+    // Recomputed when the configured threshold arrives from settings
+    let result = use_memo(use_reactive!(|(decline_threshold,)| {
+        let period = |commission: String, expenses: String, months: f64| CommissionPeriod {
+            commission: parse_amount(&commission),
+            expenses: parse_amount(&expenses),
+            months,
+        };
+        calculate_commission(&CommissionInput {
+            ytd: period(ytd_commission(), ytd_expenses(), ytd_months_covered()),
+            past_year: period(past_year_commission(), past_year_expenses(), parse_or(&past_year_months(), 12.0)),
+            additional_year: period(additional_year_commission(), additional_year_expenses(), parse_or(&additional_year_months(), 12.0)),
+            method: IncomeMethod::from_key(&selected_income()),
+            decline_threshold_percent: decline_threshold,
+        })
+    }));
 
-    let additional_year_net_income = use_memo(move || {
-        let comm = additional_year_commission().parse::<f64>().unwrap_or(0.0);
-        let exp = additional_year_expenses().parse::<f64>().unwrap_or(0.0);
-        comm - exp
-    });
+    // The selected figure and trend analysis are saved when focus leaves one of the section's fields
+    let client = use_context::<repository::Repository>();
+    let last_saved_trend = use_signal(|| None::<(f64, IncomeTrend)>);
+    let save_section_trend = move |_: FocusEvent| {
+        save_trend(client.clone(), borrower_id, "commission", result().qualifying_monthly, result().trend, last_saved_trend)
+    };
 
-    let additional_year_monthly_income = use_memo(move || {
-        let months = additional_year_months().parse::<f64>().unwrap_or(12.0);
-        if months > 0.0 {
-            additional_year_net_income() / months
-        } else {
-            0.0
-        }
-    });
-
-    // Averages and comparisons (placeholders based on comments)
-    let ytd_avg_net = use_memo(move || ytd_monthly_income());
-    let ytd_plus_1_year = use_memo(move || (ytd_monthly_income() + past_year_monthly_income()) / 2.0);
-    let ytd_plus_2_year = use_memo(move || (ytd_monthly_income() + past_year_monthly_income() + additional_year_monthly_income()) / 3.0);
-
-    // Selected income
-    let selected_monthly_income = use_memo(move || {
-        match selected_income().as_str() {
-            "ytd" => ytd_monthly_income(),
-            "past_year" => past_year_monthly_income(),
-            "additional_year" => additional_year_monthly_income(),
-            "ytd_avg" => ytd_avg_net(),
-            "ytd_plus_1" => ytd_plus_1_year(),
-            "ytd_plus_2" => ytd_plus_2_year(),
-            _ => 0.0,
-        }
-    });
+    let ytd_net_income = move || result().ytd_net;
+    let past_year_net_income = move || result().past_year_net;
+    let additional_year_net_income = move || result().additional_year_net;
+    let ytd_monthly_income = move || result().ytd_monthly;
+    let past_year_monthly_income = move || result().past_year_monthly;
+    let additional_year_monthly_income = move || result().additional_year_monthly;
+    let ytd_avg_net = move || result().averages.ytd;
+    let ytd_plus_1_year = move || result().averages.ytd_plus_one_year;
+    let ytd_plus_2_year = move || result().averages.ytd_plus_two_years;
+    let selected_monthly_income = move || result().qualifying_monthly;
 
     // Helper to format money
     fn format_money(amount: f64) -> String {
@@ -86,6 +67,7 @@ pub fn Commission() -> Element {
 
     rsx! {
         div { class: "space-y-8",
+            onfocusout: save_section_trend,
             // Commission Income Section
             div { class: "bg-gradient-to-br from-green-50 to-emerald-50 dark:from-green-900/20 dark:to-emerald-900/20 p-6 rounded-xl shadow-md border-2 border-green-200 dark:border-green-700",
                 h3 { class: "text-xl font-bold text-gray-900 dark:text-white mb-6 flex items-center gap-2",
@@ -93,6 +75,7 @@ pub fn Commission() -> Element {
                     "Commission Income"
                 }
                 div { class: "space-y-6",
+                    PayStubDates { period_end: ytd_period_end, pay_frequency: ytd_pay_frequency, hire_date, show_frequency: true, has_ytd_earnings: !ytd_commission().trim().is_empty() }
                     // YTD Commission
                     div { class: "grid grid-cols-12 gap-4 items-end",
                         div { class: "col-span-3",
@@ -126,14 +109,12 @@ pub fn Commission() -> Element {
                                 "{format_money(ytd_net_income())}"
                             }
                         }
-                        div { class: "col-span-1",
-                            Input {
-                                label: "# months",
-                                r#type: "number",
-                                name: "ytd_months",
-                                value: "{ytd_months}",
-                                placeholder: "12",
-                                oninput: move |evt: Event<FormData>| ytd_months.set(evt.value()),
+                        div { class: "col-span-1 flex flex-col",
+                            label { class: "block mb-2.5 text-sm font-semibold text-gray-900 dark:text-gray-100",
+                                "# months"
+                            }
+                            div { class: "px-4 py-3 bg-gray-100 dark:bg-gray-800 border-2 border-gray-300 rounded-lg text-gray-900 dark:text-white font-bold text-right",
+                                "{ytd_months_covered():.2}"
                             }
                         }
                         div { class: "col-span-1",
@@ -312,6 +293,8 @@ pub fn Commission() -> Element {
                 }
             }
 
+            IncomeTrendPanel { trend: result().trend, selected_monthly: result().qualifying_monthly }
+
             // Income Selection Section
             div { class: "bg-gradient-to-br from-purple-50 to-pink-50 dark:from-purple-900/20 dark:to-pink-900/20 p-6 rounded-xl shadow-md border-2 border-purple-200 dark:border-purple-700",
                 h3 { class: "text-xl font-bold text-gray-900 dark:text-white mb-6 flex items-center gap-2",
@@ -328,11 +311,11 @@ pub fn Commission() -> Element {
                             value: "{selected_income}",
                             onchange: move |evt: Event<FormData>| selected_income.set(evt.value()),
                             option { value: "ytd", "YTD Net Income" }
-                            option { value: "past_year", "Past Year Net Income" }
-                            option { value: "additional_year", "Additional Year Net Income" }
-                            option { value: "ytd_avg", "YTD Avg using net income" }
-                            option { value: "ytd_plus_1", "YTD + 1 year using Net Income" }
-                            option { value: "ytd_plus_2", "YTD + 2 year using net income" }
+                            option { value: "w2_year1", "Past Year Net Income" }
+                            option { value: "w2_year2", "Additional Year Net Income" }
+                            option { value: "ytd_1w2", "YTD + 1 year using Net Income" }
+                            option { value: "ytd_2w2", "YTD + 2 year using net income" }
+                            option { value: "lowest", "Lowest of YTD averages using net income" }
                         }
                     }
                     div { class: "flex items-center justify-between p-4 bg-purple-100 dark:bg-purple-900/50 rounded-lg",
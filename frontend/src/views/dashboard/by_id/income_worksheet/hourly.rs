@@ -1,114 +1,59 @@
 use dioxus::prelude::*;
 use crate::components::Input;
+use shared::calc::income::{calculate_hourly, parse_amount, HourlyInput, IncomeMethod, PeriodEarnings};
+use shared::calc::pay_period::ytd_months;
+use crate::views::dashboard::by_id::income_worksheet::pay_stub::PayStubDates;
+use crate::views::dashboard::by_id::income_worksheet::trend::{save_trend, IncomeTrendPanel};
+use shared::calc::income_trend::IncomeTrend;
 
 #[component]
-pub fn Hourly() -> Element {
+pub fn Hourly(borrower_id: i32, decline_threshold: f64) -> Element {
     // State management
     let mut per_hour = use_signal(|| String::new());
     let mut hours_worked = use_signal(|| String::from("40"));
     let mut ytd_earnings = use_signal(|| String::new());
-    let mut ytd_months = use_signal(|| String::from("12"));
     let mut w2_year1 = use_signal(|| String::new());
     let mut w2_year1_months = use_signal(|| String::from("12"));
     let mut w2_year2 = use_signal(|| String::new());
     let mut w2_year2_months = use_signal(|| String::from("12"));
     let mut selected_calculation = use_signal(|| String::from("none"));
     
-    // Calculate per hour income (hourly rate × hours × 52 weeks / 12 months)
-    let per_hour_income = use_memo(move || {
-        if let Ok(rate) = per_hour().parse::<f64>() {
-            if let Ok(hours) = hours_worked().parse::<f64>() {
-                rate * hours * 52.0 / 12.0
-            } else {
-                0.0
-            }
-        } else {
-            0.0
-        }
-    });
-
-    // Calculate YTD income (YTD earnings / months × 12)
-    let ytd_income = use_memo(move || {
-        if let Ok(ytd) = ytd_earnings().parse::<f64>() {
-            if let Ok(months) = ytd_months().parse::<f64>() {
-                if months > 0.0 {
-                    ytd / months * 12.0
-                } else {
-                    0.0
-                }
-            } else {
-                0.0
-            }
-        } else {
-            0.0
-        }
-    });
-
-    // Calculate W2 Year 1 income
-    let w2_year1_income = use_memo(move || {
-        if let Ok(w2) = w2_year1().parse::<f64>() {
-            if let Ok(months) = w2_year1_months().parse::<f64>() {
-                if months > 0.0 {
-                    w2 / months * 12.0
-                } else {
-                    0.0
-                }
-            } else {
-                0.0
-            }
-        } else {
-            0.0
-        }
-    });
-
-    // Calculate W2 Year 2 income
-    let w2_year2_income = use_memo(move || {
-        if let Ok(w2) = w2_year2().parse::<f64>() {
-            if let Ok(months) = w2_year2_months().parse::<f64>() {
-                if months > 0.0 {
-                    w2 / months * 12.0
-                } else {
-                    0.0
-                }
-            } else {
-                0.0
-            }
-        } else {
-            0.0
-        }
-    });
+    // YTD months come from the pay stub dates
+    let ytd_period_end = use_signal(|| String::new());
+    let ytd_pay_frequency = use_signal(|| String::from("monthly"));
+    let hire_date = use_signal(|| String::new());
+    // Zero until the period end is entered; the stub fields warn about it
+    let ytd_months_covered = move || ytd_months(&ytd_period_end(), &hire_date()).unwrap_or(0.0);
 
-    // Calculate averages
-    let ytd_avg = use_memo(move || {
-        ytd_income()
-    });
+    // Recomputed when the configured threshold arrives from settings
+    let result = use_memo(use_reactive!(|(decline_threshold,)| {
+        calculate_hourly(&HourlyInput {
+            hourly_rate: parse_amount(&per_hour()),
+            hours_per_week: parse_amount(&hours_worked()),
+            ytd: PeriodEarnings::new(parse_amount(&ytd_earnings()), ytd_months_covered()),
+            w2_year1: PeriodEarnings::new(parse_amount(&w2_year1()), parse_amount(&w2_year1_months())),
+            w2_year2: PeriodEarnings::new(parse_amount(&w2_year2()), parse_amount(&w2_year2_months())),
+            method: IncomeMethod::from_key(&selected_calculation()),
+            decline_threshold_percent: decline_threshold,
+        })
+    }));
 
-    let ytd_plus_1w2_avg = use_memo(move || {
-        (ytd_income() + w2_year1_income()) / 2.0
-    });
+    // The selected figure and trend analysis are saved when focus leaves one of the section's fields
+    let client = use_context::<repository::Repository>();
+    let last_saved_trend = use_signal(|| None::<(f64, IncomeTrend)>);
+    let save_section_trend = move |_: FocusEvent| {
+        save_trend(client.clone(), borrower_id, "hourly", result().qualifying_monthly, result().trend, last_saved_trend)
+    };
 
-    let ytd_plus_2w2_avg = use_memo(move || {
-        (ytd_income() + w2_year1_income() + w2_year2_income()) / 3.0
-    });
-
-    // Find lowest calculation
-    let lowest_calculation = use_memo(move || {
-        let mut values = vec![ytd_avg(), ytd_plus_1w2_avg(), ytd_plus_2w2_avg()];
-        values.retain(|&x| x > 0.0);
-        values.iter().fold(f64::INFINITY, |a, &b| a.min(b))
-    });
-
-    // Determine final income based on selection
-    let final_income = use_memo(move || {
-        match selected_calculation().as_str() {
-            "current" => per_hour_income(),
-            "ytd" => ytd_avg(),
-            "ytd_1w2" => ytd_plus_1w2_avg(),
-            "ytd_2w2" => ytd_plus_2w2_avg(),
-            "lowest" => if lowest_calculation().is_finite() { lowest_calculation() } else { 0.0 },
-            _ => 0.0,
-        }
-    });
+    let per_hour_income = move || result().current_monthly;
+    let ytd_income = move || result().ytd_monthly;
+    let w2_year1_income = move || result().w2_year1_monthly;
+    let w2_year2_income = move || result().w2_year2_monthly;
+    let ytd_avg = move || result().averages.ytd;
+    let ytd_plus_1w2_avg = move || result().averages.ytd_plus_one_year;
+    let ytd_plus_2w2_avg = move || result().averages.ytd_plus_two_years;
+    let lowest_calculation = move || result().averages.lowest;
+    let final_income = move || result().qualifying_monthly;
 
     // Helper to format money
     fn format_money(amount: f64) -> String {
@@ -116,6 +61,7 @@ pub fn Hourly() -> Element {
     }
     rsx! {
         div { class: "space-y-8",
+            onfocusout: save_section_trend,
             // Current Hourly Rate Section
             div { class: "bg-gradient-to-br from-blue-50 to-cyan-50 p-6 rounded-xl shadow-md border-2 border-blue-200",
                 h3 { class: "text-xl font-bold text-gray-900 dark:text-gray-100 dark:text-gray-100 mb-6 flex items-center gap-2",
@@ -170,6 +116,7 @@ pub fn Hourly() -> Element {
                     "Historical Income"
                 }
                 div { class: "space-y-4",
+                    PayStubDates { period_end: ytd_period_end, pay_frequency: ytd_pay_frequency, hire_date, show_frequency: true, has_ytd_earnings: !ytd_earnings().trim().is_empty() }
                     // YTD Earnings
                     div { class: "grid grid-cols-12 gap-4 items-end",
                         div { class: "col-span-4",
@@ -182,18 +129,17 @@ pub fn Hourly() -> Element {
                                 oninput: move |evt: Event<FormData>| ytd_earnings.set(evt.value()),
                             }
                         }
-                        div { class: "col-span-3",
-                            Input {
-                                label: "# Months",
-                                r#type: "number",
-                                name: "ytd_months",
-                                value: "{ytd_months}",
-                                oninput: move |evt: Event<FormData>| ytd_months.set(evt.value()),
+                        div { class: "col-span-3 flex flex-col",
+                            label { class: "block mb-2.5 text-sm font-semibold text-gray-900 dark:text-gray-100",
+                                "# Months"
+                            }
+                            div { class: "px-4 py-3 bg-gray-100 border-2 border-gray-300 rounded-lg text-gray-900 dark:text-gray-100 font-bold text-right",
+                                "{ytd_months_covered():.2}"
                             }
                         }
                         div { class: "col-span-5 flex flex-col",
                             label { class: "block mb-2.5 text-sm font-semibold text-gray-900 dark:text-gray-100 dark:text-gray-100",
-                                "Annual Income"
+                                "Monthly Income"
                             }
                             div { class: "px-4 py-3 bg-white border-2 border-gray-300 rounded-lg text-gray-900 dark:text-gray-100 dark:text-gray-100 font-bold text-right",
                                 "{format_money(ytd_income())}"
@@ -224,7 +170,7 @@ pub fn Hourly() -> Element {
                         }
                         div { class: "col-span-5 flex flex-col",
                             label { class: "block mb-2.5 text-sm font-semibold text-gray-900 dark:text-gray-100 dark:text-gray-100",
-                                "Annual Income"
+                                "Monthly Income"
                             }
                             div { class: "px-4 py-3 bg-white border-2 border-gray-300 rounded-lg text-gray-900 dark:text-gray-100 dark:text-gray-100 font-bold text-right",
                                 "{format_money(w2_year1_income())}"
@@ -255,7 +201,7 @@ pub fn Hourly() -> Element {
                         }
                         div { class: "col-span-5 flex flex-col",
                             label { class: "block mb-2.5 text-sm font-semibold text-gray-900 dark:text-gray-100 dark:text-gray-100",
-                                "Annual Income"
+                                "Monthly Income"
                             }
                             div { class: "px-4 py-3 bg-white border-2 border-gray-300 rounded-lg text-gray-900 dark:text-gray-100 dark:text-gray-100 font-bold text-right",
                                 "{format_money(w2_year2_income())}"
@@ -336,23 +282,16 @@ pub fn Hourly() -> Element {
                             }
                         }
                         div { class: "col-span-6 flex flex-col",
-                            {
-                                let lowest_val = if lowest_calculation().is_finite() {
-                                    lowest_calculation()
-                                } else {
-                                    0.0
-                                };
-                                rsx! {
-                                    div { class: "px-4 py-3 bg-red-100 border-2 border-red-300 rounded-lg text-gray-900 dark:text-gray-100 dark:text-gray-100 font-bold text-right text-lg",
-                                        "{format_money(lowest_val)}"
-                                    }
-                                }
+                            div { class: "px-4 py-3 bg-red-100 border-2 border-red-300 rounded-lg text-gray-900 dark:text-gray-100 dark:text-gray-100 font-bold text-right text-lg",
+                                "{format_money(lowest_calculation())}"
                             }
                         }
                     }
                 }
             }
 
+            IncomeTrendPanel { trend: result().trend, selected_monthly: result().qualifying_monthly }
+
             // Final Selection Section
             div { class: "bg-gradient-to-br from-green-50 to-emerald-50 p-6 rounded-xl shadow-md border-2 border-green-200",
                 h3 { class: "text-xl font-bold text-gray-900 dark:text-gray-100 dark:text-gray-100 mb-6 flex items-center gap-2",
@@ -380,16 +319,7 @@ pub fn Hourly() -> Element {
                                 option { value: "ytd_2w2",
                                     "YTD + 2 W2 Avg ({format_money(ytd_plus_2w2_avg())})"
                                 }
-                                {
-                                    let lowest_val = if lowest_calculation().is_finite() {
-                                        lowest_calculation()
-                                    } else {
-                                        0.0
-                                    };
-                                    rsx! {
-                                        option { value: "lowest", "Use Lowest ({format_money(lowest_val)})" }
-                                    }
-                                }
+                                option { value: "lowest", "Use Lowest ({format_money(lowest_calculation())})" }
                             }
                         }
                         div { class: "col-span-6 flex flex-col",
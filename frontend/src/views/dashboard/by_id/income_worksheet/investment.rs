@@ -0,0 +1,229 @@
+use dioxus::prelude::*;
+use shared::calc::investment::{InvestmentSourceType, apply_to_general_income, calculate_investment_income};
+use shared::calc::rule_set::IncomeRuleSet;
+use shared::models::{GeneralIncomeData, InvestmentIncomeData, InvestmentIncomeSource};
+
+// Helper to format money
+fn format_money(amount: f64) -> String {
+    format!("${:.2}", amount)
+}
+
+const INPUT_CLASS: &str = "bg-white border-2 border-gray-300 text-gray-900 text-sm rounded-lg px-4 py-3";
+
+// Interest, dividends, capital gains and trust income. Each save also records every
+// source as a general income entry, which feeds qualifying income.
+#[component]
+pub fn Investment(borrower_id: i32, rules: IncomeRuleSet) -> Element {
+    let client = use_context::<repository::Repository>();
+    let mut data = use_signal(InvestmentIncomeData::default);
+
+    let client_clone = client.clone();
+    use_effect(move || {
+        let client = client_clone.clone();
+        spawn(async move {
+            match client.get_investment_income_data(borrower_id).await {
+                Ok(Some(saved)) => data.set(saved),
+                Ok(None) => data.set(InvestmentIncomeData {
+                    sources: vec![InvestmentIncomeSource::default()],
+                    ..Default::default()
+                }),
+                Err(e) => log::error!("Failed to load investment income data: {:?}", e),
+            }
+        });
+    });
+
+    // Kept in a memo so the save callback below sees the current rule set
+    let active_rules = use_memo(use_reactive!(|(rules,)| rules));
+    let result = use_memo(move || calculate_investment_income(&data(), &active_rules()));
+
+    // Callback so the handlers below stay `Copy` inside the loop
+    let save = Callback::new(move |_: ()| {
+        let client = client.clone();
+        spawn(async move {
+            let investment = data();
+            if let Err(e) = client.save_investment_income_data(borrower_id, investment.clone()).await {
+                log::error!("Failed to save investment income data: {:?}", e);
+                return;
+            }
+
+            let mut general = match client.get_general_income_data(borrower_id).await {
+                Ok(Some(general)) => general,
+                Ok(None) => GeneralIncomeData {
+                    entries: Vec::new(),
+                    ..Default::default()
+                },
+                Err(e) => {
+                    log::error!("Failed to load general income data: {:?}", e);
+                    return;
+                }
+            };
+            let result = calculate_investment_income(&investment, &active_rules());
+            apply_to_general_income(&mut general, &investment, &result);
+            if let Err(e) = client.save_general_income_data(borrower_id, general).await {
+                log::error!("Failed to update general income data: {:?}", e);
+            }
+        });
+    });
+
+    let mut update_field = move |field: &str, value: String| {
+        {
+            let mut investment = data.write();
+            match field {
+                "funds_to_close" => investment.funds_to_close = value.parse().unwrap_or(0.0),
+                "reserves" => investment.reserves = value.parse().unwrap_or(0.0),
+                _ => {}
+            }
+        }
+        save.call(());
+    };
+
+    let mut update_source = move |index: usize, field: &str, value: String| {
+        if let Some(source) = data.write().sources.get_mut(index) {
+            match field {
+                "name" => source.name = value,
+                "source_type" => source.source_type = value,
+                "prior_year_amount" => source.prior_year_amount = value.parse().unwrap_or(0.0),
+                "recent_year_amount" => source.recent_year_amount = value.parse().unwrap_or(0.0),
+                "asset_balance" => source.asset_balance = value.parse().unwrap_or(0.0),
+                _ => {}
+            }
+        }
+        save.call(());
+    };
+
+    let add_source = move |_| {
+        data.write().sources.push(InvestmentIncomeSource::default());
+        save.call(());
+    };
+
+    let mut remove_source = move |index: usize| {
+        data.write().sources.remove(index);
+        save.call(());
+    };
+
+    rsx! {
+        div { class: "space-y-6",
+            div { class: "bg-gradient-to-br from-emerald-50 to-teal-50 p-6 rounded-xl shadow-md border-2 border-emerald-200",
+                div { class: "grid grid-cols-1 md:grid-cols-2 gap-4 mb-6",
+                    div { class: "flex flex-col",
+                        label { class: "block mb-2 text-sm font-semibold text-gray-900", "Funds to Close from These Assets" }
+                        input {
+                            r#type: "number",
+                            step: "any",
+                            value: "{data().funds_to_close}",
+                            class: INPUT_CLASS,
+                            onchange: move |e: Event<FormData>| update_field("funds_to_close", e.value()),
+                        }
+                    }
+                    div { class: "flex flex-col",
+                        label { class: "block mb-2 text-sm font-semibold text-gray-900", "Required Reserves" }
+                        input {
+                            r#type: "number",
+                            step: "any",
+                            value: "{data().reserves}",
+                            class: INPUT_CLASS,
+                            onchange: move |e: Event<FormData>| update_field("reserves", e.value()),
+                        }
+                    }
+                }
+
+                table { class: "min-w-full table-auto border-collapse border border-gray-300 text-sm bg-white",
+                    thead {
+                        tr { class: "bg-gray-50",
+                            for heading in ["Source", "Type", "Prior Year", "Recent Year", "Asset Balance", "Average", "Qualifying", ""] {
+                                th { class: "border border-gray-300 px-3 py-2 text-left text-xs font-medium text-gray-500 uppercase",
+                                    "{heading}"
+                                }
+                            }
+                        }
+                    }
+                    tbody {
+                        for (index, (source, source_result)) in data().sources.into_iter().zip(result().sources).enumerate() {
+                            tr { key: "{source.id}",
+                                td { class: "border border-gray-300 px-3 py-1",
+                                    input {
+                                        r#type: "text",
+                                        value: "{source.name}",
+                                        class: "w-full px-2 py-1 border rounded",
+                                        onchange: move |e: Event<FormData>| update_source(index, "name", e.value()),
+                                    }
+                                }
+                                td { class: "border border-gray-300 px-3 py-1",
+                                    select {
+                                        value: "{source.source_type}",
+                                        class: "w-full px-2 py-1 border rounded",
+                                        onchange: move |e: Event<FormData>| update_source(index, "source_type", e.value()),
+                                        for option_type in InvestmentSourceType::ALL {
+                                            option { value: option_type.as_key(), "{option_type.label()}" }
+                                        }
+                                    }
+                                }
+                                for (field, value) in [
+                                    ("prior_year_amount", source.prior_year_amount),
+                                    ("recent_year_amount", source.recent_year_amount),
+                                    ("asset_balance", source.asset_balance),
+                                ] {
+                                    td { class: "border border-gray-300 px-3 py-1",
+                                        input {
+                                            r#type: "number",
+                                            step: "any",
+                                            value: "{value}",
+                                            class: "w-full px-2 py-1 border rounded",
+                                            onchange: move |e: Event<FormData>| update_source(index, field, e.value()),
+                                        }
+                                    }
+                                }
+                                td { class: "border border-gray-300 px-3 py-2 text-right", "{format_money(source_result.average_monthly)}" }
+                                td { class: "border border-gray-300 px-3 py-2 text-right font-medium", "{format_money(source_result.qualifying_monthly)}" }
+                                td { class: "border border-gray-300 px-3 py-2",
+                                    button {
+                                        class: "text-red-600 hover:text-red-800 text-sm",
+                                        onclick: move |_| remove_source(index),
+                                        "Remove"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                p { class: "mt-2 text-xs text-gray-600",
+                    "Annual amounts from 1099-INT/DIV, Schedule D or K-1. A declining source uses the recent year. Leave the asset balance at zero for trust distributions that do not come from the borrower's assets."
+                }
+
+                div { class: "mt-4 space-y-1 text-sm",
+                    div { class: "flex justify-between",
+                        span { class: "text-gray-600", "Income-Producing Assets" }
+                        span { class: "font-medium text-gray-900", "{format_money(result().total_assets)}" }
+                    }
+                    div { class: "flex justify-between",
+                        span { class: "text-gray-600", "Remaining After Closing and Reserves ({result().remaining_share * 100.0:.1}%)" }
+                        span { class: "font-medium text-gray-900", "{format_money(result().remaining_assets)}" }
+                    }
+                    if let Some(months) = result().months_supported {
+                        div { class: "flex justify-between",
+                            span { class: "text-gray-600", "Months of Asset-Backed Income Supported" }
+                            span { class: "font-medium text-gray-900", "{months:.0}" }
+                        }
+                    }
+                }
+
+                if !result().meets_continuance {
+                    div { class: "mt-4 p-4 bg-red-50 border-2 border-red-300 rounded-lg text-sm text-red-800 font-medium",
+                        "The remaining assets support {result().months_supported.unwrap_or(0.0):.0} months of this income; {rules.profile.label()} requires {rules.continuance_months} months of continuance."
+                    }
+                }
+
+                div { class: "flex items-center justify-between mt-4",
+                    button {
+                        class: "bg-blue-600 text-white px-4 py-2 rounded hover:bg-blue-700 text-sm",
+                        onclick: add_source,
+                        "Add Source"
+                    }
+                    div { class: "px-4 py-3 bg-green-100 border-2 border-green-400 rounded-lg text-gray-900 font-bold text-lg",
+                        "Investment Income: {format_money(result().qualifying_monthly)}/mo"
+                    }
+                }
+            }
+        }
+    }
+}
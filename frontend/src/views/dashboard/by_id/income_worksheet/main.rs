@@ -1,10 +1,76 @@
 use dioxus::prelude::*;
 use crate::components::{IncomeAccordion, IncomeAccordionItem};
-use crate::views::dashboard::by_id::income_worksheet::{Hourly, Salary, OTBonus, Commission, OtherW2, SocialSecurity, Pension, IRA, OtherIncome};
+use shared::calc::dti::{QualifyingIncome, qualifying_income};
+use shared::calc::rule_set::{IncomeRuleSet, RuleSetProfile};
+use shared::models::{AppSettings, IncomeInformationData, LoanType};
+use crate::views::dashboard::by_id::income_worksheet::{Hourly, Salary, OTBonus, Commission, OtherW2, Military, SelfEmployment, BankStatement, Rental, SocialSecurity, Pension, IRA, Investment, OtherIncome, AccessoryIncome};
+
+// The borrower's saved income, gathered the same way the options template does
+pub(crate) async fn load_income_information(client: &repository::Repository, borrower_id: i32) -> IncomeInformationData {
+    let mut income = match client.get_options_template(borrower_id).await {
+        Ok(Some(template)) => template.income_information,
+        Ok(None) => IncomeInformationData::default(),
+        Err(e) => {
+            log::error!("Failed to load options template: {:?}", e);
+            IncomeInformationData::default()
+        }
+    };
+    income.w2_jobs_data = client.get_w2_jobs_data(borrower_id).await.ok().flatten();
+    income.w2_section_results = client.get_w2_section_results(borrower_id).await.unwrap_or_default();
+    income.general_income_data = client.get_general_income_data(borrower_id).await.ok().flatten();
+    income.self_employment_data = client.get_self_employment_data(borrower_id).await.ok().flatten();
+    income.rental_income_data = client.get_rental_income_data(borrower_id).await.ok().flatten();
+    income.bank_statement_data = client.get_bank_statement_data(borrower_id).await.ok().flatten();
+    income
+}
 
 /// The Home page component that will be rendered when the current route is `[Route::Home]`
 #[component]
 pub fn Worksheet(id: i32) -> Element {
+    let client = use_context::<repository::Repository>();
+    let mut settings = use_signal(AppSettings::default);
+    // The options template's loan type and agency pick the rule set unless one is chosen here
+    let mut loan_type = use_signal(String::new);
+    let mut agency = use_signal(String::new);
+    let mut loan_term_months = use_signal(|| 0u32);
+    let mut rule_set_override = use_signal(|| None::<RuleSetProfile>);
+    let client_for_total = client.clone();
+
+    use_effect(move || {
+        let client = client.clone();
+        spawn(async move {
+            match client.get_settings().await {
+                Ok(loaded) => settings.set(loaded),
+                Err(e) => log::error!("Failed to load settings: {:?}", e),
+            }
+            match client.get_options_template(id).await {
+                Ok(Some(template)) => {
+                    loan_type.set(template.loan_information.loan_type);
+                    agency.set(template.loan_information.agency);
+                    loan_term_months.set(template.loan_information.term_months);
+                }
+                Ok(None) => {}
+                Err(e) => log::error!("Failed to load options template: {:?}", e),
+            }
+        });
+    });
+    let decline_threshold = settings().income_decline_threshold;
+    let loan_type_profile = RuleSetProfile::for_loan_type(LoanType::from_key(&loan_type()).as_ref(), &agency());
+    let profile = rule_set_override().unwrap_or(loan_type_profile);
+    let rules = IncomeRuleSet::for_profile(profile, &settings().custom_rule_set);
+
+    // Saved worksheet results, totalled the same way the options template DTI counts them
+    let mut qualifying = use_signal(QualifyingIncome::default);
+    let mut refresh_total = use_signal(|| 0u32);
+    use_effect(use_reactive!(|(rules,)| {
+        refresh_total();
+        let client = client_for_total.clone();
+        spawn(async move {
+            let income = load_income_information(&client, id).await;
+            qualifying.set(qualifying_income(&income, &rules));
+        });
+    }));
+
     // Track which sections are displayed and included
     let mut displayed_sections = use_signal(|| Vec::<String>::new());
 
@@ -27,7 +93,7 @@ pub fn Worksheet(id: i32) -> Element {
             id: "hourly".to_string(),
             title: "Hourly Pay".to_string(),
             content: rsx! {
-                Hourly {}
+                Hourly { borrower_id: id, decline_threshold }
             },
             initially_open: false,
             include_in_calc: true,
@@ -38,7 +104,7 @@ pub fn Worksheet(id: i32) -> Element {
             id: "salary".to_string(),
             title: "Salary".to_string(),
             content: rsx! {
-                Salary {}
+                Salary { borrower_id: id, decline_threshold }
             },
             initially_open: false,
             include_in_calc: true,
@@ -49,7 +115,7 @@ pub fn Worksheet(id: i32) -> Element {
             id: "ot_bonus".to_string(),
             title: "Overtime / Bonus".to_string(),
             content: rsx! {
-                OTBonus {}
+                OTBonus { borrower_id: id, decline_threshold }
             },
             initially_open: false,
             include_in_calc: true,
@@ -60,7 +126,7 @@ pub fn Worksheet(id: i32) -> Element {
             id: "commission".to_string(),
             title: "Commission".to_string(),
             content: rsx! {
-                Commission {}
+                Commission { borrower_id: id, decline_threshold }
             },
             initially_open: false,
             include_in_calc: true,
@@ -77,6 +143,56 @@ pub fn Worksheet(id: i32) -> Element {
             include_in_calc: true,
         });
     }
+    if displayed_sections.read().contains(&"military".to_string()) {
+        pay_type_items.push(IncomeAccordionItem {
+            id: "military".to_string(),
+            title: "Military (LES)".to_string(),
+            content: rsx! {
+                Military { borrower_id: id, rules: rules.clone() }
+            },
+            initially_open: false,
+            include_in_calc: true,
+        });
+    }
+
+    // Build self-employment items
+    let mut self_employment_items = vec![];
+    if displayed_sections.read().contains(&"self_employment".to_string()) {
+        self_employment_items.push(IncomeAccordionItem {
+            id: "self_employment".to_string(),
+            title: "Self-Employment Cash Flow".to_string(),
+            content: rsx! {
+                SelfEmployment { borrower_id: id }
+            },
+            initially_open: false,
+            include_in_calc: true,
+        });
+    }
+    if displayed_sections.read().contains(&"bank_statement".to_string()) {
+        self_employment_items.push(IncomeAccordionItem {
+            id: "bank_statement".to_string(),
+            title: "Bank Statement (Non-QM)".to_string(),
+            content: rsx! {
+                BankStatement { borrower_id: id }
+            },
+            initially_open: false,
+            include_in_calc: true,
+        });
+    }
+
+    // Build rental items
+    let mut rental_items = vec![];
+    if displayed_sections.read().contains(&"rental".to_string()) {
+        rental_items.push(IncomeAccordionItem {
+            id: "rental".to_string(),
+            title: "Rental Income".to_string(),
+            content: rsx! {
+                Rental { borrower_id: id, rules: rules.clone() }
+            },
+            initially_open: false,
+            include_in_calc: true,
+        });
+    }
 
     // Build other income items
     let mut other_income_items = vec![];
@@ -85,7 +201,7 @@ pub fn Worksheet(id: i32) -> Element {
             id: "social_security".to_string(),
             title: "Social Security".to_string(),
             content: rsx! {
-                SocialSecurity {}
+                SocialSecurity { rules: rules.clone() }
             },
             initially_open: false,
             include_in_calc: true,
@@ -96,7 +212,7 @@ pub fn Worksheet(id: i32) -> Element {
             id: "pension".to_string(),
             title: "Pension".to_string(),
             content: rsx! {
-                Pension {}
+                Pension { rules: rules.clone() }
             },
             initially_open: false,
             include_in_calc: true,
@@ -107,7 +223,18 @@ pub fn Worksheet(id: i32) -> Element {
             id: "ira".to_string(),
             title: "IRA / 401(k)".to_string(),
             content: rsx! {
-                IRA {}
+                IRA { borrower_id: id, rules: rules.clone(), loan_term_months: loan_term_months() }
+            },
+            initially_open: false,
+            include_in_calc: true,
+        });
+    }
+    if displayed_sections.read().contains(&"investment".to_string()) {
+        other_income_items.push(IncomeAccordionItem {
+            id: "investment".to_string(),
+            title: "Investment Income".to_string(),
+            content: rsx! {
+                Investment { borrower_id: id, rules: rules.clone() }
             },
             initially_open: false,
             include_in_calc: true,
@@ -118,7 +245,18 @@ pub fn Worksheet(id: i32) -> Element {
             id: "other_income".to_string(),
             title: "Other Income".to_string(),
             content: rsx! {
-                OtherIncome {}
+                OtherIncome { rules: rules.clone() }
+            },
+            initially_open: false,
+            include_in_calc: true,
+        });
+    }
+    if displayed_sections.read().contains(&"accessory_income".to_string()) {
+        other_income_items.push(IncomeAccordionItem {
+            id: "accessory_income".to_string(),
+            title: "Boarder / ADU Income".to_string(),
+            content: rsx! {
+                AccessoryIncome { borrower_id: id, rules: rules.clone() }
             },
             initially_open: false,
             include_in_calc: true,
@@ -136,6 +274,26 @@ pub fn Worksheet(id: i32) -> Element {
                 p { class: "text-gray-600 dark:text-gray-400 mb-4",
                     "Choose which income types apply to this borrower. Only selected types will be displayed and included in calculations."
                 }
+                div { class: "flex items-center gap-4 mb-6",
+                    label { r#for: "rule_set", class: "text-sm font-semibold text-gray-900 dark:text-gray-100",
+                        "Income Rule Set"
+                    }
+                    select {
+                        id: "rule_set",
+                        class: "bg-white border-2 border-gray-300 text-gray-900 text-sm rounded-lg px-4 py-2 font-semibold",
+                        value: profile.as_key(),
+                        onchange: move |evt: Event<FormData>| {
+                            let selected = RuleSetProfile::from_key(&evt.value());
+                            rule_set_override.set((selected != loan_type_profile).then_some(selected));
+                        },
+                        for option_profile in RuleSetProfile::ALL {
+                            option { value: option_profile.as_key(), "{option_profile.label()}" }
+                        }
+                    }
+                    span { class: "text-sm text-gray-600 dark:text-gray-400",
+                        "Defaults to {loan_type_profile.label()} for this loan type"
+                    }
+                }
                 div { class: "grid grid-cols-1 md:grid-cols-2 lg:grid-cols-3 gap-4",
                     div { class: "flex items-center space-x-3 p-3 border border-gray-200 rounded-lg hover:bg-gray-50 transition-colors",
                         input {
@@ -222,6 +380,80 @@ pub fn Worksheet(id: i32) -> Element {
                             "Other W2 Income"
                         }
                     }
+                    div { class: "flex items-center space-x-3 p-3 border border-gray-200 rounded-lg hover:bg-gray-50 transition-colors",
+                        input {
+                            r#type: "checkbox",
+                            id: "military",
+                            class: "w-5 h-5 text-green-600 border-gray-400 rounded focus:ring-2 focus:ring-green-500 cursor-pointer",
+                            checked: displayed_sections.read().contains(&"military".to_string()),
+                            onchange: move |_| {
+                                let is_checked = displayed_sections.read().contains(&"military".to_string());
+                                handle_display_change(("military".to_string(), !is_checked));
+                            },
+                        }
+                        label {
+                            r#for: "military",
+                            class: "text-gray-900 dark:text-gray-100 font-medium cursor-pointer",
+                            "Military"
+                        }
+                    }
+                    div { class: "flex items-center space-x-3 p-3 border border-gray-200 rounded-lg hover:bg-gray-50 transition-colors",
+                        input {
+                            r#type: "checkbox",
+                            id: "self_employment",
+                            class: "w-5 h-5 text-green-600 border-gray-400 rounded focus:ring-2 focus:ring-green-500 cursor-pointer",
+                            checked: displayed_sections.read().contains(&"self_employment".to_string()),
+                            onchange: move |_| {
+                                let is_checked = displayed_sections
+                                    .read()
+                                    .contains(&"self_employment".to_string());
+                                handle_display_change(("self_employment".to_string(), !is_checked));
+                            },
+                        }
+                        label {
+                            r#for: "self_employment",
+                            class: "text-gray-900 dark:text-gray-100 font-medium cursor-pointer",
+                            "Self-Employment"
+                        }
+                    }
+                    div { class: "flex items-center space-x-3 p-3 border border-gray-200 rounded-lg hover:bg-gray-50 transition-colors",
+                        input {
+                            r#type: "checkbox",
+                            id: "bank_statement",
+                            class: "w-5 h-5 text-green-600 border-gray-400 rounded focus:ring-2 focus:ring-green-500 cursor-pointer",
+                            checked: displayed_sections.read().contains(&"bank_statement".to_string()),
+                            onchange: move |_| {
+                                let is_checked = displayed_sections
+                                    .read()
+                                    .contains(&"bank_statement".to_string());
+                                handle_display_change(("bank_statement".to_string(), !is_checked));
+                            },
+                        }
+                        label {
+                            r#for: "bank_statement",
+                            class: "text-gray-900 dark:text-gray-100 font-medium cursor-pointer",
+                            "Bank Statement (Non-QM)"
+                        }
+                    }
+                    div { class: "flex items-center space-x-3 p-3 border border-gray-200 rounded-lg hover:bg-gray-50 transition-colors",
+                        input {
+                            r#type: "checkbox",
+                            id: "rental",
+                            class: "w-5 h-5 text-green-600 border-gray-400 rounded focus:ring-2 focus:ring-green-500 cursor-pointer",
+                            checked: displayed_sections.read().contains(&"rental".to_string()),
+                            onchange: move |_| {
+                                let is_checked = displayed_sections
+                                    .read()
+                                    .contains(&"rental".to_string());
+                                handle_display_change(("rental".to_string(), !is_checked));
+                            },
+                        }
+                        label {
+                            r#for: "rental",
+                            class: "text-gray-900 dark:text-gray-100 font-medium cursor-pointer",
+                            "Rental Income"
+                        }
+                    }
                     div { class: "flex items-center space-x-3 p-3 border border-gray-200 rounded-lg hover:bg-gray-50 transition-colors",
                         input {
                             r#type: "checkbox",
@@ -275,6 +507,23 @@ pub fn Worksheet(id: i32) -> Element {
                             "IRA / 401(k)"
                         }
                     }
+                    div { class: "flex items-center space-x-3 p-3 border border-gray-200 rounded-lg hover:bg-gray-50 transition-colors",
+                        input {
+                            r#type: "checkbox",
+                            id: "investment",
+                            class: "w-5 h-5 text-green-600 border-gray-400 rounded focus:ring-2 focus:ring-green-500 cursor-pointer",
+                            checked: displayed_sections.read().contains(&"investment".to_string()),
+                            onchange: move |_| {
+                                let is_checked = displayed_sections.read().contains(&"investment".to_string());
+                                handle_display_change(("investment".to_string(), !is_checked));
+                            },
+                        }
+                        label {
+                            r#for: "investment",
+                            class: "text-gray-900 dark:text-gray-100 font-medium cursor-pointer",
+                            "Investment Income"
+                        }
+                    }
                     div { class: "flex items-center space-x-3 p-3 border border-gray-200 rounded-lg hover:bg-gray-50 transition-colors",
                         input {
                             r#type: "checkbox",
@@ -292,6 +541,23 @@ pub fn Worksheet(id: i32) -> Element {
                             "Other Income"
                         }
                     }
+                    div { class: "flex items-center space-x-3 p-3 border border-gray-200 rounded-lg hover:bg-gray-50 transition-colors",
+                        input {
+                            r#type: "checkbox",
+                            id: "accessory_income",
+                            class: "w-5 h-5 text-green-600 border-gray-400 rounded focus:ring-2 focus:ring-green-500 cursor-pointer",
+                            checked: displayed_sections.read().contains(&"accessory_income".to_string()),
+                            onchange: move |_| {
+                                let is_checked = displayed_sections.read().contains(&"accessory_income".to_string());
+                                handle_display_change(("accessory_income".to_string(), !is_checked));
+                            },
+                        }
+                        label {
+                            r#for: "accessory_income",
+                            class: "text-gray-900 dark:text-gray-100 font-medium cursor-pointer",
+                            "Boarder / ADU Income"
+                        }
+                    }
                 }
             }
 
@@ -305,6 +571,24 @@ pub fn Worksheet(id: i32) -> Element {
                 IncomeAccordion { items: pay_type_items, on_include_change: None }
             }
 
+            // Self-Employment Section
+            if !self_employment_items.is_empty() {
+                h2 { class: "text-3xl font-bold text-gray-900 dark:text-gray-100 mt-10 mb-6 flex items-center gap-3",
+                    span { class: "text-amber-600", "🏢" }
+                    "Self-Employment"
+                }
+                IncomeAccordion { items: self_employment_items, on_include_change: None }
+            }
+
+            // Rental Income Section
+            if !rental_items.is_empty() {
+                h2 { class: "text-3xl font-bold text-gray-900 dark:text-gray-100 mt-10 mb-6 flex items-center gap-3",
+                    span { class: "text-blue-600", "🏠" }
+                    "Rental Income"
+                }
+                IncomeAccordion { items: rental_items, on_include_change: None }
+            }
+
             // Other Income Section
             if !other_income_items.is_empty() {
                 h2 { class: "text-3xl font-bold text-gray-900 dark:text-gray-100 mt-10 mb-6 flex items-center gap-3",
@@ -326,7 +610,7 @@ pub fn Worksheet(id: i32) -> Element {
                             span {
                                 key: "{section_id}",
                                 class: if section_id == "hourly" || section_id == "salary" || section_id == "ot_bonus"
-    || section_id == "commission" || section_id == "other_w2" { "px-4 py-2 bg-blue-600 text-white rounded-full text-sm font-semibold shadow-md hover:bg-blue-700 transition-colors" } else { "px-4 py-2 bg-green-600 text-white rounded-full text-sm font-semibold shadow-md hover:bg-green-700 transition-colors" },
+    || section_id == "commission" || section_id == "other_w2" || section_id == "military" { "px-4 py-2 bg-blue-600 text-white rounded-full text-sm font-semibold shadow-md hover:bg-blue-700 transition-colors" } else { "px-4 py-2 bg-green-600 text-white rounded-full text-sm font-semibold shadow-md hover:bg-green-700 transition-colors" },
                                 {
                                     if section_id == "hourly" {
                                         "Hourly Pay"
@@ -338,14 +622,26 @@ pub fn Worksheet(id: i32) -> Element {
                                         "Commission"
                                     } else if section_id == "other_w2" {
                                         "Other W2"
+                                    } else if section_id == "military" {
+                                        "Military"
+                                    } else if section_id == "self_employment" {
+                                        "Self-Employment"
+                                    } else if section_id == "bank_statement" {
+                                        "Bank Statement"
+                                    } else if section_id == "rental" {
+                                        "Rental Income"
                                     } else if section_id == "social_security" {
                                         "Social Security"
                                     } else if section_id == "pension" {
                                         "Pension"
                                     } else if section_id == "ira" {
                                         "IRA/401(k)"
+                                    } else if section_id == "investment" {
+                                        "Investment Income"
                                     } else if section_id == "other_income" {
                                         "Other Income"
+                                    } else if section_id == "accessory_income" {
+                                        "Boarder / ADU"
                                     } else {
                                         section_id.as_str()
                                     }
@@ -353,6 +649,34 @@ pub fn Worksheet(id: i32) -> Element {
                             }
                         }
                     }
+
+                    // Qualifying income by source, as it feeds DTI on the options template
+                    div { class: "mt-8 space-y-1 text-sm",
+                        for (label, amount) in [
+                            ("W-2 Income", qualifying().w2),
+                            ("General Income (incl. military, retirement, investment, boarder/ADU)", qualifying().general),
+                            ("Self-Employment", qualifying().self_employment),
+                            ("Rental Income", qualifying().rental),
+                            ("Bank Statement", qualifying().bank_statement),
+                            ("Entered on Options Template", qualifying().entered),
+                        ] {
+                            div { class: "flex justify-between",
+                                span { class: "text-gray-600", "{label}" }
+                                span { class: "font-medium text-gray-900", "${amount:.2}" }
+                            }
+                        }
+                        div { class: "flex justify-between items-center border-t border-blue-200 pt-3 mt-3",
+                            span { class: "text-lg font-bold text-gray-900", "Total Qualifying Income: ${qualifying().total():.2}/mo" }
+                            button {
+                                class: "px-4 py-2 bg-blue-600 text-white rounded-lg text-sm font-semibold hover:bg-blue-700",
+                                onclick: move |_| refresh_total += 1,
+                                "Refresh"
+                            }
+                        }
+                        p { class: "text-xs text-gray-500",
+                            "This is the qualifying income used for DTI on the options template, from the saved worksheets."
+                        }
+                    }
                 }
             }
         }
@@ -0,0 +1,189 @@
+use chrono::Utc;
+use dioxus::prelude::*;
+use shared::calc::military::{apply_to_general_income, calculate_military};
+use shared::calc::rule_set::IncomeRuleSet;
+use shared::models::{GeneralIncomeData, MilitaryIncomeData};
+
+// Helper to format money
+fn format_money(amount: f64) -> String {
+    format!("${:.2}", amount)
+}
+
+const INPUT_CLASS: &str = "bg-white border-2 border-gray-300 text-gray-900 text-sm rounded-lg px-4 py-3";
+
+// LES lines, entered as monthly amounts
+fn les_fields(data: &MilitaryIncomeData) -> [(&'static str, &'static str, f64); 7] {
+    [
+        ("base_pay", "Base Pay (taxable)", data.base_pay),
+        ("flight_pay", "Flight Pay (taxable)", data.flight_pay),
+        ("hazard_pay", "Hazard Pay (taxable)", data.hazard_pay),
+        ("bah", "BAH (non-taxable)", data.bah),
+        ("bas", "BAS (non-taxable)", data.bas),
+        ("clothing_allowance", "Clothing Allowance (non-taxable)", data.clothing_allowance),
+        ("other_allowances", "Other Allowances", data.other_allowances),
+    ]
+}
+
+// Military pay from the Leave and Earnings Statement. Each save also updates the
+// borrower's military entry in general income, which feeds qualifying income.
+#[component]
+pub fn Military(borrower_id: i32, rules: IncomeRuleSet) -> Element {
+    let client = use_context::<repository::Repository>();
+    let mut data = use_signal(MilitaryIncomeData::default);
+
+    let client_clone = client.clone();
+    use_effect(move || {
+        let client = client_clone.clone();
+        spawn(async move {
+            match client.get_military_income_data(borrower_id).await {
+                Ok(Some(saved)) => data.set(saved),
+                Ok(None) => {}
+                Err(e) => log::error!("Failed to load military income data: {:?}", e),
+            }
+        });
+    });
+
+    let today = Utc::now().date_naive();
+    // Kept in a memo so the save callback below sees the current rule set
+    let active_rules = use_memo(use_reactive!(|(rules,)| rules));
+    let result = use_memo(move || calculate_military(&data(), &active_rules(), today));
+
+    // Callback so the handlers below stay `Copy` inside the loop
+    let save = Callback::new(move |_: ()| {
+        let client = client.clone();
+        spawn(async move {
+            let military = data();
+            if let Err(e) = client.save_military_income_data(borrower_id, military.clone()).await {
+                log::error!("Failed to save military income data: {:?}", e);
+                return;
+            }
+
+            let mut general = match client.get_general_income_data(borrower_id).await {
+                Ok(Some(general)) => general,
+                Ok(None) => GeneralIncomeData {
+                    entries: Vec::new(),
+                    ..Default::default()
+                },
+                Err(e) => {
+                    log::error!("Failed to load general income data: {:?}", e);
+                    return;
+                }
+            };
+            let result = calculate_military(&military, &active_rules(), today);
+            apply_to_general_income(&mut general, &military, &result);
+            if let Err(e) = client.save_general_income_data(borrower_id, general).await {
+                log::error!("Failed to update general income data: {:?}", e);
+            }
+        });
+    });
+
+    let mut update_field = move |field: &str, value: String| {
+        {
+            let mut military = data.write();
+            let amount = value.parse().unwrap_or(0.0);
+            match field {
+                "branch" => military.branch = value,
+                "ets_date" => military.ets_date = value,
+                "base_pay" => military.base_pay = amount,
+                "bah" => military.bah = amount,
+                "bas" => military.bas = amount,
+                "clothing_allowance" => military.clothing_allowance = amount,
+                "flight_pay" => military.flight_pay = amount,
+                "hazard_pay" => military.hazard_pay = amount,
+                "other_allowances" => military.other_allowances = amount,
+                "other_allowances_taxable" => military.other_allowances_taxable = value == "true",
+                _ => {}
+            }
+        }
+        save.call(());
+    };
+
+    rsx! {
+        div { class: "space-y-6",
+            div { class: "bg-gradient-to-br from-blue-50 to-cyan-50 p-6 rounded-xl shadow-md border-2 border-blue-200",
+                div { class: "grid grid-cols-1 md:grid-cols-2 gap-4 mb-6",
+                    div { class: "flex flex-col",
+                        label { class: "block mb-2 text-sm font-semibold text-gray-900", "Branch of Service" }
+                        input {
+                            r#type: "text",
+                            value: "{data().branch}",
+                            class: INPUT_CLASS,
+                            onchange: move |e: Event<FormData>| update_field("branch", e.value()),
+                        }
+                    }
+                    div { class: "flex flex-col",
+                        label { class: "block mb-2 text-sm font-semibold text-gray-900", "ETS Date" }
+                        input {
+                            r#type: "date",
+                            value: "{data().ets_date}",
+                            class: INPUT_CLASS,
+                            onchange: move |e: Event<FormData>| update_field("ets_date", e.value()),
+                        }
+                    }
+                }
+
+                table { class: "min-w-full table-auto border-collapse border border-gray-300 text-sm bg-white",
+                    tbody {
+                        for (field, label, value) in les_fields(&data()) {
+                            tr {
+                                td { class: "border border-gray-300 px-3 py-2 text-gray-700", "{label}" }
+                                td { class: "border border-gray-300 px-3 py-1",
+                                    input {
+                                        r#type: "number",
+                                        step: "any",
+                                        value: "{value}",
+                                        class: "w-full px-2 py-1 border rounded",
+                                        onchange: move |e: Event<FormData>| update_field(field, e.value()),
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                div { class: "flex items-center space-x-3 mt-3",
+                    input {
+                        r#type: "checkbox",
+                        id: "other_allowances_taxable",
+                        class: "w-5 h-5 text-blue-600 border-gray-400 rounded cursor-pointer",
+                        checked: data().other_allowances_taxable,
+                        onchange: move |e: Event<FormData>| update_field("other_allowances_taxable", e.checked().to_string()),
+                    }
+                    label {
+                        r#for: "other_allowances_taxable",
+                        class: "text-sm font-semibold text-gray-900 cursor-pointer",
+                        "Other allowances are taxable"
+                    }
+                }
+
+                div { class: "mt-4 space-y-1 text-sm",
+                    div { class: "flex justify-between",
+                        span { class: "text-gray-600", "Taxable Pay" }
+                        span { class: "font-medium text-gray-900", "{format_money(result().taxable_monthly)}" }
+                    }
+                    div { class: "flex justify-between",
+                        span { class: "text-gray-600", "Non-Taxable Allowances" }
+                        span { class: "font-medium text-gray-900", "{format_money(result().non_taxable_monthly)}" }
+                    }
+                    div { class: "flex justify-between",
+                        span { class: "text-gray-600",
+                            "Grossed Up ({rules.profile.label()} × {rules.non_taxable_gross_up * 100.0:.0}%)"
+                        }
+                        span { class: "font-medium text-gray-900", "{format_money(result().grossed_up_non_taxable)}" }
+                    }
+                }
+
+                if !result().meets_continuance {
+                    div { class: "mt-4 p-4 bg-red-50 border-2 border-red-300 rounded-lg text-sm text-red-800 font-medium",
+                        "ETS is {result().months_to_ets.unwrap_or(0.0):.0} months away; {rules.profile.label()} requires income to continue for {rules.continuance_months} months. Document reenlistment or the borrower's intent to remain in service."
+                    }
+                }
+
+                div { class: "flex justify-end mt-4",
+                    div { class: "px-4 py-3 bg-green-100 border-2 border-green-400 rounded-lg text-gray-900 font-bold text-lg",
+                        "Military Income: {format_money(result().qualifying_monthly)}/mo"
+                    }
+                }
+            }
+        }
+    }
+}
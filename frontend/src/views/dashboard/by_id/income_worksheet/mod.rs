@@ -16,9 +16,24 @@ pub use ot_bonus::OTBonus;
 pub mod commission;
 pub use commission::Commission;
 
+pub mod trend;
+pub mod pay_stub;
+
 pub mod other_w2;
 pub use other_w2::OtherW2;
 
+pub mod self_employment;
+pub use self_employment::SelfEmployment;
+
+pub mod bank_statement;
+pub use bank_statement::BankStatement;
+
+pub mod rental;
+pub use rental::Rental;
+
+pub mod military;
+pub use military::Military;
+
 
 pub mod social_security;
 pub use social_security::SocialSecurity;
@@ -29,5 +44,11 @@ pub use pension::Pension;
 pub mod ira;
 pub use ira::IRA;
 
+pub mod investment;
+pub use investment::Investment;
+
 pub mod other_income;
-pub use other_income::OtherIncome;
\ No newline at end of file
+pub use other_income::OtherIncome;
+
+pub mod accessory_income;
+pub use accessory_income::AccessoryIncome;
\ No newline at end of file
@@ -1,98 +1,54 @@
 use dioxus::prelude::*;
 use crate::components::Input;
+use shared::calc::income::{calculate_ot_bonus, parse_amount, IncomeMethod, OtBonusInput, PeriodEarnings};
+use shared::calc::pay_period::ytd_months;
+use crate::views::dashboard::by_id::income_worksheet::pay_stub::PayStubDates;
+use crate::views::dashboard::by_id::income_worksheet::trend::{save_trend, IncomeTrendPanel};
+use shared::calc::income_trend::IncomeTrend;
 
 #[component]
-pub fn OTBonus() -> Element {
+pub fn OTBonus(borrower_id: i32, decline_threshold: f64) -> Element {
     // State management
     let mut ytd_ot_bonus = use_signal(|| String::new());
-    let mut ytd_months = use_signal(|| String::from("12"));
     let mut past_year_ot = use_signal(|| String::new());
     let mut past_year_months = use_signal(|| String::from("12"));
     let mut additional_year_ot = use_signal(|| String::new());
     let mut additional_year_months = use_signal(|| String::from("12"));
     let mut selected_calculation = use_signal(|| String::from("none"));
     
-    // Calculate YTD OT/Bonus Income
-    let ytd_income = use_memo(move || {
-        if let Ok(ytd) = ytd_ot_bonus().parse::<f64>() {
-            if let Ok(months) = ytd_months().parse::<f64>() {
-                if months > 0.0 {
-                    ytd / months * 12.0
-                } else {
-                    0.0
-                }
-            } else {
-                0.0
-            }
-        } else {
-            0.0
-        }
-    });
-    
-    // Calculate Past Year OT Income
-    let past_year_income = use_memo(move || {
-        if let Ok(past) = past_year_ot().parse::<f64>() {
-            if let Ok(months) = past_year_months().parse::<f64>() {
-                if months > 0.0 {
-                    past / months * 12.0
-                } else {
-                    0.0
-                }
-            } else {
-                0.0
-            }
-        } else {
-            0.0
-        }
-    });
-    
-    // Calculate Additional Year OT Income
-    let additional_year_income = use_memo(move || {
-        if let Ok(additional) = additional_year_ot().parse::<f64>() {
-            if let Ok(months) = additional_year_months().parse::<f64>() {
-                if months > 0.0 {
-                    additional / months * 12.0
-                } else {
-                    0.0
-                }
-            } else {
-                0.0
-            }
-        } else {
-            0.0
-        }
-    });
-    
-    // Calculate averages
-    let ytd_avg = use_memo(move || {
-        ytd_income()
-    });
-    
-    let ytd_plus_1year_avg = use_memo(move || {
-        (ytd_income() + past_year_income()) / 2.0
-    });
-    
-    let ytd_plus_2year_avg = use_memo(move || {
-        (ytd_income() + past_year_income() + additional_year_income()) / 3.0
-    });
-    
-    // Find lowest calculation
-    let lowest_calculation = use_memo(move || {
-        let mut values = vec![ytd_avg(), ytd_plus_1year_avg(), ytd_plus_2year_avg()];
-        values.retain(|&x| x > 0.0);
-        values.iter().fold(f64::INFINITY, |a, &b| a.min(b))
-    });
-    
-    // Determine final income based on selection
-    let final_income = use_memo(move || {
-        match selected_calculation().as_str() {
-            "ytd" => ytd_avg(),
-            "ytd_1year" => ytd_plus_1year_avg(),
-            "ytd_2year" => ytd_plus_2year_avg(),
-            "lowest" => if lowest_calculation().is_finite() { lowest_calculation() } else { 0.0 },
-            _ => 0.0,
-        }
-    });
+    // YTD months come from the pay stub dates
+    let ytd_period_end = use_signal(|| String::new());
+    let ytd_pay_frequency = use_signal(|| String::from("monthly"));
+    let hire_date = use_signal(|| String::new());
+    // Zero until the period end is entered; the stub fields warn about it
+    let ytd_months_covered = move || ytd_months(&ytd_period_end(), &hire_date()).unwrap_or(0.0);
+
+    // Recomputed when the configured threshold arrives from settings
+    let result = use_memo(use_reactive!(|(decline_threshold,)| {
+        calculate_ot_bonus(&OtBonusInput {
+            ytd: PeriodEarnings::new(parse_amount(&ytd_ot_bonus()), ytd_months_covered()),
+            past_year: PeriodEarnings::new(parse_amount(&past_year_ot()), parse_amount(&past_year_months())),
+            additional_year: PeriodEarnings::new(parse_amount(&additional_year_ot()), parse_amount(&additional_year_months())),
+            method: IncomeMethod::from_key(&selected_calculation()),
+            decline_threshold_percent: decline_threshold,
+        })
+    }));
+
+    // The selected figure and trend analysis are saved when focus leaves one of the section's fields
+    let client = use_context::<repository::Repository>();
+    let last_saved_trend = use_signal(|| None::<(f64, IncomeTrend)>);
+    let save_section_trend = move |_: FocusEvent| {
+        save_trend(client.clone(), borrower_id, "ot_bonus", result().qualifying_monthly, result().trend, last_saved_trend)
+    };
+
+    let ytd_income = move || result().ytd_monthly;
+    let past_year_income = move || result().past_year_monthly;
+    let additional_year_income = move || result().additional_year_monthly;
+    let ytd_avg = move || result().averages.ytd;
+    let ytd_plus_1year_avg = move || result().averages.ytd_plus_one_year;
+    let ytd_plus_2year_avg = move || result().averages.ytd_plus_two_years;
+    let lowest_calculation = move || result().averages.lowest;
+    let final_income = move || result().qualifying_monthly;
     
     // Helper to format money
     fn format_money(amount: f64) -> String {
@@ -101,6 +57,7 @@ pub fn OTBonus() -> Element {
      
     rsx! {
         div { class: "space-y-8",
+            onfocusout: save_section_trend,
             // Income Inputs Section
             div { class: "bg-gradient-to-br from-orange-50 to-amber-50 p-6 rounded-xl shadow-md border-2 border-orange-200",
                 h3 { class: "text-xl font-bold text-gray-900 dark:text-gray-100 mb-6 flex items-center gap-2",
@@ -108,6 +65,7 @@ pub fn OTBonus() -> Element {
                     "Overtime & Bonus Income"
                 }
                 div { class: "space-y-4",
+                    PayStubDates { period_end: ytd_period_end, pay_frequency: ytd_pay_frequency, hire_date, show_frequency: true, has_ytd_earnings: !ytd_ot_bonus().trim().is_empty() }
                     // YTD OT/Bonus
                     div { class: "grid grid-cols-12 gap-4 items-end",
                         div { class: "col-span-4",
@@ -120,18 +78,17 @@ pub fn OTBonus() -> Element {
                                 oninput: move |evt: Event<FormData>| ytd_ot_bonus.set(evt.value()),
                             }
                         }
-                        div { class: "col-span-3",
-                            Input {
-                                label: "# Months",
-                                r#type: "number",
-                                name: "ytd_months",
-                                value: "{ytd_months}",
-                                oninput: move |evt: Event<FormData>| ytd_months.set(evt.value()),
+                        div { class: "col-span-3 flex flex-col",
+                            label { class: "block mb-2.5 text-sm font-semibold text-gray-900 dark:text-gray-100",
+                                "# Months"
+                            }
+                            div { class: "px-4 py-3 bg-gray-100 border-2 border-gray-300 rounded-lg text-gray-900 dark:text-gray-100 font-bold text-right",
+                                "{ytd_months_covered():.2}"
                             }
                         }
                         div { class: "col-span-5 flex flex-col",
                             label { class: "block mb-2.5 text-sm font-semibold text-gray-900 dark:text-gray-100",
-                                "Annual Income"
+                                "Monthly Income"
                             }
                             div { class: "px-4 py-3 bg-white border-2 border-gray-300 rounded-lg text-gray-900 dark:text-gray-100 font-bold text-right",
                                 "{format_money(ytd_income())}"
@@ -161,7 +118,7 @@ pub fn OTBonus() -> Element {
                         }
                         div { class: "col-span-5 flex flex-col",
                             label { class: "block mb-2.5 text-sm font-semibold text-gray-900 dark:text-gray-100",
-                                "Annual Income"
+                                "Monthly Income"
                             }
                             div { class: "px-4 py-3 bg-white border-2 border-gray-300 rounded-lg text-gray-900 dark:text-gray-100 font-bold text-right",
                                 "{format_money(past_year_income())}"
@@ -191,7 +148,7 @@ pub fn OTBonus() -> Element {
                         }
                         div { class: "col-span-5 flex flex-col",
                             label { class: "block mb-2.5 text-sm font-semibold text-gray-900 dark:text-gray-100",
-                                "Annual Income"
+                                "Monthly Income"
                             }
                             div { class: "px-4 py-3 bg-white border-2 border-gray-300 rounded-lg text-gray-900 dark:text-gray-100 font-bold text-right",
                                 "{format_money(additional_year_income())}"
@@ -261,22 +218,15 @@ pub fn OTBonus() -> Element {
                             }
                         }
                         div { class: "col-span-6 flex flex-col",
-                            {
-                                let lowest_val = if lowest_calculation().is_finite() {
-                                    lowest_calculation()
-                                } else {
-                                    0.0
-                                };
-                                rsx! {
-                                    div { class: "px-4 py-3 bg-indigo-100 border-2 border-indigo-300 rounded-lg text-gray-900 dark:text-gray-100 font-bold text-right text-lg",
-                                        "{format_money(lowest_val)}"
-                                    }
-                                }
+                            div { class: "px-4 py-3 bg-indigo-100 border-2 border-indigo-300 rounded-lg text-gray-900 dark:text-gray-100 font-bold text-right text-lg",
+                                "{format_money(lowest_calculation())}"
                             }
                         }
                     }
                 }
             }
+            IncomeTrendPanel { trend: result().trend, selected_monthly: result().qualifying_monthly }
+
             // Final Selection Section
             div { class: "bg-gradient-to-br from-green-50 to-emerald-50 p-6 rounded-xl shadow-md border-2 border-green-200",
                 h3 { class: "text-xl font-bold text-gray-900 dark:text-gray-100 mb-6 flex items-center gap-2",
@@ -295,22 +245,13 @@ pub fn OTBonus() -> Element {
                                 onchange: move |evt: Event<FormData>| selected_calculation.set(evt.value()),
                                 option { value: "none", "-- Select Method --" }
                                 option { value: "ytd", "YTD Average ({format_money(ytd_avg())})" }
-                                option { value: "ytd_1year",
+                                option { value: "ytd_1w2",
                                     "YTD + 1 Year Avg ({format_money(ytd_plus_1year_avg())})"
                                 }
-                                option { value: "ytd_2year",
+                                option { value: "ytd_2w2",
                                     "YTD + 2 Year Avg ({format_money(ytd_plus_2year_avg())})"
                                 }
-                                {
-                                    let lowest_val = if lowest_calculation().is_finite() {
-                                        lowest_calculation()
-                                    } else {
-                                        0.0
-                                    };
-                                    rsx! {
-                                        option { value: "lowest", "Use Lowest ({format_money(lowest_val)})" }
-                                    }
-                                }
+                                option { value: "lowest", "Use Lowest ({format_money(lowest_calculation())})" }
                             }
                         }
                         div { class: "col-span-6 flex flex-col",
@@ -1,67 +1,40 @@
 use dioxus::prelude::*;
 use crate::components::Input;
+use shared::calc::income::{calculate_other_income, parse_amount, OtherIncomeInput};
+use shared::calc::rule_set::IncomeRuleSet;
 
 #[component]
-pub fn OtherIncome() -> Element {
+pub fn OtherIncome(rules: IncomeRuleSet) -> Element {
     // State management for various income types
     let mut alimony_monthly = use_signal(|| String::new());
     let mut child_support_monthly = use_signal(|| String::new());
-    let mut rental_gross_monthly = use_signal(|| String::new());
-    let mut rental_expenses_monthly = use_signal(|| String::new());
     let mut interest_dividends_monthly = use_signal(|| String::new());
     let mut disability_monthly = use_signal(|| String::new());
     let mut workers_comp_monthly = use_signal(|| String::new());
     let mut other_income_monthly = use_signal(|| String::new());
     let mut other_income_description = use_signal(|| String::new());
 
-    // Calculate qualifying alimony (75% of gross)
-    let qualifying_alimony = use_memo(move || {
-        alimony_monthly().parse::<f64>().unwrap_or(0.0) * 0.75
-    });
+    // Recomputed when the worksheet's rule set changes
+    let result = use_memo(use_reactive!(|(rules,)| {
+        calculate_other_income(&OtherIncomeInput {
+            alimony: parse_amount(&alimony_monthly()),
+            child_support: parse_amount(&child_support_monthly()),
+            interest_dividends: parse_amount(&interest_dividends_monthly()),
+            disability: parse_amount(&disability_monthly()),
+            workers_comp: parse_amount(&workers_comp_monthly()),
+            other: parse_amount(&other_income_monthly()),
+        }, &rules)
+    }));
 
-    // Child support is typically not countable as borrower income
-    let qualifying_child_support = use_memo(move || {
-        0.0 // Child support is generally not considered qualifying income
-    });
-
-    // Calculate qualifying rental income (75% of net rental income)
-    let qualifying_rental = use_memo(move || {
-        let gross = rental_gross_monthly().parse::<f64>().unwrap_or(0.0);
-        let expenses = rental_expenses_monthly().parse::<f64>().unwrap_or(0.0);
-        let net = gross - expenses;
-        if net > 0.0 {
-            net * 0.75
-        } else {
-            0.0
-        }
-    });
-
-    // Interest and dividends (100% of taxable portion)
-    let qualifying_interest_dividends = use_memo(move || {
-        interest_dividends_monthly().parse::<f64>().unwrap_or(0.0)
-    });
-
-    // Disability income (may be considered if expected to continue)
-    let qualifying_disability = use_memo(move || {
-        disability_monthly().parse::<f64>().unwrap_or(0.0)
-    });
-
-    // Workers compensation (may be considered if expected to continue)
-    let qualifying_workers_comp = use_memo(move || {
-        workers_comp_monthly().parse::<f64>().unwrap_or(0.0)
-    });
-
-    // Other income (case-by-case basis)
-    let qualifying_other = use_memo(move || {
-        other_income_monthly().parse::<f64>().unwrap_or(0.0)
-    });
-
-    // Calculate total qualifying other income
-    let total_qualifying_other_income = use_memo(move || {
-        qualifying_alimony() + qualifying_child_support() + qualifying_rental() +
-        qualifying_interest_dividends() + qualifying_disability() + qualifying_workers_comp() +
-        qualifying_other()
-    });
+    // Alimony at the rule set's share of gross
+    let qualifying_alimony = move || result().alimony;
+    // Child support is generally not considered qualifying income
+    let qualifying_child_support = move || result().child_support;
+    let qualifying_interest_dividends = move || result().interest_dividends;
+    let qualifying_disability = move || result().disability;
+    let qualifying_workers_comp = move || result().workers_comp;
+    let qualifying_other = move || result().other;
+    let total_qualifying_other_income = move || result().qualifying_monthly;
 
     fn format_money(amount: f64) -> String {
         format!("${:.2}", amount)
@@ -89,7 +62,7 @@ pub fn OtherIncome() -> Element {
                         }
                         div { class: "col-span-6 flex flex-col",
                             label { class: "block mb-2.5 text-sm font-semibold text-gray-900 dark:text-gray-100 dark:text-gray-100",
-                                "Qualifying Amount (75%)"
+                                "Qualifying Amount ({rules.alimony_factor * 100.0:.0}%)"
                             }
                             div { class: "px-4 py-3 bg-rose-200 border-2 border-rose-400 rounded-lg text-gray-900 dark:text-gray-100 dark:text-gray-100 font-bold text-right",
                                 "{format_money(qualifying_alimony())}"
@@ -98,8 +71,8 @@ pub fn OtherIncome() -> Element {
                     }
                     div { class: "mt-4 p-4 bg-yellow-50 border-2 border-yellow-300 rounded-lg",
                         p { class: "text-sm text-gray-900 dark:text-gray-100 dark:text-gray-100 font-medium",
-                            span { class: "font-bold text-yellow-700", "📋 Fannie Mae: " }
-                            "75% of gross alimony received is considered qualifying income. Must be documented with court order and payment history."
+                            span { class: "font-bold text-yellow-700", "📋 {rules.profile.label()}: " }
+                            "{rules.alimony_factor * 100.0:.0}% of gross alimony received is considered qualifying income. Must be documented with court order and payment history."
                         }
                     }
                 }
@@ -141,52 +114,6 @@ pub fn OtherIncome() -> Element {
                 }
             }
 
-            // Rental Income Section
-            div { class: "bg-gradient-to-br from-blue-50 to-cyan-50 p-6 rounded-xl shadow-md border-2 border-blue-200",
-                h3 { class: "text-xl font-bold text-gray-900 dark:text-gray-100 dark:text-gray-100 mb-6 flex items-center gap-2",
-                    span { class: "text-blue-600", "🏠" }
-                    "Rental Income"
-                }
-                div { class: "space-y-4",
-                    div { class: "grid grid-cols-12 gap-4 items-end",
-                        div { class: "col-span-4",
-                            Input {
-                                label: "Monthly Gross Rental Income",
-                                r#type: "number",
-                                name: "rental_gross_monthly",
-                                value: "{rental_gross_monthly}",
-                                placeholder: "0.00",
-                                oninput: move |evt: Event<FormData>| rental_gross_monthly.set(evt.value()),
-                            }
-                        }
-                        div { class: "col-span-4",
-                            Input {
-                                label: "Monthly Rental Expenses",
-                                r#type: "number",
-                                name: "rental_expenses_monthly",
-                                value: "{rental_expenses_monthly}",
-                                placeholder: "0.00",
-                                oninput: move |evt: Event<FormData>| rental_expenses_monthly.set(evt.value()),
-                            }
-                        }
-                        div { class: "col-span-4 flex flex-col",
-                            label { class: "block mb-2.5 text-sm font-semibold text-gray-900 dark:text-gray-100 dark:text-gray-100",
-                                "Qualifying Amount (75% of Net)"
-                            }
-                            div { class: "px-4 py-3 bg-blue-200 border-2 border-blue-400 rounded-lg text-gray-900 dark:text-gray-100 dark:text-gray-100 font-bold text-right",
-                                "{format_money(qualifying_rental())}"
-                            }
-                        }
-                    }
-                    div { class: "mt-4 p-4 bg-yellow-50 border-2 border-yellow-300 rounded-lg",
-                        p { class: "text-sm text-gray-900 dark:text-gray-100 dark:text-gray-100 font-medium",
-                            span { class: "font-bold text-yellow-700", "📋 Fannie Mae: " }
-                            "75% of net rental income (gross minus expenses) is considered qualifying. Expenses must be documented and reasonable."
-                        }
-                    }
-                }
-            }
-
             // Investment Income Section
             div { class: "bg-gradient-to-br from-green-50 to-emerald-50 p-6 rounded-xl shadow-md border-2 border-green-200",
                 h3 { class: "text-xl font-bold text-gray-900 dark:text-gray-100 dark:text-gray-100 mb-6 flex items-center gap-2",
@@ -353,7 +280,6 @@ pub fn OtherIncome() -> Element {
                             li { "• Award letters, court orders, or contracts" }
                             li { "• Payment history (minimum 12-24 months)" }
                             li { "• Documentation of expected continuation" }
-                            li { "• Expense documentation for rental income" }
                         }
                     }
                 }
@@ -1,5 +1,6 @@
 use dioxus::prelude::*;
 use crate::components::Input;
+use shared::calc::income::{calculate_other_w2, parse_amount, OtherW2Input};
 
 #[component]
 pub fn OtherW2() -> Element {
@@ -13,53 +14,29 @@ pub fn OtherW2() -> Element {
     let mut sick_pay_monthly = use_signal(|| String::new());
     let mut holiday_vacation_pay_monthly = use_signal(|| String::new());
 
-    // Calculate qualifying amounts based on Fannie Mae/Freddie Mac guidelines
-    let qualifying_tips = use_memo(move || {
-        // Tips are 100% qualifying if documented and consistent
-        tips_monthly().parse::<f64>().unwrap_or(0.0)
+    // Qualifying amounts based on Fannie Mae/Freddie Mac guidelines
+    let result = use_memo(move || {
+        calculate_other_w2(&OtherW2Input {
+            tips: parse_amount(&tips_monthly()),
+            shift_differential: parse_amount(&shift_differential_monthly()),
+            on_call_pay: parse_amount(&on_call_pay_monthly()),
+            call_back_pay: parse_amount(&call_back_pay_monthly()),
+            hazard_pay: parse_amount(&hazard_pay_monthly()),
+            profit_sharing: parse_amount(&profit_sharing_monthly()),
+            sick_pay: parse_amount(&sick_pay_monthly()),
+            holiday_vacation_pay: parse_amount(&holiday_vacation_pay_monthly()),
+        })
     });
 
-    let qualifying_shift_differential = use_memo(move || {
-        // Shift differentials are 100% qualifying if regular
-        shift_differential_monthly().parse::<f64>().unwrap_or(0.0)
-    });
-
-    let qualifying_on_call_pay = use_memo(move || {
-        // On-call pay may be considered if regular and documented
-        on_call_pay_monthly().parse::<f64>().unwrap_or(0.0)
-    });
-
-    let qualifying_call_back_pay = use_memo(move || {
-        // Call-back pay may be considered if regular
-        call_back_pay_monthly().parse::<f64>().unwrap_or(0.0)
-    });
-
-    let qualifying_hazard_pay = use_memo(move || {
-        // Hazard pay is 100% qualifying if regular
-        hazard_pay_monthly().parse::<f64>().unwrap_or(0.0)
-    });
-
-    let qualifying_profit_sharing = use_memo(move || {
-        // Profit sharing may be considered if consistent (usually requires 2+ year history)
-        profit_sharing_monthly().parse::<f64>().unwrap_or(0.0)
-    });
-
-    let qualifying_sick_pay = use_memo(move || {
-        // Sick pay is generally not considered qualifying income
-        0.0
-    });
-
-    let qualifying_holiday_vacation_pay = use_memo(move || {
-        // Holiday/vacation pay may be considered if paid out separately and consistent
-        holiday_vacation_pay_monthly().parse::<f64>().unwrap_or(0.0)
-    });
-
-    // Calculate total qualifying other W2 income
-    let total_qualifying_other_w2 = use_memo(move || {
-        qualifying_tips() + qualifying_shift_differential() + qualifying_on_call_pay() +
-        qualifying_call_back_pay() + qualifying_hazard_pay() + qualifying_profit_sharing() +
-        qualifying_sick_pay() + qualifying_holiday_vacation_pay()
-    });
+    let qualifying_tips = move || result().tips;
+    let qualifying_shift_differential = move || result().shift_differential;
+    let qualifying_on_call_pay = move || result().on_call_pay;
+    let qualifying_call_back_pay = move || result().call_back_pay;
+    let qualifying_hazard_pay = move || result().hazard_pay;
+    let qualifying_profit_sharing = move || result().profit_sharing;
+    let qualifying_sick_pay = move || result().sick_pay;
+    let qualifying_holiday_vacation_pay = move || result().holiday_vacation_pay;
+    let total_qualifying_other_w2 = move || result().qualifying_monthly;
 
     fn format_money(amount: f64) -> String {
         format!("${:.2}", amount)
@@ -0,0 +1,77 @@
+use dioxus::prelude::*;
+use crate::components::Input;
+use shared::calc::income::PayFrequency;
+use shared::calc::pay_period::{parse_date, ytd_period};
+
+// Pay stub dates that set the YTD months, shared by the W-2 sections. Salary already
+// asks for its pay frequency, so it passes `show_frequency: false` and its own signal.
+// `has_ytd_earnings` turns the missing-date hint into a warning once YTD pay is entered.
+#[component]
+pub fn PayStubDates(
+    mut period_end: Signal<String>,
+    mut pay_frequency: Signal<String>,
+    mut hire_date: Signal<String>,
+    show_frequency: bool,
+    has_ytd_earnings: bool,
+) -> Element {
+    let period = parse_date(&period_end()).and_then(|end| {
+        ytd_period(end, PayFrequency::from_key(&pay_frequency()), parse_date(&hire_date()))
+    });
+
+    rsx! {
+        div { class: "grid grid-cols-12 gap-4 items-end",
+            div { class: "col-span-3",
+                Input {
+                    label: "Pay Stub Period End",
+                    r#type: "date",
+                    name: "ytd_period_end",
+                    value: "{period_end}",
+                    oninput: move |evt: Event<FormData>| period_end.set(evt.value()),
+                }
+            }
+            if show_frequency {
+                div { class: "col-span-3 flex flex-col",
+                    label { class: "block mb-2.5 text-sm font-semibold text-gray-900 dark:text-gray-100",
+                        "Pay Frequency"
+                    }
+                    select {
+                        class: "bg-white border-2 border-gray-300 text-gray-900 dark:text-gray-100 text-sm rounded-lg block w-full px-4 py-3 shadow-sm font-semibold",
+                        value: "{pay_frequency}",
+                        onchange: move |evt: Event<FormData>| pay_frequency.set(evt.value()),
+                        option { value: "monthly", "Monthly" }
+                        option { value: "bi-weekly", "Bi-Weekly" }
+                        option { value: "semi-monthly", "Semi-Monthly" }
+                        option { value: "weekly", "Weekly" }
+                    }
+                }
+            }
+            div { class: "col-span-3",
+                Input {
+                    label: "Hire Date (if this year)",
+                    r#type: "date",
+                    name: "hire_date",
+                    value: "{hire_date}",
+                    oninput: move |evt: Event<FormData>| hire_date.set(evt.value()),
+                }
+            }
+            div { class: "col-span-3 flex flex-col text-sm text-gray-700",
+                match period {
+                    Some(period) => rsx! {
+                        span { "From {period.start.format(\"%m/%d/%Y\")}" }
+                        span { class: "font-semibold text-gray-900",
+                            "{period.months:.2} months, {period.pay_periods:.1} pay periods"
+                        }
+                    },
+                    None if has_ytd_earnings => rsx! {
+                        span { class: "p-2 bg-yellow-50 border border-yellow-300 rounded text-gray-900 font-medium",
+                            "YTD earnings are left out of the averages until the pay stub period end is entered"
+                        }
+                    },
+                    None => rsx! {
+                        span { class: "text-gray-500", "Enter the pay stub period end to calculate YTD months" }
+                    },
+                }
+            }
+        }
+    }
+}
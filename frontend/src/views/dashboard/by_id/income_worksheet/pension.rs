@@ -1,8 +1,10 @@
 use dioxus::prelude::*;
 use crate::components::Input;
+use shared::calc::income::{calculate_pension, parse_amount, parse_or, PensionInput};
+use shared::calc::rule_set::IncomeRuleSet;
 
 #[component]
-pub fn Pension() -> Element {
+pub fn Pension(rules: IncomeRuleSet) -> Element {
     // State management
     let mut monthly_pension = use_signal(|| String::new());
     let mut taxable_portion = use_signal(|| String::from("100"));
@@ -10,56 +12,23 @@ pub fn Pension() -> Element {
     let mut years_of_service = use_signal(|| String::new());
     let mut vesting_percentage = use_signal(|| String::from("100"));
     
-    // Calculate gross monthly pension
-    let gross_monthly = use_memo(move || {
-        monthly_pension().parse::<f64>().unwrap_or(0.0)
-    });
-    
-    // Calculate taxable portion
-    let taxable_amount = use_memo(move || {
-        let gross = gross_monthly();
-        let tax_pct = taxable_portion().parse::<f64>().unwrap_or(100.0) / 100.0;
-        gross * tax_pct
-    });
-    
-    // Calculate non-taxable portion
-    let non_taxable_amount = use_memo(move || {
-        let gross = gross_monthly();
-        let tax_pct = taxable_portion().parse::<f64>().unwrap_or(100.0) / 100.0;
-        gross * (1.0 - tax_pct)
-    });
-    
-    // Calculate Fannie Mae qualifying income (70/85 rule)
-    let fannie_mae_income = use_memo(move || {
-        let gross = gross_monthly();
-        if gross <= 1000.0 {
-            gross * 0.70 // 70% of first $1,000
-        } else {
-            let first_1000 = 1000.0 * 0.70;
-            let remainder = gross - 1000.0;
-            let remainder_qualified = remainder * 0.85; // 85% of amounts over $1,000
-            first_1000 + remainder_qualified
-        }
-    });
-    
-    // Calculate annualized pension income
-    let annualized_pension = use_memo(move || {
-        gross_monthly() * 12.0
-    });
-    
-    // Calculate vesting-adjusted income
-    let vested_income = use_memo(move || {
-        let annual = annualized_pension();
-        let vesting_pct = vesting_percentage().parse::<f64>().unwrap_or(100.0) / 100.0;
-        annual * vesting_pct
-    });
-    
-    // Determine final qualifying income (lowest of Fannie Mae method or vested amount)
-    let qualifying_income = use_memo(move || {
-        let fannie_mae = fannie_mae_income();
-        let vested = vested_income();
-        fannie_mae.min(vested)
-    });
+    // Recomputed when the worksheet's rule set changes
+    let result = use_memo(use_reactive!(|(rules,)| {
+        calculate_pension(&PensionInput {
+            gross_monthly: parse_amount(&monthly_pension()),
+            taxable_percent: parse_or(&taxable_portion(), 100.0),
+            vesting_percent: parse_or(&vesting_percentage(), 100.0),
+        }, &rules)
+    }));
+
+    let gross_monthly = move || result().gross_monthly;
+    let taxable_amount = move || result().taxable_monthly;
+    let non_taxable_amount = move || result().non_taxable_monthly;
+    let annualized_pension = move || result().annualized;
+    // Tiered haircut from the rule set
+    let tiered_income = move || result().tiered_monthly;
+    // Lowest of the tiered method or vested amount
+    let qualifying_income = move || result().qualifying_monthly;
     
     fn format_money(amount: f64) -> String {
         format!("${:.2}", amount)
@@ -218,23 +187,27 @@ pub fn Pension() -> Element {
                     div { class: "grid grid-cols-12 gap-4 items-center pt-4 border-t-2 border-indigo-300",
                         div { class: "col-span-6 flex items-center gap-3",
                             div { class: "px-4 py-2 bg-red-200 border-2 border-red-400 rounded-lg text-gray-900 dark:text-gray-100 font-bold text-center min-w-[200px]",
-                                "Fannie Mae Qualifying Income"
+                                "{rules.profile.label()} Qualifying Income"
                             }
                         }
                         div { class: "col-span-6 flex flex-col",
                             div { class: "px-4 py-3 bg-red-100 border-2 border-red-300 rounded-lg text-gray-900 dark:text-gray-100 font-bold text-right text-lg",
-                                "{format_money(fannie_mae_income())}"
+                                "{format_money(tiered_income())}"
                             }
                         }
                     }
                 }
 
                 div { class: "mt-4 p-4 bg-yellow-50 border-2 border-yellow-300 rounded-lg",
-                    h4 { class: "font-bold text-yellow-800 mb-2", "Fannie Mae Pension Guidelines:" }
+                    h4 { class: "font-bold text-yellow-800 mb-2", "{rules.profile.label()} Pension Guidelines:" }
                     ul { class: "text-sm text-gray-900 dark:text-gray-100 space-y-1",
-                        li { "• 70% of the first $1,000 of monthly pension income" }
-                        li { "• 85% of amounts over $1,000" }
-                        li { "• Use the lower of Fannie Mae calculation or vested amount" }
+                        li {
+                            "• {rules.pension_first_tier_factor * 100.0:.0}% of the first {format_money(rules.pension_first_tier_limit)} of monthly pension income"
+                        }
+                        li {
+                            "• {rules.pension_second_tier_factor * 100.0:.0}% of amounts over {format_money(rules.pension_first_tier_limit)}"
+                        }
+                        li { "• Use the lower of the tiered calculation or vested amount" }
                         li { "• Taxable portion only (non-taxable portions excluded)" }
                         li { "• Must be documented and consistent for 12-24 months" }
                     }
@@ -270,7 +243,7 @@ pub fn Pension() -> Element {
                                 "Qualifying Monthly Income"
                             }
                             div { class: "px-4 py-3 bg-green-200 border-2 border-green-400 rounded-lg text-gray-900 dark:text-gray-100 font-bold text-right text-xl",
-                                "{format_money(qualifying_income() / 12.0)}"
+                                "{format_money(qualifying_income())}"
                             }
                         }
                     }
@@ -0,0 +1,248 @@
+use dioxus::prelude::*;
+use shared::calc::rule_set::IncomeRuleSet;
+use shared::calc::rental::{RentalMethod, analyze_property, total_monthly_income, total_monthly_loss};
+use shared::models::{RentalIncomeData, RentalProperty};
+
+// Helper to format money
+fn format_money(amount: f64) -> String {
+    format!("${:.2}", amount)
+}
+
+const INPUT_CLASS: &str = "bg-white border-2 border-gray-300 text-gray-900 text-sm rounded-lg px-4 py-3";
+
+// Schedule E lines, entered as annual amounts from the most recent return
+fn schedule_e_fields(property: &RentalProperty) -> [(&'static str, &'static str, f64); 7] {
+    [
+        ("rents_received", "+ Rents received", property.rents_received),
+        ("total_expenses", "− Total expenses", property.total_expenses),
+        ("depreciation", "+ Depreciation", property.depreciation),
+        ("mortgage_interest", "+ Mortgage interest", property.mortgage_interest),
+        ("taxes", "+ Taxes", property.taxes),
+        ("insurance", "+ Insurance", property.insurance),
+        ("hoa_dues", "+ HOA dues", property.hoa_dues),
+    ]
+}
+
+// Rental income by property. Saved like the self-employment worksheet, because net
+// income feeds qualifying income and net losses count as debts on the options template.
+#[component]
+pub fn Rental(borrower_id: i32, rules: IncomeRuleSet) -> Element {
+    let client = use_context::<repository::Repository>();
+    let mut data = use_signal(RentalIncomeData::default);
+
+    let client_clone = client.clone();
+    use_effect(move || {
+        let client = client_clone.clone();
+        spawn(async move {
+            match client.get_rental_income_data(borrower_id).await {
+                Ok(Some(saved)) => data.set(saved),
+                Ok(None) => data.set(RentalIncomeData {
+                    properties: vec![RentalProperty::default()],
+                }),
+                Err(e) => log::error!("Failed to load rental income data: {:?}", e),
+            }
+        });
+    });
+
+    // Callback so the handlers below stay `Copy` inside the loops
+    let save = Callback::new(move |_: ()| {
+        let client = client.clone();
+        spawn(async move {
+            if let Err(e) = client.save_rental_income_data(borrower_id, data()).await {
+                log::error!("Failed to save rental income data: {:?}", e);
+            }
+        });
+    });
+
+    let mut update_property = move |index: usize, field: &str, value: String| {
+        if let Some(property) = data.write().properties.get_mut(index) {
+            let amount = value.parse().unwrap_or(0.0);
+            match field {
+                "address" => property.address = value,
+                "is_subject_property" => property.is_subject_property = value == "true",
+                "method" => property.method = value,
+                "rents_received" => property.rents_received = amount,
+                "total_expenses" => property.total_expenses = amount,
+                "depreciation" => property.depreciation = amount,
+                "mortgage_interest" => property.mortgage_interest = amount,
+                "taxes" => property.taxes = amount,
+                "insurance" => property.insurance = amount,
+                "hoa_dues" => property.hoa_dues = amount,
+                "months_in_service" => property.months_in_service = value.parse().unwrap_or(0),
+                "gross_monthly_rent" => property.gross_monthly_rent = amount,
+                "monthly_pitia" => property.monthly_pitia = amount,
+                _ => {}
+            }
+        }
+        save.call(());
+    };
+
+    let add_property = move |_| {
+        data.write().properties.push(RentalProperty::default());
+        save.call(());
+    };
+
+    let mut remove_property = move |index: usize| {
+        data.write().properties.remove(index);
+        save.call(());
+    };
+
+    rsx! {
+        div { class: "space-y-6",
+            for (index, property) in data().properties.into_iter().enumerate() {
+                {
+                    let method = RentalMethod::from_key(&property.method);
+                    let result = analyze_property(&property, &rules);
+                    rsx! {
+                        div { key: "{property.id}", class: "bg-gradient-to-br from-blue-50 to-cyan-50 p-6 rounded-xl shadow-md border-2 border-blue-200",
+                            div { class: "grid grid-cols-1 md:grid-cols-3 gap-4 mb-6",
+                                div { class: "flex flex-col",
+                                    label { class: "block mb-2 text-sm font-semibold text-gray-900", "Property Address" }
+                                    input {
+                                        r#type: "text",
+                                        value: "{property.address}",
+                                        class: INPUT_CLASS,
+                                        onchange: move |e: Event<FormData>| update_property(index, "address", e.value()),
+                                    }
+                                }
+                                div { class: "flex flex-col",
+                                    label { class: "block mb-2 text-sm font-semibold text-gray-900", "Method" }
+                                    select {
+                                        value: "{property.method}",
+                                        class: "{INPUT_CLASS} font-semibold",
+                                        onchange: move |e: Event<FormData>| update_property(index, "method", e.value()),
+                                        for option_method in RentalMethod::ALL {
+                                            option { value: option_method.as_key(), "{option_method.label()}" }
+                                        }
+                                    }
+                                }
+                                div { class: "flex items-center space-x-3 mt-7",
+                                    input {
+                                        r#type: "checkbox",
+                                        id: "subject_{property.id}",
+                                        class: "w-5 h-5 text-blue-600 border-gray-400 rounded cursor-pointer",
+                                        checked: property.is_subject_property,
+                                        onchange: move |e: Event<FormData>| update_property(index, "is_subject_property", e.checked().to_string()),
+                                    }
+                                    label {
+                                        r#for: "subject_{property.id}",
+                                        class: "text-sm font-semibold text-gray-900 cursor-pointer",
+                                        "Subject property"
+                                    }
+                                }
+                            }
+
+                            if method == RentalMethod::ScheduleE {
+                                table { class: "min-w-full table-auto border-collapse border border-gray-300 text-sm bg-white",
+                                    tbody {
+                                        for (field, label, value) in schedule_e_fields(&property) {
+                                            tr {
+                                                td { class: "border border-gray-300 px-3 py-2 text-gray-700", "{label}" }
+                                                td { class: "border border-gray-300 px-3 py-1",
+                                                    input {
+                                                        r#type: "number",
+                                                        step: "any",
+                                                        value: "{value}",
+                                                        class: "w-full px-2 py-1 border rounded",
+                                                        onchange: move |e: Event<FormData>| update_property(index, field, e.value()),
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        tr {
+                                            td { class: "border border-gray-300 px-3 py-2 text-gray-700", "÷ Months in service" }
+                                            td { class: "border border-gray-300 px-3 py-1",
+                                                input {
+                                                    r#type: "number",
+                                                    min: "0",
+                                                    max: "12",
+                                                    value: "{property.months_in_service}",
+                                                    class: "w-full px-2 py-1 border rounded",
+                                                    onchange: move |e: Event<FormData>| update_property(index, "months_in_service", e.value()),
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            } else {
+                                div { class: "flex flex-col md:w-1/3",
+                                    label { class: "block mb-2 text-sm font-semibold text-gray-900", "Gross Monthly Rent (Lease)" }
+                                    input {
+                                        r#type: "number",
+                                        step: "any",
+                                        value: "{property.gross_monthly_rent}",
+                                        class: INPUT_CLASS,
+                                        onchange: move |e: Event<FormData>| update_property(index, "gross_monthly_rent", e.value()),
+                                    }
+                                }
+                            }
+
+                            if !property.is_subject_property {
+                                div { class: "flex flex-col md:w-1/3 mt-4",
+                                    label { class: "block mb-2 text-sm font-semibold text-gray-900", "Monthly PITIA" }
+                                    input {
+                                        r#type: "number",
+                                        step: "any",
+                                        value: "{property.monthly_pitia}",
+                                        class: INPUT_CLASS,
+                                        onchange: move |e: Event<FormData>| update_property(index, "monthly_pitia", e.value()),
+                                    }
+                                }
+                            }
+
+                            div { class: "mt-4 space-y-1 text-sm",
+                                div { class: "flex justify-between",
+                                    span { class: "text-gray-600", "Monthly Rental Income" }
+                                    span { class: "font-medium text-gray-900", "{format_money(result.gross_monthly)}" }
+                                }
+                                if property.is_subject_property {
+                                    p { class: "text-gray-600",
+                                        "The subject property's PITIA is the proposed housing payment, so rental income is added to income without netting."
+                                    }
+                                } else {
+                                    div { class: "flex justify-between",
+                                        span { class: "text-gray-600", "Less PITIA" }
+                                        span { class: "font-medium text-gray-900", "{format_money(property.monthly_pitia)}" }
+                                    }
+                                }
+                            }
+
+                            div { class: "flex items-center justify-between mt-4",
+                                button {
+                                    class: "text-red-600 hover:text-red-800 text-sm",
+                                    onclick: move |_| remove_property(index),
+                                    "Remove Property"
+                                }
+                                if result.monthly_loss > 0.0 {
+                                    div { class: "px-4 py-3 bg-red-100 border-2 border-red-400 rounded-lg text-red-800 font-bold text-lg",
+                                        "Net Loss (debt): {format_money(result.monthly_loss)}"
+                                    }
+                                } else {
+                                    div { class: "px-4 py-3 bg-blue-200 border-2 border-blue-400 rounded-lg text-gray-900 font-bold text-lg",
+                                        "Net Income: {format_money(result.monthly_income)}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            div { class: "flex items-center justify-between",
+                button {
+                    class: "bg-blue-600 text-white px-4 py-2 rounded hover:bg-blue-700 text-sm",
+                    onclick: add_property,
+                    "Add Property"
+                }
+                div { class: "flex gap-4",
+                    div { class: "px-4 py-3 bg-green-100 border-2 border-green-400 rounded-lg text-gray-900 font-bold text-lg",
+                        "Rental Income: {format_money(total_monthly_income(&data(), &rules))}/mo"
+                    }
+                    div { class: "px-4 py-3 bg-red-50 border-2 border-red-300 rounded-lg text-gray-900 font-bold text-lg",
+                        "Rental Losses: {format_money(total_monthly_loss(&data(), &rules))}/mo"
+                    }
+                }
+            }
+        }
+    }
+}
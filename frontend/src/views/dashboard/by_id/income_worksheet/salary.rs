@@ -1,99 +1,54 @@
 use dioxus::prelude::*;
 use crate::components::Input;
+use shared::calc::income::{calculate_salary, parse_amount, IncomeMethod, PayFrequency, PeriodEarnings, SalaryInput};
+use shared::calc::pay_period::ytd_months;
+use crate::views::dashboard::by_id::income_worksheet::pay_stub::PayStubDates;
+use crate::views::dashboard::by_id::income_worksheet::trend::{save_trend, IncomeTrendPanel};
+use shared::calc::income_trend::IncomeTrend;
 
 #[component]
-pub fn Salary() -> Element {
+pub fn Salary(borrower_id: i32, decline_threshold: f64) -> Element {
     // State management
     let mut salary_amount = use_signal(|| String::new());
     let mut pay_frequency = use_signal(|| String::from("monthly"));
     let mut ytd_salary = use_signal(|| String::new());
-    let mut ytd_months = use_signal(|| String::from("12"));
     let mut w2_year1 = use_signal(|| String::new());
     let mut w2_year1_months = use_signal(|| String::from("12"));
     let mut w2_year2 = use_signal(|| String::new());
     let mut w2_year2_months = use_signal(|| String::from("12"));
     let mut selected_base = use_signal(|| String::from("none"));
     
-    // Calculate income based on pay frequency
-    let frequency_multiplier = use_memo(move || {
-        match pay_frequency().as_str() {
-            "monthly" => 1.0,
-            "bi-weekly" => 26.0 / 12.0,
-            "semi-monthly" => 24.0 / 12.0,
-            "weekly" => 52.0 / 12.0,
-            _ => 1.0,
-        }
-    });
-    
-    let calculated_income = use_memo(move || {
-        if let Ok(amount) = salary_amount().parse::<f64>() {
-            amount * frequency_multiplier()
-        } else {
-            0.0
-        }
-    });
-    
-    // Calculate YTD Monthly Average
-    let ytd_monthly_avg = use_memo(move || {
-        if let Ok(ytd) = ytd_salary().parse::<f64>() {
-            if let Ok(months) = ytd_months().parse::<f64>() {
-                if months > 0.0 {
-                    ytd / months
-                } else {
-                    0.0
-                }
-            } else {
-                0.0
-            }
-        } else {
-            0.0
-        }
-    });
-    
-    // Calculate W2 Year 1 Income
-    let w2_year1_income = use_memo(move || {
-        if let Ok(w2) = w2_year1().parse::<f64>() {
-            if let Ok(months) = w2_year1_months().parse::<f64>() {
-                if months > 0.0 {
-                    w2 / months
-                } else {
-                    0.0
-                }
-            } else {
-                0.0
-            }
-        } else {
-            0.0
-        }
-    });
-    
-    // Calculate W2 Year 2 Income
-    let w2_year2_income = use_memo(move || {
-        if let Ok(w2) = w2_year2().parse::<f64>() {
-            if let Ok(months) = w2_year2_months().parse::<f64>() {
-                if months > 0.0 {
-                    w2 / months
-                } else {
-                    0.0
-                }
-            } else {
-                0.0
-            }
-        } else {
-            0.0
-        }
-    });
-    
-    // Determine base used to qualify
-    let base_used_to_qualify = use_memo(move || {
-        match selected_base().as_str() {
-            "current" => calculated_income(),
-            "ytd" => ytd_monthly_avg(),
-            "w2_year1" => w2_year1_income(),
-            "w2_year2" => w2_year2_income(),
-            _ => 0.0,
-        }
-    });
+    // YTD months come from the pay stub dates
+    let ytd_period_end = use_signal(|| String::new());
+    let hire_date = use_signal(|| String::new());
+    // Zero until the period end is entered; the stub fields warn about it
+    let ytd_months_covered = move || ytd_months(&ytd_period_end(), &hire_date()).unwrap_or(0.0);
+
+    // Recomputed when the configured threshold arrives from settings
+    let result = use_memo(use_reactive!(|(decline_threshold,)| {
+        calculate_salary(&SalaryInput {
+            salary_amount: parse_amount(&salary_amount()),
+            pay_frequency: PayFrequency::from_key(&pay_frequency()),
+            ytd: PeriodEarnings::new(parse_amount(&ytd_salary()), ytd_months_covered()),
+            w2_year1: PeriodEarnings::new(parse_amount(&w2_year1()), parse_amount(&w2_year1_months())),
+            w2_year2: PeriodEarnings::new(parse_amount(&w2_year2()), parse_amount(&w2_year2_months())),
+            method: IncomeMethod::from_key(&selected_base()),
+            decline_threshold_percent: decline_threshold,
+        })
+    }));
+
+    // The selected figure and trend analysis are saved when focus leaves one of the section's fields
+    let client = use_context::<repository::Repository>();
+    let last_saved_trend = use_signal(|| None::<(f64, IncomeTrend)>);
+    let save_section_trend = move |_: FocusEvent| {
+        save_trend(client.clone(), borrower_id, "salary", result().qualifying_monthly, result().trend, last_saved_trend)
+    };
+
+    let calculated_income = move || result().current_monthly;
+    let ytd_monthly_avg = move || result().ytd_monthly;
+    let w2_year1_income = move || result().w2_year1_monthly;
+    let w2_year2_income = move || result().w2_year2_monthly;
+    let base_used_to_qualify = move || result().qualifying_monthly;
     
     // Helper to format money
     fn format_money(amount: f64) -> String {
@@ -102,6 +57,7 @@ pub fn Salary() -> Element {
     
     rsx! {
         div { class: "space-y-8",
+            onfocusout: save_section_trend,
             // Current Salary Section
             div { class: "bg-gradient-to-br from-purple-50 to-pink-50 p-6 rounded-xl shadow-md border-2 border-purple-200",
                 h3 { class: "text-xl font-bold text-gray-900 dark:text-gray-100 mb-6 flex items-center gap-2",
@@ -152,6 +108,7 @@ pub fn Salary() -> Element {
                     "Historical Income"
                 }
                 div { class: "space-y-4",
+                    PayStubDates { period_end: ytd_period_end, pay_frequency: pay_frequency, hire_date, show_frequency: false, has_ytd_earnings: !ytd_salary().trim().is_empty() }
                     // YTD Salary
                     div { class: "grid grid-cols-12 gap-4 items-end",
                         div { class: "col-span-4",
@@ -164,13 +121,12 @@ pub fn Salary() -> Element {
                                 oninput: move |evt: Event<FormData>| ytd_salary.set(evt.value()),
                             }
                         }
-                        div { class: "col-span-3",
-                            Input {
-                                label: "# Months",
-                                r#type: "number",
-                                name: "ytd_months",
-                                value: "{ytd_months}",
-                                oninput: move |evt: Event<FormData>| ytd_months.set(evt.value()),
+                        div { class: "col-span-3 flex flex-col",
+                            label { class: "block mb-2.5 text-sm font-semibold text-gray-900 dark:text-gray-100",
+                                "# Months"
+                            }
+                            div { class: "px-4 py-3 bg-gray-100 border-2 border-gray-300 rounded-lg text-gray-900 dark:text-gray-100 font-bold text-right",
+                                "{ytd_months_covered():.2}"
                             }
                         }
                         div { class: "col-span-5 flex flex-col",
@@ -244,6 +200,8 @@ pub fn Salary() -> Element {
                     }
                 }
             }
+            IncomeTrendPanel { trend: result().trend, selected_monthly: result().qualifying_monthly }
+
             // Base Used to Qualify Section
             div { class: "bg-gradient-to-br from-green-50 to-emerald-50 p-6 rounded-xl shadow-md border-2 border-green-200",
                 h3 { class: "text-xl font-bold text-gray-900 dark:text-gray-100 mb-6 flex items-center gap-2",
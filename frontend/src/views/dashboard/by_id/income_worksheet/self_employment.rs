@@ -0,0 +1,202 @@
+use dioxus::prelude::*;
+use shared::calc::self_employment::{BusinessType, LineItem, analyze_business, total_monthly_income};
+use shared::models::{SelfEmploymentBusiness, SelfEmploymentData, SelfEmploymentYear};
+
+// Helper to format money
+fn format_money(amount: f64) -> String {
+    format!("${:.2}", amount)
+}
+
+// Cash-flow analysis for self-employed borrowers. Unlike the other worksheet sections
+// this one is saved, because its monthly result feeds qualifying income on the options
+// template.
+#[component]
+pub fn SelfEmployment(borrower_id: i32) -> Element {
+    let client = use_context::<repository::Repository>();
+    let mut data = use_signal(SelfEmploymentData::default);
+
+    let client_clone = client.clone();
+    use_effect(move || {
+        let client = client_clone.clone();
+        spawn(async move {
+            match client.get_self_employment_data(borrower_id).await {
+                Ok(Some(saved)) => data.set(saved),
+                Ok(None) => data.set(SelfEmploymentData {
+                    businesses: vec![SelfEmploymentBusiness::default()],
+                }),
+                Err(e) => log::error!("Failed to load self-employment data: {:?}", e),
+            }
+        });
+    });
+
+    // Callback so the handlers below stay `Copy` inside the loops
+    let save = Callback::new(move |_: ()| {
+        let client = client.clone();
+        spawn(async move {
+            if let Err(e) = client.save_self_employment_data(borrower_id, data()).await {
+                log::error!("Failed to save self-employment data: {:?}", e);
+            }
+        });
+    });
+
+    let mut update_business = move |index: usize, field: &str, value: String| {
+        if let Some(business) = data.write().businesses.get_mut(index) {
+            match field {
+                "business_name" => business.business_name = value,
+                "business_type" => business.business_type = value,
+                "ownership_percent" => business.ownership_percent = value.parse().unwrap_or(0.0),
+                "prior_tax_year" => business.prior_year.tax_year = value.parse().unwrap_or(0),
+                "recent_tax_year" => business.recent_year.tax_year = value.parse().unwrap_or(0),
+                _ => {}
+            }
+        }
+        save.call(());
+    };
+
+    let mut update_line = move |index: usize, recent: bool, item: LineItem, value: String| {
+        if let Some(business) = data.write().businesses.get_mut(index) {
+            let year: &mut SelfEmploymentYear = if recent { &mut business.recent_year } else { &mut business.prior_year };
+            item.set_value(year, value.parse().unwrap_or(0.0));
+        }
+        save.call(());
+    };
+
+    let add_business = move |_| {
+        data.write().businesses.push(SelfEmploymentBusiness::default());
+        save.call(());
+    };
+
+    let mut remove_business = move |index: usize| {
+        data.write().businesses.remove(index);
+        save.call(());
+    };
+
+    rsx! {
+        div { class: "space-y-6",
+            for (index, business) in data().businesses.into_iter().enumerate() {
+                {
+                    let business_type = BusinessType::from_key(&business.business_type);
+                    let result = analyze_business(&business);
+                    rsx! {
+                        div { key: "{business.id}", class: "bg-gradient-to-br from-amber-50 to-orange-50 p-6 rounded-xl shadow-md border-2 border-amber-200",
+                            div { class: "grid grid-cols-1 md:grid-cols-3 gap-4 mb-6",
+                                div { class: "flex flex-col",
+                                    label { class: "block mb-2 text-sm font-semibold text-gray-900", "Business Name" }
+                                    input {
+                                        r#type: "text",
+                                        value: "{business.business_name}",
+                                        class: "bg-white border-2 border-gray-300 text-gray-900 text-sm rounded-lg px-4 py-3",
+                                        onchange: move |e: Event<FormData>| update_business(index, "business_name", e.value()),
+                                    }
+                                }
+                                div { class: "flex flex-col",
+                                    label { class: "block mb-2 text-sm font-semibold text-gray-900", "Tax Return" }
+                                    select {
+                                        value: "{business.business_type}",
+                                        class: "bg-white border-2 border-gray-300 text-gray-900 text-sm rounded-lg px-4 py-3 font-semibold",
+                                        onchange: move |e: Event<FormData>| update_business(index, "business_type", e.value()),
+                                        for option_type in BusinessType::ALL {
+                                            option { value: option_type.as_key(), "{option_type.label()}" }
+                                        }
+                                    }
+                                }
+                                if business_type.uses_ownership() {
+                                    div { class: "flex flex-col",
+                                        label { class: "block mb-2 text-sm font-semibold text-gray-900", "Ownership %" }
+                                        input {
+                                            r#type: "number",
+                                            step: "any",
+                                            value: "{business.ownership_percent}",
+                                            class: "bg-white border-2 border-gray-300 text-gray-900 text-sm rounded-lg px-4 py-3",
+                                            onchange: move |e: Event<FormData>| update_business(index, "ownership_percent", e.value()),
+                                        }
+                                    }
+                                }
+                            }
+
+                            table { class: "min-w-full table-auto border-collapse border border-gray-300 text-sm bg-white",
+                                thead {
+                                    tr { class: "bg-gray-50",
+                                        th { class: "border border-gray-300 px-3 py-2 text-left text-xs font-medium text-gray-500 uppercase", "Line" }
+                                        th { class: "border border-gray-300 px-3 py-2 text-left text-xs font-medium text-gray-500 uppercase",
+                                            "Prior Year "
+                                            input {
+                                                r#type: "number",
+                                                value: "{business.prior_year.tax_year}",
+                                                class: "w-20 px-1 py-1 border rounded",
+                                                onchange: move |e: Event<FormData>| update_business(index, "prior_tax_year", e.value()),
+                                            }
+                                        }
+                                        th { class: "border border-gray-300 px-3 py-2 text-left text-xs font-medium text-gray-500 uppercase",
+                                            "Recent Year "
+                                            input {
+                                                r#type: "number",
+                                                value: "{business.recent_year.tax_year}",
+                                                class: "w-20 px-1 py-1 border rounded",
+                                                onchange: move |e: Event<FormData>| update_business(index, "recent_tax_year", e.value()),
+                                            }
+                                        }
+                                    }
+                                }
+                                tbody {
+                                    for item in business_type.line_items() {
+                                        tr {
+                                            td { class: "border border-gray-300 px-3 py-2 text-gray-700",
+                                                if item.is_deduction() { "− " } else { "+ " }
+                                                "{item.label(business_type)}"
+                                            }
+                                            for (recent, year) in [(false, &business.prior_year), (true, &business.recent_year)] {
+                                                td { class: "border border-gray-300 px-3 py-1",
+                                                    input {
+                                                        r#type: "number",
+                                                        step: "any",
+                                                        value: "{item.value(year)}",
+                                                        class: "w-full px-2 py-1 border rounded",
+                                                        onchange: move |e: Event<FormData>| update_line(index, recent, item, e.value()),
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                    tr { class: "bg-amber-100 font-semibold",
+                                        td { class: "border border-gray-300 px-3 py-2", "Annual Cash Flow" }
+                                        td { class: "border border-gray-300 px-3 py-2", "{format_money(result.prior_year_annual)}" }
+                                        td { class: "border border-gray-300 px-3 py-2", "{format_money(result.recent_year_annual)}" }
+                                    }
+                                }
+                            }
+
+                            if result.declining {
+                                div { class: "mt-4 p-3 bg-red-50 border border-red-200 rounded-lg text-sm text-red-800",
+                                    "Cash flow declined {result.decline_percent:.1}% from the prior year. Only the recent year is used; document that the income has stabilized."
+                                }
+                            }
+
+                            div { class: "flex items-center justify-between mt-4",
+                                button {
+                                    class: "text-red-600 hover:text-red-800 text-sm",
+                                    onclick: move |_| remove_business(index),
+                                    "Remove Business"
+                                }
+                                div { class: "px-4 py-3 bg-amber-200 border-2 border-amber-400 rounded-lg text-gray-900 font-bold text-lg",
+                                    "Monthly: {format_money(result.qualifying_monthly)}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            div { class: "flex items-center justify-between",
+                button {
+                    class: "bg-blue-600 text-white px-4 py-2 rounded hover:bg-blue-700 text-sm",
+                    onclick: add_business,
+                    "Add Business"
+                }
+                div { class: "px-4 py-3 bg-green-100 border-2 border-green-400 rounded-lg text-gray-900 font-bold text-lg",
+                    "Self-Employment Income: {format_money(total_monthly_income(&data()))}/mo"
+                }
+            }
+        }
+    }
+}
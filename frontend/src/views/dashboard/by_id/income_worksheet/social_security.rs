@@ -1,8 +1,10 @@
 use dioxus::prelude::*;
 use crate::components::Input;
+use shared::calc::income::{calculate_social_security, parse_amount, SocialSecurityInput};
+use shared::calc::rule_set::IncomeRuleSet;
 
 #[component] 
-pub fn SocialSecurity() -> Element {
+pub fn SocialSecurity(rules: IncomeRuleSet) -> Element {
     // State management - With Documentation
     let mut annual_benefit = use_signal(|| String::new());
     let mut taxable_portion = use_signal(|| String::new());
@@ -13,75 +15,29 @@ pub fn SocialSecurity() -> Element {
     let mut total_annual_benefit = use_signal(|| String::new());
     let mut months_without_doc = use_signal(|| String::from("12"));
     
-    // Calculate With Documentation
-    let taxable_income = use_memo(move || {
-        if let Ok(taxable) = taxable_portion().parse::<f64>() {
-            taxable * 1.0 // x 100%
-        } else {
-            0.0
-        }
-    });
-    
-    let non_taxable_income = use_memo(move || {
-        if let Ok(non_tax) = non_taxable().parse::<f64>() {
-            non_tax * 1.25 // x 125%
-        } else {
-            0.0
-        }
-    });
-    
-    let total_income_with_doc = use_memo(move || {
-        taxable_income() + non_taxable_income()
-    });
-    
-    let monthly_income_with_doc = use_memo(move || {
-        if let Ok(months) = months_with_doc().parse::<f64>() {
-            if months > 0.0 {
-                total_income_with_doc() / months
-            } else {
-                0.0
-            }
-        } else {
-            0.0
-        }
-    });
-    
-    // Calculate Without Documentation
-    let annual_benefit_85 = use_memo(move || {
-        if let Ok(benefit) = total_annual_benefit().parse::<f64>() {
-            benefit * 0.85 // Annual Benefit x 85%
-        } else {
-            0.0
-        }
-    });
-    
-    let annual_benefit_15 = use_memo(move || {
-        if let Ok(benefit) = total_annual_benefit().parse::<f64>() {
-            benefit * 0.15 // Annual Benefit x 15%
-        } else {
-            0.0
-        }
-    });
-    
-    let gross_up_25 = use_memo(move || {
-        annual_benefit_15() * 1.25 // Gross up 25%
-    });
-    
-    let total_gross_up = use_memo(move || {
-        annual_benefit_85() + gross_up_25()
-    });
-    
-    let monthly_income_without_doc = use_memo(move || {
-        if let Ok(months) = months_without_doc().parse::<f64>() {
-            if months > 0.0 {
-                total_gross_up() / months
-            } else {
-                0.0
-            }
-        } else {
-            0.0
-        }
-    });
+    // Recomputed when the worksheet's rule set changes
+    let result = use_memo(use_reactive!(|(rules,)| {
+        calculate_social_security(&SocialSecurityInput {
+            taxable_portion: parse_amount(&taxable_portion()),
+            non_taxable_portion: parse_amount(&non_taxable()),
+            months_with_doc: parse_amount(&months_with_doc()),
+            total_annual_benefit: parse_amount(&total_annual_benefit()),
+            months_without_doc: parse_amount(&months_without_doc()),
+        }, &rules)
+    }));
+
+    // With Documentation
+    let taxable_income = move || result().taxable_income;
+    let non_taxable_income = move || result().non_taxable_grossed_up;
+    let total_income_with_doc = move || result().total_with_doc;
+    let monthly_income_with_doc = move || result().monthly_with_doc;
+
+    // Without Documentation
+    let annual_benefit_85 = move || result().taxable_share;
+    let annual_benefit_15 = move || result().non_taxable_share;
+    let gross_up_25 = move || result().non_taxable_share_grossed_up;
+    let total_gross_up = move || result().total_without_doc;
+    let monthly_income_without_doc = move || result().monthly_without_doc;
     
     // Helper to format money
     fn format_money(amount: f64) -> String {
@@ -155,7 +111,7 @@ pub fn SocialSecurity() -> Element {
                                 "Multiplier"
                             }
                             div { class: "px-4 py-3 bg-blue-100 border-2 border-blue-300 rounded-lg text-gray-900 dark:text-gray-100 font-semibold text-center",
-                                "× 125%"
+                                "× {rules.non_taxable_gross_up * 100.0:.0}%"
                             }
                         }
                         div { class: "col-span-6 flex flex-col",
@@ -217,33 +173,33 @@ pub fn SocialSecurity() -> Element {
                             }
                         }
                     }
-                    // 85% Calculation
+                    // Taxable share
                     div { class: "grid grid-cols-12 gap-4 items-end",
                         div { class: "col-span-6 flex flex-col",
                             label { class: "block mb-2.5 text-sm font-semibold text-gray-900 dark:text-gray-100",
-                                "Annual Benefit × 85%"
+                                "Annual Benefit × {rules.social_security_taxable_share * 100.0:.0}%"
                             }
                             div { class: "px-4 py-3 bg-white border-2 border-gray-300 rounded-lg text-gray-900 dark:text-gray-100 font-bold text-right",
                                 "{format_money(annual_benefit_85())}"
                             }
                         }
                     }
-                    // 15% Calculation
+                    // Non-taxable share
                     div { class: "grid grid-cols-12 gap-4 items-end",
                         div { class: "col-span-6 flex flex-col",
                             label { class: "block mb-2.5 text-sm font-semibold text-gray-900 dark:text-gray-100",
-                                "Annual Benefit × 15%"
+                                "Annual Benefit × {(1.0 - rules.social_security_taxable_share) * 100.0:.0}%"
                             }
                             div { class: "px-4 py-3 bg-white border-2 border-gray-300 rounded-lg text-gray-900 dark:text-gray-100 font-bold text-right",
                                 "{format_money(annual_benefit_15())}"
                             }
                         }
                     }
-                    // Gross up 25%
+                    // Gross up
                     div { class: "grid grid-cols-12 gap-4 items-end",
                         div { class: "col-span-6 flex flex-col",
                             label { class: "block mb-2.5 text-sm font-semibold text-gray-900 dark:text-gray-100",
-                                "Gross up 25%"
+                                "Gross up {(rules.non_taxable_gross_up - 1.0) * 100.0:.0}%"
                             }
                             div { class: "px-4 py-3 bg-green-100 border-2 border-green-300 rounded-lg text-gray-900 dark:text-gray-100 font-bold text-right",
                                 "{format_money(gross_up_25())}"
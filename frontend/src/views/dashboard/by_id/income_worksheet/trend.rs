@@ -0,0 +1,96 @@
+use dioxus::prelude::*;
+use shared::calc::income_trend::IncomeTrend;
+
+// Helper to format money
+fn format_money(amount: f64) -> String {
+    format!("${:.2}", amount)
+}
+
+fn format_change(change: Option<f64>) -> String {
+    match change {
+        Some(change) => format!("{:+.1}%", change),
+        None => "—".to_string(),
+    }
+}
+
+/// Saves a section's trend analysis, with the figure from its selected method that DTI
+/// qualifies with, so the reasoning stays with the worksheet. Called when focus leaves
+/// the section's fields; sections without income, and results that have not changed
+/// since `last_saved`, are skipped.
+pub fn save_trend(
+    client: repository::Repository,
+    borrower_id: i32,
+    section: &'static str,
+    qualifying_monthly: f64,
+    trend: IncomeTrend,
+    mut last_saved: Signal<Option<(f64, IncomeTrend)>>,
+) {
+    if !trend.has_income() {
+        return;
+    }
+    let saved = (qualifying_monthly, trend.clone());
+    if last_saved.peek().as_ref() == Some(&saved) {
+        return;
+    }
+    last_saved.set(Some(saved));
+    spawn(async move {
+        if let Err(e) = client.save_income_trend(borrower_id, section, qualifying_monthly, &trend).await {
+            log::error!("Failed to save {} income trend: {:?}", section, e);
+            last_saved.set(None);
+        }
+    });
+}
+
+// Year-over-year comparison shown under a W-2 section's calculation methods.
+// `selected_monthly` is the figure from the chosen method, shown beside the recommendation.
+#[component]
+pub fn IncomeTrendPanel(trend: IncomeTrend, selected_monthly: f64) -> Element {
+    let (panel_class, heading) = if trend.significant_decline {
+        ("bg-red-50 border-red-300", "Declining Income")
+    } else if trend.declining {
+        ("bg-yellow-50 border-yellow-300", "Declining Income (within threshold)")
+    } else {
+        ("bg-blue-50 border-blue-300", "Income Trend")
+    };
+
+    rsx! {
+        div { class: "p-6 rounded-xl shadow-md border-2 {panel_class}",
+            h3 { class: "text-xl font-bold text-gray-900 dark:text-gray-100 mb-4 flex items-center gap-2",
+                span { "📉" }
+                "{heading}"
+            }
+            div { class: "grid grid-cols-3 gap-4 text-sm mb-4",
+                div { class: "flex flex-col",
+                    span { class: "text-gray-600", "Second Prior Year" }
+                    span { class: "font-bold text-gray-900", "{format_money(trend.second_prior_year_monthly)}/mo" }
+                }
+                div { class: "flex flex-col",
+                    span { class: "text-gray-600", "Prior Year ({format_change(trend.prior_year_change_percent)})" }
+                    span { class: "font-bold text-gray-900", "{format_money(trend.prior_year_monthly)}/mo" }
+                }
+                div { class: "flex flex-col",
+                    span { class: "text-gray-600", "YTD ({format_change(trend.ytd_change_percent)})" }
+                    span { class: "font-bold text-gray-900", "{format_money(trend.ytd_monthly)}/mo" }
+                }
+            }
+            if let Some(recommended) = trend.recommended_monthly {
+                div { class: "grid grid-cols-2 gap-4 text-sm mb-2",
+                    div { class: "flex flex-col",
+                        span { class: "text-gray-600", "Selected Method" }
+                        span { class: "font-bold text-gray-900", "{format_money(selected_monthly)}/mo" }
+                    }
+                    div { class: "flex flex-col",
+                        span { class: "text-gray-600", "Recommended (threshold {trend.decline_threshold_percent:.1}%)" }
+                        span { class: "font-bold text-gray-900", "{format_money(recommended)}/mo" }
+                    }
+                }
+                if (selected_monthly - recommended).abs() >= 0.005 {
+                    p { class: "text-sm font-semibold text-red-700 mb-2",
+                        "The selected method differs from the recommendation; choose the method that matches it or document why it does not apply."
+                    }
+                }
+            }
+            p { class: "text-sm text-gray-700", "{trend.reasoning}" }
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use dioxus::prelude::*;
+use shared::calc::savings;
 use shared::models::BenefitToBorrowerData;
 
 #[component]
@@ -11,30 +12,10 @@ pub fn BenefitToBorrowerSection(data: BenefitToBorrowerData, loan_purpose: Strin
     });
 
     // Calculate existing mortgage payment
-    let existing_mortgage_payment = use_memo(move || {
-        let data = local_data();
-        let mut total = data.existing_pi;
-
-        if data.escrow_taxes { total += data.existing_taxes; }
-        if data.escrow_insurance { total += data.existing_insurance; }
-        if data.escrow_flood_insurance { total += data.existing_flood_insurance; }
-
-        total += data.existing_pmi + data.existing_hoa;
-        total
-    });
+    let existing_mortgage_payment = use_memo(move || savings::existing_mortgage_payment(&local_data()));
 
     // Calculate proposed mortgage payment
-    let proposed_mortgage_payment = use_memo(move || {
-        let data = local_data();
-        let mut total = data.proposed_pi;
-
-        if data.escrow_taxes { total += data.proposed_taxes; }
-        if data.escrow_insurance { total += data.proposed_insurance; }
-        if data.escrow_flood_insurance { total += data.proposed_flood_insurance; }
-
-        total += data.proposed_pmi + data.proposed_hoa;
-        total
-    });
+    let proposed_mortgage_payment = use_memo(move || savings::proposed_mortgage_payment(&local_data()));
 
     // Calculate overage/shortage (benefit to borrower)
     let overage_shortage = use_memo({
@@ -61,18 +42,10 @@ pub fn BenefitToBorrowerSection(data: BenefitToBorrowerData, loan_purpose: Strin
     });
 
     // Calculate existing total obligations
-    let existing_total_obligations = use_memo(move || {
-        let data = local_data();
-        data.existing_pi + data.existing_taxes + data.existing_insurance +
-        data.existing_flood_insurance + data.existing_pmi + data.existing_hoa
-    });
+    let existing_total_obligations = use_memo(move || savings::existing_total_obligations(&local_data()));
 
     // Calculate proposed total obligations
-    let proposed_total_obligations = use_memo(move || {
-        let data = local_data();
-        data.proposed_pi + data.proposed_taxes + data.proposed_insurance +
-        data.proposed_flood_insurance + data.proposed_pmi + data.proposed_hoa
-    });
+    let proposed_total_obligations = use_memo(move || savings::proposed_total_obligations(&local_data()));
 
     // Generic update function for numeric fields
     let mut update_field = move |field_name: &str, value: String| {
@@ -106,6 +79,8 @@ pub fn BenefitToBorrowerSection(data: BenefitToBorrowerData, loan_purpose: Strin
             "escrow_taxes" => data.escrow_taxes = checked,
             "escrow_insurance" => data.escrow_insurance = checked,
             "escrow_flood_insurance" => data.escrow_flood_insurance = checked,
+            "existing_pi_override" => data.existing_pi_override = checked,
+            "proposed_pi_override" => data.proposed_pi_override = checked,
             _ => {}
         }
         local_data.set(data.clone());
@@ -142,12 +117,24 @@ pub fn BenefitToBorrowerSection(data: BenefitToBorrowerData, loan_purpose: Strin
                             if loan_purpose != "purchase" {
                                 td { class: "border border-gray-300 px-2 sm:px-4 py-2",
                                     input {
-                                        r#type: "text",
+                                        r#type: "number",
                                         id: "existingPI",
                                         name: "existingPI",
-                                        value: "{local_data().existing_pi}",
-                                        readonly: true,
-                                        class: "w-full px-1 sm:px-2 py-1 border rounded bg-gray-50 text-sm",
+                                        value: format!("{:.2}", local_data().existing_pi),
+                                        readonly: !local_data().existing_pi_override,
+                                        oninput: move |e: Event<FormData>| update_field("existing_pi", e.value()),
+                                        class: if local_data().existing_pi_override { "w-full px-1 sm:px-2 py-1 border rounded text-sm" } else { "w-full px-1 sm:px-2 py-1 border rounded bg-gray-50 text-sm" },
+                                    }
+                                    label { class: "flex items-center gap-1 mt-1 text-xs text-gray-600",
+                                        input {
+                                            r#type: "checkbox",
+                                            id: "existingPIOverride",
+                                            name: "existingPIOverride",
+                                            checked: "{local_data().existing_pi_override}",
+                                            onchange: move |e| update_checkbox("existing_pi_override", e.checked()),
+                                            class: "w-3 h-3",
+                                        }
+                                        "Override"
                                     }
                                 }
                             }
@@ -156,9 +143,21 @@ pub fn BenefitToBorrowerSection(data: BenefitToBorrowerData, loan_purpose: Strin
                                     r#type: "number",
                                     id: "proposedPI",
                                     name: "proposedPI",
-                                    value: "{local_data().proposed_pi}",
+                                    value: format!("{:.2}", local_data().proposed_pi),
+                                    readonly: !local_data().proposed_pi_override,
                                     oninput: move |e: Event<FormData>| update_field("proposed_pi", e.value()),
-                                    class: "w-full px-2 py-1 border rounded",
+                                    class: if local_data().proposed_pi_override { "w-full px-2 py-1 border rounded" } else { "w-full px-2 py-1 border rounded bg-gray-50" },
+                                }
+                                label { class: "flex items-center gap-1 mt-1 text-xs text-gray-600",
+                                    input {
+                                        r#type: "checkbox",
+                                        id: "proposedPIOverride",
+                                        name: "proposedPIOverride",
+                                        checked: "{local_data().proposed_pi_override}",
+                                        onchange: move |e| update_checkbox("proposed_pi_override", e.checked()),
+                                        class: "w-3 h-3",
+                                    }
+                                    "Override"
                                 }
                             }
                             td { class: "border border-gray-300 px-4 py-2", "" }
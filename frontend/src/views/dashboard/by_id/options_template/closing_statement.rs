@@ -0,0 +1,79 @@
+use dioxus::prelude::*;
+use shared::calc::cash_to_close::ClosingStatement;
+
+// Helper to format money
+fn format_money(amount: f64) -> String {
+    if amount < 0.0 {
+        format!("-${:.2}", -amount)
+    } else {
+        format!("${:.2}", amount)
+    }
+}
+
+// The closing statement is worked out by `shared::calc::cash_to_close` from the fee,
+// pricing, payoff and consumer debt sections, so this section only displays it.
+#[component]
+pub fn ClosingStatementSection(data: ClosingStatement) -> Element {
+    let rows = [
+        ("Purchase Price", data.purchase_price),
+        ("Mortgage Payoffs", data.mortgage_payoffs),
+        ("Debts Paid at Closing", data.debt_payoffs),
+        ("Lender and Third-Party Fees", data.loan_fees),
+        ("Title Fees", data.title_fees),
+        ("Discount Points", data.discount_points),
+        ("Prepaids and Escrows", data.prepaids_escrows),
+        ("Lender Credit", -data.lender_credit),
+        ("Financed Premium", data.financed_premium),
+        ("New Loan Amount", -data.loan_amount),
+    ];
+    let (total_label, total) = if data.cash_to_close < 0.0 {
+        ("Cash Back to Borrower", data.cash_back())
+    } else {
+        ("Cash to Close", data.cash_to_close)
+    };
+    let (status_class, status_text) = if data.within_purpose {
+        ("px-2 py-1 rounded text-sm font-semibold bg-green-50 text-green-800", "Fits Purpose")
+    } else {
+        ("px-2 py-1 rounded text-sm font-semibold bg-red-50 text-red-800", "Check Purpose")
+    };
+
+    rsx! {
+        div { class: "bg-white p-4 sm:p-6 rounded-lg shadow-md mb-6",
+            div { class: "flex items-center justify-between mb-4",
+                h4 { class: "text-lg font-semibold text-black", "Closing Statement" }
+                span { class: status_class, "{status_text}" }
+            }
+
+            div { class: "space-y-1 text-sm",
+                for (label, amount) in rows {
+                    if amount != 0.0 {
+                        div { class: "flex justify-between",
+                            span { class: "text-gray-600", "{label}" }
+                            span { class: "font-medium text-gray-900", "{format_money(amount)}" }
+                        }
+                    }
+                }
+                div { class: "flex justify-between border-t border-gray-300 pt-2 mt-2",
+                    span { class: "text-gray-600", "Total Closing Costs" }
+                    span { class: "font-medium text-gray-900", "{format_money(data.total_closing_costs)}" }
+                }
+                div { class: "flex justify-between",
+                    span { class: "font-semibold text-gray-900", "{total_label}" }
+                    span { class: "font-bold text-gray-900", "{format_money(total)}" }
+                }
+                if let Some(limit) = data.max_cash_back {
+                    div { class: "flex justify-between",
+                        span { class: "text-gray-600", "Most Cash Back Allowed" }
+                        span { class: "font-medium text-gray-900", "{format_money(limit)}" }
+                    }
+                }
+            }
+
+            for warning in data.warnings.iter() {
+                div { class: "mt-4 p-3 bg-yellow-50 border border-yellow-300 rounded-lg text-sm text-gray-900",
+                    "{warning}"
+                }
+            }
+        }
+    }
+}
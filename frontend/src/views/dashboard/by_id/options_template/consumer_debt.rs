@@ -1,9 +1,22 @@
 use dioxus::prelude::*;
-use shared::models::{ConsumerDebtData, ConsumerDebtItemData};
+use shared::calc::consumer_debt::evaluate_debts;
+use shared::models::{ConsumerDebtData, ConsumerDebtItemData, LoanType};
 
+// `loan_type` and `qualifying_income` select the agency rules whose notes are shown
+// under each debt
 #[component]
-pub fn ConsumerDebtSection(data: ConsumerDebtData, on_change: EventHandler<ConsumerDebtData>) -> Element {
+pub fn ConsumerDebtSection(
+    data: ConsumerDebtData,
+    loan_type: String,
+    qualifying_income: f64,
+    on_change: EventHandler<ConsumerDebtData>,
+) -> Element {
     let mut local_data = use_signal(|| data.clone());
+    let adjustments = evaluate_debts(
+        &local_data().consumer_debts,
+        LoanType::from_key(&loan_type).as_ref(),
+        qualifying_income,
+    );
 
     // Update local data when prop changes
     use_effect(move || {
@@ -38,6 +51,7 @@ pub fn ConsumerDebtSection(data: ConsumerDebtData, on_change: EventHandler<Consu
                     "interest_rate" => debt.interest_rate = value.parse().ok(),
                     "omit_from_dti" => debt.omit_from_dti = value == "true",
                     "pay_off_at_closing" => debt.pay_off_at_closing = value == "true",
+                    "student_loan_status" => debt.student_loan_status = value,
                     _ => {}
                 }
             }
@@ -58,6 +72,9 @@ pub fn ConsumerDebtSection(data: ConsumerDebtData, on_change: EventHandler<Consu
                             th { class: "border border-gray-300 px-2 sm:px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase",
                                 "Type"
                             }
+                            th { class: "border border-gray-300 px-2 sm:px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase",
+                                "Student Loan"
+                            }
                             th { class: "border border-gray-300 px-2 sm:px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase",
                                 "Balance"
                             }
@@ -82,7 +99,7 @@ pub fn ConsumerDebtSection(data: ConsumerDebtData, on_change: EventHandler<Consu
                         }
                     }
                     tbody {
-                        for (index , debt) in local_data().consumer_debts.iter().enumerate() {
+                        for (index , (debt , adjustment)) in local_data().consumer_debts.iter().zip(adjustments).enumerate() {
                             tr {
                                 td { class: "border border-gray-300 px-2 sm:px-4 py-2",
                                     input {
@@ -106,6 +123,18 @@ pub fn ConsumerDebtSection(data: ConsumerDebtData, on_change: EventHandler<Consu
                                         option { value: "Lease", "Lease" }
                                     }
                                 }
+                                td { class: "border border-gray-300 px-2 sm:px-4 py-2",
+                                    select {
+                                        name: "studentLoanStatus",
+                                        value: "{debt.student_loan_status}",
+                                        class: "w-full px-1 sm:px-2 py-1 border rounded text-sm",
+                                        onchange: move |e| update_debt(index, "student_loan_status".to_string(), e.value()),
+                                        option { value: "", "No" }
+                                        option { value: "repayment", "Repayment" }
+                                        option { value: "deferred", "Deferred" }
+                                        option { value: "idr", "Income-driven" }
+                                    }
+                                }
                                 td { class: "border border-gray-300 px-2 sm:px-4 py-2",
                                     input {
                                         r#type: "number",
@@ -173,6 +202,20 @@ pub fn ConsumerDebtSection(data: ConsumerDebtData, on_change: EventHandler<Consu
                                     }
                                 }
                             }
+                            if !adjustment.notes.is_empty() {
+                                tr {
+                                    td {
+                                        colspan: "10",
+                                        class: if adjustment.omit_suggested { "border border-gray-300 px-2 sm:px-4 py-2 text-xs bg-yellow-50 text-yellow-800" } else { "border border-gray-300 px-2 sm:px-4 py-2 text-xs bg-blue-50 text-blue-800" },
+                                        if adjustment.qualifying_payment != debt.monthly_payment {
+                                            div { class: "font-semibold", "Qualifying payment: ${adjustment.qualifying_payment:.2}" }
+                                        }
+                                        for note in adjustment.notes.iter() {
+                                            div { "{note}" }
+                                        }
+                                    }
+                                }
+                            }
                         }
                     }
                 }
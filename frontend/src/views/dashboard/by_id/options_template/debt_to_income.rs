@@ -0,0 +1,88 @@
+use dioxus::prelude::*;
+use shared::models::DebtToIncomeData;
+
+fn format_limit(max: Option<f64>) -> String {
+    max.map_or("No limit".to_string(), |max| format!("{max:.2}%"))
+}
+
+// Ratios are computed by `shared::calc::dti` on every recalculation; the only input
+// here is whether compensating factors have been documented.
+#[component]
+pub fn DebtToIncomeSection(data: DebtToIncomeData, on_change: EventHandler<DebtToIncomeData>) -> Element {
+    let (status_class, status_text) = if data.within_limits {
+        ("px-2 py-1 rounded text-sm font-semibold bg-green-50 text-green-800", "Within limits")
+    } else if data.qualifying_income <= 0.0 {
+        ("px-2 py-1 rounded text-sm font-semibold bg-gray-100 text-gray-700", "No income")
+    } else {
+        ("px-2 py-1 rounded text-sm font-semibold bg-red-50 text-red-800", "Over limit")
+    };
+
+    let ratios = [
+        ("Front-End DTI", "Housing ÷ income", data.front_end_ratio, data.max_front_end_ratio),
+        ("Back-End DTI", "(Housing + debts) ÷ income", data.back_end_ratio, data.max_back_end_ratio),
+    ];
+
+    rsx! {
+        div { class: "bg-white p-4 sm:p-6 rounded-lg shadow-md mb-6",
+            div { class: "flex items-center justify-between mb-4",
+                h4 { class: "text-lg font-semibold text-black", "Debt to Income" }
+                span { class: status_class, "{status_text}" }
+            }
+
+            div { class: "grid grid-cols-1 sm:grid-cols-3 gap-4 mb-4 text-sm",
+                div { class: "p-3 bg-gray-50 rounded-lg",
+                    div { class: "text-gray-600", "Qualifying Income" }
+                    div { class: "font-semibold text-gray-900", "${data.qualifying_income:.2}/mo" }
+                }
+                div { class: "p-3 bg-gray-50 rounded-lg",
+                    div { class: "text-gray-600", "Housing Payment" }
+                    div { class: "font-semibold text-gray-900", "${data.housing_payment:.2}/mo" }
+                }
+                div { class: "p-3 bg-gray-50 rounded-lg",
+                    div { class: "text-gray-600", "Consumer Debts" }
+                    div { class: "font-semibold text-gray-900", "${data.monthly_debts:.2}/mo" }
+                }
+            }
+
+            div { class: "grid grid-cols-1 sm:grid-cols-2 gap-4 mb-4",
+                for (label, formula, actual, max) in ratios {
+                    div { key: "{label}", class: "p-4 border border-gray-200 rounded-lg",
+                        div { class: "text-sm font-medium text-gray-700", "{label}" }
+                        div {
+                            class: if max.is_some_and(|max| actual > max) { "text-3xl font-bold text-red-600" } else { "text-3xl font-bold text-gray-900" },
+                            "{actual:.2}%"
+                        }
+                        div { class: "text-xs text-gray-500", "{formula} · Max {format_limit(max)}" }
+                    }
+                }
+            }
+
+            if !data.income_warnings.is_empty() {
+                div { class: "mb-4 p-3 bg-yellow-50 border border-yellow-200 rounded-lg text-sm text-yellow-800",
+                    div { class: "font-semibold mb-1", "Declining income" }
+                    for warning in data.income_warnings.iter() {
+                        div { key: "{warning}", "{warning}" }
+                    }
+                }
+            }
+
+            label { class: "flex items-center text-sm text-gray-700",
+                input {
+                    r#type: "checkbox",
+                    id: "compensatingFactors",
+                    checked: "{data.compensating_factors}",
+                    class: "mr-2 h-4 w-4 text-blue-600 border-gray-300 rounded focus:ring-blue-500",
+                    onchange: {
+                        let data = data.clone();
+                        move |e: Event<FormData>| {
+                            let mut updated = data.clone();
+                            updated.compensating_factors = e.checked();
+                            on_change.call(updated);
+                        }
+                    },
+                }
+                "Compensating factors documented (allows the program's higher limits)"
+            }
+        }
+    }
+}
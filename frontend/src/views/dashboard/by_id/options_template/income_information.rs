@@ -1,20 +1,25 @@
 use dioxus::prelude::*;
+use shared::calc::bank_statement;
+use shared::calc::dti::{qualifying_income, w2_job_monthly_income};
+use shared::calc::self_employment::analyze_business;
+use shared::calc::rental::analyze_property;
+use shared::calc::rule_set::IncomeRuleSet;
 use shared::models::{IncomeInformationData, IncomeSource};
 
 #[component]
-pub fn IncomeInformationSection(data: IncomeInformationData, on_change: EventHandler<IncomeInformationData>) -> Element {
+pub fn IncomeInformationSection(
+    data: IncomeInformationData,
+    rules: IncomeRuleSet,
+    on_change: EventHandler<IncomeInformationData>,
+) -> Element {
     let mut local_data = use_signal(|| data.clone());
 
-    // Calculate income from W2 jobs if available
-    let w2_income_sources = use_memo(move || {
-        if let Some(w2_data) = &local_data().w2_jobs_data {
+    // Income sources from W2 jobs and the self-employment worksheet; `rules` follows the
+    // loan type, so rental income is recomputed when it changes
+    let income_sources = use_memo(use_reactive!(|(rules,)| {
+        let mut sources = if let Some(w2_data) = &local_data().w2_jobs_data {
             w2_data.jobs.iter().enumerate().map(|(index, job)| {
-                let annual_salary = job.annual_salary.parse::<f64>().unwrap_or(0.0);
-                let monthly_salary = annual_salary / 12.0;
-                let commission = job.commission_monthly.parse::<f64>().unwrap_or(0.0);
-                let bonus = job.bonus_monthly.parse::<f64>().unwrap_or(0.0);
-                let overtime = job.overtime_monthly.parse::<f64>().unwrap_or(0.0);
-                let total_monthly = monthly_salary + commission + bonus + overtime;
+                let total_monthly = w2_job_monthly_income(job);
 
                 IncomeSource {
                     id: format!("w2_job_{}", index),
@@ -31,15 +36,62 @@ pub fn IncomeInformationSection(data: IncomeInformationData, on_change: EventHan
         } else {
             // No fallback mock data - show empty if no W2 data available
             Vec::new()
+        };
+
+        // Businesses from the self-employment worksheet
+        if let Some(self_employment) = &local_data().self_employment_data {
+            sources.extend(self_employment.businesses.iter().enumerate().map(|(index, business)| IncomeSource {
+                id: format!("self_employment_{}", index),
+                name: if business.business_name.is_empty() {
+                    format!("Business #{}", index + 1)
+                } else {
+                    business.business_name.clone()
+                },
+                monthly_amount: analyze_business(business).qualifying_monthly,
+                category: "Self-Employment".to_string(),
+                included_in_dti: true,
+            }));
         }
-    });
 
-    let total_monthly_income = use_memo(move || {
-        w2_income_sources().iter()
-            .filter(|source| source.included_in_dti)
-            .map(|source| source.monthly_amount)
-            .sum::<f64>()
-    });
+        // Accounts from the bank-statement worksheet
+        if let Some(bank_statement) = &local_data().bank_statement_data {
+            sources.extend(bank_statement.accounts.iter().enumerate().map(|(index, account)| IncomeSource {
+                id: format!("bank_statement_{}", index),
+                name: if account.account_name.is_empty() {
+                    format!("Bank Statement Account #{}", index + 1)
+                } else {
+                    account.account_name.clone()
+                },
+                monthly_amount: bank_statement::analyze_account(account).qualifying_monthly,
+                category: "Bank Statement".to_string(),
+                included_in_dti: true,
+            }));
+        }
+
+        // Rental properties netting income; losses are counted with the debts instead
+        if let Some(rental) = &local_data().rental_income_data {
+            sources.extend(rental.properties.iter().enumerate().filter_map(|(index, property)| {
+                let result = analyze_property(property, &rules);
+                (result.monthly_income > 0.0).then(|| IncomeSource {
+                    id: format!("rental_{}", index),
+                    name: if property.address.is_empty() {
+                        format!("Rental Property #{}", index + 1)
+                    } else {
+                        property.address.clone()
+                    },
+                    monthly_amount: result.monthly_income,
+                    category: "Rental".to_string(),
+                    included_in_dti: true,
+                })
+            }));
+        }
+
+        sources
+    }));
+
+    // The same total DTI qualifies with, so general income and the entered amounts are
+    // counted the way `shared::calc::dti` counts them
+    let total_monthly_income = use_memo(use_reactive!(|(rules,)| qualifying_income(&local_data(), &rules).total()));
 
     // Update local data when prop changes
     use_effect(move || {
@@ -67,7 +119,7 @@ pub fn IncomeInformationSection(data: IncomeInformationData, on_change: EventHan
         div { class: "bg-white dark:bg-gray-800 p-6 rounded-lg shadow-md mb-6",
             h4 { class: "text-lg font-semibold mb-4 text-black", "Income Information" }
 
-            // Income Sources from W2 Jobs
+            // Income Sources from W2 Jobs and self-employment
             div { class: "mb-6",
                 h5 { class: "text-md font-medium mb-3 text-gray-800 flex items-center gap-2",
                     "Income Sources"
@@ -77,7 +129,7 @@ pub fn IncomeInformationSection(data: IncomeInformationData, on_change: EventHan
                         }
                     }
                 }
-                if w2_income_sources().is_empty() {
+                if income_sources().is_empty() {
                     div { class: "text-center py-8 text-gray-500 dark:text-gray-400 border border-gray-200 dark:border-gray-600 rounded-lg bg-gray-50 dark:bg-gray-700",
                         div { class: "text-lg mb-2", "📊" }
                         div { class: "font-medium mb-1", "No Income Sources Found" }
@@ -87,7 +139,7 @@ pub fn IncomeInformationSection(data: IncomeInformationData, on_change: EventHan
                     }
                 } else {
                     div { class: "space-y-3 max-h-64 overflow-y-auto border border-gray-200 dark:border-gray-600 rounded-lg p-4 bg-gray-50 dark:bg-gray-700",
-                        for income in w2_income_sources() {
+                        for income in income_sources() {
                             div { class: "flex items-center justify-between p-3 bg-white dark:bg-gray-800 rounded-lg border border-gray-200 dark:border-gray-600",
                                 div { class: "flex items-center space-x-3",
                                     div { class: "w-4 h-4 rounded-full bg-green-500 flex items-center justify-center",
@@ -155,11 +207,11 @@ pub fn IncomeInformationSection(data: IncomeInformationData, on_change: EventHan
                         h5 { class: "text-md font-semibold text-gray-800",
                             "Total Monthly Qualifying Income"
                         }
-                        p { class: "text-sm text-gray-600", "From W-2 jobs + additional income" }
+                        p { class: "text-sm text-gray-600", "From the income worksheets, or the amounts entered above when they have none" }
                     }
                     div { class: "text-right",
                         div { class: "text-2xl font-bold text-green-700",
-                            "${total_monthly_income() + local_data().borrower_monthly_income + local_data().coborrower_monthly_income:.0}"
+                            "${total_monthly_income():.0}"
                         }
                         div { class: "text-sm text-gray-600", "per month" }
                     }
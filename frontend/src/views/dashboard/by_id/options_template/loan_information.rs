@@ -20,6 +20,8 @@ pub fn LoanInformationSection(data: LoanInformationData, on_change: EventHandler
             "term_months" => new_data.term_months = value.parse().unwrap_or(360),
             "purpose" => new_data.purpose = value,
             "appraisal_waiver" => new_data.appraisal_waiver = value == "true",
+            "credit_score" => new_data.credit_score = value.parse().unwrap_or(0),
+            "agency" => new_data.agency = value,
             _ => {}
         }
         local_data.set(new_data.clone());
@@ -70,10 +72,25 @@ pub fn LoanInformationSection(data: LoanInformationData, on_change: EventHandler
                         option { value: "cnv", "CNV" }
                         option { value: "fha", "FHA" }
                         option { value: "va", "VA" }
+                        option { value: "usda", "USDA" }
                         option { value: "nonQM", "Non-QM" }
                     }
                 }
 
+                // Agency, which picks the income rules for conventional loans
+                if local_data().loan_type == "cnv" {
+                    div { class: "bg-gray-50 p-3 rounded-lg",
+                        label { class: "block text-sm font-medium text-gray-700 mb-2", "Agency" }
+                        select {
+                            value: "{local_data().agency}",
+                            class: "w-full px-3 py-2 border border-gray-300 rounded-md focus:ring-2 focus:ring-blue-500 focus:border-blue-500",
+                            onchange: move |evt: Event<FormData>| update_data("agency", evt.value()),
+                            option { value: "fannie", "Fannie Mae" }
+                            option { value: "freddie", "Freddie Mac" }
+                        }
+                    }
+                }
+
                 // Term (months)
                 div { class: "bg-gray-50 p-3 rounded-lg",
                     label { class: "block text-sm font-medium text-gray-700 mb-2", "Term (months)" }
@@ -99,6 +116,17 @@ pub fn LoanInformationSection(data: LoanInformationData, on_change: EventHandler
                     }
                 }
 
+                // Credit Score
+                div { class: "bg-gray-50 p-3 rounded-lg",
+                    label { class: "block text-sm font-medium text-gray-700 mb-2", "Credit Score" }
+                    input {
+                        r#type: "number",
+                        value: "{local_data().credit_score}",
+                        class: "w-full px-3 py-2 border border-gray-300 rounded-md focus:ring-2 focus:ring-blue-500 focus:border-blue-500",
+                        oninput: move |evt: Event<FormData>| update_data("credit_score", evt.value()),
+                    }
+                }
+
                 // Appraisal Waiver
                 div { class: "bg-gray-50 p-3 rounded-lg",
                     label { class: "flex items-center text-sm font-medium text-gray-700",
@@ -130,12 +158,18 @@ pub fn LoanInformationSection(data: LoanInformationData, on_change: EventHandler
                             th { class: "border border-gray-300 px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase",
                                 "Loan"
                             }
+                            th { class: "border border-gray-300 px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase",
+                                "Agency"
+                            }
                             th { class: "border border-gray-300 px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase",
                                 "Term (mo)"
                             }
                             th { class: "border border-gray-300 px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase",
                                 "Purpose"
                             }
+                            th { class: "border border-gray-300 px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase",
+                                "Credit Score"
+                            }
                             th { class: "border border-gray-300 px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase",
                                 "Appraisal Waiver"
                             }
@@ -173,9 +207,23 @@ pub fn LoanInformationSection(data: LoanInformationData, on_change: EventHandler
                                     option { value: "cnv", "CNV" }
                                     option { value: "fha", "FHA" }
                                     option { value: "va", "VA" }
+                                    option { value: "usda", "USDA" }
                                     option { value: "nonQM", "Non-QM" }
                                 }
                             }
+                            td { class: "border border-gray-300 px-4 py-2",
+                                if local_data().loan_type == "cnv" {
+                                    select {
+                                        value: "{local_data().agency}",
+                                        class: "w-full px-2 py-1 border rounded",
+                                        onchange: move |evt: Event<FormData>| update_data("agency", evt.value()),
+                                        option { value: "fannie", "Fannie Mae" }
+                                        option { value: "freddie", "Freddie Mac" }
+                                    }
+                                } else {
+                                    span { class: "text-gray-500", "—" }
+                                }
+                            }
                             td { class: "border border-gray-300 px-4 py-2",
                                 input {
                                     r#type: "number",
@@ -195,6 +243,14 @@ pub fn LoanInformationSection(data: LoanInformationData, on_change: EventHandler
                                     option { value: "irrrl", "IRRRL/Streamline" }
                                 }
                             }
+                            td { class: "border border-gray-300 px-4 py-2",
+                                input {
+                                    r#type: "number",
+                                    value: "{local_data().credit_score}",
+                                    class: "w-full px-2 py-1 border rounded",
+                                    oninput: move |evt: Event<FormData>| update_data("credit_score", evt.value()),
+                                }
+                            }
                             td { class: "border border-gray-300 px-4 py-2 text-center",
                                 input {
                                     r#type: "checkbox",
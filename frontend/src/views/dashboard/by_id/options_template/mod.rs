@@ -1,6 +1,9 @@
 pub mod savings;
 pub use savings::SavingsSection;
 
+pub mod net_tangible_benefit;
+pub use net_tangible_benefit::NetTangibleBenefitSection;
+
 pub mod loan_information;
 pub use loan_information::LoanInformationSection;
 
@@ -22,11 +25,20 @@ pub use pricing::PricingSection;
 pub mod consumer_debt;
 pub use consumer_debt::ConsumerDebtSection;
 
+pub mod debt_to_income;
+pub use debt_to_income::DebtToIncomeSection;
+
+pub mod residual_income;
+pub use residual_income::ResidualIncomeSection;
+
 
 
 pub mod title_fees;
 pub use title_fees::TitleFeesSection;
 
+pub mod closing_statement;
+pub use closing_statement::ClosingStatementSection;
+
 pub mod income_information;
 pub use income_information::IncomeInformationSection;
 
@@ -1,5 +1,5 @@
 use dioxus::prelude::*;
-use shared::models::MortgagePayoffsData;
+use shared::models::{ExistingLoanData, MortgagePayoffsData};
 
 #[component]
 pub fn MortgagePayoffsSection(data: MortgagePayoffsData, on_change: EventHandler<MortgagePayoffsData>) -> Element {
@@ -9,6 +9,27 @@ pub fn MortgagePayoffsSection(data: MortgagePayoffsData, on_change: EventHandler
     use_effect(move || {
         local_data.set(data.clone());
     });
+
+    // Rows map to lien positions 1-3; missing positions are created on first edit
+    let mut update_loan = move |index: usize, field: &str, value: String| {
+        local_data.with_mut(|data| {
+            while data.existing_loans.len() <= index {
+                let position = data.existing_loans.len() as u8 + 1;
+                data.existing_loans.push(ExistingLoanData { position, ..Default::default() });
+            }
+            let loan = &mut data.existing_loans[index];
+            match field {
+                "loan_balance" => loan.loan_balance = value.parse().unwrap_or(0.0),
+                "monthly_payment" => loan.monthly_payment = value.parse().unwrap_or(0.0),
+                "remaining_term_months" => loan.remaining_term_months = value.parse().unwrap_or(0),
+                "interest_rate" => loan.interest_rate = value.parse().unwrap_or(0.0),
+                "is_subordinate" => loan.is_subordinate = value == "true",
+                _ => {}
+            }
+            on_change.call(data.clone());
+        });
+    };
+
     rsx! {
         div { class: "bg-white p-4 sm:p-6 rounded-lg shadow-md mb-6",
             h4 { class: "text-lg font-semibold mb-4 text-black", "Mortgage Payoffs and Payments" }
@@ -37,138 +58,65 @@ pub fn MortgagePayoffsSection(data: MortgagePayoffsData, on_change: EventHandler
                         }
                     }
                     tbody {
-                        tr {
-                            td { class: "border border-gray-300 px-2 sm:px-4 py-2 font-semibold",
-                                "1st"
-                            }
-                            td { class: "border border-gray-300 px-2 sm:px-4 py-2",
-                                input {
-                                    r#type: "number",
-                                    name: "loanBalance1",
-                                    id: "loanBalance1",
-                                    class: "w-full px-1 sm:px-2 py-1 border rounded text-sm",
-                                }
-                            }
-                            td { class: "border border-gray-300 px-2 sm:px-4 py-2",
-                                input {
-                                    r#type: "number",
-                                    name: "payment1",
-                                    id: "payment1",
-                                    class: "w-full px-1 sm:px-2 py-1 border rounded text-sm",
-                                }
-                            }
-                            td { class: "border border-gray-300 px-2 sm:px-4 py-2",
-                                input {
-                                    r#type: "number",
-                                    name: "oldTerm1",
-                                    id: "oldTerm1",
-                                    class: "w-full px-1 sm:px-2 py-1 border rounded text-sm",
-                                }
-                            }
-                            td { class: "border border-gray-300 px-2 sm:px-4 py-2",
-                                input {
-                                    r#type: "number",
-                                    name: "oldRate1",
-                                    id: "oldRate1",
-                                    class: "w-full px-1 sm:px-2 py-1 border rounded text-sm",
-                                }
-                            }
-                            td { class: "border border-gray-300 px-2 sm:px-4 py-2 text-center",
-                                input {
-                                    r#type: "checkbox",
-                                    name: "sub1",
-                                    id: "sub1",
-                                    class: "w-3 h-3 sm:w-4 sm:h-4",
-                                }
-                            }
-                        }
-                        tr {
-                            td { class: "border border-gray-300 px-2 sm:px-4 py-2 font-semibold",
-                                "2nd"
-                            }
-                            td { class: "border border-gray-300 px-2 sm:px-4 py-2",
-                                input {
-                                    r#type: "number",
-                                    name: "loanBalance2",
-                                    id: "loanBalance2",
-                                    class: "w-full px-1 sm:px-2 py-1 border rounded text-sm",
-                                }
-                            }
-                            td { class: "border border-gray-300 px-2 sm:px-4 py-2",
-                                input {
-                                    r#type: "number",
-                                    name: "payment2",
-                                    id: "payment2",
-                                    class: "w-full px-1 sm:px-2 py-1 border rounded text-sm",
-                                }
-                            }
-                            td { class: "border border-gray-300 px-2 sm:px-4 py-2",
-                                input {
-                                    r#type: "number",
-                                    name: "oldTerm2",
-                                    id: "oldTerm2",
-                                    class: "w-full px-1 sm:px-2 py-1 border rounded text-sm",
-                                }
-                            }
-                            td { class: "border border-gray-300 px-2 sm:px-4 py-2",
-                                input {
-                                    r#type: "number",
-                                    name: "oldRate2",
-                                    id: "oldRate2",
-                                    class: "w-full px-1 sm:px-2 py-1 border rounded text-sm",
-                                }
-                            }
-                            td { class: "border border-gray-300 px-2 sm:px-4 py-2 text-center",
-                                input {
-                                    r#type: "checkbox",
-                                    name: "sub2",
-                                    id: "sub2",
-                                    class: "w-3 h-3 sm:w-4 sm:h-4",
-                                }
-                            }
-                        }
-                        tr {
-                            td { class: "border border-gray-300 px-2 sm:px-4 py-2 font-semibold",
-                                "3rd"
-                            }
-                            td { class: "border border-gray-300 px-2 sm:px-4 py-2",
-                                input {
-                                    r#type: "number",
-                                    name: "loanBalance3",
-                                    id: "loanBalance3",
-                                    class: "w-full px-1 sm:px-2 py-1 border rounded text-sm",
-                                }
-                            }
-                            td { class: "border border-gray-300 px-2 sm:px-4 py-2",
-                                input {
-                                    r#type: "number",
-                                    name: "payment3",
-                                    id: "payment3",
-                                    class: "w-full px-1 sm:px-2 py-1 border rounded text-sm",
-                                }
-                            }
-                            td { class: "border border-gray-300 px-2 sm:px-4 py-2",
-                                input {
-                                    r#type: "number",
-                                    name: "oldTerm3",
-                                    id: "oldTerm3",
-                                    class: "w-full px-1 sm:px-2 py-1 border rounded text-sm",
-                                }
-                            }
-                            td { class: "border border-gray-300 px-2 sm:px-4 py-2",
-                                input {
-                                    r#type: "number",
-                                    name: "oldRate3",
-                                    id: "oldRate3",
-                                    class: "w-full px-1 sm:px-2 py-1 border rounded text-sm",
-                                }
-                            }
-                            td { class: "border border-gray-300 px-2 sm:px-4 py-2 text-center",
-                                input {
-                                    r#type: "checkbox",
-                                    name: "sub3",
-                                    id: "sub3",
-                                    class: "w-3 h-3 sm:w-4 sm:h-4",
+                        for (index , label) in ["1st", "2nd", "3rd"].into_iter().enumerate() {
+                            {
+                                let loan = local_data().existing_loans.get(index).cloned().unwrap_or_default();
+                                rsx! {
+                                    tr {
+                                        td { class: "border border-gray-300 px-2 sm:px-4 py-2 font-semibold",
+                                            "{label}"
+                                        }
+                                        td { class: "border border-gray-300 px-2 sm:px-4 py-2",
+                                            input {
+                                                r#type: "number",
+                                                name: "loanBalance{index + 1}",
+                                                id: "loanBalance{index + 1}",
+                                                value: "{loan.loan_balance}",
+                                                class: "w-full px-1 sm:px-2 py-1 border rounded text-sm",
+                                                oninput: move |e: Event<FormData>| update_loan(index, "loan_balance", e.value()),
+                                            }
+                                        }
+                                        td { class: "border border-gray-300 px-2 sm:px-4 py-2",
+                                            input {
+                                                r#type: "number",
+                                                name: "payment{index + 1}",
+                                                id: "payment{index + 1}",
+                                                value: "{loan.monthly_payment}",
+                                                class: "w-full px-1 sm:px-2 py-1 border rounded text-sm",
+                                                oninput: move |e: Event<FormData>| update_loan(index, "monthly_payment", e.value()),
+                                            }
+                                        }
+                                        td { class: "border border-gray-300 px-2 sm:px-4 py-2",
+                                            input {
+                                                r#type: "number",
+                                                name: "oldTerm{index + 1}",
+                                                id: "oldTerm{index + 1}",
+                                                value: "{loan.remaining_term_months}",
+                                                class: "w-full px-1 sm:px-2 py-1 border rounded text-sm",
+                                                oninput: move |e: Event<FormData>| update_loan(index, "remaining_term_months", e.value()),
+                                            }
+                                        }
+                                        td { class: "border border-gray-300 px-2 sm:px-4 py-2",
+                                            input {
+                                                r#type: "number",
+                                                name: "oldRate{index + 1}",
+                                                id: "oldRate{index + 1}",
+                                                value: "{loan.interest_rate}",
+                                                class: "w-full px-1 sm:px-2 py-1 border rounded text-sm",
+                                                oninput: move |e: Event<FormData>| update_loan(index, "interest_rate", e.value()),
+                                            }
+                                        }
+                                        td { class: "border border-gray-300 px-2 sm:px-4 py-2 text-center",
+                                            input {
+                                                r#type: "checkbox",
+                                                name: "sub{index + 1}",
+                                                id: "sub{index + 1}",
+                                                checked: "{loan.is_subordinate}",
+                                                class: "w-3 h-3 sm:w-4 sm:h-4",
+                                                onchange: move |e: Event<FormData>| update_loan(index, "is_subordinate", e.checked().to_string()),
+                                            }
+                                        }
+                                    }
                                 }
                             }
                         }
@@ -0,0 +1,60 @@
+use dioxus::prelude::*;
+use shared::models::NetTangibleBenefitData;
+
+// Streamline benefit tests are evaluated by `shared::calc::net_tangible_benefit`
+// whenever the template is recalculated; this section only reports the findings.
+#[component]
+pub fn NetTangibleBenefitSection(data: NetTangibleBenefitData) -> Element {
+    let (status_class, status_text) = if data.passed {
+        ("px-2 py-1 rounded text-sm font-semibold bg-green-50 text-green-800", "Pass")
+    } else {
+        ("px-2 py-1 rounded text-sm font-semibold bg-red-50 text-red-800", "Fail")
+    };
+
+    rsx! {
+        div { class: "bg-white p-4 sm:p-6 rounded-lg shadow-md mb-6",
+            div { class: "flex items-center justify-between mb-4",
+                h4 { class: "text-lg font-semibold text-black", "Net Tangible Benefit - {data.program}" }
+                span { class: status_class, "{status_text}" }
+            }
+
+            div { class: "overflow-x-auto",
+                table { class: "min-w-full table-auto border-collapse border border-gray-300",
+                    thead {
+                        tr { class: "bg-gray-50",
+                            th { class: "border border-gray-300 px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase",
+                                "Test"
+                            }
+                            th { class: "border border-gray-300 px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase",
+                                "Required"
+                            }
+                            th { class: "border border-gray-300 px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase",
+                                "Actual"
+                            }
+                            th { class: "border border-gray-300 px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase",
+                                "Details"
+                            }
+                            th { class: "border border-gray-300 px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase",
+                                "Result"
+                            }
+                        }
+                    }
+                    tbody {
+                        for finding in data.findings.iter() {
+                            tr { key: "{finding.rule}",
+                                td { class: "border border-gray-300 px-4 py-2 text-sm", "{finding.rule}" }
+                                td { class: "border border-gray-300 px-4 py-2 text-sm", "{finding.threshold:.2}" }
+                                td { class: "border border-gray-300 px-4 py-2 text-sm", "{finding.actual:.2}" }
+                                td { class: "border border-gray-300 px-4 py-2 text-sm text-gray-600", "{finding.detail}" }
+                                td {
+                                    class: if finding.passed { "border border-gray-300 px-4 py-2 text-sm font-semibold text-green-700" } else { "border border-gray-300 px-4 py-2 text-sm font-semibold text-red-700" },
+                                    if finding.passed { "Pass" } else { "Fail" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
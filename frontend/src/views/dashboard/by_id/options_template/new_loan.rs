@@ -1,8 +1,13 @@
 use dioxus::prelude::*;
-use shared::models::NewLoanData;
+use shared::models::{LoanToValueData, NewLoanData};
 
 #[component]
-pub fn NewLoanSection(data: NewLoanData, on_change: EventHandler<NewLoanData>) -> Element {
+pub fn NewLoanSection(
+    data: NewLoanData,
+    loan_type: String,
+    loan_to_value: LoanToValueData,
+    on_change: EventHandler<NewLoanData>,
+) -> Element {
     let mut local_data = use_signal(|| data.clone());
 
     // Update local data when prop changes
@@ -75,6 +80,13 @@ pub fn NewLoanSection(data: NewLoanData, on_change: EventHandler<NewLoanData>) -
                                 r#type: "number",
                                 name: "downPayment",
                                 id: "downPayment",
+                                value: "{local_data().down_payment}",
+                                oninput: move |e| {
+                                    let mut updated = local_data();
+                                    updated.down_payment = e.value().parse().unwrap_or(0.0);
+                                    local_data.set(updated.clone());
+                                    on_change.call(updated);
+                                },
                                 class: "sm:col-span-2 px-3 py-2 border border-gray-300 rounded-md focus:ring-2 focus:ring-blue-500 focus:border-blue-500",
                                 placeholder: "Enter down payment",
                             }
@@ -91,6 +103,13 @@ pub fn NewLoanSection(data: NewLoanData, on_change: EventHandler<NewLoanData>) -
                                 r#type: "number",
                                 name: "baseLoanAmount",
                                 id: "baseLoanAmount",
+                                value: "{local_data().base_loan_amount}",
+                                oninput: move |e| {
+                                    let mut updated = local_data();
+                                    updated.base_loan_amount = e.value().parse().unwrap_or(0.0);
+                                    local_data.set(updated.clone());
+                                    on_change.call(updated);
+                                },
                                 class: "sm:col-span-2 px-3 py-2 border border-gray-300 rounded-md focus:ring-2 focus:ring-blue-500 focus:border-blue-500",
                                 placeholder: "Enter base loan amount",
                             }
@@ -107,12 +126,58 @@ pub fn NewLoanSection(data: NewLoanData, on_change: EventHandler<NewLoanData>) -
                                 r#type: "number",
                                 name: "subordinatedAmount",
                                 id: "subordinatedAmount",
+                                value: "{local_data().subordinated_amount}",
+                                oninput: move |e| {
+                                    let mut updated = local_data();
+                                    updated.subordinated_amount = e.value().parse().unwrap_or(0.0);
+                                    local_data.set(updated.clone());
+                                    on_change.call(updated);
+                                },
                                 class: "sm:col-span-2 px-3 py-2 border border-gray-300 rounded-md focus:ring-2 focus:ring-blue-500 focus:border-blue-500",
                                 placeholder: "Enter subordinated amount",
                             }
                         }
 
-                        // FF/UMIP
+                        // Subordinate HELOC - the full line counts toward HCLTV
+                        div { class: "grid grid-cols-1 sm:grid-cols-3 gap-4 items-center",
+                            label {
+                                r#for: "helocBalance",
+                                class: "text-sm font-medium text-gray-700 sm:col-span-1",
+                                "HELOC Balance / Line:"
+                            }
+                            div { class: "sm:col-span-2 grid grid-cols-2 gap-2",
+                                input {
+                                    r#type: "number",
+                                    name: "helocBalance",
+                                    id: "helocBalance",
+                                    value: "{local_data().heloc_balance}",
+                                    oninput: move |e| {
+                                        let mut updated = local_data();
+                                        updated.heloc_balance = e.value().parse().unwrap_or(0.0);
+                                        local_data.set(updated.clone());
+                                        on_change.call(updated);
+                                    },
+                                    class: "px-3 py-2 border border-gray-300 rounded-md focus:ring-2 focus:ring-blue-500 focus:border-blue-500",
+                                    placeholder: "Drawn balance",
+                                }
+                                input {
+                                    r#type: "number",
+                                    name: "helocCreditLimit",
+                                    id: "helocCreditLimit",
+                                    value: "{local_data().heloc_credit_limit}",
+                                    oninput: move |e| {
+                                        let mut updated = local_data();
+                                        updated.heloc_credit_limit = e.value().parse().unwrap_or(0.0);
+                                        local_data.set(updated.clone());
+                                        on_change.call(updated);
+                                    },
+                                    class: "px-3 py-2 border border-gray-300 rounded-md focus:ring-2 focus:ring-blue-500 focus:border-blue-500",
+                                    placeholder: "Credit limit",
+                                }
+                            }
+                        }
+
+                        // FF/UMIP (readonly, set from the loan type)
                         div { class: "grid grid-cols-1 sm:grid-cols-3 gap-4 items-center",
                             label {
                                 r#for: "ffUmip",
@@ -124,16 +189,128 @@ pub fn NewLoanSection(data: NewLoanData, on_change: EventHandler<NewLoanData>) -
                                     r#type: "number",
                                     name: "ffUmip",
                                     id: "ffUmip",
-                                    class: "flex-1 px-3 py-2 border border-gray-300 rounded-l-md focus:ring-2 focus:ring-blue-500 focus:border-blue-500",
-                                    placeholder: "Enter FF/UMIP",
+                                    value: "{local_data().ff_umip_percentage:.2}",
+                                    readonly: true,
+                                    class: "w-24 px-3 py-2 border border-gray-300 rounded-l-md bg-gray-50 text-gray-700",
                                 }
-                                span { class: "inline-flex items-center px-3 py-2 border border-l-0 border-gray-300 bg-gray-50 rounded-r-md text-sm text-gray-500",
+                                span { class: "inline-flex items-center px-3 py-2 border border-l-0 border-gray-300 bg-gray-50 text-sm text-gray-500",
                                     "%"
                                 }
+                                input {
+                                    r#type: "text",
+                                    name: "ffUmipAmount",
+                                    id: "ffUmipAmount",
+                                    value: "{local_data().ff_umip_amount:.2}",
+                                    readonly: true,
+                                    class: "flex-1 px-3 py-2 border border-l-0 border-gray-300 rounded-r-md bg-gray-50 text-gray-700",
+                                }
                             }
                         }
 
-                        // UMIP Refund
+                        // Prior FHA loan - drives the UMIP refund
+                        if loan_type == "fha" {
+                            div { class: "grid grid-cols-1 sm:grid-cols-3 gap-4 items-center",
+                                label {
+                                    r#for: "priorUfmip",
+                                    class: "text-sm font-medium text-gray-700 sm:col-span-1",
+                                    "Prior UFMIP Paid:"
+                                }
+                                input {
+                                    r#type: "number",
+                                    name: "priorUfmip",
+                                    id: "priorUfmip",
+                                    value: "{local_data().prior_ufmip}",
+                                    oninput: move |e| {
+                                        let mut updated = local_data();
+                                        updated.prior_ufmip = e.value().parse().unwrap_or(0.0);
+                                        local_data.set(updated.clone());
+                                        on_change.call(updated);
+                                    },
+                                    class: "sm:col-span-2 px-3 py-2 border border-gray-300 rounded-md focus:ring-2 focus:ring-blue-500 focus:border-blue-500",
+                                    placeholder: "UFMIP on the loan being refinanced",
+                                }
+                            }
+
+                            div { class: "grid grid-cols-1 sm:grid-cols-3 gap-4 items-center",
+                                label {
+                                    r#for: "monthsSinceEndorsement",
+                                    class: "text-sm font-medium text-gray-700 sm:col-span-1",
+                                    "Months Since Endorsement:"
+                                }
+                                input {
+                                    r#type: "number",
+                                    name: "monthsSinceEndorsement",
+                                    id: "monthsSinceEndorsement",
+                                    value: "{local_data().months_since_endorsement}",
+                                    oninput: move |e| {
+                                        let mut updated = local_data();
+                                        updated.months_since_endorsement = e.value().parse().unwrap_or(0);
+                                        local_data.set(updated.clone());
+                                        on_change.call(updated);
+                                    },
+                                    class: "sm:col-span-2 px-3 py-2 border border-gray-300 rounded-md focus:ring-2 focus:ring-blue-500 focus:border-blue-500",
+                                    placeholder: "Months since prior FHA endorsement",
+                                }
+                            }
+                        }
+
+                        // VA funding fee inputs
+                        if loan_type == "va" {
+                            div { class: "grid grid-cols-1 sm:grid-cols-3 gap-4 items-center",
+                                span { class: "text-sm font-medium text-gray-700 sm:col-span-1", "VA Funding Fee:" }
+                                div { class: "sm:col-span-2 flex flex-wrap gap-4",
+                                    label { class: "flex items-center text-sm text-gray-700",
+                                        input {
+                                            r#type: "checkbox",
+                                            id: "vaSubsequentUse",
+                                            checked: "{local_data().va_subsequent_use}",
+                                            class: "mr-2 h-4 w-4 text-blue-600 border-gray-300 rounded focus:ring-blue-500",
+                                            onchange: move |e| {
+                                                let mut updated = local_data();
+                                                updated.va_subsequent_use = e.checked();
+                                                local_data.set(updated.clone());
+                                                on_change.call(updated);
+                                            },
+                                        }
+                                        "Subsequent use"
+                                    }
+                                    label { class: "flex items-center text-sm text-gray-700",
+                                        input {
+                                            r#type: "checkbox",
+                                            id: "vaFundingFeeExempt",
+                                            checked: "{local_data().va_funding_fee_exempt}",
+                                            class: "mr-2 h-4 w-4 text-blue-600 border-gray-300 rounded focus:ring-blue-500",
+                                            onchange: move |e| {
+                                                let mut updated = local_data();
+                                                updated.va_funding_fee_exempt = e.checked();
+                                                local_data.set(updated.clone());
+                                                on_change.call(updated);
+                                            },
+                                        }
+                                        "Exempt (service-connected disability)"
+                                    }
+                                }
+                            }
+                        }
+
+                        // Conventional MI from the rate card (readonly)
+                        if loan_type == "cnv" {
+                            div { class: "grid grid-cols-1 sm:grid-cols-3 gap-4 items-center",
+                                span { class: "text-sm font-medium text-gray-700 sm:col-span-1", "Mortgage Insurance:" }
+                                if local_data().mi_coverage > 0.0 {
+                                    p { class: "sm:col-span-2 text-sm text-gray-700",
+                                        "{local_data().mi_coverage:.0}% coverage at {local_data().mi_annual_rate:.3}% annually, "
+                                        "cancels automatically in month {local_data().pmi_termination_month}"
+                                    }
+                                } else {
+                                    p { class: "sm:col-span-2 text-sm text-gray-500",
+                                        "None required, or no matching rate card row"
+                                    }
+                                }
+                            }
+                        }
+
+                        // UMIP Refund (readonly)
                         div { class: "grid grid-cols-1 sm:grid-cols-3 gap-4 items-center",
                             label {
                                 r#for: "umipRefund",
@@ -141,11 +318,13 @@ pub fn NewLoanSection(data: NewLoanData, on_change: EventHandler<NewLoanData>) -
                                 "UMIP Refund:"
                             }
                             input {
-                                r#type: "number",
+                                r#type: "text",
                                 name: "umipRefund",
                                 id: "umipRefund",
-                                class: "sm:col-span-2 px-3 py-2 border border-gray-300 rounded-md focus:ring-2 focus:ring-blue-500 focus:border-blue-500",
-                                placeholder: "Enter UMIP refund",
+                                value: "{local_data().umip_refund:.2}",
+                                readonly: true,
+                                class: "sm:col-span-2 px-3 py-2 border border-gray-300 rounded-md bg-gray-50 text-gray-700",
+                                placeholder: "Calculated automatically",
                             }
                         }
 
@@ -160,6 +339,7 @@ pub fn NewLoanSection(data: NewLoanData, on_change: EventHandler<NewLoanData>) -
                                 r#type: "text",
                                 name: "totalLoanAmount",
                                 id: "totalLoanAmount",
+                                value: "{local_data().total_loan_amount}",
                                 readonly: true,
                                 class: "sm:col-span-2 px-3 py-2 border border-gray-300 rounded-md bg-gray-50 text-gray-700",
                                 placeholder: "Calculated automatically",
@@ -177,6 +357,7 @@ pub fn NewLoanSection(data: NewLoanData, on_change: EventHandler<NewLoanData>) -
                                 r#type: "number",
                                 name: "noteRate",
                                 id: "noteRate",
+                                value: "{local_data().note_rate}",
                                 readonly: true,
                                 class: "sm:col-span-2 px-3 py-2 border border-gray-300 rounded-md bg-gray-50 text-gray-700",
                                 placeholder: "Calculated automatically",
@@ -187,39 +368,41 @@ pub fn NewLoanSection(data: NewLoanData, on_change: EventHandler<NewLoanData>) -
 
                 // Right column - Calculated values
                 div { class: "space-y-4",
-                    // LTV
-                    div {
-                        label {
-                            r#for: "LTV",
-                            class: "block text-sm font-medium text-gray-700 mb-2",
-                            "LTV"
-                        }
-                        input {
-                            r#type: "number",
-                            value: "0",
-                            id: "LTVvalue",
-                            readonly: true,
-                            class: "w-full px-3 py-2 border border-gray-300 rounded-md bg-gray-50 text-gray-700",
+                    for (label, id, actual, max) in [
+                        ("LTV", "LTVvalue", loan_to_value.ltv, loan_to_value.max_ltv),
+                        ("CLTV", "CLTVvalue", loan_to_value.cltv, loan_to_value.max_cltv),
+                        ("HCLTV", "HCLTVvalue", loan_to_value.hcltv, loan_to_value.max_hcltv),
+                    ]
+                    {
+                        div { key: "{id}",
+                            label {
+                                r#for: "{id}",
+                                class: "block text-sm font-medium text-gray-700 mb-2",
+                                "{label}"
+                            }
+                            input {
+                                r#type: "text",
+                                value: "{actual:.2}%",
+                                id: "{id}",
+                                readonly: true,
+                                class: if max.is_some_and(|max| actual > max) { "w-full px-3 py-2 border border-red-300 rounded-md bg-red-50 text-red-700" } else { "w-full px-3 py-2 border border-gray-300 rounded-md bg-gray-50 text-gray-700" },
+                            }
+                            if let Some(max) = max {
+                                p { class: "mt-1 text-xs text-gray-500", "Max {max:.2}%" }
+                            }
                         }
                     }
 
-                    // CLTV
-                    div {
-                        label {
-                            r#for: "CLTV",
-                            class: "block text-sm font-medium text-gray-700 mb-2",
-                            "CLTV"
-                        }
-                        input {
-                            r#type: "number",
-                            value: "0",
-                            id: "CLTVvalue",
-                            readonly: true,
-                            class: "w-full px-3 py-2 border border-gray-300 rounded-md bg-gray-50 text-gray-700",
+                    // Program limit warnings
+                    if !loan_to_value.warnings.is_empty() {
+                        div { class: "p-3 bg-yellow-50 border border-yellow-200 rounded-lg",
+                            for warning in loan_to_value.warnings.iter() {
+                                p { class: "text-sm text-yellow-800", "{warning}" }
+                            }
                         }
                     }
                 }
             }
         }
     }
-}
\ No newline at end of file
+}
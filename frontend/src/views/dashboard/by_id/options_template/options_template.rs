@@ -1,6 +1,13 @@
 use dioxus::prelude::*;
 use crate::views::dashboard::by_id::options_template::*;
 use shared::models::*;
+use shared::calc::ltv::check_template;
+use shared::calc::apr::pricing_option_aprs;
+use shared::calc::cash_to_close::calculate_cash_to_close;
+use shared::calc::dti::declining_income_warnings;
+use shared::calc::options_template::recalculate;
+use shared::calc::pricing::selected_pricing_option;
+use shared::calc::rule_set::IncomeRuleSet;
 use repository::Repository;
 
 #[component]
@@ -11,13 +18,27 @@ pub fn OptionsTemplate(id: i32) -> Element {
     // Main state for all options template data
     let mut template_data = use_signal(|| OptionsTemplateData::default());
 
+    // Conventional MI rate card used when recalculating PMI
+    let mut mi_rate_card = use_signal(Vec::<MiRateCardEntry>::new);
+
+    // Back-end DTI limit from settings, used for programs without their own limits
+    let mut default_max_dti = use_signal(|| AppSettings::default().dti_threshold);
+
+    // Custom income rule set from settings, used by Non-QM loans
+    let mut custom_rules = use_signal(IncomeRuleSet::default);
+
     // Auto-save functionality
     let save_timeout = use_signal(|| None::<i32>);
 
     // Function to trigger auto-save
     let client_for_save = client.clone();
     let save_timeout_for_save = save_timeout.clone();
-    let save_data = move |data: OptionsTemplateData| {
+    let save_data = move |mut data: OptionsTemplateData| {
+        // Refresh derived values (P&I, totals) before saving
+        recalculate(&mut data, &mi_rate_card(), default_max_dti(), &custom_rules());
+        let mut template_signal = template_data;
+        template_signal.set(data.clone());
+
         // Cancel any existing timeout
         if let Some(_timeout_id) = save_timeout_for_save() {
             // In a real implementation, you'd cancel the timeout
@@ -34,6 +55,19 @@ pub fn OptionsTemplate(id: i32) -> Element {
     use_effect(move || {
         let client_clone = client.clone();
         spawn(async move {
+            match client_clone.get_mi_rate_card().await {
+                Ok(rate_card) => mi_rate_card.set(rate_card),
+                Err(e) => println!("Failed to load MI rate card: {:?}", e),
+            }
+
+            match client_clone.get_settings().await {
+                Ok(settings) => {
+                    default_max_dti.set(settings.dti_threshold);
+                    custom_rules.set(settings.custom_rule_set);
+                }
+                Err(e) => println!("Failed to load settings: {:?}", e),
+            }
+
             // Load options template data
             match client_clone.get_options_template(id).await {
                 Ok(Some(mut data)) => {
@@ -72,6 +106,37 @@ pub fn OptionsTemplate(id: i32) -> Element {
                     println!("Failed to load options template: {:?}", e);
                 }
             }
+
+            // The W-2 worksheet sections' selected figures feed qualifying income for DTI
+            match client_clone.get_w2_section_results(id).await {
+                Ok(results) => template_data.write().income_information.w2_section_results = results,
+                Err(e) => println!("Failed to load W-2 worksheet results: {:?}", e),
+            }
+
+            // General income worksheet feeds qualifying income for DTI
+            match client_clone.get_general_income_data(id).await {
+                Ok(general_income) => template_data.write().income_information.general_income_data = general_income,
+                Err(e) => println!("Failed to load general income data: {:?}", e),
+            }
+
+            match client_clone.get_self_employment_data(id).await {
+                Ok(self_employment) => template_data.write().income_information.self_employment_data = self_employment,
+                Err(e) => println!("Failed to load self-employment data: {:?}", e),
+            }
+
+            match client_clone.get_rental_income_data(id).await {
+                Ok(rental_income) => template_data.write().income_information.rental_income_data = rental_income,
+                Err(e) => println!("Failed to load rental income data: {:?}", e),
+            }
+
+            match client_clone.get_bank_statement_data(id).await {
+                Ok(bank_statement) => template_data.write().income_information.bank_statement_data = bank_statement,
+                Err(e) => println!("Failed to load bank statement data: {:?}", e),
+            }
+
+            // Declining income warnings aren't saved with the DTI result
+            let income_warnings = declining_income_warnings(&template_data().income_information);
+            template_data.write().debt_to_income.income_warnings = income_warnings;
         });
     });
 
@@ -135,6 +200,8 @@ pub fn OptionsTemplate(id: i32) -> Element {
 
             NewLoanSection {
                 data: template_data().new_loan.clone(),
+                loan_type: template_data().loan_information.loan_type.clone(),
+                loan_to_value: check_template(&template_data()),
                 on_change: {
                     let save_data_clone = save_data.clone();
                     move |new_data: NewLoanData| {
@@ -159,6 +226,8 @@ pub fn OptionsTemplate(id: i32) -> Element {
             PricingSection {
                 data: template_data().pricing.clone(),
                 total_loan_amount: template_data().new_loan.total_loan_amount,
+                term_months: template_data().loan_information.term_months,
+                aprs: pricing_option_aprs(&template_data()),
                 on_change: {
                     let save_data_clone = save_data.clone();
                     move |new_data: PricingData| {
@@ -170,6 +239,8 @@ pub fn OptionsTemplate(id: i32) -> Element {
 
             ConsumerDebtSection {
                 data: template_data().consumer_debt.clone(),
+                loan_type: template_data().loan_information.loan_type.clone(),
+                qualifying_income: template_data().debt_to_income.qualifying_income,
                 on_change: {
                     let save_data_clone = save_data.clone();
                     move |new_data: ConsumerDebtData| {
@@ -180,6 +251,7 @@ pub fn OptionsTemplate(id: i32) -> Element {
             }
             OtherFeesSection {
                 data: template_data().other_fees.clone(),
+                lender_credit_from_pricing: selected_pricing_option(&template_data()).is_some(),
                 on_change: {
                     let save_data_clone = save_data.clone();
                     move |new_data: OtherFeesData| {
@@ -198,8 +270,16 @@ pub fn OptionsTemplate(id: i32) -> Element {
                     }
                 },
             }
+            ClosingStatementSection {
+                data: calculate_cash_to_close(&template_data()),
+            }
             IncomeInformationSection {
                 data: template_data().income_information.clone(),
+                rules: IncomeRuleSet::for_loan_type(
+                    LoanType::from_key(&template_data().loan_information.loan_type).as_ref(),
+                    &template_data().loan_information.agency,
+                    &custom_rules(),
+                ),
                 on_change: {
                     let save_data_clone = save_data.clone();
                     move |new_data: IncomeInformationData| {
@@ -209,24 +289,50 @@ pub fn OptionsTemplate(id: i32) -> Element {
                 },
             }
 
+            DebtToIncomeSection {
+                data: template_data().debt_to_income.clone(),
+                on_change: {
+                    let save_data_clone = save_data.clone();
+                    move |new_data: DebtToIncomeData| {
+                        template_data.write().debt_to_income = new_data.clone();
+                        save_data_clone(template_data());
+                    }
+                },
+            }
+
+            if template_data().loan_information.loan_type == "va" {
+                ResidualIncomeSection {
+                    data: template_data().residual_income.clone(),
+                    on_change: {
+                        let save_data_clone = save_data.clone();
+                        move |new_data: ResidualIncomeData| {
+                            template_data.write().residual_income = new_data.clone();
+                            save_data_clone(template_data());
+                        }
+                    },
+                }
+            }
+
             // Savings section - only show if loan purpose is NOT Purchase
             {
                 let loan_purpose = template_data().loan_information.purpose.clone();
                 if loan_purpose != "purchase" {
-                    let save_data_clone = save_data.clone();
                     rsx! {
                         SavingsSection {
                             data: template_data().savings.clone(),
-                            on_change: move |new_data: SavingsData| {
-                                template_data.write().savings = new_data.clone();
-                                save_data_clone(template_data());
-                            },
                         }
                     }
                 } else {
                     rsx! {}
                 }
             }
+
+            // Net tangible benefit tests - only for VA IRRRL and FHA streamline scenarios
+            if !template_data().net_tangible_benefit.program.is_empty() {
+                NetTangibleBenefitSection {
+                    data: template_data().net_tangible_benefit.clone(),
+                }
+            }
         }
     }
 }
\ No newline at end of file
@@ -1,8 +1,9 @@
 use dioxus::prelude::*;
 use shared::models::OtherFeesData;
 
+// `lender_credit_from_pricing` is set when the selected pricing option supplies the credit
 #[component]
-pub fn OtherFeesSection(data: OtherFeesData, on_change: EventHandler<OtherFeesData>) -> Element {
+pub fn OtherFeesSection(data: OtherFeesData, lender_credit_from_pricing: bool, on_change: EventHandler<OtherFeesData>) -> Element {
     let mut local_data = use_signal(|| data.clone());
 
     // Update local data when prop changes
@@ -126,13 +127,17 @@ pub fn OtherFeesSection(data: OtherFeesData, on_change: EventHandler<OtherFeesDa
                         r#type: "number",
                         name: "lenderCredit",
                         value: "{local_data().lender_credit}",
+                        readonly: lender_credit_from_pricing,
                         oninput: move |e| {
                             if let Ok(val) = e.value().parse::<f64>() {
                                 local_data.write().lender_credit = val;
                                 on_change.call(local_data());
                             }
                         },
-                        class: "w-full px-3 py-2 border border-gray-300 rounded-md",
+                        class: if lender_credit_from_pricing { "w-full px-3 py-2 border border-gray-300 rounded-md bg-gray-50" } else { "w-full px-3 py-2 border border-gray-300 rounded-md" },
+                    }
+                    if lender_credit_from_pricing {
+                        p { class: "mt-1 text-xs text-gray-500", "YSP from the selected pricing option" }
                     }
                 }
                 div {
@@ -180,6 +185,7 @@ pub fn OtherFeesSection(data: OtherFeesData, on_change: EventHandler<OtherFeesDa
                         class: "w-full px-3 py-2 border border-gray-300 rounded-md",
                     }
                 }
+                // Both totals come from the closing statement
                 div { class: "md:col-span-2 lg:col-span-3 grid grid-cols-1 md:grid-cols-2 gap-4",
                     div {
                         label { class: "block text-sm font-medium text-gray-700 mb-1",
@@ -188,31 +194,21 @@ pub fn OtherFeesSection(data: OtherFeesData, on_change: EventHandler<OtherFeesDa
                         input {
                             r#type: "number",
                             name: "closingCosts",
-                            value: "{local_data().total_closing_costs}",
-                            oninput: move |e| {
-                                if let Ok(val) = e.value().parse::<f64>() {
-                                    local_data.write().total_closing_costs = val;
-                                    on_change.call(local_data());
-                                }
-                            },
-                            class: "w-full px-3 py-2 border border-gray-300 rounded-md",
+                            value: "{local_data().total_closing_costs:.2}",
+                            readonly: true,
+                            class: "w-full px-3 py-2 border border-gray-300 rounded-md bg-gray-50",
                         }
                     }
                     div {
                         label { class: "block text-sm font-medium text-gray-700 mb-1",
-                            "Cash Out (Bring to Close):"
+                            "Cash Out (Negative: Bring to Close):"
                         }
                         input {
                             r#type: "number",
                             name: "cashOutAmount",
-                            value: "{local_data().cash_out_amount}",
-                            oninput: move |e| {
-                                if let Ok(val) = e.value().parse::<f64>() {
-                                    local_data.write().cash_out_amount = val;
-                                    on_change.call(local_data());
-                                }
-                            },
-                            class: "w-full px-3 py-2 border border-gray-300 rounded-md",
+                            value: "{local_data().cash_out_amount:.2}",
+                            readonly: true,
+                            class: "w-full px-3 py-2 border border-gray-300 rounded-md bg-gray-50",
                         }
                     }
                 }
@@ -1,8 +1,25 @@
 use dioxus::prelude::*;
+use shared::calc::pricing::{compare_options, derive_point_dollars};
 use shared::models::PricingData;
 
+// Signed dollar difference, blank when there is nothing to compare against
+fn format_difference(amount: Option<f64>) -> String {
+    match amount {
+        Some(amount) if amount < 0.0 => format!("-${:.2}", -amount),
+        Some(amount) => format!("+${:.2}", amount),
+        None => "—".to_string(),
+    }
+}
+
+// `aprs` holds each option's APR, worked out from the whole template
 #[component]
-pub fn PricingSection(data: PricingData, total_loan_amount: f64, on_change: EventHandler<PricingData>) -> Element {
+pub fn PricingSection(
+    data: PricingData,
+    total_loan_amount: f64,
+    term_months: u32,
+    aprs: Vec<Option<f64>>,
+    on_change: EventHandler<PricingData>,
+) -> Element {
     let mut local_data = use_signal(|| data.clone());
 
     // Update local data when prop changes
@@ -10,7 +27,17 @@ pub fn PricingSection(data: PricingData, total_loan_amount: f64, on_change: Even
         local_data.set(data.clone());
     });
 
+    // YSP and BD dollars always follow the percentages
+    let mut update = move |change: &dyn Fn(&mut PricingData)| {
+        let mut pricing = local_data();
+        change(&mut pricing);
+        derive_point_dollars(&mut pricing, total_loan_amount);
+        local_data.set(pricing.clone());
+        on_change(pricing);
+    };
+
     let pricing_options = local_data().pricing_options.clone();
+    let comparisons = compare_options(&local_data(), total_loan_amount, term_months);
     rsx! {
         div { class: "bg-white p-4 sm:p-6 rounded-lg shadow-md mb-6",
             h4 { class: "text-lg font-semibold mb-4 text-black", "Pricing" }
@@ -18,40 +45,25 @@ pub fn PricingSection(data: PricingData, total_loan_amount: f64, on_change: Even
                 table { class: "min-w-full table-auto border-collapse border border-gray-300",
                     thead {
                         tr { class: "bg-gray-50",
-                            th { class: "border border-gray-300 px-2 sm:px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase",
-                                "Description"
-                            }
-                            th { class: "border border-gray-300 px-2 sm:px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase",
-                                "Rate"
-                            }
-                            th { class: "border border-gray-300 px-2 sm:px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase",
-                                "YSP %"
-                            }
-                            th { class: "border border-gray-300 px-2 sm:px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase",
-                                "YSP $"
-                            }
-                            th { class: "border border-gray-300 px-2 sm:px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase",
-                                "BD %"
-                            }
-                            th { class: "border border-gray-300 px-2 sm:px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase",
-                                "BD $"
-                            }
-                            th { class: "border border-gray-300 px-2 sm:px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase",
-                                "Select"
+                            for heading in ["Description", "Rate", "APR", "YSP %", "YSP $", "BD %", "BD $", "P&I", "P&I vs Selected", "Cost / Credit", "Break-Even", "Select"] {
+                                th { class: "border border-gray-300 px-2 sm:px-4 py-2 text-left text-xs font-medium text-gray-500 uppercase",
+                                    "{heading}"
+                                }
                             }
                         }
                     }
                     tbody {
                         // Render pricing options dynamically
-                        {pricing_options.iter().enumerate().map(|(i, option)| rsx! {
-                            tr {
+                        {pricing_options.iter().zip(comparisons).enumerate().map(|(i, (option, comparison))| {
+                            let apr = aprs.get(i).copied().flatten();
+                            rsx! {
+                            tr { class: if option.is_selected { "bg-blue-50" } else { "" },
                                 td { class: "border border-gray-300 px-2 sm:px-4 py-2",
                                     input {
                                         r#type: "text",
                                         value: "{option.description}",
                                         oninput: move |e| {
-                                            local_data.write().pricing_options[i].description = e.value();
-                                            on_change(local_data());
+                                            update(&|pricing: &mut PricingData| pricing.pricing_options[i].description = e.value());
                                         },
                                         class: "w-full px-1 sm:px-2 py-1 border rounded text-sm",
                                     }
@@ -62,23 +74,26 @@ pub fn PricingSection(data: PricingData, total_loan_amount: f64, on_change: Even
                                         value: "{option.note_rate}",
                                         oninput: move |e| {
                                             if let Ok(val) = e.value().parse::<f64>() {
-                                                local_data.write().pricing_options[i].note_rate = val;
-                                                on_change(local_data());
+                                                update(&|pricing: &mut PricingData| pricing.pricing_options[i].note_rate = val);
                                             }
                                         },
                                         class: "w-full px-1 sm:px-2 py-1 border rounded text-sm",
                                     }
                                 }
+                                td { class: "border border-gray-300 px-2 sm:px-4 py-2 text-right text-sm",
+                                    if let Some(apr) = apr {
+                                        "{apr:.3}%"
+                                    } else {
+                                        "—"
+                                    }
+                                }
                                 td { class: "border border-gray-300 px-2 sm:px-4 py-2",
                                     input {
                                         r#type: "number",
                                         value: "{option.ysp_percentage}",
                                         oninput: move |e| {
                                             if let Ok(val) = e.value().parse::<f64>() {
-                                                local_data.write().pricing_options[i].ysp_percentage = val;
-                                                local_data.write().pricing_options[i].ysp_dollar = val * total_loan_amount
-                                                    / 100.0;
-                                                on_change(local_data());
+                                                update(&|pricing: &mut PricingData| pricing.pricing_options[i].ysp_percentage = val);
                                             }
                                         },
                                         class: "w-full px-1 sm:px-2 py-1 border rounded text-sm",
@@ -87,7 +102,7 @@ pub fn PricingSection(data: PricingData, total_loan_amount: f64, on_change: Even
                                 td { class: "border border-gray-300 px-2 sm:px-4 py-2",
                                     input {
                                         r#type: "number",
-                                        value: "{option.ysp_percentage * total_loan_amount / 100.0:.2}",
+                                        value: "{option.ysp_dollar:.2}",
                                         readonly: true,
                                         class: "w-full px-1 sm:px-2 py-1 border rounded bg-gray-50 text-sm",
                                     }
@@ -98,10 +113,7 @@ pub fn PricingSection(data: PricingData, total_loan_amount: f64, on_change: Even
                                         value: "{option.bd_percentage}",
                                         oninput: move |e| {
                                             if let Ok(val) = e.value().parse::<f64>() {
-                                                local_data.write().pricing_options[i].bd_percentage = val;
-                                                local_data.write().pricing_options[i].bd_dollar = val * total_loan_amount
-                                                    / 100.0;
-                                                on_change(local_data());
+                                                update(&|pricing: &mut PricingData| pricing.pricing_options[i].bd_percentage = val);
                                             }
                                         },
                                         class: "w-full px-1 sm:px-2 py-1 border rounded text-sm",
@@ -110,27 +122,53 @@ pub fn PricingSection(data: PricingData, total_loan_amount: f64, on_change: Even
                                 td { class: "border border-gray-300 px-2 sm:px-4 py-2",
                                     input {
                                         r#type: "number",
-                                        value: "{option.bd_percentage * total_loan_amount / 100.0:.2}",
+                                        value: "{option.bd_dollar:.2}",
                                         readonly: true,
                                         class: "w-full px-1 sm:px-2 py-1 border rounded bg-gray-50 text-sm",
                                     }
                                 }
+                                td { class: "border border-gray-300 px-2 sm:px-4 py-2 text-right text-sm", "${comparison.monthly_pi:.2}" }
+                                td { class: "border border-gray-300 px-2 sm:px-4 py-2 text-right text-sm",
+                                    "{format_difference(comparison.payment_difference)}"
+                                }
+                                td { class: "border border-gray-300 px-2 sm:px-4 py-2 text-right text-sm",
+                                    if comparison.net_cost < 0.0 {
+                                        "${-comparison.net_cost:.2} credit"
+                                    } else {
+                                        "${comparison.net_cost:.2} cost"
+                                    }
+                                }
+                                td { class: "border border-gray-300 px-2 sm:px-4 py-2 text-right text-sm",
+                                    if let Some(months) = comparison.break_even_months {
+                                        "{months:.1} months"
+                                    } else {
+                                        "—"
+                                    }
+                                }
                                 td { class: "border border-gray-300 px-2 sm:px-4 py-2 text-center",
                                     input {
                                         r#type: "checkbox",
                                         checked: "{option.is_selected}",
                                         onchange: move |e| {
-                                            local_data.write().pricing_options[i].is_selected = e.checked();
-                                            on_change(local_data());
+                                            // Only one option can be selected
+                                            let checked = e.checked();
+                                            update(&|pricing: &mut PricingData| {
+                                                for (index, option) in pricing.pricing_options.iter_mut().enumerate() {
+                                                    option.is_selected = checked && index == i;
+                                                }
+                                            });
                                         },
                                         class: "w-3 h-3 sm:w-4 sm:h-4",
                                     }
                                 }
                             }
-                        })}
+                        }})}
                     }
                 }
             }
+            p { class: "mt-2 text-xs text-gray-500",
+                "YSP and BD dollars are priced on the total loan amount. Break-even is how many months the lower payment takes to repay the higher cost, measured against the selected option, which sets the note rate and lender credit. APR follows Regulation Z, Appendix J, with points, lender fees, closing agent fees and financed premiums as prepaid finance charges and mortgage insurance in the payments."
+            }
         }
     }
-}
\ No newline at end of file
+}
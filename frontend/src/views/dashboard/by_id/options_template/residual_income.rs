@@ -0,0 +1,104 @@
+use dioxus::prelude::*;
+use shared::calc::residual_income::CUSHION_DTI_THRESHOLD;
+use shared::models::ResidualIncomeData;
+
+const INPUT_CLASS: &str = "sm:col-span-2 px-3 py-2 border border-gray-300 rounded-md focus:ring-2 focus:ring-blue-500 focus:border-blue-500";
+
+// Shown for VA loans. Income, housing and debts come from the DTI section; the user
+// enters the property state, family size, living area and income taxes.
+#[component]
+pub fn ResidualIncomeSection(data: ResidualIncomeData, on_change: EventHandler<ResidualIncomeData>) -> Element {
+    let (status_class, status_text) = if data.region.is_empty() {
+        ("px-2 py-1 rounded text-sm font-semibold bg-gray-100 text-gray-700", "Enter a state")
+    } else if data.meets_guideline {
+        ("px-2 py-1 rounded text-sm font-semibold bg-green-50 text-green-800", "Meets guideline")
+    } else {
+        ("px-2 py-1 rounded text-sm font-semibold bg-red-50 text-red-800", "Below guideline")
+    };
+
+    let update = move |data: &ResidualIncomeData, field: &str, value: String| {
+        let mut updated = data.clone();
+        match field {
+            "state" => updated.state = value.trim().to_uppercase(),
+            "family_size" => updated.family_size = value.parse().unwrap_or(0),
+            "square_feet" => updated.square_feet = value.parse().unwrap_or(0.0),
+            "monthly_taxes" => updated.monthly_taxes = value.parse().unwrap_or(0.0),
+            _ => {}
+        }
+        on_change.call(updated);
+    };
+
+    let inputs = [
+        ("state", "State", "text", data.state.clone(), "e.g. TX"),
+        ("family_size", "Family Size", "number", data.family_size.to_string(), "Borrowers and dependents"),
+        ("square_feet", "Living Area (sq ft)", "number", data.square_feet.to_string(), "Enter square footage"),
+        ("monthly_taxes", "Monthly Income Taxes", "number", data.monthly_taxes.to_string(), "Federal, state, local, FICA"),
+    ];
+
+    rsx! {
+        div { class: "bg-white p-4 sm:p-6 rounded-lg shadow-md mb-6",
+            div { class: "flex items-center justify-between mb-4",
+                h4 { class: "text-lg font-semibold text-black", "VA Residual Income" }
+                span { class: status_class, "{status_text}" }
+            }
+
+            div { class: "grid grid-cols-1 lg:grid-cols-2 gap-6",
+                div { class: "space-y-4",
+                    for (field, label, input_type, value, placeholder) in inputs {
+                        div { key: "{field}", class: "grid grid-cols-1 sm:grid-cols-3 gap-4 items-center",
+                            label {
+                                r#for: "{field}",
+                                class: "text-sm font-semibold text-gray-700 sm:col-span-1",
+                                "{label}:"
+                            }
+                            input {
+                                r#type: input_type,
+                                id: "{field}",
+                                value: "{value}",
+                                class: INPUT_CLASS,
+                                placeholder: placeholder,
+                                onchange: {
+                                    let data = data.clone();
+                                    move |e: Event<FormData>| update(&data, field, e.value())
+                                },
+                            }
+                        }
+                    }
+                }
+
+                div { class: "space-y-2 text-sm",
+                    div { class: "flex justify-between",
+                        span { class: "text-gray-600", "Region" }
+                        span { class: "font-medium text-gray-900",
+                            if data.region.is_empty() { "—" } else { "{data.region}" }
+                        }
+                    }
+                    div { class: "flex justify-between",
+                        span { class: "text-gray-600", "Maintenance & Utilities" }
+                        span { class: "font-medium text-gray-900", "${data.maintenance_utilities:.2}/mo" }
+                    }
+                    div { class: "flex justify-between border-t pt-2",
+                        span { class: "text-gray-700 font-semibold", "Residual Income" }
+                        span {
+                            class: if data.meets_guideline { "font-bold text-green-700" } else { "font-bold text-red-600" },
+                            "${data.residual_income:.2}/mo"
+                        }
+                    }
+                    div { class: "flex justify-between",
+                        span { class: "text-gray-600", "Table Guideline" }
+                        span { class: "font-medium text-gray-900", "${data.guideline_residual_income:.2}/mo" }
+                    }
+                    div { class: "flex justify-between",
+                        span { class: "text-gray-600", "Required" }
+                        span { class: "font-medium text-gray-900", "${data.required_residual_income:.2}/mo" }
+                    }
+                    if data.cushion_applied {
+                        div { class: "p-2 bg-yellow-50 border border-yellow-200 rounded text-yellow-800",
+                            "DTI is above {CUSHION_DTI_THRESHOLD:.0}%, so residual income must exceed the guideline by 20%."
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,30 +1,10 @@
 use dioxus::prelude::*;
 use shared::models::SavingsData;
 
+// Savings are derived from the benefit-to-borrower, consumer debt and fee sections
+// by `shared::calc::savings`, so this section only displays them.
 #[component]
-pub fn SavingsSection(data: SavingsData, on_change: EventHandler<SavingsData>) -> Element {
-    let mut local_data = use_signal(|| data.clone());
-
-    // Update local data when prop changes
-    use_effect(move || {
-        local_data.set(data.clone());
-    });
-
-    // Helper function to update data and trigger on_change
-    let mut update_data = move |field: &str, value: String| {
-        let mut new_data = local_data();
-        match field {
-            "monthly_savings" => new_data.monthly_savings = value.parse().unwrap_or(0.0),
-            "annual_savings" => new_data.annual_savings = value.parse().unwrap_or(0.0),
-            "debt_paid" => new_data.debt_paid = value.parse().unwrap_or(0.0),
-            "payment_reduction" => new_data.payment_reduction = value.parse().unwrap_or(0.0),
-            "recoup_period_months" => new_data.recoup_period_months = value.parse().unwrap_or(0.0),
-            _ => {}
-        }
-        local_data.set(new_data.clone());
-        on_change.call(new_data);
-    };
-
+pub fn SavingsSection(data: SavingsData) -> Element {
     rsx! {
         div { class: "bg-white p-4 sm:p-6 rounded-lg shadow-md mb-6",
             h4 { class: "text-lg font-semibold mb-4 text-black", "Savings" }
@@ -36,9 +16,9 @@ pub fn SavingsSection(data: SavingsData, on_change: EventHandler<SavingsData>) -
                     label { class: "block text-sm font-medium text-gray-700 mb-2", "Monthly Savings" }
                     input {
                         r#type: "number",
-                        value: "{local_data().monthly_savings}",
-                        class: "w-full px-3 py-2 border border-gray-300 rounded-md focus:ring-2 focus:ring-blue-500 focus:border-blue-500",
-                        oninput: move |evt: Event<FormData>| update_data("monthly_savings", evt.value()),
+                        value: "{data.monthly_savings:.2}",
+                        readonly: true,
+                        class: "w-full px-3 py-2 border border-gray-300 rounded-md bg-gray-50",
                     }
                 }
 
@@ -47,9 +27,9 @@ pub fn SavingsSection(data: SavingsData, on_change: EventHandler<SavingsData>) -
                     label { class: "block text-sm font-medium text-gray-700 mb-2", "Annual Savings" }
                     input {
                         r#type: "number",
-                        value: "{local_data().annual_savings}",
-                        class: "w-full px-3 py-2 border border-gray-300 rounded-md focus:ring-2 focus:ring-blue-500 focus:border-blue-500",
-                        oninput: move |evt: Event<FormData>| update_data("annual_savings", evt.value()),
+                        value: "{data.annual_savings:.2}",
+                        readonly: true,
+                        class: "w-full px-3 py-2 border border-gray-300 rounded-md bg-gray-50",
                     }
                 }
 
@@ -58,9 +38,9 @@ pub fn SavingsSection(data: SavingsData, on_change: EventHandler<SavingsData>) -
                     label { class: "block text-sm font-medium text-gray-700 mb-2", "Debt Paid" }
                     input {
                         r#type: "number",
-                        value: "{local_data().debt_paid}",
-                        class: "w-full px-3 py-2 border border-gray-300 rounded-md focus:ring-2 focus:ring-blue-500 focus:border-blue-500",
-                        oninput: move |evt: Event<FormData>| update_data("debt_paid", evt.value()),
+                        value: "{data.debt_paid:.2}",
+                        readonly: true,
+                        class: "w-full px-3 py-2 border border-gray-300 rounded-md bg-gray-50",
                     }
                 }
 
@@ -69,9 +49,9 @@ pub fn SavingsSection(data: SavingsData, on_change: EventHandler<SavingsData>) -
                     label { class: "block text-sm font-medium text-gray-700 mb-2", "Payment Reduction" }
                     input {
                         r#type: "number",
-                        value: "{local_data().payment_reduction}",
-                        class: "w-full px-3 py-2 border border-gray-300 rounded-md focus:ring-2 focus:ring-blue-500 focus:border-blue-500",
-                        oninput: move |evt: Event<FormData>| update_data("payment_reduction", evt.value()),
+                        value: "{data.payment_reduction:.2}",
+                        readonly: true,
+                        class: "w-full px-3 py-2 border border-gray-300 rounded-md bg-gray-50",
                     }
                 }
 
@@ -82,9 +62,9 @@ pub fn SavingsSection(data: SavingsData, on_change: EventHandler<SavingsData>) -
                     }
                     input {
                         r#type: "number",
-                        value: "{local_data().recoup_period_months}",
-                        class: "w-full px-3 py-2 border border-gray-300 rounded-md focus:ring-2 focus:ring-blue-500 focus:border-blue-500",
-                        oninput: move |evt: Event<FormData>| update_data("recoup_period_months", evt.value()),
+                        value: "{data.recoup_period_months:.2}",
+                        readonly: true,
+                        class: "w-full px-3 py-2 border border-gray-300 rounded-md bg-gray-50",
                     }
                 }
             }
@@ -116,41 +96,41 @@ pub fn SavingsSection(data: SavingsData, on_change: EventHandler<SavingsData>) -
                             td { class: "border border-gray-300 px-4 py-2",
                                 input {
                                     r#type: "number",
-                                    value: "{local_data().monthly_savings}",
-                                    class: "w-full px-2 py-1 border rounded",
-                                    oninput: move |evt: Event<FormData>| update_data("monthly_savings", evt.value()),
+                                    value: "{data.monthly_savings:.2}",
+                                    readonly: true,
+                                    class: "w-full px-2 py-1 border rounded bg-gray-50",
                                 }
                             }
                             td { class: "border border-gray-300 px-4 py-2",
                                 input {
                                     r#type: "number",
-                                    value: "{local_data().annual_savings}",
-                                    class: "w-full px-2 py-1 border rounded",
-                                    oninput: move |evt: Event<FormData>| update_data("annual_savings", evt.value()),
+                                    value: "{data.annual_savings:.2}",
+                                    readonly: true,
+                                    class: "w-full px-2 py-1 border rounded bg-gray-50",
                                 }
                             }
                             td { class: "border border-gray-300 px-4 py-2",
                                 input {
                                     r#type: "number",
-                                    value: "{local_data().debt_paid}",
-                                    class: "w-full px-2 py-1 border rounded",
-                                    oninput: move |evt: Event<FormData>| update_data("debt_paid", evt.value()),
+                                    value: "{data.debt_paid:.2}",
+                                    readonly: true,
+                                    class: "w-full px-2 py-1 border rounded bg-gray-50",
                                 }
                             }
                             td { class: "border border-gray-300 px-4 py-2",
                                 input {
                                     r#type: "number",
-                                    value: "{local_data().payment_reduction}",
-                                    class: "w-full px-2 py-1 border rounded",
-                                    oninput: move |evt: Event<FormData>| update_data("payment_reduction", evt.value()),
+                                    value: "{data.payment_reduction:.2}",
+                                    readonly: true,
+                                    class: "w-full px-2 py-1 border rounded bg-gray-50",
                                 }
                             }
                             td { class: "border border-gray-300 px-4 py-2",
                                 input {
                                     r#type: "number",
-                                    value: "{local_data().recoup_period_months}",
-                                    class: "w-full px-2 py-1 border rounded",
-                                    oninput: move |evt: Event<FormData>| update_data("recoup_period_months", evt.value()),
+                                    value: "{data.recoup_period_months:.2}",
+                                    readonly: true,
+                                    class: "w-full px-2 py-1 border rounded bg-gray-50",
                                 }
                             }
                         }
@@ -0,0 +1,357 @@
+use dioxus::prelude::*;
+use shared::calc::dscr::calculate_dscr;
+use shared::models::{
+    AppSettings, CreateDSCRCalculator, DSCRCalculator, DSCRCalculatorQueryParams, Term, UpdateDSCRCalculator,
+};
+use uuid::Uuid;
+
+const PER_PAGE: u64 = 10;
+const INPUT_CLASS: &str = "w-full border border-gray-300 rounded-md px-3 py-2 text-gray-900 focus:ring-2 focus:ring-blue-500 focus:border-blue-500";
+
+fn format_money(amount: f64) -> String {
+    format!("${:.2}", amount)
+}
+
+/// Debt service coverage calculator for investor loans, with saved scenarios
+#[component]
+pub fn DscrCalculator() -> Element {
+    let client = use_context::<repository::Repository>();
+    let mut settings = use_signal(AppSettings::default);
+    let mut scenario = use_signal(DSCRCalculator::default);
+    // Set once the scenario on screen has been saved
+    let mut saved_id = use_signal(|| None::<Uuid>);
+
+    let mut saved = use_signal(Vec::<DSCRCalculator>::new);
+    let mut total = use_signal(|| 0u64);
+    let mut page = use_signal(|| 1u64);
+    let mut search = use_signal(String::new);
+    let mut error_message = use_signal(|| None::<String>);
+
+    let client_clone = client.clone();
+    use_effect(move || {
+        let client = client_clone.clone();
+        spawn(async move {
+            match client.get_settings().await {
+                Ok(loaded) => settings.set(loaded),
+                Err(e) => log::error!("Failed to load settings: {:?}", e),
+            }
+        });
+    });
+
+    // Reload the saved list whenever the page or search changes
+    let client_clone = client.clone();
+    let load_saved = Callback::new(move |_: ()| {
+        let client = client_clone.clone();
+        let params = DSCRCalculatorQueryParams {
+            page: Some(page()),
+            per_page: Some(PER_PAGE),
+            search: Some(search()),
+        };
+        spawn(async move {
+            match client.list_dscr_calculators(params.clone()).await {
+                Ok(list) => saved.set(list),
+                Err(e) => error_message.set(Some(format!("Failed to load scenarios: {:?}", e))),
+            }
+            match client.count_dscr_calculators(params).await {
+                Ok(count) => total.set(count),
+                Err(e) => log::error!("Failed to count scenarios: {:?}", e),
+            }
+        });
+    });
+
+    use_effect(move || load_saved.call(()));
+
+    let result = use_memo(move || calculate_dscr(&scenario(), settings().min_dscr));
+
+    let mut update_field = move |field: &str, value: String| {
+        let amount = value.parse().unwrap_or(0.0);
+        let mut current = scenario.write();
+        match field {
+            "property_address" => current.property_address = value,
+            "purchase_price" => current.purchase_price = amount,
+            "down_payment" => current.down_payment = amount,
+            "rate" => current.rate = amount,
+            "term" => current.term = Term::from_months(value.parse().unwrap_or(0)),
+            "annual_property_tax" => current.annual_property_tax = amount,
+            "annual_insurance" => current.annual_insurance = amount,
+            "monthly_hoa" => current.monthly_hoa = amount,
+            "e_x_rent" => current.e_x_rent = amount,
+            _ => {}
+        }
+    };
+
+    let client_clone = client.clone();
+    let save_scenario = move |_| {
+        let client = client_clone.clone();
+        let current = scenario();
+        spawn(async move {
+            let saved_result = match saved_id() {
+                Some(id) => client
+                    .update_dscr_calculator(
+                        id,
+                        UpdateDSCRCalculator {
+                            property_address: Some(current.property_address),
+                            purchase_price: Some(current.purchase_price),
+                            down_payment: Some(current.down_payment),
+                            rate: Some(current.rate),
+                            term: Some(current.term),
+                            annual_property_tax: Some(current.annual_property_tax),
+                            annual_insurance: Some(current.annual_insurance),
+                            monthly_hoa: Some(current.monthly_hoa),
+                            e_x_rent: Some(current.e_x_rent),
+                        },
+                    )
+                    .await
+                    .map(|updated| updated.map(|updated| updated.id)),
+                None => client
+                    .create_dscr_calculator(CreateDSCRCalculator {
+                        property_address: current.property_address,
+                        purchase_price: current.purchase_price,
+                        down_payment: current.down_payment,
+                        rate: current.rate,
+                        term: current.term,
+                        annual_property_tax: current.annual_property_tax,
+                        annual_insurance: current.annual_insurance,
+                        monthly_hoa: current.monthly_hoa,
+                        e_x_rent: current.e_x_rent,
+                    })
+                    .await
+                    .map(|created| Some(created.id)),
+            };
+            match saved_result {
+                Ok(id) => {
+                    saved_id.set(id);
+                    load_saved.call(());
+                }
+                Err(e) => error_message.set(Some(format!("Failed to save scenario: {:?}", e))),
+            }
+        });
+    };
+
+    let new_scenario = move |_| {
+        scenario.set(DSCRCalculator::default());
+        saved_id.set(None);
+    };
+
+    let client_clone = client.clone();
+    let delete_scenario = Callback::new(move |id: Uuid| {
+        let client = client_clone.clone();
+        spawn(async move {
+            match client.delete_dscr_calculator(id).await {
+                Ok(_) => {
+                    if saved_id() == Some(id) {
+                        saved_id.set(None);
+                    }
+                    load_saved.call(());
+                }
+                Err(e) => error_message.set(Some(format!("Failed to delete scenario: {:?}", e))),
+            }
+        });
+    });
+
+    let current = scenario();
+    let result = result();
+    let page_count = total().div_ceil(PER_PAGE).max(1);
+    let inputs = [
+        ("purchase_price", "Purchase Price", current.purchase_price),
+        ("down_payment", "Down Payment", current.down_payment),
+        ("rate", "Interest Rate (%)", current.rate),
+        ("annual_property_tax", "Annual Property Tax", current.annual_property_tax),
+        ("annual_insurance", "Annual Insurance", current.annual_insurance),
+        ("monthly_hoa", "Monthly HOA", current.monthly_hoa),
+        ("e_x_rent", "Expected Monthly Rent", current.e_x_rent),
+    ];
+
+    rsx! {
+        div { class: "min-h-screen bg-gray-100 dark:bg-gray-900 p-6",
+            div { class: "max-w-7xl mx-auto",
+                div { class: "mb-8",
+                    h1 { class: "text-3xl font-bold text-gray-900 dark:text-gray-100", "DSCR Calculator" }
+                    p { class: "text-gray-600 mt-2",
+                        "Expected rent against the full monthly payment for investor loans"
+                    }
+                }
+
+                if let Some(error) = error_message() {
+                    div { class: "mb-6 p-4 bg-red-50 border border-red-200 rounded-lg text-red-800", "{error}" }
+                }
+
+                div { class: "grid grid-cols-1 lg:grid-cols-3 gap-6",
+                    div { class: "bg-white p-6 rounded-lg shadow-md lg:col-span-2",
+                        div { class: "grid grid-cols-1 md:grid-cols-2 gap-4",
+                            div { class: "md:col-span-2",
+                                label { class: "block mb-1 text-sm font-semibold text-gray-700", "Property Address" }
+                                input {
+                                    r#type: "text",
+                                    value: "{current.property_address}",
+                                    class: INPUT_CLASS,
+                                    onchange: move |e: Event<FormData>| update_field("property_address", e.value()),
+                                }
+                            }
+                            for (field, label, value) in inputs {
+                                div { key: "{field}",
+                                    label { class: "block mb-1 text-sm font-semibold text-gray-700", "{label}" }
+                                    input {
+                                        r#type: "number",
+                                        step: "any",
+                                        value: "{value}",
+                                        class: INPUT_CLASS,
+                                        onchange: move |e: Event<FormData>| update_field(field, e.value()),
+                                    }
+                                }
+                            }
+                            div {
+                                label { class: "block mb-1 text-sm font-semibold text-gray-700", "Term" }
+                                select {
+                                    value: "{current.term.months()}",
+                                    class: INPUT_CLASS,
+                                    onchange: move |e: Event<FormData>| update_field("term", e.value()),
+                                    for term in Term::ALL {
+                                        option { value: "{term.months()}", "{term.years()} years" }
+                                    }
+                                }
+                            }
+                        }
+
+                        div { class: "flex gap-3 mt-6",
+                            button {
+                                class: "bg-blue-600 text-white px-4 py-2 rounded hover:bg-blue-700 text-sm",
+                                onclick: save_scenario,
+                                if saved_id().is_some() { "Update Scenario" } else { "Save Scenario" }
+                            }
+                            button {
+                                class: "bg-gray-200 text-gray-800 px-4 py-2 rounded hover:bg-gray-300 text-sm",
+                                onclick: new_scenario,
+                                "New Scenario"
+                            }
+                        }
+                    }
+
+                    div { class: "bg-white p-6 rounded-lg shadow-md space-y-2 text-sm",
+                        h2 { class: "text-lg font-semibold text-gray-900 mb-2", "Monthly PITIA" }
+                        div { class: "flex justify-between",
+                            span { class: "text-gray-600", "Loan Amount" }
+                            span { class: "font-medium text-gray-900", "{format_money(result.loan_amount)}" }
+                        }
+                        div { class: "flex justify-between",
+                            span { class: "text-gray-600", "Principal & Interest" }
+                            span { class: "font-medium text-gray-900", "{format_money(result.principal_interest)}" }
+                        }
+                        div { class: "flex justify-between",
+                            span { class: "text-gray-600", "Taxes" }
+                            span { class: "font-medium text-gray-900", "{format_money(result.monthly_taxes)}" }
+                        }
+                        div { class: "flex justify-between",
+                            span { class: "text-gray-600", "Insurance" }
+                            span { class: "font-medium text-gray-900", "{format_money(result.monthly_insurance)}" }
+                        }
+                        div { class: "flex justify-between",
+                            span { class: "text-gray-600", "HOA" }
+                            span { class: "font-medium text-gray-900", "{format_money(result.monthly_hoa)}" }
+                        }
+                        div { class: "flex justify-between border-t pt-2",
+                            span { class: "text-gray-700 font-semibold", "PITIA" }
+                            span { class: "font-bold text-gray-900", "{format_money(result.pitia)}" }
+                        }
+                        div { class: "flex justify-between items-center border-t pt-2",
+                            span { class: "text-gray-700 font-semibold", "DSCR" }
+                            span {
+                                class: if result.meets_minimum { "text-2xl font-bold text-green-700" } else { "text-2xl font-bold text-red-600" },
+                                "{result.ratio:.2}"
+                            }
+                        }
+                        p { class: if result.meets_minimum { "text-green-700" } else { "text-red-600" },
+                            if result.meets_minimum {
+                                "Meets the {result.min_ratio:.2} minimum"
+                            } else {
+                                "Below the {result.min_ratio:.2} minimum"
+                            }
+                        }
+                    }
+                }
+
+                div { class: "bg-white p-6 rounded-lg shadow-md mt-6",
+                    div { class: "flex items-center justify-between mb-4",
+                        h2 { class: "text-lg font-semibold text-gray-900", "Saved Scenarios" }
+                        input {
+                            r#type: "search",
+                            placeholder: "Search by address",
+                            value: "{search}",
+                            class: "border border-gray-300 rounded-md px-3 py-2 text-gray-900 text-sm",
+                            oninput: move |e: Event<FormData>| {
+                                search.set(e.value());
+                                page.set(1);
+                            },
+                        }
+                    }
+
+                    if saved().is_empty() {
+                        p { class: "text-sm text-gray-500", "No saved scenarios" }
+                    } else {
+                        table { class: "min-w-full table-auto text-sm",
+                            thead {
+                                tr { class: "bg-gray-50 text-left text-xs font-medium text-gray-500 uppercase",
+                                    th { class: "px-3 py-2", "Address" }
+                                    th { class: "px-3 py-2", "Price" }
+                                    th { class: "px-3 py-2", "Rent" }
+                                    th { class: "px-3 py-2", "DSCR" }
+                                    th { class: "px-3 py-2", "" }
+                                }
+                            }
+                            tbody {
+                                for item in saved() {
+                                    {
+                                        let ratio = calculate_dscr(&item, settings().min_dscr).ratio;
+                                        let id = item.id;
+                                        let opened = item.clone();
+                                        rsx! {
+                                            tr { key: "{id}", class: "border-t hover:bg-gray-50",
+                                                td { class: "px-3 py-2 text-gray-900",
+                                                    if item.property_address.is_empty() { "Untitled" } else { "{item.property_address}" }
+                                                }
+                                                td { class: "px-3 py-2 text-gray-700", "{format_money(item.purchase_price)}" }
+                                                td { class: "px-3 py-2 text-gray-700", "{format_money(item.e_x_rent)}" }
+                                                td { class: "px-3 py-2 font-semibold text-gray-900", "{ratio:.2}" }
+                                                td { class: "px-3 py-2 text-right space-x-3",
+                                                    button {
+                                                        class: "text-blue-600 hover:text-blue-800",
+                                                        onclick: move |_| {
+                                                            scenario.set(opened.clone());
+                                                            saved_id.set(Some(id));
+                                                        },
+                                                        "Open"
+                                                    }
+                                                    button {
+                                                        class: "text-red-600 hover:text-red-800",
+                                                        onclick: move |_| delete_scenario.call(id),
+                                                        "Delete"
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    div { class: "flex items-center justify-between mt-4 text-sm",
+                        button {
+                            class: "px-3 py-1 border rounded disabled:opacity-50",
+                            disabled: page() <= 1,
+                            onclick: move |_| page.set(page() - 1),
+                            "Previous"
+                        }
+                        span { class: "text-gray-600", "Page {page} of {page_count} ({total} scenarios)" }
+                        button {
+                            class: "px-3 py-1 border rounded disabled:opacity-50",
+                            disabled: page() >= page_count,
+                            onclick: move |_| page.set(page() + 1),
+                            "Next"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
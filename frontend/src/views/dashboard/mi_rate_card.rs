@@ -0,0 +1,189 @@
+use dioxus::prelude::*;
+use shared::calc::pmi::{RATE_CARD_CSV_HEADER, rate_card_to_csv};
+use shared::models::MiRateCardEntry;
+
+// Editor for the conventional MI rate card used by the options template PMI estimate
+#[component]
+pub fn MiRateCardSettings() -> Element {
+    let client = use_context::<repository::Repository>();
+    let mut rate_card = use_signal(Vec::<MiRateCardEntry>::new);
+    let mut csv_text = use_signal(String::new);
+    let mut status_message = use_signal(|| None::<String>);
+
+    let client_clone = client.clone();
+    use_effect(move || {
+        let client = client_clone.clone();
+        spawn(async move {
+            match client.get_mi_rate_card().await {
+                Ok(entries) => rate_card.set(entries),
+                Err(e) => log::error!("Failed to load MI rate card: {:?}", e),
+            }
+        });
+    });
+
+    // Saves one row after a cell is edited
+    let client_clone = client.clone();
+    let update_entry = move |index: usize, field: &str, value: String| {
+        let mut entry = match rate_card().get(index) {
+            Some(entry) => entry.clone(),
+            None => return,
+        };
+        match field {
+            "ltv_min" => entry.ltv_min = value.parse().unwrap_or(0.0),
+            "ltv_max" => entry.ltv_max = value.parse().unwrap_or(0.0),
+            "credit_score_min" => entry.credit_score_min = value.parse().unwrap_or(0),
+            "credit_score_max" => entry.credit_score_max = value.parse().unwrap_or(0),
+            "coverage" => entry.coverage = value.parse().unwrap_or(0.0),
+            "term_min_months" => entry.term_min_months = value.parse().unwrap_or(0),
+            "term_max_months" => entry.term_max_months = value.parse().unwrap_or(0),
+            "annual_rate" => entry.annual_rate = value.parse().unwrap_or(0.0),
+            _ => {}
+        }
+        rate_card.write()[index] = entry.clone();
+
+        let client = client_clone.clone();
+        spawn(async move {
+            match client.save_mi_rate_card_entry(entry).await {
+                Ok(id) => rate_card.write()[index].id = id,
+                Err(e) => log::error!("Failed to save MI rate card row: {:?}", e),
+            }
+        });
+    };
+
+    let client_clone = client.clone();
+    let add_entry = move |_| {
+        let client = client_clone.clone();
+        spawn(async move {
+            let entry = MiRateCardEntry {
+                term_min_months: 241,
+                term_max_months: 480,
+                credit_score_max: 850,
+                ..Default::default()
+            };
+            match client.save_mi_rate_card_entry(entry.clone()).await {
+                Ok(id) => rate_card.write().push(MiRateCardEntry { id, ..entry }),
+                Err(e) => log::error!("Failed to add MI rate card row: {:?}", e),
+            }
+        });
+    };
+
+    let client_clone = client.clone();
+    let delete_entry = move |id: i32| {
+        let client = client_clone.clone();
+        spawn(async move {
+            match client.delete_mi_rate_card_entry(id).await {
+                Ok(_) => rate_card.write().retain(|entry| entry.id != id),
+                Err(e) => log::error!("Failed to delete MI rate card row: {:?}", e),
+            }
+        });
+    };
+
+    let client_clone = client.clone();
+    let import_csv = move |_| {
+        let client = client_clone.clone();
+        spawn(async move {
+            match client.import_mi_rate_card_csv(&csv_text()).await {
+                Ok(count) => {
+                    status_message.set(Some(format!("Imported {} rows", count)));
+                    csv_text.set(String::new());
+                    match client.get_mi_rate_card().await {
+                        Ok(entries) => rate_card.set(entries),
+                        Err(e) => log::error!("Failed to reload MI rate card: {:?}", e),
+                    }
+                }
+                Err(e) => status_message.set(Some(format!("Import failed: {}", e))),
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "bg-white p-6 rounded-lg shadow-md",
+            h2 { class: "text-xl font-semibold text-gray-800 mb-2", "Mortgage Insurance Rate Card" }
+            p { class: "text-sm text-gray-500 mb-4",
+                "Annual MI rates for conventional loans. A row applies when the LTV is above the minimum and at or below the maximum."
+            }
+
+            div { class: "overflow-x-auto",
+                table { class: "min-w-full table-auto border-collapse border border-gray-300 text-sm",
+                    thead {
+                        tr { class: "bg-gray-50",
+                            for heading in ["LTV From", "LTV To", "Score From", "Score To", "Coverage %", "Term From", "Term To", "Annual Rate %", ""] {
+                                th { class: "border border-gray-300 px-2 py-2 text-left text-xs font-medium text-gray-500 uppercase",
+                                    "{heading}"
+                                }
+                            }
+                        }
+                    }
+                    tbody {
+                        for (index, entry) in rate_card().into_iter().enumerate() {
+                            tr { key: "{entry.id}",
+                                for (field, value) in [
+                                    ("ltv_min", entry.ltv_min.to_string()),
+                                    ("ltv_max", entry.ltv_max.to_string()),
+                                    ("credit_score_min", entry.credit_score_min.to_string()),
+                                    ("credit_score_max", entry.credit_score_max.to_string()),
+                                    ("coverage", entry.coverage.to_string()),
+                                    ("term_min_months", entry.term_min_months.to_string()),
+                                    ("term_max_months", entry.term_max_months.to_string()),
+                                    ("annual_rate", entry.annual_rate.to_string()),
+                                ] {
+                                    td { class: "border border-gray-300 px-2 py-1",
+                                        input {
+                                            r#type: "number",
+                                            step: "any",
+                                            value: "{value}",
+                                            class: "w-20 px-1 py-1 border rounded",
+                                            onchange: {
+                                                let mut update_entry = update_entry.clone();
+                                                move |e: Event<FormData>| update_entry(index, field, e.value())
+                                            },
+                                        }
+                                    }
+                                }
+                                td { class: "border border-gray-300 px-2 py-1",
+                                    button {
+                                        class: "text-red-600 hover:text-red-800",
+                                        onclick: {
+                                            let mut delete_entry = delete_entry.clone();
+                                            let id = entry.id;
+                                            move |_| delete_entry(id)
+                                        },
+                                        "Remove"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            button {
+                class: "mt-3 bg-blue-600 text-white px-4 py-2 rounded hover:bg-blue-700 text-sm",
+                onclick: add_entry,
+                "Add Row"
+            }
+
+            // CSV import replaces the whole card
+            div { class: "mt-6",
+                label { class: "text-sm font-medium text-gray-700", "Import from CSV" }
+                p { class: "text-xs text-gray-500 mb-2", "Columns: {RATE_CARD_CSV_HEADER}" }
+                textarea {
+                    class: "w-full h-32 border border-gray-300 rounded-md px-3 py-2 font-mono text-xs text-gray-900",
+                    placeholder: "{rate_card_to_csv(&[])}",
+                    value: "{csv_text}",
+                    oninput: move |e| csv_text.set(e.value()),
+                }
+                div { class: "flex items-center gap-4 mt-2",
+                    button {
+                        class: "bg-green-600 text-white px-4 py-2 rounded hover:bg-green-700 text-sm",
+                        onclick: import_csv,
+                        "Replace Rate Card"
+                    }
+                    if let Some(message) = status_message() {
+                        span { class: "text-sm text-gray-600", "{message}" }
+                    }
+                }
+            }
+        }
+    }
+}
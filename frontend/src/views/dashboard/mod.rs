@@ -7,6 +7,12 @@ pub use analytics::Analytics;
 pub mod settings;
 pub use settings::Settings;
 
+pub mod dscr;
+pub use dscr::DscrCalculator;
+
+pub mod mi_rate_card;
+pub use mi_rate_card::MiRateCardSettings;
+
 pub mod main_dash;
 pub use main_dash::MainDashboard;
 
@@ -1,6 +1,8 @@
 use dioxus::prelude::*;
+use shared::calc::rule_set::IncomeRuleSet;
 use shared::models::AppSettings;
 use crate::components::ThemeToggle;
+use crate::views::dashboard::MiRateCardSettings;
 
 #[component]
 pub fn Settings() -> Element {
@@ -46,6 +48,38 @@ pub fn Settings() -> Element {
         });
     };
 
+    // Saves one factor of the custom income rule set after it is edited
+    let client_clone = client.clone();
+    let update_custom_rule = move |field: &str, value: String| {
+        let Ok(value) = value.parse::<f64>() else {
+            return;
+        };
+        {
+            let mut current = settings.write();
+            let rules = &mut current.custom_rule_set;
+            match field {
+                "non_taxable_gross_up" => rules.non_taxable_gross_up = value,
+                "social_security_taxable_share" => rules.social_security_taxable_share = value,
+                "pension_first_tier_limit" => rules.pension_first_tier_limit = value,
+                "pension_first_tier_factor" => rules.pension_first_tier_factor = value,
+                "pension_second_tier_factor" => rules.pension_second_tier_factor = value,
+                "alimony_factor" => rules.alimony_factor = value,
+                "rental_vacancy_factor" => rules.rental_vacancy_factor = value,
+                "continuance_months" => rules.continuance_months = value.max(0.0) as u32,
+                "retirement_asset_factor" => rules.retirement_asset_factor = value,
+                "asset_depletion_months" => rules.asset_depletion_months = value.max(0.0) as u32,
+                _ => {}
+            }
+        }
+        let client = client_clone.clone();
+        spawn(async move {
+            match client.save_settings(settings()).await {
+                Ok(_) => log::info!("Settings saved"),
+                Err(e) => log::error!("Failed to save: {:?}", e),
+            }
+        });
+    };
+
     rsx! {
         div { class: "min-h-screen bg-gray-100 p-6",
             div { class: "max-w-4xl mx-auto",
@@ -247,10 +281,10 @@ pub fn Settings() -> Element {
                                 div { class: "flex items-center justify-between",
                                     div {
                                         label { class: "text-sm font-medium text-gray-700",
-                                            "DTI Threshold Warning"
+                                            "Default DTI Limit"
                                         }
                                         p { class: "text-sm text-gray-500",
-                                            "Warn when DTI exceeds this percentage"
+                                            "Back-end limit for Non-QM loans; FHA, VA and conventional use program limits"
                                         }
                                     }
                                     input {
@@ -274,9 +308,97 @@ pub fn Settings() -> Element {
                                     }
                                     span { class: "text-sm text-gray-500 ml-2", "%" }
                                 }
+                                div { class: "flex items-center justify-between",
+                                    div {
+                                        label { class: "text-sm font-medium text-gray-700",
+                                            "Minimum DSCR"
+                                        }
+                                        p { class: "text-sm text-gray-500",
+                                            "Lowest rent-to-PITIA ratio accepted by the DSCR calculator"
+                                        }
+                                    }
+                                    input {
+                                        r#type: "number",
+                                        step: "0.01",
+                                        value: "{settings().min_dscr}",
+                                        onchange: move |e| {
+                                            if let Ok(min_dscr) = e.value().parse::<f64>() {
+                                                let client = use_context::<repository::Repository>();
+                                                settings.write().min_dscr = min_dscr;
+                                                spawn(async move {
+                                                    let current_settings = settings();
+                                                    match client.save_settings(current_settings).await {
+                                                        Ok(_) => log::info!("Settings saved"),
+                                                        Err(e) => log::error!("Failed to save: {:?}", e),
+                                                    }
+                                                });
+                                            }
+                                        },
+                                        class: "border border-gray-300 rounded-md px-3 py-2 w-20 text-gray-900",
+                                    }
+                                }
+                                div { class: "flex items-center justify-between",
+                                    div {
+                                        label { class: "text-sm font-medium text-gray-700",
+                                            "Income Decline Threshold (%)"
+                                        }
+                                        p { class: "text-sm text-gray-500",
+                                            "Year-over-year decline beyond which the worksheet qualifies on the declining figure alone"
+                                        }
+                                    }
+                                    input {
+                                        r#type: "number",
+                                        step: "0.1",
+                                        value: "{settings().income_decline_threshold}",
+                                        onchange: move |e| {
+                                            if let Ok(threshold) = e.value().parse::<f64>() {
+                                                let client = use_context::<repository::Repository>();
+                                                settings.write().income_decline_threshold = threshold;
+                                                spawn(async move {
+                                                    let current_settings = settings();
+                                                    match client.save_settings(current_settings).await {
+                                                        Ok(_) => log::info!("Settings saved"),
+                                                        Err(e) => log::error!("Failed to save: {:?}", e),
+                                                    }
+                                                });
+                                            }
+                                        },
+                                        class: "border border-gray-300 rounded-md px-3 py-2 w-20 text-gray-900",
+                                    }
+                                }
                             }
                         }
 
+                        // Custom Income Rule Set
+                        div { class: "bg-white p-6 rounded-lg shadow-md",
+                            h2 { class: "text-xl font-semibold text-gray-800 mb-2",
+                                "Custom Income Rule Set"
+                            }
+                            p { class: "text-sm text-gray-500 mb-4",
+                                "Factors used by the income worksheet for Non-QM loans and whenever the Custom profile is selected"
+                            }
+                            div { class: "space-y-4",
+                                for (field, label, value) in custom_rule_fields(&settings().custom_rule_set) {
+                                    div { key: "{field}", class: "flex items-center justify-between",
+                                        label { class: "text-sm font-medium text-gray-700", "{label}" }
+                                        input {
+                                            r#type: "number",
+                                            step: "0.01",
+                                            value: "{value}",
+                                            onchange: {
+                                                let mut update_custom_rule = update_custom_rule.clone();
+                                                move |e: Event<FormData>| update_custom_rule(field, e.value())
+                                            },
+                                            class: "border border-gray-300 rounded-md px-3 py-2 w-28 text-gray-900",
+                                        }
+                                    }
+                                }
+                            }
+                        }
+
+                        // Mortgage Insurance Rate Card
+                        MiRateCardSettings {}
+
                         // About & Support
                         div { class: "bg-white p-6 rounded-lg shadow-md",
                             h2 { class: "text-xl font-semibold text-gray-800 mb-4",
@@ -325,4 +447,19 @@ pub fn Settings() -> Element {
                 }
             }
         }
-    }}
\ No newline at end of file
+    }}
+// Editable fields of the custom rule set as (key, label, current value)
+fn custom_rule_fields(rules: &IncomeRuleSet) -> Vec<(&'static str, &'static str, f64)> {
+    vec![
+        ("non_taxable_gross_up", "Non-Taxable Gross-Up Factor", rules.non_taxable_gross_up),
+        ("social_security_taxable_share", "Social Security Taxable Share", rules.social_security_taxable_share),
+        ("pension_first_tier_limit", "Pension First Tier Limit ($)", rules.pension_first_tier_limit),
+        ("pension_first_tier_factor", "Pension First Tier Factor", rules.pension_first_tier_factor),
+        ("pension_second_tier_factor", "Pension Second Tier Factor", rules.pension_second_tier_factor),
+        ("alimony_factor", "Alimony Factor", rules.alimony_factor),
+        ("rental_vacancy_factor", "Rental Lease Factor (after vacancy)", rules.rental_vacancy_factor),
+        ("continuance_months", "Continuance (months)", rules.continuance_months as f64),
+        ("retirement_asset_factor", "Restricted Retirement Asset Factor", rules.retirement_asset_factor),
+        ("asset_depletion_months", "Asset Depletion Divisor (months)", rules.asset_depletion_months as f64),
+    ]
+}
@@ -27,6 +27,7 @@ pub fn parse_loan_type(s: &str) -> LoanType {
         "CNV" => LoanType::CNV,
         "FHA" => LoanType::FHA,
         "VA" => LoanType::VA,
+        "USDA" => LoanType::USDA,
         "NonQM" => LoanType::NonQM,
         _ => LoanType::CNV,
     }
@@ -52,6 +53,15 @@ pub fn parse_credit_type(s: &str) -> CreditType {
     }
 }
 
+pub fn parse_student_loan_status(s: &str) -> Option<StudentLoanStatus> {
+    match s {
+        "Repayment" => Some(StudentLoanStatus::Repayment),
+        "Deferred" => Some(StudentLoanStatus::Deferred),
+        "IncomeDriven" => Some(StudentLoanStatus::IncomeDriven),
+        _ => None,
+    }
+}
+
 pub fn parse_status(s: &str) -> Status {
     match s {
         "Active" => Status::Active,
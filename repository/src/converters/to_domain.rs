@@ -39,6 +39,8 @@ pub fn loan_information_to_domain(model: &loan_information::Model) -> LoanInform
         new_term_months: model.new_term_months,
         loan_purpose: parse_loan_purpose(&model.loan_purpose),
         appraisal_waiver: model.appraisal_waiver,
+        credit_score: model.credit_score.max(0) as u32,
+        agency: model.agency.clone(),
         created_at: model.created_at,
         updated_at: model.updated_at,
     }
@@ -187,3 +189,21 @@ pub fn condition_to_domain(model: &database::entities::client::condition::Model)
         updated_at: model.updated_at,
     }
 }
+
+/// Convert database dscr_calculators model to domain model
+pub fn dscr_calculator_to_domain(model: &dscr_calculators::Model) -> DSCRCalculator {
+    DSCRCalculator {
+        id: model.id,
+        property_address: model.property_address.clone(),
+        purchase_price: model.purchase_price.to_f64().unwrap_or(0.0),
+        down_payment: model.down_payment.to_f64().unwrap_or(0.0),
+        rate: model.rate.to_f64().unwrap_or(0.0),
+        term: Term::from_months(model.term_months.max(0) as u32),
+        annual_property_tax: model.annual_property_tax.to_f64().unwrap_or(0.0),
+        annual_insurance: model.annual_insurance.to_f64().unwrap_or(0.0),
+        monthly_hoa: model.monthly_hoa.to_f64().unwrap_or(0.0),
+        e_x_rent: model.expected_rent.to_f64().unwrap_or(0.0),
+        created_at: model.created_at,
+        updated_at: model.updated_at,
+    }
+}
@@ -117,6 +117,28 @@ impl Repository {
         settings::SettingsRepository::save(&db, settings).await
     }
 
+    // ===== MI Rate Card Operations =====
+
+    pub async fn get_mi_rate_card(&self) -> Result<Vec<shared::models::MiRateCardEntry>, Box<dyn std::error::Error>> {
+        let db = self.db().await;
+        mi_rate_card::MiRateCardRepository::get_all(&db).await
+    }
+
+    pub async fn save_mi_rate_card_entry(&self, entry: shared::models::MiRateCardEntry) -> Result<i32, Box<dyn std::error::Error>> {
+        let db = self.db().await;
+        mi_rate_card::MiRateCardRepository::save(&*db, entry).await
+    }
+
+    pub async fn delete_mi_rate_card_entry(&self, id: i32) -> Result<(), Box<dyn std::error::Error>> {
+        let db = self.db().await;
+        mi_rate_card::MiRateCardRepository::delete(&db, id).await
+    }
+
+    pub async fn import_mi_rate_card_csv(&self, csv: &str) -> Result<usize, Box<dyn std::error::Error>> {
+        let db = self.db().await;
+        mi_rate_card::MiRateCardRepository::import_csv(&db, csv).await
+    }
+
     // ===== Income Operations =====
     // Delegated to income module
     pub async fn save_income_information(&self, income: shared::models::IncomeInformation) -> Result<(), Box<dyn std::error::Error>> {
@@ -170,6 +192,171 @@ impl Repository {
         general_income::delete_general_income_data(&db, borrower_id).await
     }
 
+    // ===== Self-Employment Income Operations =====
+    pub async fn save_self_employment_data(&self, borrower_id: i32, data: shared::models::SelfEmploymentData) -> Result<(), Box<dyn std::error::Error>> {
+        let db = self.db.lock().await;
+        self_employment::save_self_employment_data(&db, borrower_id, data).await
+    }
+
+    pub async fn get_self_employment_data(&self, borrower_id: i32) -> Result<Option<shared::models::SelfEmploymentData>, Box<dyn std::error::Error>> {
+        let db = self.db.lock().await;
+        self_employment::get_self_employment_data(&db, borrower_id).await
+    }
+
+    pub async fn delete_self_employment_data(&self, borrower_id: i32) -> Result<(), Box<dyn std::error::Error>> {
+        let db = self.db.lock().await;
+        self_employment::delete_self_employment_data(&db, borrower_id).await
+    }
+
+    // ===== Rental Income Operations =====
+    pub async fn save_rental_income_data(&self, borrower_id: i32, data: shared::models::RentalIncomeData) -> Result<(), Box<dyn std::error::Error>> {
+        let db = self.db.lock().await;
+        rental_income::save_rental_income_data(&db, borrower_id, data).await
+    }
+
+    pub async fn get_rental_income_data(&self, borrower_id: i32) -> Result<Option<shared::models::RentalIncomeData>, Box<dyn std::error::Error>> {
+        let db = self.db.lock().await;
+        rental_income::get_rental_income_data(&db, borrower_id).await
+    }
+
+    pub async fn delete_rental_income_data(&self, borrower_id: i32) -> Result<(), Box<dyn std::error::Error>> {
+        let db = self.db.lock().await;
+        rental_income::delete_rental_income_data(&db, borrower_id).await
+    }
+
+    // ===== Income Trend Operations =====
+    pub async fn save_income_trend(&self, borrower_id: i32, section: &str, qualifying_monthly: f64, trend: &shared::calc::income_trend::IncomeTrend) -> Result<(), Box<dyn std::error::Error>> {
+        let db = self.db.lock().await;
+        income_trend::save_income_trend(&db, borrower_id, section, qualifying_monthly, trend).await
+    }
+
+    pub async fn get_income_trend(&self, borrower_id: i32, section: &str) -> Result<Option<shared::calc::income_trend::IncomeTrend>, Box<dyn std::error::Error>> {
+        let db = self.db.lock().await;
+        income_trend::get_income_trend(&db, borrower_id, section).await
+    }
+
+    pub async fn get_w2_section_results(&self, borrower_id: i32) -> Result<Vec<shared::calc::income_trend::W2SectionResult>, Box<dyn std::error::Error>> {
+        let db = self.db.lock().await;
+        income_trend::get_w2_section_results(&db, borrower_id).await
+    }
+
+    pub async fn delete_income_trends(&self, borrower_id: i32) -> Result<(), Box<dyn std::error::Error>> {
+        let db = self.db.lock().await;
+        income_trend::delete_income_trends(&db, borrower_id).await
+    }
+
+    // ===== DSCR Calculator Operations =====
+
+    pub async fn create_dscr_calculator(&self, data: shared::models::CreateDSCRCalculator) -> Result<shared::models::DSCRCalculator, Box<dyn std::error::Error>> {
+        let db = self.db().await;
+        dscr::DSCRRepository::create(&db, data).await
+    }
+
+    pub async fn get_dscr_calculator(&self, id: uuid::Uuid) -> Result<Option<shared::models::DSCRCalculator>, Box<dyn std::error::Error>> {
+        let db = self.db().await;
+        dscr::DSCRRepository::get_by_id(&db, id).await
+    }
+
+    pub async fn list_dscr_calculators(&self, params: shared::models::DSCRCalculatorQueryParams) -> Result<Vec<shared::models::DSCRCalculator>, Box<dyn std::error::Error>> {
+        let db = self.db().await;
+        dscr::DSCRRepository::list(&db, &params).await
+    }
+
+    pub async fn count_dscr_calculators(&self, params: shared::models::DSCRCalculatorQueryParams) -> Result<u64, Box<dyn std::error::Error>> {
+        let db = self.db().await;
+        dscr::DSCRRepository::count(&db, &params).await
+    }
+
+    pub async fn update_dscr_calculator(&self, id: uuid::Uuid, data: shared::models::UpdateDSCRCalculator) -> Result<Option<shared::models::DSCRCalculator>, Box<dyn std::error::Error>> {
+        let db = self.db().await;
+        dscr::DSCRRepository::update(&db, id, data).await
+    }
+
+    pub async fn delete_dscr_calculator(&self, id: uuid::Uuid) -> Result<(), Box<dyn std::error::Error>> {
+        let db = self.db().await;
+        dscr::DSCRRepository::delete(&db, id).await
+    }
+
+    // ===== Bank Statement Operations =====
+    pub async fn save_bank_statement_data(&self, borrower_id: i32, data: shared::models::BankStatementData) -> Result<(), Box<dyn std::error::Error>> {
+        let db = self.db.lock().await;
+        bank_statement::save_bank_statement_data(&db, borrower_id, data).await
+    }
+
+    pub async fn get_bank_statement_data(&self, borrower_id: i32) -> Result<Option<shared::models::BankStatementData>, Box<dyn std::error::Error>> {
+        let db = self.db.lock().await;
+        bank_statement::get_bank_statement_data(&db, borrower_id).await
+    }
+
+    pub async fn delete_bank_statement_data(&self, borrower_id: i32) -> Result<(), Box<dyn std::error::Error>> {
+        let db = self.db.lock().await;
+        bank_statement::delete_bank_statement_data(&db, borrower_id).await
+    }
+
+    // ===== Boarder and ADU Income Operations =====
+    pub async fn save_accessory_income_data(&self, borrower_id: i32, data: shared::models::AccessoryIncomeData) -> Result<(), Box<dyn std::error::Error>> {
+        let db = self.db.lock().await;
+        accessory_income::save_accessory_income_data(&db, borrower_id, data).await
+    }
+
+    pub async fn get_accessory_income_data(&self, borrower_id: i32) -> Result<Option<shared::models::AccessoryIncomeData>, Box<dyn std::error::Error>> {
+        let db = self.db.lock().await;
+        accessory_income::get_accessory_income_data(&db, borrower_id).await
+    }
+
+    pub async fn delete_accessory_income_data(&self, borrower_id: i32) -> Result<(), Box<dyn std::error::Error>> {
+        let db = self.db.lock().await;
+        accessory_income::delete_accessory_income_data(&db, borrower_id).await
+    }
+
+    // ===== Investment Income Operations =====
+    pub async fn save_investment_income_data(&self, borrower_id: i32, data: shared::models::InvestmentIncomeData) -> Result<(), Box<dyn std::error::Error>> {
+        let db = self.db.lock().await;
+        investment_income::save_investment_income_data(&db, borrower_id, data).await
+    }
+
+    pub async fn get_investment_income_data(&self, borrower_id: i32) -> Result<Option<shared::models::InvestmentIncomeData>, Box<dyn std::error::Error>> {
+        let db = self.db.lock().await;
+        investment_income::get_investment_income_data(&db, borrower_id).await
+    }
+
+    pub async fn delete_investment_income_data(&self, borrower_id: i32) -> Result<(), Box<dyn std::error::Error>> {
+        let db = self.db.lock().await;
+        investment_income::delete_investment_income_data(&db, borrower_id).await
+    }
+
+    // ===== Military Income Operations =====
+    pub async fn save_military_income_data(&self, borrower_id: i32, data: shared::models::MilitaryIncomeData) -> Result<(), Box<dyn std::error::Error>> {
+        let db = self.db.lock().await;
+        military_income::save_military_income_data(&db, borrower_id, data).await
+    }
+
+    pub async fn get_military_income_data(&self, borrower_id: i32) -> Result<Option<shared::models::MilitaryIncomeData>, Box<dyn std::error::Error>> {
+        let db = self.db.lock().await;
+        military_income::get_military_income_data(&db, borrower_id).await
+    }
+
+    pub async fn delete_military_income_data(&self, borrower_id: i32) -> Result<(), Box<dyn std::error::Error>> {
+        let db = self.db.lock().await;
+        military_income::delete_military_income_data(&db, borrower_id).await
+    }
+
+    // ===== Retirement Asset Operations =====
+    pub async fn save_retirement_assets_data(&self, borrower_id: i32, data: shared::models::RetirementAssetsData) -> Result<(), Box<dyn std::error::Error>> {
+        let db = self.db.lock().await;
+        retirement_assets::save_retirement_assets_data(&db, borrower_id, data).await
+    }
+
+    pub async fn get_retirement_assets_data(&self, borrower_id: i32) -> Result<Option<shared::models::RetirementAssetsData>, Box<dyn std::error::Error>> {
+        let db = self.db.lock().await;
+        retirement_assets::get_retirement_assets_data(&db, borrower_id).await
+    }
+
+    pub async fn delete_retirement_assets_data(&self, borrower_id: i32) -> Result<(), Box<dyn std::error::Error>> {
+        let db = self.db.lock().await;
+        retirement_assets::delete_retirement_assets_data(&db, borrower_id).await
+    }
+
     // ===== Options Template Operations =====
     // Delegated to options_template module (complex operations)
     pub async fn save_options_template(&self, template: shared::models::OptionsTemplateData, borrower_id: i32) -> Result<(), Box<dyn std::error::Error>> {
@@ -0,0 +1,98 @@
+use sea_orm::{DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, ColumnTrait, Set, ActiveModelTrait};
+use shared::models::{AccessoryIncomeData, BoarderRentMonth};
+use database::entities::{accessory_income, boarder_rent_months};
+use rust_decimal::{Decimal, prelude::ToPrimitive};
+use chrono::Utc;
+use uuid::Uuid;
+
+fn decimal(value: f64) -> Decimal {
+    Decimal::from_f64_retain(value).unwrap_or_default().round_dp(2)
+}
+
+/// Save the boarder and ADU worksheet for a borrower, replacing any saved rent history
+pub async fn save_accessory_income_data(
+    db: &DatabaseConnection,
+    borrower_id: i32,
+    data: AccessoryIncomeData,
+) -> Result<(), Box<dyn std::error::Error>> {
+    delete_accessory_income_data(db, borrower_id).await?;
+
+    let accessory_active = accessory_income::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        borrower_id: Set(borrower_id),
+        program: Set(data.program),
+        boarder_name: Set(data.boarder_name),
+        adu_monthly_rent: Set(decimal(data.adu_monthly_rent)),
+        adu_lease_signed: Set(data.adu_lease_signed),
+        created_at: Set(Utc::now()),
+        updated_at: Set(Utc::now()),
+    };
+    accessory_active.insert(db).await?;
+
+    for (index, month) in data.boarder_months.into_iter().enumerate() {
+        let month_active = boarder_rent_months::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            borrower_id: Set(borrower_id),
+            month_index: Set(index as i32),
+            month: Set(month.month),
+            amount: Set(decimal(month.amount)),
+            created_at: Set(Utc::now()),
+            updated_at: Set(Utc::now()),
+        };
+        month_active.insert(db).await?;
+    }
+
+    Ok(())
+}
+
+/// Load the boarder and ADU worksheet for a borrower
+pub async fn get_accessory_income_data(
+    db: &DatabaseConnection,
+    borrower_id: i32,
+) -> Result<Option<AccessoryIncomeData>, Box<dyn std::error::Error>> {
+    let Some(accessory) = accessory_income::Entity::find()
+        .filter(accessory_income::Column::BorrowerId.eq(borrower_id))
+        .one(db)
+        .await?
+    else {
+        return Ok(None);
+    };
+
+    let months = boarder_rent_months::Entity::find()
+        .filter(boarder_rent_months::Column::BorrowerId.eq(borrower_id))
+        .order_by_asc(boarder_rent_months::Column::MonthIndex)
+        .all(db)
+        .await?;
+
+    Ok(Some(AccessoryIncomeData {
+        program: accessory.program,
+        boarder_name: accessory.boarder_name,
+        boarder_months: months
+            .into_iter()
+            .map(|model| BoarderRentMonth {
+                month: model.month,
+                amount: model.amount.to_f64().unwrap_or(0.0),
+            })
+            .collect(),
+        adu_monthly_rent: accessory.adu_monthly_rent.to_f64().unwrap_or(0.0),
+        adu_lease_signed: accessory.adu_lease_signed,
+    }))
+}
+
+/// Delete the boarder and ADU worksheet for a borrower
+pub async fn delete_accessory_income_data(
+    db: &DatabaseConnection,
+    borrower_id: i32,
+) -> Result<(), Box<dyn std::error::Error>> {
+    boarder_rent_months::Entity::delete_many()
+        .filter(boarder_rent_months::Column::BorrowerId.eq(borrower_id))
+        .exec(db)
+        .await?;
+
+    accessory_income::Entity::delete_many()
+        .filter(accessory_income::Column::BorrowerId.eq(borrower_id))
+        .exec(db)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use sea_orm::{DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, ColumnTrait, Set, ActiveModelTrait};
+use shared::models::{BankStatementAccount, BankStatementData, BankStatementMonth};
+use database::entities::{bank_statement_accounts, bank_statement_months};
+use rust_decimal::{Decimal, prelude::ToPrimitive};
+use chrono::Utc;
+use uuid::Uuid;
+
+fn decimal(value: f64) -> Decimal {
+    Decimal::from_f64_retain(value).unwrap_or_default()
+}
+
+fn convert_month(model: &bank_statement_months::Model) -> BankStatementMonth {
+    BankStatementMonth {
+        month: model.month.clone(),
+        total_deposits: model.total_deposits.to_f64().unwrap_or(0.0),
+        excluded_deposits: model.excluded_deposits.to_f64().unwrap_or(0.0),
+        nsf_count: model.nsf_count.max(0) as u32,
+    }
+}
+
+/// Save the bank-statement worksheet for a borrower, replacing any saved accounts
+pub async fn save_bank_statement_data(
+    db: &DatabaseConnection,
+    borrower_id: i32,
+    data: BankStatementData,
+) -> Result<(), Box<dyn std::error::Error>> {
+    delete_bank_statement_data(db, borrower_id).await?;
+
+    for account in &data.accounts {
+        let account_active = bank_statement_accounts::ActiveModel {
+            id: Set(account.id.clone()),
+            borrower_id: Set(borrower_id),
+            account_name: Set(account.account_name.clone()),
+            account_type: Set(account.account_type.clone()),
+            expense_method: Set(account.expense_method.clone()),
+            expense_percent: Set(decimal(account.expense_percent)),
+            ownership_percent: Set(decimal(account.ownership_percent)),
+            created_at: Set(Utc::now()),
+            updated_at: Set(Utc::now()),
+        };
+        account_active.insert(db).await?;
+
+        for (index, month) in account.months.iter().enumerate() {
+            let month_active = bank_statement_months::ActiveModel {
+                id: Set(Uuid::new_v4()),
+                account_id: Set(account.id.clone()),
+                borrower_id: Set(borrower_id),
+                month_index: Set(index as i32),
+                month: Set(month.month.clone()),
+                total_deposits: Set(decimal(month.total_deposits)),
+                excluded_deposits: Set(decimal(month.excluded_deposits)),
+                nsf_count: Set(month.nsf_count as i32),
+                created_at: Set(Utc::now()),
+                updated_at: Set(Utc::now()),
+            };
+            month_active.insert(db).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Load the bank-statement worksheet for a borrower
+pub async fn get_bank_statement_data(
+    db: &DatabaseConnection,
+    borrower_id: i32,
+) -> Result<Option<BankStatementData>, Box<dyn std::error::Error>> {
+    let accounts = bank_statement_accounts::Entity::find()
+        .filter(bank_statement_accounts::Column::BorrowerId.eq(borrower_id))
+        .all(db)
+        .await?;
+
+    if accounts.is_empty() {
+        return Ok(None);
+    }
+
+    let months = bank_statement_months::Entity::find()
+        .filter(bank_statement_months::Column::BorrowerId.eq(borrower_id))
+        .order_by_asc(bank_statement_months::Column::MonthIndex)
+        .all(db)
+        .await?;
+
+    let accounts = accounts
+        .into_iter()
+        .map(|model| BankStatementAccount {
+            months: months
+                .iter()
+                .filter(|month| month.account_id == model.id)
+                .map(convert_month)
+                .collect(),
+            expense_percent: model.expense_percent.to_f64().unwrap_or(0.0),
+            ownership_percent: model.ownership_percent.to_f64().unwrap_or(0.0),
+            id: model.id,
+            account_name: model.account_name,
+            account_type: model.account_type,
+            expense_method: model.expense_method,
+        })
+        .collect();
+
+    Ok(Some(BankStatementData { accounts }))
+}
+
+/// Delete the bank-statement worksheet for a borrower
+pub async fn delete_bank_statement_data(
+    db: &DatabaseConnection,
+    borrower_id: i32,
+) -> Result<(), Box<dyn std::error::Error>> {
+    // Delete months first (due to foreign key constraints)
+    bank_statement_months::Entity::delete_many()
+        .filter(bank_statement_months::Column::BorrowerId.eq(borrower_id))
+        .exec(db)
+        .await?;
+
+    bank_statement_accounts::Entity::delete_many()
+        .filter(bank_statement_accounts::Column::BorrowerId.eq(borrower_id))
+        .exec(db)
+        .await?;
+
+    Ok(())
+}
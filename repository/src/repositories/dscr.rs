@@ -0,0 +1,114 @@
+//! DSCR repository - handles saved investor-loan DSCR scenarios
+
+use sea_orm::{DatabaseConnection, EntityTrait, ActiveModelTrait, Set, PaginatorTrait, QueryFilter, QueryOrder, ColumnTrait, Select};
+use shared::models::*;
+use database::entities::dscr_calculators;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use crate::converters::*;
+
+const DEFAULT_PER_PAGE: u64 = 20;
+
+fn decimal(value: f64) -> Decimal {
+    Decimal::from_f64_retain(value).unwrap_or_default().round_dp(2)
+}
+
+// The rate column keeps three decimal places, e.g. 6.125%
+fn rate_decimal(value: f64) -> Decimal {
+    Decimal::from_f64_retain(value).unwrap_or_default().round_dp(3)
+}
+
+pub struct DSCRRepository;
+
+impl DSCRRepository {
+    // Scenarios whose address contains the search text, newest first
+    fn filtered(params: &DSCRCalculatorQueryParams) -> Select<dscr_calculators::Entity> {
+        let mut query = dscr_calculators::Entity::find().order_by_desc(dscr_calculators::Column::UpdatedAt);
+        if let Some(search) = params.search.as_deref().map(str::trim).filter(|search| !search.is_empty()) {
+            query = query.filter(dscr_calculators::Column::PropertyAddress.contains(search));
+        }
+        query
+    }
+
+    pub async fn create(db: &DatabaseConnection, data: CreateDSCRCalculator) -> Result<DSCRCalculator, Box<dyn std::error::Error>> {
+        let now = chrono::Utc::now();
+        let active_model = dscr_calculators::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            property_address: Set(data.property_address),
+            purchase_price: Set(decimal(data.purchase_price)),
+            down_payment: Set(decimal(data.down_payment)),
+            rate: Set(rate_decimal(data.rate)),
+            term_months: Set(data.term.months() as i32),
+            annual_property_tax: Set(decimal(data.annual_property_tax)),
+            annual_insurance: Set(decimal(data.annual_insurance)),
+            monthly_hoa: Set(decimal(data.monthly_hoa)),
+            expected_rent: Set(decimal(data.e_x_rent)),
+            created_at: Set(now),
+            updated_at: Set(now),
+        };
+        let inserted = active_model.insert(db).await?;
+        Ok(dscr_calculator_to_domain(&inserted))
+    }
+
+    pub async fn get_by_id(db: &DatabaseConnection, id: Uuid) -> Result<Option<DSCRCalculator>, Box<dyn std::error::Error>> {
+        let entity = dscr_calculators::Entity::find_by_id(id).one(db).await?;
+        Ok(entity.as_ref().map(dscr_calculator_to_domain))
+    }
+
+    /// One page of scenarios. Pages start at 1.
+    pub async fn list(db: &DatabaseConnection, params: &DSCRCalculatorQueryParams) -> Result<Vec<DSCRCalculator>, Box<dyn std::error::Error>> {
+        let per_page = params.per_page.unwrap_or(DEFAULT_PER_PAGE).max(1);
+        let page = params.page.unwrap_or(1).max(1) - 1;
+        let entities = Self::filtered(params).paginate(db, per_page).fetch_page(page).await?;
+        Ok(entities.iter().map(dscr_calculator_to_domain).collect())
+    }
+
+    /// Number of scenarios matching the search, across all pages.
+    pub async fn count(db: &DatabaseConnection, params: &DSCRCalculatorQueryParams) -> Result<u64, Box<dyn std::error::Error>> {
+        Ok(Self::filtered(params).count(db).await?)
+    }
+
+    pub async fn update(db: &DatabaseConnection, id: Uuid, data: UpdateDSCRCalculator) -> Result<Option<DSCRCalculator>, Box<dyn std::error::Error>> {
+        let Some(entity) = dscr_calculators::Entity::find_by_id(id).one(db).await? else {
+            return Ok(None);
+        };
+
+        let mut active_model: dscr_calculators::ActiveModel = entity.into();
+        if let Some(property_address) = data.property_address {
+            active_model.property_address = Set(property_address);
+        }
+        if let Some(purchase_price) = data.purchase_price {
+            active_model.purchase_price = Set(decimal(purchase_price));
+        }
+        if let Some(down_payment) = data.down_payment {
+            active_model.down_payment = Set(decimal(down_payment));
+        }
+        if let Some(rate) = data.rate {
+            active_model.rate = Set(rate_decimal(rate));
+        }
+        if let Some(term) = data.term {
+            active_model.term_months = Set(term.months() as i32);
+        }
+        if let Some(annual_property_tax) = data.annual_property_tax {
+            active_model.annual_property_tax = Set(decimal(annual_property_tax));
+        }
+        if let Some(annual_insurance) = data.annual_insurance {
+            active_model.annual_insurance = Set(decimal(annual_insurance));
+        }
+        if let Some(monthly_hoa) = data.monthly_hoa {
+            active_model.monthly_hoa = Set(decimal(monthly_hoa));
+        }
+        if let Some(e_x_rent) = data.e_x_rent {
+            active_model.expected_rent = Set(decimal(e_x_rent));
+        }
+        active_model.updated_at = Set(chrono::Utc::now());
+
+        let updated = active_model.update(db).await?;
+        Ok(Some(dscr_calculator_to_domain(&updated)))
+    }
+
+    pub async fn delete(db: &DatabaseConnection, id: Uuid) -> Result<(), Box<dyn std::error::Error>> {
+        dscr_calculators::Entity::delete_by_id(id).exec(db).await?;
+        Ok(())
+    }
+}
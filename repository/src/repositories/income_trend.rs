@@ -0,0 +1,115 @@
+use sea_orm::{DatabaseConnection, EntityTrait, QueryFilter, ColumnTrait, Set, ActiveModelTrait, TransactionTrait};
+use shared::calc::income_trend::{IncomeTrend, W2SectionResult};
+use database::entities::income_trend_analyses;
+use rust_decimal::{Decimal, prelude::ToPrimitive};
+use chrono::Utc;
+use uuid::Uuid;
+
+fn decimal(value: f64) -> Decimal {
+    Decimal::from_f64_retain(value).unwrap_or_default().round_dp(2)
+}
+
+fn convert_trend(model: income_trend_analyses::Model) -> IncomeTrend {
+    let amount = |value: Decimal| value.to_f64().unwrap_or(0.0);
+    IncomeTrend {
+        ytd_monthly: amount(model.ytd_monthly),
+        prior_year_monthly: amount(model.prior_year_monthly),
+        second_prior_year_monthly: amount(model.second_prior_year_monthly),
+        prior_year_change_percent: model.prior_year_change_percent.map(amount),
+        ytd_change_percent: model.ytd_change_percent.map(amount),
+        decline_threshold_percent: amount(model.decline_threshold_percent),
+        declining: model.declining,
+        significant_decline: model.significant_decline,
+        recommended_monthly: model.recommended_monthly.map(amount),
+        reasoning: model.reasoning,
+    }
+}
+
+/// Save the trend analysis for one worksheet section with the figure from its selected
+/// calculation method, replacing any saved analysis. The replacement runs in one
+/// transaction so the section never ends up with no row or with two.
+pub async fn save_income_trend(
+    db: &DatabaseConnection,
+    borrower_id: i32,
+    section: &str,
+    qualifying_monthly: f64,
+    trend: &IncomeTrend,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let txn = db.begin().await?;
+    income_trend_analyses::Entity::delete_many()
+        .filter(income_trend_analyses::Column::BorrowerId.eq(borrower_id))
+        .filter(income_trend_analyses::Column::Section.eq(section))
+        .exec(&txn)
+        .await?;
+
+    let trend_active = income_trend_analyses::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        borrower_id: Set(borrower_id),
+        section: Set(section.to_string()),
+        qualifying_monthly: Set(decimal(qualifying_monthly)),
+        ytd_monthly: Set(decimal(trend.ytd_monthly)),
+        prior_year_monthly: Set(decimal(trend.prior_year_monthly)),
+        second_prior_year_monthly: Set(decimal(trend.second_prior_year_monthly)),
+        prior_year_change_percent: Set(trend.prior_year_change_percent.map(decimal)),
+        ytd_change_percent: Set(trend.ytd_change_percent.map(decimal)),
+        decline_threshold_percent: Set(decimal(trend.decline_threshold_percent)),
+        declining: Set(trend.declining),
+        significant_decline: Set(trend.significant_decline),
+        recommended_monthly: Set(trend.recommended_monthly.map(decimal)),
+        reasoning: Set(trend.reasoning.clone()),
+        created_at: Set(Utc::now()),
+        updated_at: Set(Utc::now()),
+    };
+    trend_active.insert(&txn).await?;
+    txn.commit().await?;
+
+    Ok(())
+}
+
+/// Load the saved trend analysis for one worksheet section
+pub async fn get_income_trend(
+    db: &DatabaseConnection,
+    borrower_id: i32,
+    section: &str,
+) -> Result<Option<IncomeTrend>, Box<dyn std::error::Error>> {
+    let trend = income_trend_analyses::Entity::find()
+        .filter(income_trend_analyses::Column::BorrowerId.eq(borrower_id))
+        .filter(income_trend_analyses::Column::Section.eq(section))
+        .one(db)
+        .await?;
+
+    Ok(trend.map(convert_trend))
+}
+
+/// Load every saved W-2 worksheet section for a borrower
+pub async fn get_w2_section_results(
+    db: &DatabaseConnection,
+    borrower_id: i32,
+) -> Result<Vec<W2SectionResult>, Box<dyn std::error::Error>> {
+    let models = income_trend_analyses::Entity::find()
+        .filter(income_trend_analyses::Column::BorrowerId.eq(borrower_id))
+        .all(db)
+        .await?;
+
+    Ok(models
+        .into_iter()
+        .map(|model| W2SectionResult {
+            section: model.section.clone(),
+            qualifying_monthly: model.qualifying_monthly.to_f64().unwrap_or(0.0),
+            trend: convert_trend(model),
+        })
+        .collect())
+}
+
+/// Delete every saved trend analysis for a borrower
+pub async fn delete_income_trends(
+    db: &DatabaseConnection,
+    borrower_id: i32,
+) -> Result<(), Box<dyn std::error::Error>> {
+    income_trend_analyses::Entity::delete_many()
+        .filter(income_trend_analyses::Column::BorrowerId.eq(borrower_id))
+        .exec(db)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use sea_orm::{DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, ColumnTrait, Set, ActiveModelTrait};
+use shared::models::{InvestmentIncomeData, InvestmentIncomeSource};
+use database::entities::{investment_income, investment_income_sources};
+use rust_decimal::{Decimal, prelude::ToPrimitive};
+use chrono::Utc;
+use uuid::Uuid;
+
+fn decimal(value: f64) -> Decimal {
+    Decimal::from_f64_retain(value).unwrap_or_default().round_dp(2)
+}
+
+fn convert_source(model: investment_income_sources::Model) -> InvestmentIncomeSource {
+    InvestmentIncomeSource {
+        prior_year_amount: model.prior_year_amount.to_f64().unwrap_or(0.0),
+        recent_year_amount: model.recent_year_amount.to_f64().unwrap_or(0.0),
+        asset_balance: model.asset_balance.to_f64().unwrap_or(0.0),
+        id: model.id,
+        name: model.name,
+        source_type: model.source_type,
+    }
+}
+
+/// Save the investment income worksheet for a borrower, replacing any saved sources
+pub async fn save_investment_income_data(
+    db: &DatabaseConnection,
+    borrower_id: i32,
+    data: InvestmentIncomeData,
+) -> Result<(), Box<dyn std::error::Error>> {
+    delete_investment_income_data(db, borrower_id).await?;
+
+    let investment_active = investment_income::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        borrower_id: Set(borrower_id),
+        funds_to_close: Set(decimal(data.funds_to_close)),
+        reserves: Set(decimal(data.reserves)),
+        created_at: Set(Utc::now()),
+        updated_at: Set(Utc::now()),
+    };
+    investment_active.insert(db).await?;
+
+    for (index, source) in data.sources.into_iter().enumerate() {
+        let source_active = investment_income_sources::ActiveModel {
+            id: Set(source.id),
+            borrower_id: Set(borrower_id),
+            sort_order: Set(index as i32),
+            name: Set(source.name),
+            source_type: Set(source.source_type),
+            prior_year_amount: Set(decimal(source.prior_year_amount)),
+            recent_year_amount: Set(decimal(source.recent_year_amount)),
+            asset_balance: Set(decimal(source.asset_balance)),
+            created_at: Set(Utc::now()),
+            updated_at: Set(Utc::now()),
+        };
+        source_active.insert(db).await?;
+    }
+
+    Ok(())
+}
+
+/// Load the investment income worksheet for a borrower
+pub async fn get_investment_income_data(
+    db: &DatabaseConnection,
+    borrower_id: i32,
+) -> Result<Option<InvestmentIncomeData>, Box<dyn std::error::Error>> {
+    let Some(investment) = investment_income::Entity::find()
+        .filter(investment_income::Column::BorrowerId.eq(borrower_id))
+        .one(db)
+        .await?
+    else {
+        return Ok(None);
+    };
+
+    let sources = investment_income_sources::Entity::find()
+        .filter(investment_income_sources::Column::BorrowerId.eq(borrower_id))
+        .order_by_asc(investment_income_sources::Column::SortOrder)
+        .all(db)
+        .await?;
+
+    Ok(Some(InvestmentIncomeData {
+        funds_to_close: investment.funds_to_close.to_f64().unwrap_or(0.0),
+        reserves: investment.reserves.to_f64().unwrap_or(0.0),
+        sources: sources.into_iter().map(convert_source).collect(),
+    }))
+}
+
+/// Delete the investment income worksheet for a borrower
+pub async fn delete_investment_income_data(
+    db: &DatabaseConnection,
+    borrower_id: i32,
+) -> Result<(), Box<dyn std::error::Error>> {
+    investment_income_sources::Entity::delete_many()
+        .filter(investment_income_sources::Column::BorrowerId.eq(borrower_id))
+        .exec(db)
+        .await?;
+
+    investment_income::Entity::delete_many()
+        .filter(investment_income::Column::BorrowerId.eq(borrower_id))
+        .exec(db)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+//! MI rate card repository - conventional mortgage insurance pricing rows
+
+use sea_orm::{ActiveModelTrait, DatabaseConnection, EntityTrait, QueryOrder, Set, TransactionTrait};
+use shared::calc::pmi::parse_rate_card_csv;
+use shared::models::MiRateCardEntry;
+use database::entities::mi_rate_cards;
+use chrono::Utc;
+
+pub struct MiRateCardRepository;
+
+impl MiRateCardRepository {
+    pub async fn get_all(db: &DatabaseConnection) -> Result<Vec<MiRateCardEntry>, Box<dyn std::error::Error>> {
+        let entities = mi_rate_cards::Entity::find()
+            .order_by_asc(mi_rate_cards::Column::LtvMin)
+            .order_by_asc(mi_rate_cards::Column::CreditScoreMin)
+            .all(db)
+            .await?;
+        Ok(entities.iter().map(to_domain).collect())
+    }
+
+    /// Inserts the entry when its id is 0, otherwise updates it. Returns the stored id.
+    pub async fn save<C: sea_orm::ConnectionTrait>(db: &C, entry: MiRateCardEntry) -> Result<i32, Box<dyn std::error::Error>> {
+        let mut active_model = mi_rate_cards::ActiveModel {
+            id: sea_orm::ActiveValue::NotSet,
+            ltv_min: Set(entry.ltv_min),
+            ltv_max: Set(entry.ltv_max),
+            credit_score_min: Set(entry.credit_score_min as i32),
+            credit_score_max: Set(entry.credit_score_max as i32),
+            coverage: Set(entry.coverage),
+            term_min_months: Set(entry.term_min_months as i32),
+            term_max_months: Set(entry.term_max_months as i32),
+            annual_rate: Set(entry.annual_rate),
+            created_at: Set(Utc::now()),
+            updated_at: Set(Utc::now()),
+        };
+
+        if entry.id == 0 {
+            let inserted = active_model.insert(db).await?;
+            Ok(inserted.id)
+        } else {
+            active_model.id = Set(entry.id);
+            active_model.created_at = sea_orm::ActiveValue::NotSet;
+            let updated = active_model.update(db).await?;
+            Ok(updated.id)
+        }
+    }
+
+    pub async fn delete(db: &DatabaseConnection, id: i32) -> Result<(), Box<dyn std::error::Error>> {
+        mi_rate_cards::Entity::delete_by_id(id).exec(db).await?;
+        Ok(())
+    }
+
+    /// Replaces the whole rate card with the rows in `csv`. Nothing changes if any row
+    /// fails to parse. Returns the number of rows imported.
+    pub async fn import_csv(db: &DatabaseConnection, csv: &str) -> Result<usize, Box<dyn std::error::Error>> {
+        let entries = parse_rate_card_csv(csv)?;
+
+        let txn = db.begin().await?;
+        mi_rate_cards::Entity::delete_many().exec(&txn).await?;
+        for entry in &entries {
+            Self::save(&txn, entry.clone()).await?;
+        }
+        txn.commit().await?;
+
+        Ok(entries.len())
+    }
+}
+
+fn to_domain(model: &mi_rate_cards::Model) -> MiRateCardEntry {
+    MiRateCardEntry {
+        id: model.id,
+        ltv_min: model.ltv_min,
+        ltv_max: model.ltv_max,
+        credit_score_min: model.credit_score_min.max(0) as u32,
+        credit_score_max: model.credit_score_max.max(0) as u32,
+        coverage: model.coverage,
+        term_min_months: model.term_min_months.max(0) as u32,
+        term_max_months: model.term_max_months.max(0) as u32,
+        annual_rate: model.annual_rate,
+    }
+}
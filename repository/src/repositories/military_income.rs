@@ -0,0 +1,81 @@
+use sea_orm::{DatabaseConnection, EntityTrait, QueryFilter, ColumnTrait, Set, ActiveModelTrait};
+use shared::models::MilitaryIncomeData;
+use database::entities::military_income;
+use rust_decimal::{Decimal, prelude::ToPrimitive};
+use chrono::Utc;
+use uuid::Uuid;
+
+fn decimal(value: f64) -> Decimal {
+    Decimal::from_f64_retain(value).unwrap_or_default().round_dp(2)
+}
+
+fn convert_military_income(model: military_income::Model) -> MilitaryIncomeData {
+    let amount = |value: Decimal| value.to_f64().unwrap_or(0.0);
+    MilitaryIncomeData {
+        base_pay: amount(model.base_pay),
+        bah: amount(model.bah),
+        bas: amount(model.bas),
+        clothing_allowance: amount(model.clothing_allowance),
+        flight_pay: amount(model.flight_pay),
+        hazard_pay: amount(model.hazard_pay),
+        other_allowances: amount(model.other_allowances),
+        other_allowances_taxable: model.other_allowances_taxable,
+        branch: model.branch,
+        ets_date: model.ets_date,
+    }
+}
+
+/// Save the military income section for a borrower, replacing any saved LES figures
+pub async fn save_military_income_data(
+    db: &DatabaseConnection,
+    borrower_id: i32,
+    data: MilitaryIncomeData,
+) -> Result<(), Box<dyn std::error::Error>> {
+    delete_military_income_data(db, borrower_id).await?;
+
+    let military_active = military_income::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        borrower_id: Set(borrower_id),
+        branch: Set(data.branch),
+        ets_date: Set(data.ets_date),
+        base_pay: Set(decimal(data.base_pay)),
+        bah: Set(decimal(data.bah)),
+        bas: Set(decimal(data.bas)),
+        clothing_allowance: Set(decimal(data.clothing_allowance)),
+        flight_pay: Set(decimal(data.flight_pay)),
+        hazard_pay: Set(decimal(data.hazard_pay)),
+        other_allowances: Set(decimal(data.other_allowances)),
+        other_allowances_taxable: Set(data.other_allowances_taxable),
+        created_at: Set(Utc::now()),
+        updated_at: Set(Utc::now()),
+    };
+    military_active.insert(db).await?;
+
+    Ok(())
+}
+
+/// Load the military income section for a borrower
+pub async fn get_military_income_data(
+    db: &DatabaseConnection,
+    borrower_id: i32,
+) -> Result<Option<MilitaryIncomeData>, Box<dyn std::error::Error>> {
+    let military = military_income::Entity::find()
+        .filter(military_income::Column::BorrowerId.eq(borrower_id))
+        .one(db)
+        .await?;
+
+    Ok(military.map(convert_military_income))
+}
+
+/// Delete the military income section for a borrower
+pub async fn delete_military_income_data(
+    db: &DatabaseConnection,
+    borrower_id: i32,
+) -> Result<(), Box<dyn std::error::Error>> {
+    military_income::Entity::delete_many()
+        .filter(military_income::Column::BorrowerId.eq(borrower_id))
+        .exec(db)
+        .await?;
+
+    Ok(())
+}
@@ -1,15 +1,25 @@
 //! Repository implementations for data access
 
+pub mod accessory_income;
+pub mod bank_statement;
 pub mod borrower;
 pub mod campaigns;
 pub mod condition;
 pub mod document;
+pub mod dscr;
 pub mod general_income;
 pub mod income;
+pub mod income_trend;
+pub mod investment_income;
 pub mod loan;
+pub mod mi_rate_card;
+pub mod military_income;
 pub mod note;
 pub mod options_template;
 pub mod outreach_templates;
+pub mod rental_income;
+pub mod retirement_assets;
+pub mod self_employment;
 pub mod settings;
 pub mod timeline_events;
 pub mod w2_jobs;
@@ -3,13 +3,14 @@ use shared::models::*;
 use database::entities::{
     loan_information, new_loan_details, benefit_to_borrower, other_fees, 
     income_information, savings_calculations, existing_loans, pricing_options,
-    consumer_debt,
+    consumer_debt, net_tangible_benefit_findings, debt_to_income_calculations,
+    residual_income_calculations, title_fees,
 };
 use uuid::Uuid;
 use rust_decimal::{Decimal, prelude::ToPrimitive};
 use chrono::Utc;
 
-use crate::{parse_credit_type, parse_property_type, parse_occupancy_type, parse_loan_type, parse_loan_purpose};
+use crate::{parse_credit_type, parse_student_loan_status, parse_property_type, parse_occupancy_type, parse_loan_type, parse_loan_purpose};
 
 // Options Template CRUD operations - composite operations that handle all related data
 
@@ -26,6 +27,8 @@ pub async fn save_options_template(db: &DatabaseConnection, template: OptionsTem
             new_term_months: Set(loan_info.new_term_months),
             loan_purpose: Set(loan_info.loan_purpose.to_string()),
             appraisal_waiver: Set(loan_info.appraisal_waiver),
+            credit_score: Set(loan_info.credit_score as i32),
+            agency: Set(loan_info.agency),
             created_at: Set(loan_info.created_at),
             updated_at: Set(Utc::now()),
         };
@@ -40,13 +43,23 @@ pub async fn save_options_template(db: &DatabaseConnection, template: OptionsTem
             down_payment: Set(Decimal::from_f64_retain(new_loan.down_payment).unwrap()),
             base_loan_amount: Set(Decimal::from_f64_retain(new_loan.base_loan_amount).unwrap()),
             subordinated_amount: Set(Decimal::from_f64_retain(new_loan.subordinated_amount).unwrap()),
+            heloc_balance: Set(Decimal::from_f64_retain(new_loan.heloc_balance).unwrap()),
+            heloc_credit_limit: Set(Decimal::from_f64_retain(new_loan.heloc_credit_limit).unwrap()),
             total_loan_amount: Set(Decimal::from_f64_retain(new_loan.total_loan_amount).unwrap()),
             note_rate: Set(Decimal::from_f64_retain(new_loan.note_rate).unwrap()),
             appraisal_waiver: Set(new_loan.appraisal_waiver),
             created_at: Set(new_loan.created_at),
             updated_at: Set(Utc::now()),
-            ff_umip_percentage: Set(Decimal::from_f64_retain(0.0).unwrap()), // Default values for fields not in frontend
-            umip_refund: Set(Decimal::from_f64_retain(0.0).unwrap()),
+            ff_umip_percentage: Set(Decimal::from_f64_retain(new_loan.ff_umip_percentage).unwrap()),
+            ff_umip_amount: Set(Decimal::from_f64_retain(new_loan.ff_umip_amount).unwrap()),
+            umip_refund: Set(Decimal::from_f64_retain(new_loan.umip_refund).unwrap()),
+            prior_ufmip: Set(Decimal::from_f64_retain(new_loan.prior_ufmip).unwrap()),
+            months_since_endorsement: Set(new_loan.months_since_endorsement as i32),
+            va_subsequent_use: Set(new_loan.va_subsequent_use),
+            va_funding_fee_exempt: Set(new_loan.va_funding_fee_exempt),
+            mi_coverage: Set(Decimal::from_f64_retain(new_loan.mi_coverage).unwrap()),
+            mi_annual_rate: Set(Decimal::from_f64_retain(new_loan.mi_annual_rate).unwrap()),
+            pmi_termination_month: Set(new_loan.pmi_termination_month as i32),
         };
         new_loan_active.insert(db).await?;
 
@@ -75,6 +88,8 @@ pub async fn save_options_template(db: &DatabaseConnection, template: OptionsTem
             debt_paydown: Set(Decimal::from_f64_retain(benefit.debt_paydown).unwrap()),
             existing_total_obligations: Set(Decimal::from_f64_retain(benefit.existing_total_obligations).unwrap()),
             proposed_total_obligations: Set(Decimal::from_f64_retain(benefit.proposed_total_obligations).unwrap()),
+            existing_pi_override: Set(benefit.existing_pi_override),
+            proposed_pi_override: Set(benefit.proposed_pi_override),
             created_at: Set(benefit.created_at),
             updated_at: Set(Utc::now()),
         };
@@ -146,6 +161,48 @@ pub async fn save_options_template(db: &DatabaseConnection, template: OptionsTem
             existing_loan_active.insert(db).await?;
         }
 
+        // Save pricing options, replacing the borrower's previous set
+        pricing_options::Entity::delete_many()
+            .filter(pricing_options::Column::BorrowerId.eq(borrower_id))
+            .exec(db)
+            .await?;
+        for option in &template.pricing.pricing_options {
+            let option_active = pricing_options::ActiveModel {
+                id: Set(Uuid::new_v4()),
+                borrower_id: Set(borrower_id),
+                description: Set(option.description.clone()),
+                note_rate: Set(Decimal::from_f64_retain(option.note_rate).unwrap_or_default().round_dp(3)),
+                ysp_percentage: Set(Decimal::from_f64_retain(option.ysp_percentage).unwrap_or_default().round_dp(2)),
+                ysp_dollar: Set(Decimal::from_f64_retain(option.ysp_dollar).unwrap_or_default().round_dp(2)),
+                bd_percentage: Set(Decimal::from_f64_retain(option.bd_percentage).unwrap_or_default().round_dp(2)),
+                bd_dollar: Set(Decimal::from_f64_retain(option.bd_dollar).unwrap_or_default().round_dp(2)),
+                is_selected: Set(option.is_selected),
+                created_at: Set(Utc::now()),
+                updated_at: Set(Utc::now()),
+            };
+            option_active.insert(db).await?;
+        }
+
+        // Save title fees, replacing the borrower's previous row
+        title_fees::Entity::delete_many()
+            .filter(title_fees::Column::BorrowerId.eq(borrower_id))
+            .exec(db)
+            .await?;
+        let title = &template.title_fees;
+        let title_active = title_fees::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            borrower_id: Set(borrower_id),
+            title_insurance: Set(Decimal::from_f64_retain(title.title_insurance).unwrap_or_default().round_dp(2)),
+            title_search: Set(Decimal::from_f64_retain(title.title_search).unwrap_or_default().round_dp(2)),
+            title_examination: Set(Decimal::from_f64_retain(title.title_examination).unwrap_or_default().round_dp(2)),
+            title_closing_fee: Set(Decimal::from_f64_retain(title.title_closing_fee).unwrap_or_default().round_dp(2)),
+            title_courier_fee: Set(Decimal::from_f64_retain(title.title_courier_fee).unwrap_or_default().round_dp(2)),
+            title_other_fees: Set(Decimal::from_f64_retain(title.title_other_fees).unwrap_or_default().round_dp(2)),
+            created_at: Set(Utc::now()),
+            updated_at: Set(Utc::now()),
+        };
+        title_active.insert(db).await?;
+
         // Save consumer debts
         for debt_item in &template.consumer_debt.consumer_debts {
             let consumer_debt = convert_to_consumer_debt(debt_item, borrower_id);
@@ -160,12 +217,83 @@ pub async fn save_options_template(db: &DatabaseConnection, template: OptionsTem
                 interest_rate: Set(consumer_debt.interest_rate.map(|r| Decimal::from_f64_retain(r).unwrap())),
                 omit_from_dti: Set(consumer_debt.omit_from_dti),
                 pay_off_at_closing: Set(consumer_debt.pay_off_at_closing),
+                student_loan_status: Set(consumer_debt.student_loan_status.map(|status| status.to_string())),
                 created_at: Set(consumer_debt.created_at),
                 updated_at: Set(Utc::now()),
             };
             consumer_debt_active.insert(db).await?;
         }
 
+        // Save net tangible benefit findings, replacing the borrower's previous set
+        net_tangible_benefit_findings::Entity::delete_many()
+            .filter(net_tangible_benefit_findings::Column::BorrowerId.eq(borrower_id))
+            .exec(db)
+            .await?;
+        let ntb = &template.net_tangible_benefit;
+        for finding in &ntb.findings {
+            let finding_active = net_tangible_benefit_findings::ActiveModel {
+                id: Set(Uuid::new_v4()),
+                borrower_id: Set(borrower_id),
+                program: Set(ntb.program.clone()),
+                rule: Set(finding.rule.clone()),
+                passed: Set(finding.passed),
+                threshold: Set(Decimal::from_f64_retain(finding.threshold).unwrap_or_default()),
+                actual: Set(Decimal::from_f64_retain(finding.actual).unwrap_or_default()),
+                detail: Set(finding.detail.clone()),
+                created_at: Set(Utc::now()),
+                updated_at: Set(Utc::now()),
+            };
+            finding_active.insert(db).await?;
+        }
+
+        // Save debt to income calculation, replacing the borrower's previous result
+        debt_to_income_calculations::Entity::delete_many()
+            .filter(debt_to_income_calculations::Column::BorrowerId.eq(borrower_id))
+            .exec(db)
+            .await?;
+        let dti = &template.debt_to_income;
+        let dti_active = debt_to_income_calculations::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            borrower_id: Set(borrower_id),
+            front_end_ratio: Set(Decimal::from_f64_retain(dti.front_end_ratio).unwrap_or_default()),
+            back_end_ratio: Set(Decimal::from_f64_retain(dti.back_end_ratio).unwrap_or_default()),
+            qualifying_income: Set(Decimal::from_f64_retain(dti.qualifying_income).unwrap_or_default()),
+            housing_payment: Set(Decimal::from_f64_retain(dti.housing_payment).unwrap_or_default()),
+            monthly_debts: Set(Decimal::from_f64_retain(dti.monthly_debts).unwrap_or_default()),
+            compensating_factors: Set(dti.compensating_factors),
+            max_front_end_ratio: Set(dti.max_front_end_ratio.and_then(Decimal::from_f64_retain)),
+            max_back_end_ratio: Set(dti.max_back_end_ratio.and_then(Decimal::from_f64_retain)),
+            within_limits: Set(dti.within_limits),
+            created_at: Set(Utc::now()),
+            updated_at: Set(Utc::now()),
+        };
+        dti_active.insert(db).await?;
+
+        // Save VA residual income calculation, replacing the borrower's previous result
+        residual_income_calculations::Entity::delete_many()
+            .filter(residual_income_calculations::Column::BorrowerId.eq(borrower_id))
+            .exec(db)
+            .await?;
+        let residual = &template.residual_income;
+        let residual_active = residual_income_calculations::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            borrower_id: Set(borrower_id),
+            state: Set(residual.state.clone()),
+            family_size: Set(residual.family_size as i32),
+            square_feet: Set(Decimal::from_f64_retain(residual.square_feet).unwrap_or_default()),
+            monthly_taxes: Set(Decimal::from_f64_retain(residual.monthly_taxes).unwrap_or_default()),
+            region: Set(residual.region.clone()),
+            maintenance_utilities: Set(Decimal::from_f64_retain(residual.maintenance_utilities).unwrap_or_default()),
+            residual_income: Set(Decimal::from_f64_retain(residual.residual_income).unwrap_or_default()),
+            guideline_residual_income: Set(Decimal::from_f64_retain(residual.guideline_residual_income).unwrap_or_default()),
+            required_residual_income: Set(Decimal::from_f64_retain(residual.required_residual_income).unwrap_or_default()),
+            cushion_applied: Set(residual.cushion_applied),
+            meets_guideline: Set(residual.meets_guideline),
+            created_at: Set(Utc::now()),
+            updated_at: Set(Utc::now()),
+        };
+        residual_active.insert(db).await?;
+
         Ok(())
     }
 
@@ -190,6 +318,8 @@ pub async fn save_options_template(db: &DatabaseConnection, template: OptionsTem
             term_months: loan_info_model.new_term_months,
             purpose: loan_info_model.loan_purpose.clone(),
             appraisal_waiver: loan_info_model.appraisal_waiver,
+            credit_score: loan_info_model.credit_score.max(0) as u32,
+            agency: loan_info_model.agency.clone(),
         };
 
         // Get new loan details
@@ -206,6 +336,18 @@ pub async fn save_options_template(db: &DatabaseConnection, template: OptionsTem
                 down_payment_percent: 0.0, // Calculate if needed
                 base_loan_amount: model.base_loan_amount.to_f64().unwrap_or(0.0),
                 subordinated_amount: model.subordinated_amount.to_f64().unwrap_or(0.0),
+                heloc_balance: model.heloc_balance.to_f64().unwrap_or(0.0),
+                heloc_credit_limit: model.heloc_credit_limit.to_f64().unwrap_or(0.0),
+                ff_umip_percentage: model.ff_umip_percentage.to_f64().unwrap_or(0.0),
+                ff_umip_amount: model.ff_umip_amount.to_f64().unwrap_or(0.0),
+                umip_refund: model.umip_refund.to_f64().unwrap_or(0.0),
+                prior_ufmip: model.prior_ufmip.to_f64().unwrap_or(0.0),
+                months_since_endorsement: model.months_since_endorsement.max(0) as u32,
+                va_subsequent_use: model.va_subsequent_use,
+                va_funding_fee_exempt: model.va_funding_fee_exempt,
+                mi_coverage: model.mi_coverage.to_f64().unwrap_or(0.0),
+                mi_annual_rate: model.mi_annual_rate.to_f64().unwrap_or(0.0),
+                pmi_termination_month: model.pmi_termination_month.max(0) as u32,
                 total_loan_amount: model.total_loan_amount.to_f64().unwrap_or(0.0),
                 note_rate: model.note_rate.to_f64().unwrap_or(0.0),
                 appraisal_waiver: model.appraisal_waiver,
@@ -243,6 +385,8 @@ pub async fn save_options_template(db: &DatabaseConnection, template: OptionsTem
                 debt_paydown: model.debt_paydown.to_f64().unwrap_or(0.0),
                 existing_total_obligations: model.existing_total_obligations.to_f64().unwrap_or(0.0),
                 proposed_total_obligations: model.proposed_total_obligations.to_f64().unwrap_or(0.0),
+                existing_pi_override: model.existing_pi_override,
+                proposed_pi_override: model.proposed_pi_override,
             }
         } else {
             BenefitToBorrowerData::default()
@@ -288,6 +432,10 @@ pub async fn save_options_template(db: &DatabaseConnection, template: OptionsTem
                 back_end_ratio: model.back_end_ratio.to_f64().unwrap_or(0.0),
                 general_income_data: None,
                 w2_jobs_data: None,
+                w2_section_results: Vec::new(),
+                self_employment_data: None,
+                rental_income_data: None,
+                bank_statement_data: None,
             }
         } else {
             IncomeInformationData::default()
@@ -334,7 +482,7 @@ pub async fn save_options_template(db: &DatabaseConnection, template: OptionsTem
 
         // Get pricing options
         let pricing_entities = pricing_options::Entity::find()
-            .filter(pricing_options::Column::Id.starts_with(&borrower_id.to_string()))
+            .filter(pricing_options::Column::BorrowerId.eq(borrower_id))
             .all(&*db)
             .await?;
 
@@ -370,6 +518,11 @@ pub async fn save_options_template(db: &DatabaseConnection, template: OptionsTem
                 interest_rate: model.interest_rate.map(|r| r.to_f64().unwrap_or(0.0)),
                 omit_from_dti: model.omit_from_dti,
                 pay_off_at_closing: model.pay_off_at_closing,
+                student_loan_status: model
+                    .student_loan_status
+                    .as_deref()
+                    .and_then(parse_student_loan_status)
+                    .map_or(String::new(), |status| status.key().to_string()),
             }
         }).collect();
 
@@ -377,9 +530,91 @@ pub async fn save_options_template(db: &DatabaseConnection, template: OptionsTem
             consumer_debts,
         };
 
-        // For now, return defaults for debt to income, and title fees
-        let debt_to_income = DebtToIncomeData::default();
-        let title_fees = TitleFeesData::default();
+        // Get net tangible benefit findings
+        let finding_entities = net_tangible_benefit_findings::Entity::find()
+            .filter(net_tangible_benefit_findings::Column::BorrowerId.eq(borrower_id))
+            .all(db)
+            .await?;
+
+        let net_tangible_benefit = NetTangibleBenefitData {
+            program: finding_entities.first().map(|model| model.program.clone()).unwrap_or_default(),
+            passed: !finding_entities.is_empty() && finding_entities.iter().all(|model| model.passed),
+            findings: finding_entities.into_iter().map(|model| {
+                NetTangibleBenefitFindingData {
+                    rule: model.rule,
+                    passed: model.passed,
+                    threshold: model.threshold.to_f64().unwrap_or(0.0),
+                    actual: model.actual.to_f64().unwrap_or(0.0),
+                    detail: model.detail,
+                }
+            }).collect(),
+        };
+
+        // Get debt to income calculation
+        let dti_entity = debt_to_income_calculations::Entity::find()
+            .filter(debt_to_income_calculations::Column::BorrowerId.eq(borrower_id))
+            .one(db)
+            .await?;
+
+        let debt_to_income = if let Some(model) = dti_entity {
+            DebtToIncomeData {
+                front_end_ratio: model.front_end_ratio.to_f64().unwrap_or(0.0),
+                back_end_ratio: model.back_end_ratio.to_f64().unwrap_or(0.0),
+                qualifying_income: model.qualifying_income.to_f64().unwrap_or(0.0),
+                housing_payment: model.housing_payment.to_f64().unwrap_or(0.0),
+                monthly_debts: model.monthly_debts.to_f64().unwrap_or(0.0),
+                compensating_factors: model.compensating_factors,
+                max_front_end_ratio: model.max_front_end_ratio.and_then(|max| max.to_f64()),
+                max_back_end_ratio: model.max_back_end_ratio.and_then(|max| max.to_f64()),
+                within_limits: model.within_limits,
+                income_warnings: Vec::new(),
+            }
+        } else {
+            DebtToIncomeData::default()
+        };
+
+        // Get VA residual income calculation
+        let residual_entity = residual_income_calculations::Entity::find()
+            .filter(residual_income_calculations::Column::BorrowerId.eq(borrower_id))
+            .one(db)
+            .await?;
+
+        let residual_income = if let Some(model) = residual_entity {
+            ResidualIncomeData {
+                state: model.state,
+                family_size: model.family_size as u32,
+                square_feet: model.square_feet.to_f64().unwrap_or(0.0),
+                monthly_taxes: model.monthly_taxes.to_f64().unwrap_or(0.0),
+                region: model.region,
+                maintenance_utilities: model.maintenance_utilities.to_f64().unwrap_or(0.0),
+                residual_income: model.residual_income.to_f64().unwrap_or(0.0),
+                guideline_residual_income: model.guideline_residual_income.to_f64().unwrap_or(0.0),
+                required_residual_income: model.required_residual_income.to_f64().unwrap_or(0.0),
+                cushion_applied: model.cushion_applied,
+                meets_guideline: model.meets_guideline,
+            }
+        } else {
+            ResidualIncomeData::default()
+        };
+
+        // Get title fees
+        let title_entity = title_fees::Entity::find()
+            .filter(title_fees::Column::BorrowerId.eq(borrower_id))
+            .one(db)
+            .await?;
+
+        let title_fees = if let Some(model) = title_entity {
+            TitleFeesData {
+                title_insurance: model.title_insurance.to_f64().unwrap_or(0.0),
+                title_search: model.title_search.to_f64().unwrap_or(0.0),
+                title_examination: model.title_examination.to_f64().unwrap_or(0.0),
+                title_closing_fee: model.title_closing_fee.to_f64().unwrap_or(0.0),
+                title_courier_fee: model.title_courier_fee.to_f64().unwrap_or(0.0),
+                title_other_fees: model.title_other_fees.to_f64().unwrap_or(0.0),
+            }
+        } else {
+            TitleFeesData::default()
+        };
 
         let template = OptionsTemplateData {
             loan_information,
@@ -390,9 +625,11 @@ pub async fn save_options_template(db: &DatabaseConnection, template: OptionsTem
             pricing,
             consumer_debt,
             debt_to_income,
+            residual_income,
             title_fees,
             income_information,
             savings,
+            net_tangible_benefit,
         };
 
         Ok(Some(template))
@@ -446,7 +683,12 @@ pub async fn save_options_template(db: &DatabaseConnection, template: OptionsTem
             .await?;
 
         pricing_options::Entity::delete_many()
-            .filter(pricing_options::Column::Id.starts_with(&borrower_prefix))
+            .filter(pricing_options::Column::BorrowerId.eq(borrower_id))
+            .exec(db)
+            .await?;
+
+        title_fees::Entity::delete_many()
+            .filter(title_fees::Column::BorrowerId.eq(borrower_id))
             .exec(db)
             .await?;
 
@@ -455,6 +697,21 @@ pub async fn save_options_template(db: &DatabaseConnection, template: OptionsTem
             .exec(db)
             .await?;
 
+        net_tangible_benefit_findings::Entity::delete_many()
+            .filter(net_tangible_benefit_findings::Column::BorrowerId.eq(borrower_id))
+            .exec(db)
+            .await?;
+
+        debt_to_income_calculations::Entity::delete_many()
+            .filter(debt_to_income_calculations::Column::BorrowerId.eq(borrower_id))
+            .exec(db)
+            .await?;
+
+        residual_income_calculations::Entity::delete_many()
+            .filter(residual_income_calculations::Column::BorrowerId.eq(borrower_id))
+            .exec(db)
+            .await?;
+
         Ok(())
     }
 
@@ -480,6 +737,8 @@ pub async fn save_options_template(db: &DatabaseConnection, template: OptionsTem
             new_term_months: data.term_months,
             loan_purpose: parse_loan_purpose(&data.purpose),
             appraisal_waiver: data.appraisal_waiver,
+            credit_score: data.credit_score,
+            agency: data.agency.clone(),
             created_at: Utc::now(),
             updated_at: Utc::now(),
         }
@@ -493,8 +752,18 @@ pub async fn save_options_template(db: &DatabaseConnection, template: OptionsTem
             down_payment: data.down_payment,
             base_loan_amount: data.base_loan_amount,
             subordinated_amount: data.subordinated_amount,
-            ff_umip_percentage: 0.0, // Default value
-            umip_refund: 0.0, // Default value
+            heloc_balance: data.heloc_balance,
+            heloc_credit_limit: data.heloc_credit_limit,
+            ff_umip_percentage: data.ff_umip_percentage,
+            ff_umip_amount: data.ff_umip_amount,
+            umip_refund: data.umip_refund,
+            prior_ufmip: data.prior_ufmip,
+            months_since_endorsement: data.months_since_endorsement,
+            va_subsequent_use: data.va_subsequent_use,
+            va_funding_fee_exempt: data.va_funding_fee_exempt,
+            mi_coverage: data.mi_coverage,
+            mi_annual_rate: data.mi_annual_rate,
+            pmi_termination_month: data.pmi_termination_month,
             total_loan_amount: data.total_loan_amount,
             note_rate: data.note_rate,
             appraisal_waiver: data.appraisal_waiver,
@@ -527,6 +796,8 @@ pub async fn save_options_template(db: &DatabaseConnection, template: OptionsTem
             debt_paydown: data.debt_paydown,
             existing_total_obligations: data.existing_total_obligations,
             proposed_total_obligations: data.proposed_total_obligations,
+            existing_pi_override: data.existing_pi_override,
+            proposed_pi_override: data.proposed_pi_override,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         }
@@ -618,6 +889,7 @@ pub async fn save_options_template(db: &DatabaseConnection, template: OptionsTem
             interest_rate: data.interest_rate,
             omit_from_dti: data.omit_from_dti,
             pay_off_at_closing: data.pay_off_at_closing,
+            student_loan_status: StudentLoanStatus::from_key(&data.student_loan_status),
             created_at: Utc::now(),
             updated_at: Utc::now(),
         }
@@ -0,0 +1,95 @@
+use sea_orm::{DatabaseConnection, EntityTrait, QueryFilter, ColumnTrait, Set, ActiveModelTrait};
+use shared::models::{RentalIncomeData, RentalProperty};
+use database::entities::rental_properties;
+use rust_decimal::{Decimal, prelude::ToPrimitive};
+use chrono::Utc;
+
+fn decimal(value: f64) -> Decimal {
+    Decimal::from_f64_retain(value).unwrap_or_default()
+}
+
+fn convert_property(model: rental_properties::Model) -> RentalProperty {
+    let amount = |value: Decimal| value.to_f64().unwrap_or(0.0);
+    RentalProperty {
+        rents_received: amount(model.rents_received),
+        total_expenses: amount(model.total_expenses),
+        depreciation: amount(model.depreciation),
+        mortgage_interest: amount(model.mortgage_interest),
+        taxes: amount(model.taxes),
+        insurance: amount(model.insurance),
+        hoa_dues: amount(model.hoa_dues),
+        months_in_service: model.months_in_service.max(0) as u32,
+        gross_monthly_rent: amount(model.gross_monthly_rent),
+        monthly_pitia: amount(model.monthly_pitia),
+        id: model.id,
+        address: model.address,
+        is_subject_property: model.is_subject_property,
+        method: model.method,
+    }
+}
+
+/// Save the rental income worksheet for a borrower, replacing any saved properties
+pub async fn save_rental_income_data(
+    db: &DatabaseConnection,
+    borrower_id: i32,
+    data: RentalIncomeData,
+) -> Result<(), Box<dyn std::error::Error>> {
+    delete_rental_income_data(db, borrower_id).await?;
+
+    for property in &data.properties {
+        let property_active = rental_properties::ActiveModel {
+            id: Set(property.id.clone()),
+            borrower_id: Set(borrower_id),
+            address: Set(property.address.clone()),
+            is_subject_property: Set(property.is_subject_property),
+            method: Set(property.method.clone()),
+            rents_received: Set(decimal(property.rents_received)),
+            total_expenses: Set(decimal(property.total_expenses)),
+            depreciation: Set(decimal(property.depreciation)),
+            mortgage_interest: Set(decimal(property.mortgage_interest)),
+            taxes: Set(decimal(property.taxes)),
+            insurance: Set(decimal(property.insurance)),
+            hoa_dues: Set(decimal(property.hoa_dues)),
+            months_in_service: Set(property.months_in_service as i32),
+            gross_monthly_rent: Set(decimal(property.gross_monthly_rent)),
+            monthly_pitia: Set(decimal(property.monthly_pitia)),
+            created_at: Set(Utc::now()),
+            updated_at: Set(Utc::now()),
+        };
+        property_active.insert(db).await?;
+    }
+
+    Ok(())
+}
+
+/// Load the rental income worksheet for a borrower
+pub async fn get_rental_income_data(
+    db: &DatabaseConnection,
+    borrower_id: i32,
+) -> Result<Option<RentalIncomeData>, Box<dyn std::error::Error>> {
+    let properties = rental_properties::Entity::find()
+        .filter(rental_properties::Column::BorrowerId.eq(borrower_id))
+        .all(db)
+        .await?;
+
+    if properties.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(RentalIncomeData {
+        properties: properties.into_iter().map(convert_property).collect(),
+    }))
+}
+
+/// Delete the rental income worksheet for a borrower
+pub async fn delete_rental_income_data(
+    db: &DatabaseConnection,
+    borrower_id: i32,
+) -> Result<(), Box<dyn std::error::Error>> {
+    rental_properties::Entity::delete_many()
+        .filter(rental_properties::Column::BorrowerId.eq(borrower_id))
+        .exec(db)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use sea_orm::{DatabaseConnection, EntityTrait, QueryFilter, ColumnTrait, Set, ActiveModelTrait};
+use shared::models::{RetirementAccount, RetirementAssetsData};
+use database::entities::retirement_accounts;
+use rust_decimal::{Decimal, prelude::ToPrimitive};
+use chrono::Utc;
+
+fn decimal(value: f64) -> Decimal {
+    Decimal::from_f64_retain(value).unwrap_or_default()
+}
+
+fn convert_account(model: retirement_accounts::Model) -> RetirementAccount {
+    RetirementAccount {
+        balance: model.balance.to_f64().unwrap_or(0.0),
+        distribution_amount: model.distribution_amount.to_f64().unwrap_or(0.0),
+        id: model.id,
+        description: model.description,
+        account_type: model.account_type,
+        is_vested: model.is_vested,
+        penalty_free: model.penalty_free,
+        distribution_frequency: model.distribution_frequency,
+    }
+}
+
+/// Save the retirement accounts for a borrower, replacing any saved accounts
+pub async fn save_retirement_assets_data(
+    db: &DatabaseConnection,
+    borrower_id: i32,
+    data: RetirementAssetsData,
+) -> Result<(), Box<dyn std::error::Error>> {
+    delete_retirement_assets_data(db, borrower_id).await?;
+
+    for account in &data.accounts {
+        let account_active = retirement_accounts::ActiveModel {
+            id: Set(account.id.clone()),
+            borrower_id: Set(borrower_id),
+            description: Set(account.description.clone()),
+            account_type: Set(account.account_type.clone()),
+            balance: Set(decimal(account.balance)),
+            is_vested: Set(account.is_vested),
+            penalty_free: Set(account.penalty_free),
+            distribution_amount: Set(decimal(account.distribution_amount)),
+            distribution_frequency: Set(account.distribution_frequency.clone()),
+            created_at: Set(Utc::now()),
+            updated_at: Set(Utc::now()),
+        };
+        account_active.insert(db).await?;
+    }
+
+    Ok(())
+}
+
+/// Load the retirement accounts for a borrower
+pub async fn get_retirement_assets_data(
+    db: &DatabaseConnection,
+    borrower_id: i32,
+) -> Result<Option<RetirementAssetsData>, Box<dyn std::error::Error>> {
+    let accounts = retirement_accounts::Entity::find()
+        .filter(retirement_accounts::Column::BorrowerId.eq(borrower_id))
+        .all(db)
+        .await?;
+
+    if accounts.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(RetirementAssetsData {
+        accounts: accounts.into_iter().map(convert_account).collect(),
+    }))
+}
+
+/// Delete the retirement accounts for a borrower
+pub async fn delete_retirement_assets_data(
+    db: &DatabaseConnection,
+    borrower_id: i32,
+) -> Result<(), Box<dyn std::error::Error>> {
+    retirement_accounts::Entity::delete_many()
+        .filter(retirement_accounts::Column::BorrowerId.eq(borrower_id))
+        .exec(db)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,144 @@
+use sea_orm::{DatabaseConnection, EntityTrait, QueryFilter, ColumnTrait, Set, ActiveModelTrait};
+use shared::models::{SelfEmploymentBusiness, SelfEmploymentData, SelfEmploymentYear};
+use database::entities::{self_employment_businesses, self_employment_tax_years};
+use rust_decimal::{Decimal, prelude::ToPrimitive};
+use chrono::Utc;
+use uuid::Uuid;
+
+fn decimal(value: f64) -> Decimal {
+    Decimal::from_f64_retain(value).unwrap_or_default()
+}
+
+fn tax_year_active(
+    year: &SelfEmploymentYear,
+    business_id: &str,
+    borrower_id: i32,
+    is_recent_year: bool,
+) -> self_employment_tax_years::ActiveModel {
+    self_employment_tax_years::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        business_id: Set(business_id.to_string()),
+        borrower_id: Set(borrower_id),
+        is_recent_year: Set(is_recent_year),
+        tax_year: Set(year.tax_year),
+        net_income: Set(decimal(year.net_income)),
+        guaranteed_payments: Set(decimal(year.guaranteed_payments)),
+        w2_wages: Set(decimal(year.w2_wages)),
+        nonrecurring_income: Set(decimal(year.nonrecurring_income)),
+        depreciation: Set(decimal(year.depreciation)),
+        depletion: Set(decimal(year.depletion)),
+        amortization: Set(decimal(year.amortization)),
+        business_use_of_home: Set(decimal(year.business_use_of_home)),
+        notes_payable_short_term: Set(decimal(year.notes_payable_short_term)),
+        meals_exclusion: Set(decimal(year.meals_exclusion)),
+        total_tax: Set(decimal(year.total_tax)),
+        created_at: Set(Utc::now()),
+        updated_at: Set(Utc::now()),
+    }
+}
+
+fn convert_tax_year(model: &self_employment_tax_years::Model) -> SelfEmploymentYear {
+    let amount = |value: Decimal| value.to_f64().unwrap_or(0.0);
+    SelfEmploymentYear {
+        tax_year: model.tax_year,
+        net_income: amount(model.net_income),
+        guaranteed_payments: amount(model.guaranteed_payments),
+        w2_wages: amount(model.w2_wages),
+        nonrecurring_income: amount(model.nonrecurring_income),
+        depreciation: amount(model.depreciation),
+        depletion: amount(model.depletion),
+        amortization: amount(model.amortization),
+        business_use_of_home: amount(model.business_use_of_home),
+        notes_payable_short_term: amount(model.notes_payable_short_term),
+        meals_exclusion: amount(model.meals_exclusion),
+        total_tax: amount(model.total_tax),
+    }
+}
+
+/// Save the self-employment worksheet for a borrower, replacing any saved businesses
+pub async fn save_self_employment_data(
+    db: &DatabaseConnection,
+    borrower_id: i32,
+    data: SelfEmploymentData,
+) -> Result<(), Box<dyn std::error::Error>> {
+    delete_self_employment_data(db, borrower_id).await?;
+
+    for business in &data.businesses {
+        let business_active = self_employment_businesses::ActiveModel {
+            id: Set(business.id.clone()),
+            borrower_id: Set(borrower_id),
+            business_name: Set(business.business_name.clone()),
+            business_type: Set(business.business_type.clone()),
+            ownership_percent: Set(decimal(business.ownership_percent)),
+            created_at: Set(Utc::now()),
+            updated_at: Set(Utc::now()),
+        };
+        business_active.insert(db).await?;
+
+        tax_year_active(&business.prior_year, &business.id, borrower_id, false).insert(db).await?;
+        tax_year_active(&business.recent_year, &business.id, borrower_id, true).insert(db).await?;
+    }
+
+    Ok(())
+}
+
+/// Load the self-employment worksheet for a borrower
+pub async fn get_self_employment_data(
+    db: &DatabaseConnection,
+    borrower_id: i32,
+) -> Result<Option<SelfEmploymentData>, Box<dyn std::error::Error>> {
+    let businesses = self_employment_businesses::Entity::find()
+        .filter(self_employment_businesses::Column::BorrowerId.eq(borrower_id))
+        .all(db)
+        .await?;
+
+    if businesses.is_empty() {
+        return Ok(None);
+    }
+
+    let tax_years = self_employment_tax_years::Entity::find()
+        .filter(self_employment_tax_years::Column::BorrowerId.eq(borrower_id))
+        .all(db)
+        .await?;
+
+    let find_year = |business_id: &str, is_recent_year: bool| {
+        tax_years
+            .iter()
+            .find(|year| year.business_id == business_id && year.is_recent_year == is_recent_year)
+            .map(convert_tax_year)
+            .unwrap_or_default()
+    };
+
+    let businesses = businesses
+        .into_iter()
+        .map(|model| SelfEmploymentBusiness {
+            prior_year: find_year(&model.id, false),
+            recent_year: find_year(&model.id, true),
+            ownership_percent: model.ownership_percent.to_f64().unwrap_or(0.0),
+            id: model.id,
+            business_name: model.business_name,
+            business_type: model.business_type,
+        })
+        .collect();
+
+    Ok(Some(SelfEmploymentData { businesses }))
+}
+
+/// Delete the self-employment worksheet for a borrower
+pub async fn delete_self_employment_data(
+    db: &DatabaseConnection,
+    borrower_id: i32,
+) -> Result<(), Box<dyn std::error::Error>> {
+    // Delete tax years first (due to foreign key constraints)
+    self_employment_tax_years::Entity::delete_many()
+        .filter(self_employment_tax_years::Column::BorrowerId.eq(borrower_id))
+        .exec(db)
+        .await?;
+
+    self_employment_businesses::Entity::delete_many()
+        .filter(self_employment_businesses::Column::BorrowerId.eq(borrower_id))
+        .exec(db)
+        .await?;
+
+    Ok(())
+}
@@ -18,6 +18,9 @@ impl SettingsRepository {
                 currency: model.currency,
                 default_loan_term: model.default_loan_term,
                 dti_threshold: model.dti_threshold,
+                min_dscr: model.min_dscr,
+                income_decline_threshold: model.income_decline_threshold,
+                custom_rule_set: serde_json::from_value(model.custom_rule_set).unwrap_or_default(),
                 auto_backup: model.auto_backup,
             }),
             None => {
@@ -29,12 +32,16 @@ impl SettingsRepository {
     }
 
     pub async fn save(db: &DatabaseConnection, settings: AppSettings) -> Result<(), Box<dyn std::error::Error>> {
+        let custom_rule_set = serde_json::to_value(&settings.custom_rule_set)?;
         let active_model = settings::ActiveModel {
             id: Set(settings.id),
             theme: Set(settings.theme.clone()),
             currency: Set(settings.currency.clone()),
             default_loan_term: Set(settings.default_loan_term),
             dti_threshold: Set(settings.dti_threshold),
+            min_dscr: Set(settings.min_dscr),
+            income_decline_threshold: Set(settings.income_decline_threshold),
+            custom_rule_set: Set(custom_rule_set.clone()),
             auto_backup: Set(settings.auto_backup),
             created_at: Set(Utc::now()),
             updated_at: Set(Utc::now()),
@@ -49,6 +56,9 @@ impl SettingsRepository {
                     currency: Set(settings.currency),
                     default_loan_term: Set(settings.default_loan_term),
                     dti_threshold: Set(settings.dti_threshold),
+                    min_dscr: Set(settings.min_dscr),
+                    income_decline_threshold: Set(settings.income_decline_threshold),
+                    custom_rule_set: Set(custom_rule_set),
                     auto_backup: Set(settings.auto_backup),
                     created_at: Set(Utc::now()),
                     updated_at: Set(Utc::now()),
@@ -0,0 +1,313 @@
+// shared/src/calc/accessory_income.rs
+// Boarder rent and accessory dwelling unit (ADU) income. HomeReady and Home Possible
+// count rent from a boarder who has paid for most of the last twelve months, averaged
+// over the full twelve. ADU rent counts at 75% of the lease. Each source is limited to
+// a share of total qualifying income, and that total includes the sources themselves.
+use serde::{Deserialize, Serialize};
+
+use crate::calc::amortization::round_cents;
+use crate::calc::dti::qualifying_monthly_income;
+use crate::calc::income::parse_amount;
+use crate::calc::rule_set::IncomeRuleSet;
+use crate::models::enums::IncomeType;
+use crate::models::{AccessoryIncomeData, GeneralIncomeData, GeneralIncomeEntry, IncomeInformationData};
+
+/// Share of the ADU lease rent counted after vacancy and maintenance.
+pub const ADU_RENT_FACTOR: f64 = 0.75;
+/// Boarder and ADU income may each be at most this share of total qualifying income.
+pub const PROGRAM_INCOME_SHARE_LIMIT: f64 = 0.30;
+/// Boarder rent is averaged over this many months of history.
+pub const BOARDER_HISTORY_MONTHS: usize = 12;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum AffordableProgram {
+    #[default]
+    HomeReady,
+    HomePossible,
+    Standard,
+}
+
+impl AffordableProgram {
+    pub const ALL: [AffordableProgram; 3] = [
+        AffordableProgram::HomeReady,
+        AffordableProgram::HomePossible,
+        AffordableProgram::Standard,
+    ];
+
+    pub fn from_key(key: &str) -> Self {
+        match key {
+            "home_possible" => AffordableProgram::HomePossible,
+            "standard" => AffordableProgram::Standard,
+            _ => AffordableProgram::HomeReady,
+        }
+    }
+
+    pub fn as_key(&self) -> &'static str {
+        match self {
+            AffordableProgram::HomeReady => "homeready",
+            AffordableProgram::HomePossible => "home_possible",
+            AffordableProgram::Standard => "standard",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            AffordableProgram::HomeReady => "HomeReady",
+            AffordableProgram::HomePossible => "Home Possible",
+            AffordableProgram::Standard => "Standard conventional",
+        }
+    }
+
+    /// Months of the last twelve the boarder must have paid rent; `None` when the
+    /// program does not count boarder income.
+    pub fn boarder_months_required(&self) -> Option<usize> {
+        match self {
+            AffordableProgram::HomeReady => Some(9),
+            AffordableProgram::HomePossible => Some(12),
+            AffordableProgram::Standard => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AccessoryIncomeResult {
+    pub boarder_months_paid: usize,
+    pub boarder_average_monthly: f64,
+    pub boarder_qualifying: f64,
+    pub adu_counted_rent: f64,
+    pub adu_qualifying: f64,
+    // Total qualifying income with both sources at their capped amounts
+    pub total_qualifying_income: f64,
+    pub income_limit: f64,
+    pub qualifying_monthly: f64,
+    pub warnings: Vec<String>,
+}
+
+/// Total qualifying income when each of `sources` is limited to `share` of the total.
+/// Only the larger source is capped first; the smaller one is capped only if it also
+/// exceeds the share of the reduced total.
+pub fn capped_total(other_income: f64, sources: [f64; 2], share: f64) -> f64 {
+    let (low, high) = if sources[0] <= sources[1] { (sources[0], sources[1]) } else { (sources[1], sources[0]) };
+    let uncapped = other_income + low + high;
+    if high <= share * uncapped {
+        return uncapped;
+    }
+    let high_capped = (other_income + low) / (1.0 - share);
+    if low <= share * high_capped {
+        return high_capped;
+    }
+    other_income / (1.0 - 2.0 * share)
+}
+
+/// Qualifying income from every other source, leaving out the boarder and ADU entries
+/// already recorded in general income.
+pub fn other_qualifying_income(income: &IncomeInformationData, rules: &IncomeRuleSet) -> f64 {
+    let boarder_type = IncomeType::BoarderIncome.to_string();
+    let adu_type = IncomeType::AccessoryUnit.to_string();
+    let recorded: f64 = income
+        .general_income_data
+        .iter()
+        .flat_map(|data| data.entries.iter())
+        .filter(|entry| entry.income_type == boarder_type || entry.income_type == adu_type)
+        .map(|entry| parse_amount(&entry.monthly_amount))
+        .sum();
+    (qualifying_monthly_income(income, rules) - recorded).max(0.0)
+}
+
+pub fn calculate_accessory_income(data: &AccessoryIncomeData, other_income: f64) -> AccessoryIncomeResult {
+    let program = AffordableProgram::from_key(&data.program);
+    let mut warnings = Vec::new();
+
+    let boarder_months_paid = data.boarder_months.iter().filter(|month| month.amount > 0.0).count();
+    let boarder_total: f64 = data.boarder_months.iter().map(|month| month.amount.max(0.0)).sum();
+    let boarder_average_monthly = round_cents(boarder_total / BOARDER_HISTORY_MONTHS as f64);
+    let boarder_eligible = match program.boarder_months_required() {
+        _ if boarder_average_monthly == 0.0 => 0.0,
+        None => {
+            warnings.push(format!("{} does not count boarder income.", program.label()));
+            0.0
+        }
+        Some(required) if boarder_months_paid < required => {
+            warnings.push(format!(
+                "{} requires rent from the boarder for {required} of the last {BOARDER_HISTORY_MONTHS} months; {boarder_months_paid} documented.",
+                program.label()
+            ));
+            0.0
+        }
+        Some(_) => boarder_average_monthly,
+    };
+
+    let adu_counted_rent = round_cents(data.adu_monthly_rent.max(0.0) * ADU_RENT_FACTOR);
+    if adu_counted_rent > 0.0 && !data.adu_lease_signed {
+        warnings.push("ADU rent needs a signed lease.".to_string());
+    }
+
+    let total_qualifying_income = round_cents(capped_total(other_income, [boarder_eligible, adu_counted_rent], PROGRAM_INCOME_SHARE_LIMIT));
+    let income_limit = round_cents(total_qualifying_income * PROGRAM_INCOME_SHARE_LIMIT);
+    let boarder_qualifying = boarder_eligible.min(income_limit);
+    let adu_qualifying = adu_counted_rent.min(income_limit);
+
+    let share = PROGRAM_INCOME_SHARE_LIMIT * 100.0;
+    if boarder_qualifying < boarder_eligible {
+        warnings.push(format!("Boarder income is limited to {share:.0}% of total qualifying income."));
+    }
+    if adu_qualifying < adu_counted_rent {
+        warnings.push(format!("ADU rent is limited to {share:.0}% of total qualifying income."));
+    }
+
+    AccessoryIncomeResult {
+        boarder_months_paid,
+        boarder_average_monthly,
+        boarder_qualifying,
+        adu_counted_rent,
+        adu_qualifying,
+        total_qualifying_income,
+        income_limit,
+        qualifying_monthly: boarder_qualifying + adu_qualifying,
+        warnings,
+    }
+}
+
+/// Records the boarder and ADU income as general income entries, removing an entry
+/// when its source no longer counts.
+pub fn apply_to_general_income(general: &mut GeneralIncomeData, data: &AccessoryIncomeData, result: &AccessoryIncomeResult) {
+    let sources = [
+        (IncomeType::BoarderIncome, data.boarder_name.clone(), "Boarder rent, 12-month average", result.boarder_qualifying),
+        (IncomeType::AccessoryUnit, String::new(), "Accessory dwelling unit lease", result.adu_qualifying),
+    ];
+
+    for (income_type, source_name, description, monthly) in sources {
+        let income_type = income_type.to_string();
+        if monthly <= 0.0 {
+            general.entries.retain(|entry| entry.income_type != income_type);
+            continue;
+        }
+
+        let index = match general.entries.iter().position(|entry| entry.income_type == income_type) {
+            Some(index) => index,
+            None => {
+                general.entries.push(GeneralIncomeEntry {
+                    income_type,
+                    ..Default::default()
+                });
+                general.entries.len() - 1
+            }
+        };
+
+        let entry = &mut general.entries[index];
+        entry.source_name = source_name;
+        entry.description = description.to_string();
+        entry.monthly_amount = format!("{:.2}", monthly);
+        entry.annual_amount = format!("{:.2}", monthly * 12.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::BoarderRentMonth;
+
+    fn boarder(amounts: &[f64]) -> AccessoryIncomeData {
+        AccessoryIncomeData {
+            boarder_months: amounts
+                .iter()
+                .map(|amount| BoarderRentMonth {
+                    amount: *amount,
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_boarder_history_and_program() {
+        let mut amounts = [800.0; 12];
+        amounts[..3].fill(0.0);
+        let data = boarder(&amounts);
+
+        // Nine of twelve months, averaged over twelve
+        let result = calculate_accessory_income(&data, 6_000.0);
+        assert_eq!(result.boarder_months_paid, 9);
+        assert_eq!(result.boarder_average_monthly, 600.0);
+        assert_eq!(result.boarder_qualifying, 600.0);
+        assert!(result.warnings.is_empty());
+
+        let home_possible = AccessoryIncomeData {
+            program: "home_possible".to_string(),
+            ..data.clone()
+        };
+        let result = calculate_accessory_income(&home_possible, 6_000.0);
+        assert_eq!(result.boarder_qualifying, 0.0);
+        assert_eq!(result.warnings.len(), 1);
+
+        let standard = AccessoryIncomeData {
+            program: "standard".to_string(),
+            ..data
+        };
+        assert_eq!(calculate_accessory_income(&standard, 6_000.0).qualifying_monthly, 0.0);
+    }
+
+    #[test]
+    fn test_income_share_limit() {
+        // $1,500 of boarder rent against $2,100 of other income: 30% of $3,000
+        let data = boarder(&[1_500.0; 12]);
+        let result = calculate_accessory_income(&data, 2_100.0);
+        assert_eq!(result.total_qualifying_income, 3_000.0);
+        assert_eq!(result.boarder_qualifying, 900.0);
+        assert_eq!(result.warnings.len(), 1);
+
+        assert_eq!(capped_total(4_000.0, [0.0, 0.0], 0.3), 4_000.0);
+        assert_eq!(capped_total(4_000.0, [5_000.0, 5_000.0], 0.3), 10_000.0);
+    }
+
+    #[test]
+    fn test_adu_rent() {
+        let data = AccessoryIncomeData {
+            adu_monthly_rent: 1_200.0,
+            adu_lease_signed: true,
+            ..Default::default()
+        };
+        let result = calculate_accessory_income(&data, 5_000.0);
+        assert_eq!(result.adu_counted_rent, 900.0);
+        assert_eq!(result.adu_qualifying, 900.0);
+        assert!(result.warnings.is_empty());
+
+        let unsigned = AccessoryIncomeData {
+            adu_lease_signed: false,
+            ..data
+        };
+        assert_eq!(calculate_accessory_income(&unsigned, 5_000.0).warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_apply_to_general_income() {
+        let mut data = boarder(&[600.0; 12]);
+        data.adu_monthly_rent = 1_000.0;
+        data.adu_lease_signed = true;
+        let mut general = GeneralIncomeData {
+            entries: vec![GeneralIncomeEntry {
+                monthly_amount: "5000".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let result = calculate_accessory_income(&data, 5_000.0);
+        apply_to_general_income(&mut general, &data, &result);
+        assert_eq!(general.entries.len(), 3);
+        assert_eq!(general.entries[1].income_type, "Boarder Income");
+        assert_eq!(general.entries[2].monthly_amount, "750.00");
+
+        let income = IncomeInformationData {
+            general_income_data: Some(general.clone()),
+            ..Default::default()
+        };
+        assert_eq!(other_qualifying_income(&income, &IncomeRuleSet::default()), 5_000.0);
+
+        data.adu_monthly_rent = 0.0;
+        let result = calculate_accessory_income(&data, 5_000.0);
+        apply_to_general_income(&mut general, &data, &result);
+        assert_eq!(general.entries.len(), 2);
+    }
+}
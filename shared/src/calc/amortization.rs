@@ -0,0 +1,157 @@
+// shared/src/calc/amortization.rs
+// Fixed-rate amortization. Rates are annual percentages (6.5 means 6.5%).
+use serde::{Deserialize, Serialize};
+
+/// Rounds to whole cents.
+pub fn round_cents(amount: f64) -> f64 {
+    (amount * 100.0).round() / 100.0
+}
+
+/// Monthly principal and interest payment, rounded to cents.
+pub fn monthly_payment(principal: f64, annual_rate: f64, term_months: u32) -> f64 {
+    if principal <= 0.0 || term_months == 0 {
+        return 0.0;
+    }
+
+    let n = term_months as f64;
+    let r = annual_rate / 100.0 / 12.0;
+    if r <= 0.0 {
+        return round_cents(principal / n);
+    }
+
+    round_cents(principal * r / (1.0 - (1.0 + r).powf(-n)))
+}
+
+// One month of an amortization schedule
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AmortizationRow {
+    pub month: u32,
+    pub payment: f64,
+    pub principal: f64,
+    pub interest: f64,
+    pub balance: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct AmortizationSchedule {
+    pub monthly_payment: f64,
+    pub rows: Vec<AmortizationRow>,
+    pub total_interest: f64,
+    pub total_paid: f64,
+}
+
+impl AmortizationSchedule {
+    /// Balance remaining after `month` payments have been made.
+    pub fn balance_after(&self, month: u32) -> f64 {
+        match month {
+            0 => self.rows.first().map_or(0.0, |row| row.balance + row.principal),
+            m => self
+                .rows
+                .get(m as usize - 1)
+                .or(self.rows.last())
+                .map_or(0.0, |row| row.balance),
+        }
+    }
+}
+
+/// Builds a month-by-month schedule. Interest is rounded to cents each month and
+/// the final payment absorbs whatever rounding is left so the balance ends at zero.
+pub fn build_schedule(principal: f64, annual_rate: f64, term_months: u32) -> AmortizationSchedule {
+    let payment = monthly_payment(principal, annual_rate, term_months);
+    if payment <= 0.0 {
+        return AmortizationSchedule::default();
+    }
+
+    let r = annual_rate / 100.0 / 12.0;
+    let mut balance = round_cents(principal);
+    let mut rows = Vec::with_capacity(term_months as usize);
+    let mut total_interest = 0.0;
+    let mut total_paid = 0.0;
+
+    for month in 1..=term_months {
+        let interest = round_cents(balance * r);
+        let mut principal_paid = round_cents(payment - interest);
+        if month == term_months || principal_paid > balance {
+            principal_paid = balance;
+        }
+        let paid = round_cents(principal_paid + interest);
+        balance = round_cents(balance - principal_paid);
+
+        total_interest += interest;
+        total_paid += paid;
+        rows.push(AmortizationRow {
+            month,
+            payment: paid,
+            principal: principal_paid,
+            interest,
+            balance,
+        });
+
+        if balance <= 0.0 {
+            break;
+        }
+    }
+
+    AmortizationSchedule {
+        monthly_payment: payment,
+        rows,
+        total_interest: round_cents(total_interest),
+        total_paid: round_cents(total_paid),
+    }
+}
+
+/// Total interest paid over the life of the loan.
+pub fn total_interest(principal: f64, annual_rate: f64, term_months: u32) -> f64 {
+    build_schedule(principal, annual_rate, term_months).total_interest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_monthly_payment_known_values() {
+        assert_eq!(monthly_payment(200_000.0, 6.0, 360), 1199.10);
+        assert_eq!(monthly_payment(300_000.0, 7.0, 360), 1995.91);
+        assert_eq!(monthly_payment(150_000.0, 5.5, 180), 1225.63);
+    }
+
+    #[test]
+    fn test_monthly_payment_edge_cases() {
+        assert_eq!(monthly_payment(0.0, 6.0, 360), 0.0);
+        assert_eq!(monthly_payment(100_000.0, 6.0, 0), 0.0);
+        assert_eq!(monthly_payment(120_000.0, 0.0, 120), 1000.0);
+    }
+
+    #[test]
+    fn test_schedule_pays_off_balance() {
+        let schedule = build_schedule(200_000.0, 6.0, 360);
+        assert_eq!(schedule.rows.len(), 360);
+        assert_eq!(schedule.rows.last().unwrap().balance, 0.0);
+
+        let first = &schedule.rows[0];
+        assert_eq!(first.interest, 1000.0);
+        assert_eq!(first.principal, 199.10);
+        assert_eq!(first.balance, 199_800.90);
+
+        let principal: f64 = schedule.rows.iter().map(|row| row.principal).sum();
+        assert!((principal - 200_000.0).abs() < 0.01);
+        assert!((schedule.total_paid - (200_000.0 + schedule.total_interest)).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_total_interest() {
+        let interest = total_interest(200_000.0, 6.0, 360);
+        // 360 payments of $1,199.10 less principal, give or take final-payment rounding
+        assert!((interest - 231_676.0).abs() < 5.0, "got {interest}");
+    }
+
+    #[test]
+    fn test_balance_after() {
+        let schedule = build_schedule(200_000.0, 6.0, 360);
+        assert_eq!(schedule.balance_after(0), 200_000.0);
+        assert_eq!(schedule.balance_after(1), 199_800.90);
+        assert_eq!(schedule.balance_after(360), 0.0);
+        assert_eq!(schedule.balance_after(500), 0.0);
+    }
+}
@@ -0,0 +1,264 @@
+// shared/src/calc/apr.rs
+// Annual percentage rate by the actuarial method of Regulation Z, Appendix J. The APR
+// is the rate that discounts the payment stream back to the amount financed: the loan
+// amount less prepaid finance charges. For a mortgage the stream is P&I plus mortgage
+// insurance for as long as it is paid.
+use crate::calc::amortization::{build_schedule, monthly_payment, round_cents};
+use crate::calc::mip::mip_duration_months;
+use crate::calc::options_template::{base_ltv, property_value};
+use crate::calc::pmi::termination_month;
+use crate::models::{OptionsTemplateData, OtherFeesData, PricingOptionData, TitleFeesData};
+
+/// Time from the advance to the first payment, as whole unit periods plus a fraction
+/// of one. Appendix J counts the fraction in days over 30 for monthly payments.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FirstPeriod {
+    pub whole_periods: u32,
+    pub fraction: f64,
+}
+
+impl Default for FirstPeriod {
+    // A regular first period: the first payment one unit period after the advance
+    fn default() -> Self {
+        Self {
+            whole_periods: 1,
+            fraction: 0.0,
+        }
+    }
+}
+
+// Present value of the payments at periodic rate `i`, per the Appendix J general equation
+fn present_value(payments: &[f64], first_period: FirstPeriod, i: f64) -> f64 {
+    let fraction = 1.0 + first_period.fraction * i;
+    payments
+        .iter()
+        .enumerate()
+        .map(|(k, payment)| payment / (fraction * (1.0 + i).powi((first_period.whole_periods as usize + k) as i32)))
+        .sum()
+}
+
+/// APR, as a percentage, of one advance of `amount_financed` repaid by `payments`, one
+/// per unit period. `None` when the payments do not repay the amount financed.
+pub fn annual_percentage_rate(amount_financed: f64, payments: &[f64], periods_per_year: u32, first_period: FirstPeriod) -> Option<f64> {
+    let total: f64 = payments.iter().sum();
+    if amount_financed <= 0.0 || total < amount_financed {
+        return None;
+    }
+
+    // The present value falls as the rate rises, so bisect on the periodic rate
+    let (mut low, mut high) = (0.0_f64, 1.0_f64);
+    for _ in 0..200 {
+        let mid = (low + high) / 2.0;
+        if present_value(payments, first_period, mid) > amount_financed {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    Some((low + high) / 2.0 * periods_per_year as f64 * 100.0)
+}
+
+/// Prepaid finance charges for a pricing option: lender and investor fees, the
+/// settlement and courier fees charged by the closing agent, discount points and any
+/// financed upfront premium, less the option's lender credit. Appraisal, title
+/// insurance, search and examination fees and escrow deposits are not finance charges.
+pub fn prepaid_finance_charges(fees: &OtherFeesData, title: &TitleFeesData, option: &PricingOptionData, financed_premium: f64) -> f64 {
+    let lender_fees = fees.investor_fee + fees.admin_fees + fees.tax_service + fees.flood_certification;
+    let closing_agent_fees = title.title_closing_fee + title.title_courier_fee;
+    (lender_fees + closing_agent_fees + option.bd_dollar + financed_premium - option.ysp_dollar).max(0.0)
+}
+
+/// Monthly payments over the term: P&I, plus `monthly_mi[k]` in month `k`. Months past
+/// the end of `monthly_mi` pay P&I only.
+pub fn mortgage_payment_stream(loan_amount: f64, note_rate: f64, term_months: u32, monthly_mi: &[f64]) -> Vec<f64> {
+    let pi = monthly_payment(loan_amount, note_rate, term_months);
+    (0..term_months as usize)
+        .map(|month| pi + monthly_mi.get(month).copied().unwrap_or(0.0))
+        .collect()
+}
+
+/// FHA annual MIP by month. The premium is reset each loan year on the balance then
+/// outstanding, so it falls as the loan pays down; `first_year_mip` is the premium
+/// quoted on the base loan amount, which excludes the financed UFMIP, so the balance
+/// is taken from the base loan's amortization.
+pub fn fha_mip_stream(base_loan_amount: f64, note_rate: f64, term_months: u32, first_year_mip: f64, mip_months: u32) -> Vec<f64> {
+    if base_loan_amount <= 0.0 {
+        return Vec::new();
+    }
+    let schedule = build_schedule(base_loan_amount, note_rate, term_months);
+    (0..mip_months.min(term_months))
+        .map(|month| {
+            let balance = schedule.balance_after(month - month % 12);
+            round_cents(first_year_mip * balance / base_loan_amount)
+        })
+        .collect()
+}
+
+// Mortgage insurance paid each month on the new loan at `note_rate`
+fn mi_payments(data: &OptionsTemplateData, note_rate: f64) -> Vec<f64> {
+    let loan = &data.new_loan;
+    let term_months = data.loan_information.term_months;
+    let monthly_mi = data.benefit_to_borrower.proposed_pmi;
+    if monthly_mi <= 0.0 {
+        return Vec::new();
+    }
+    match data.loan_information.loan_type.as_str() {
+        "fha" => fha_mip_stream(
+            loan.base_loan_amount,
+            note_rate,
+            term_months,
+            monthly_mi,
+            mip_duration_months(base_ltv(data), term_months),
+        ),
+        // The stored termination month is for the template's rate; each option amortizes
+        // at its own
+        "cnv" => {
+            let months = termination_month(loan.total_loan_amount, note_rate, term_months, property_value(data));
+            vec![monthly_mi; months as usize]
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// APR for one pricing option on the template's loan. Dollar amounts on the option are
+/// taken as already derived.
+pub fn pricing_option_apr(data: &OptionsTemplateData, option: &PricingOptionData) -> Option<f64> {
+    let loan = &data.new_loan;
+    let term_months = data.loan_information.term_months;
+    if option.note_rate <= 0.0 || term_months == 0 {
+        return None;
+    }
+
+    let financed_premium = loan.ff_umip_amount - loan.umip_refund;
+    let amount_financed = loan.total_loan_amount
+        - prepaid_finance_charges(&data.other_fees, &data.title_fees, option, financed_premium);
+    let payments = mortgage_payment_stream(
+        loan.total_loan_amount,
+        option.note_rate,
+        term_months,
+        &mi_payments(data, option.note_rate),
+    );
+    annual_percentage_rate(amount_financed, &payments, 12, FirstPeriod::default())
+}
+
+/// APR for each pricing option, in the same order as the options.
+pub fn pricing_option_aprs(data: &OptionsTemplateData) -> Vec<Option<f64>> {
+    data.pricing
+        .pricing_options
+        .iter()
+        .map(|option| pricing_option_apr(data, option))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_apr(apr: Option<f64>) -> f64 {
+        (apr.unwrap() * 100.0).round() / 100.0
+    }
+
+    // Appendix J, (c)(1)(i): monthly payments with a regular first period
+    #[test]
+    fn test_appendix_j_regular_first_period() {
+        let apr = annual_percentage_rate(5_000.0, &[230.0; 24], 12, FirstPeriod::default());
+        assert_eq!(round_apr(apr), 9.69);
+    }
+
+    // Appendix J, (c)(1)(ii): advance on February 10, first payment April 1
+    #[test]
+    fn test_appendix_j_long_first_period() {
+        let first_period = FirstPeriod {
+            whole_periods: 1,
+            fraction: 19.0 / 30.0,
+        };
+        let apr = annual_percentage_rate(6_000.0, &[200.0; 36], 12, first_period);
+        assert_eq!(round_apr(apr), 11.82);
+    }
+
+    // Paying sooner than a full period after the advance raises the rate
+    #[test]
+    fn test_short_first_period() {
+        let regular = annual_percentage_rate(5_000.0, &[230.0; 24], 12, FirstPeriod::default()).unwrap();
+        let short = FirstPeriod {
+            whole_periods: 0,
+            fraction: 19.0 / 30.0,
+        };
+        assert!(annual_percentage_rate(5_000.0, &[230.0; 24], 12, short).unwrap() > regular);
+    }
+
+    #[test]
+    fn test_no_apr_when_payments_fall_short() {
+        assert_eq!(annual_percentage_rate(5_000.0, &[100.0; 12], 12, FirstPeriod::default()), None);
+    }
+
+    #[test]
+    fn test_pricing_option_apr() {
+        let mut data = OptionsTemplateData::default();
+        data.loan_information.term_months = 360;
+        data.new_loan.total_loan_amount = 300_000.0;
+        let option = PricingOptionData {
+            note_rate: 6.5,
+            ..Default::default()
+        };
+        // No finance charges: the APR is the note rate, give or take the payment's rounding
+        assert!((pricing_option_apr(&data, &option).unwrap() - 6.5).abs() < 1e-3);
+
+        // $3,000 of points and $1,500 of lender fees
+        data.other_fees.admin_fees = 1_000.0;
+        data.title_fees.title_closing_fee = 500.0;
+        data.title_fees.title_insurance = 1_200.0;
+        let points = PricingOptionData {
+            bd_dollar: 3_000.0,
+            ..option.clone()
+        };
+        assert_eq!(prepaid_finance_charges(&data.other_fees, &data.title_fees, &points, 0.0), 4_500.0);
+        assert_eq!(round_apr(pricing_option_apr(&data, &points)), 6.65);
+
+        // Mortgage insurance raises the APR while it is paid. FHA MIP runs the full term
+        // at 96.8% LTV and resets each year on the declining balance.
+        data.loan_information.loan_type = "fha".to_string();
+        data.benefit_to_borrower.proposed_pmi = 137.5;
+        data.new_loan.market_value = 310_000.0;
+        data.new_loan.base_loan_amount = 300_000.0;
+        let with_mip = pricing_option_apr(&data, &points).unwrap();
+        assert_eq!((with_mip * 1_000.0).round() / 1_000.0, 7.210);
+
+        // Conventional MI stops at 78% LTV on the option's own amortization, whatever
+        // termination month is stored on the loan
+        data.loan_information.loan_type = "cnv".to_string();
+        data.benefit_to_borrower.proposed_pmi = 100.0;
+        let with_pmi = pricing_option_apr(&data, &points).unwrap();
+        data.new_loan.pmi_termination_month = 12;
+        assert_eq!(pricing_option_apr(&data, &points).unwrap(), with_pmi);
+        assert_eq!((with_pmi * 1_000.0).round() / 1_000.0, 6.973);
+    }
+
+    #[test]
+    fn test_fha_mip_resets_yearly() {
+        let mip = fha_mip_stream(300_000.0, 6.5, 360, 137.5, 360);
+        assert_eq!(mip.len(), 360);
+        // Level within a year, lower each year after
+        assert_eq!(mip[0], 137.5);
+        assert_eq!(mip[11], 137.5);
+        assert_eq!(mip[12], 135.96);
+        assert_eq!(mip[24], 134.32);
+    }
+
+    #[test]
+    fn test_fha_mip_follows_base_loan() {
+        // $5,250 of UFMIP financed on a $300,000 base loan; the premium resets on the
+        // base loan's balance
+        let mut data = OptionsTemplateData::default();
+        data.loan_information.loan_type = "fha".to_string();
+        data.loan_information.term_months = 360;
+        data.new_loan.market_value = 310_000.0;
+        data.new_loan.base_loan_amount = 300_000.0;
+        data.new_loan.total_loan_amount = 305_250.0;
+        data.benefit_to_borrower.proposed_pmi = 137.5;
+        let mip = mi_payments(&data, 6.5);
+        assert_eq!(mip.len(), 360);
+        assert_eq!(mip[11], 137.5);
+        assert_eq!(mip[12], 135.96);
+    }
+}
@@ -0,0 +1,229 @@
+// shared/src/calc/bank_statement.rs
+// Qualifying income from 12 or 24 months of bank statements for Non-QM loans. Eligible
+// deposits are the statement totals less transfers and non-business deposits. Business
+// accounts are reduced by an expense factor, either a fixed percentage or the one in a
+// CPA letter, and by the borrower's ownership share; personal accounts count eligible
+// deposits as they are.
+use serde::{Deserialize, Serialize};
+
+use crate::calc::amortization::round_cents;
+use crate::models::{BankStatementAccount, BankStatementData};
+
+/// Months more than this many standard deviations from the average are flagged.
+pub const OUTLIER_STANDARD_DEVIATIONS: f64 = 2.0;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum BankAccountType {
+    #[default]
+    Business,
+    Personal,
+}
+
+impl BankAccountType {
+    pub const ALL: [BankAccountType; 2] = [BankAccountType::Business, BankAccountType::Personal];
+
+    pub fn from_key(key: &str) -> Self {
+        match key {
+            "personal" => BankAccountType::Personal,
+            _ => BankAccountType::Business,
+        }
+    }
+
+    pub fn as_key(&self) -> &'static str {
+        match self {
+            BankAccountType::Business => "business",
+            BankAccountType::Personal => "personal",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            BankAccountType::Business => "Business",
+            BankAccountType::Personal => "Personal",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum ExpenseMethod {
+    #[default]
+    Fixed,
+    CpaLetter,
+}
+
+impl ExpenseMethod {
+    pub const ALL: [ExpenseMethod; 2] = [ExpenseMethod::Fixed, ExpenseMethod::CpaLetter];
+
+    pub fn from_key(key: &str) -> Self {
+        match key {
+            "cpa" => ExpenseMethod::CpaLetter,
+            _ => ExpenseMethod::Fixed,
+        }
+    }
+
+    pub fn as_key(&self) -> &'static str {
+        match self {
+            ExpenseMethod::Fixed => "fixed",
+            ExpenseMethod::CpaLetter => "cpa",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ExpenseMethod::Fixed => "Fixed expense factor",
+            ExpenseMethod::CpaLetter => "CPA letter",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BankStatementResult {
+    pub months_counted: usize,
+    pub eligible_deposits: f64,
+    pub average_monthly_deposits: f64,
+    // Zero for personal accounts
+    pub expense_percent: f64,
+    pub qualifying_monthly: f64,
+    pub nsf_count: u32,
+    // Indexes into the account's months
+    pub nsf_months: Vec<usize>,
+    pub outlier_months: Vec<usize>,
+}
+
+/// Months whose eligible deposits are outliers against the rest of the statements.
+pub fn outlier_months(deposits: &[f64]) -> Vec<usize> {
+    if deposits.len() < 3 {
+        return Vec::new();
+    }
+    let count = deposits.len() as f64;
+    let mean = deposits.iter().sum::<f64>() / count;
+    let std_dev = (deposits.iter().map(|d| (d - mean).powi(2)).sum::<f64>() / count).sqrt();
+    if std_dev == 0.0 {
+        return Vec::new();
+    }
+
+    deposits
+        .iter()
+        .enumerate()
+        .filter(|(_, d)| ((*d - mean) / std_dev).abs() > OUTLIER_STANDARD_DEVIATIONS)
+        .map(|(index, _)| index)
+        .collect()
+}
+
+pub fn analyze_account(account: &BankStatementAccount) -> BankStatementResult {
+    let deposits: Vec<f64> = account
+        .months
+        .iter()
+        .map(|month| (month.total_deposits - month.excluded_deposits).max(0.0))
+        .collect();
+    let months_counted = deposits.len();
+    let eligible_deposits = round_cents(deposits.iter().sum());
+    let average_monthly_deposits = if months_counted > 0 {
+        round_cents(eligible_deposits / months_counted as f64)
+    } else {
+        0.0
+    };
+
+    let (expense_percent, ownership_share) = match BankAccountType::from_key(&account.account_type) {
+        BankAccountType::Business => (account.expense_percent.clamp(0.0, 100.0), account.ownership_percent.clamp(0.0, 100.0) / 100.0),
+        BankAccountType::Personal => (0.0, 1.0),
+    };
+    let qualifying_monthly = round_cents(average_monthly_deposits * (1.0 - expense_percent / 100.0) * ownership_share);
+
+    let nsf_months: Vec<usize> = account
+        .months
+        .iter()
+        .enumerate()
+        .filter(|(_, month)| month.nsf_count > 0)
+        .map(|(index, _)| index)
+        .collect();
+
+    BankStatementResult {
+        months_counted,
+        eligible_deposits,
+        average_monthly_deposits,
+        expense_percent,
+        qualifying_monthly,
+        nsf_count: account.months.iter().map(|month| month.nsf_count).sum(),
+        nsf_months,
+        outlier_months: outlier_months(&deposits),
+    }
+}
+
+/// Qualifying bank-statement income across every account.
+pub fn total_monthly_income(data: &BankStatementData) -> f64 {
+    data.accounts.iter().map(|account| analyze_account(account).qualifying_monthly).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::BankStatementMonth;
+
+    fn months(deposits: &[f64]) -> Vec<BankStatementMonth> {
+        deposits
+            .iter()
+            .map(|total| BankStatementMonth {
+                total_deposits: *total,
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_business_account_expense_and_ownership() {
+        let mut account = BankStatementAccount {
+            ownership_percent: 50.0,
+            months: months(&[20_000.0; 12]),
+            ..Default::default()
+        };
+        account.months[0].excluded_deposits = 12_000.0;
+
+        let result = analyze_account(&account);
+        assert_eq!(result.eligible_deposits, 228_000.0);
+        assert_eq!(result.average_monthly_deposits, 19_000.0);
+        // 50% fixed expense factor, then half ownership
+        assert_eq!(result.qualifying_monthly, 4_750.0);
+
+        let cpa = BankStatementAccount {
+            expense_method: "cpa".to_string(),
+            expense_percent: 30.0,
+            ownership_percent: 100.0,
+            ..account.clone()
+        };
+        assert_eq!(analyze_account(&cpa).qualifying_monthly, 13_300.0);
+    }
+
+    #[test]
+    fn test_personal_account_counts_eligible_deposits() {
+        let account = BankStatementAccount {
+            account_type: "personal".to_string(),
+            months: months(&[8_000.0; 24]),
+            ..Default::default()
+        };
+        let result = analyze_account(&account);
+        assert_eq!(result.months_counted, 24);
+        assert_eq!(result.expense_percent, 0.0);
+        assert_eq!(result.qualifying_monthly, 8_000.0);
+        assert_eq!(total_monthly_income(&BankStatementData { accounts: vec![account] }), 8_000.0);
+    }
+
+    #[test]
+    fn test_nsf_and_outlier_flags() {
+        let mut deposits = [10_000.0; 12];
+        deposits[7] = 45_000.0;
+        let mut account = BankStatementAccount {
+            months: months(&deposits),
+            ..Default::default()
+        };
+        account.months[3].nsf_count = 2;
+        account.months[9].nsf_count = 1;
+
+        let result = analyze_account(&account);
+        assert_eq!(result.nsf_count, 3);
+        assert_eq!(result.nsf_months, vec![3, 9]);
+        assert_eq!(result.outlier_months, vec![7]);
+
+        assert!(outlier_months(&[10_000.0; 12]).is_empty());
+    }
+}
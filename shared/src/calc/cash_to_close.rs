@@ -0,0 +1,264 @@
+// shared/src/calc/cash_to_close.rs
+// Closing statement for the options template. Funds needed at closing are the purchase
+// price or the liens being paid off, debts paid at closing, closing costs, prepaids
+// and escrows, and any financed upfront premium. The new loan and the lender credit
+// pay for them. What is left over is the cash the borrower brings, or on a refinance
+// the cash they get back. That cash back is checked against the loan purpose.
+use serde::{Deserialize, Serialize};
+
+use crate::calc::amortization::round_cents;
+use crate::calc::pricing::selected_pricing_option;
+use crate::models::{LoanPurpose, OptionsTemplateData};
+
+/// Limited cash-out refinances return at most the lesser of 2% of the loan and $2,000.
+pub const LIMITED_CASH_OUT_SHARE: f64 = 0.02;
+pub const LIMITED_CASH_OUT_MAX: f64 = 2_000.0;
+/// Streamlines, and FHA rate and term refinances, return at most $500.
+pub const STREAMLINE_MAX_CASH_BACK: f64 = 500.0;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ClosingStatement {
+    // Lender and third-party fees, appraisal included
+    pub loan_fees: f64,
+    pub title_fees: f64,
+    // Discount points on the selected pricing option
+    pub discount_points: f64,
+    // Padded tax and insurance deposits
+    pub prepaids_escrows: f64,
+    // Lender credit applied, never more than the costs it pays for
+    pub lender_credit: f64,
+    pub total_closing_costs: f64,
+    pub purchase_price: f64,
+    // First liens paid off; subordinate liens stay in place
+    pub mortgage_payoffs: f64,
+    pub debt_payoffs: f64,
+    // Upfront MIP or funding fee added to the loan, less any UFMIP refund
+    pub financed_premium: f64,
+    pub loan_amount: f64,
+    // Positive when the borrower brings funds, negative for cash back
+    pub cash_to_close: f64,
+    // `None` when the purpose places no limit on cash back
+    pub max_cash_back: Option<f64>,
+    pub within_purpose: bool,
+    pub warnings: Vec<String>,
+}
+
+impl ClosingStatement {
+    /// Cash the borrower receives at closing. Zero when they bring funds.
+    pub fn cash_back(&self) -> f64 {
+        (-self.cash_to_close).max(0.0)
+    }
+}
+
+/// Most the borrower may take back for `purpose`, or `None` when there is no limit.
+pub fn max_cash_back(purpose: Option<&LoanPurpose>, loan_type: &str, loan_amount: f64) -> Option<f64> {
+    match purpose {
+        Some(LoanPurpose::CashOut) => None,
+        Some(LoanPurpose::Purchase) => Some(0.0),
+        Some(LoanPurpose::IRRRLStreamline) => Some(STREAMLINE_MAX_CASH_BACK),
+        Some(LoanPurpose::Refinance) if loan_type == "fha" => Some(STREAMLINE_MAX_CASH_BACK),
+        Some(LoanPurpose::Refinance) => {
+            Some(round_cents((loan_amount * LIMITED_CASH_OUT_SHARE).min(LIMITED_CASH_OUT_MAX)))
+        }
+        None => None,
+    }
+}
+
+pub fn calculate_cash_to_close(data: &OptionsTemplateData) -> ClosingStatement {
+    let fees = &data.other_fees;
+    let title = &data.title_fees;
+    let loan = &data.new_loan;
+    let purpose = LoanPurpose::from_key(&data.loan_information.purpose);
+    let is_purchase = purpose == Some(LoanPurpose::Purchase);
+
+    let loan_fees = round_cents(
+        fees.third_party_fees
+            + fees.appraisal_fee
+            + fees.investor_fee
+            + fees.admin_fees
+            + fees.tax_service
+            + fees.flood_certification,
+    );
+    let title_fees = round_cents(
+        title.title_insurance
+            + title.title_search
+            + title.title_examination
+            + title.title_closing_fee
+            + title.title_courier_fee
+            + title.title_other_fees,
+    );
+    let discount_points = selected_pricing_option(data).map_or(0.0, |option| option.bd_dollar);
+    let prepaids_escrows = round_cents(fees.padded_taxes + fees.padded_insurance);
+
+    // Credit beyond the costs is not paid out to the borrower
+    let gross_costs = loan_fees + title_fees + discount_points + prepaids_escrows;
+    let lender_credit = fees.lender_credit.clamp(0.0, gross_costs);
+    let total_closing_costs = round_cents(gross_costs - lender_credit);
+
+    let (purchase_price, mortgage_payoffs) = if is_purchase {
+        (loan.sales_price, 0.0)
+    } else {
+        let payoffs: f64 = data
+            .mortgage_payoffs
+            .existing_loans
+            .iter()
+            .filter(|existing| !existing.is_subordinate)
+            .map(|existing| existing.loan_balance)
+            .sum();
+        (0.0, round_cents(payoffs))
+    };
+    let debt_payoffs = round_cents(
+        data.consumer_debt
+            .consumer_debts
+            .iter()
+            .filter(|debt| debt.pay_off_at_closing)
+            .map(|debt| debt.balance)
+            .sum(),
+    );
+    let financed_premium = round_cents(loan.ff_umip_amount - loan.umip_refund);
+
+    let cash_to_close = round_cents(
+        purchase_price + mortgage_payoffs + debt_payoffs + total_closing_costs + financed_premium
+            - loan.total_loan_amount,
+    );
+
+    let max_cash_back = max_cash_back(purpose.as_ref(), &data.loan_information.loan_type, loan.total_loan_amount);
+    let cash_back = (-cash_to_close).max(0.0);
+    let over_limit = max_cash_back.is_some_and(|limit| cash_back > limit);
+    // Only a cash-out refinance may pay off consumer debt
+    let is_rate_term = matches!(purpose, Some(LoanPurpose::Refinance | LoanPurpose::IRRRLStreamline));
+    let pays_off_debt = is_rate_term && debt_payoffs > 0.0;
+
+    let mut warnings = Vec::new();
+    if over_limit && is_purchase {
+        warnings.push(format!("The loan covers more than the purchase: ${cash_back:.2} would go back to the borrower."));
+    } else if over_limit {
+        warnings.push(format!(
+            "${cash_back:.2} back to the borrower is over the ${:.2} allowed; this is a cash-out refinance.",
+            max_cash_back.unwrap_or_default()
+        ));
+    }
+    if pays_off_debt {
+        warnings.push("Paying off consumer debt at closing makes this a cash-out refinance.".to_string());
+    }
+    if purpose == Some(LoanPurpose::CashOut) && cash_back == 0.0 {
+        warnings.push("No cash goes back to the borrower; this can be priced as a limited cash-out refinance.".to_string());
+    }
+
+    ClosingStatement {
+        loan_fees,
+        title_fees,
+        discount_points,
+        prepaids_escrows,
+        lender_credit,
+        total_closing_costs,
+        purchase_price,
+        mortgage_payoffs,
+        debt_payoffs,
+        financed_premium,
+        loan_amount: loan.total_loan_amount,
+        cash_to_close,
+        max_cash_back,
+        within_purpose: !over_limit && !pays_off_debt,
+        warnings,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ConsumerDebtItemData, ExistingLoanData, PricingOptionData};
+
+    fn refinance(purpose: &str) -> OptionsTemplateData {
+        let mut data = OptionsTemplateData::default();
+        data.loan_information.purpose = purpose.to_string();
+        data.loan_information.loan_type = "cnv".to_string();
+        data.new_loan.total_loan_amount = 200_000.0;
+        data.mortgage_payoffs.existing_loans = vec![
+            ExistingLoanData {
+                position: 1,
+                loan_balance: 190_000.0,
+                ..Default::default()
+            },
+            ExistingLoanData {
+                position: 2,
+                loan_balance: 25_000.0,
+                is_subordinate: true,
+                ..Default::default()
+            },
+        ];
+        data.other_fees.appraisal_fee = 600.0;
+        data.other_fees.admin_fees = 1_000.0;
+        data.other_fees.padded_taxes = 1_200.0;
+        data.title_fees.title_insurance = 900.0;
+        data.title_fees.title_closing_fee = 500.0;
+        data
+    }
+
+    #[test]
+    fn test_purchase_cash_to_close() {
+        let mut data = refinance("purchase");
+        data.loan_information.loan_type = "fha".to_string();
+        data.new_loan.sales_price = 210_000.0;
+        data.new_loan.ff_umip_amount = 3_500.0;
+        data.new_loan.total_loan_amount = 206_150.0;
+        data.pricing.pricing_options = vec![PricingOptionData {
+            bd_dollar: 1_000.0,
+            is_selected: true,
+            ..Default::default()
+        }];
+        data.other_fees.lender_credit = 300.0;
+
+        let statement = calculate_cash_to_close(&data);
+        assert_eq!(statement.loan_fees, 1_600.0);
+        assert_eq!(statement.title_fees, 1_400.0);
+        assert_eq!(statement.total_closing_costs, 1_600.0 + 1_400.0 + 1_000.0 + 1_200.0 - 300.0);
+        // Payoffs do not apply to a purchase
+        assert_eq!(statement.mortgage_payoffs, 0.0);
+        assert_eq!(statement.cash_to_close, 210_000.0 + 4_900.0 + 3_500.0 - 206_150.0);
+        assert!(statement.within_purpose);
+    }
+
+    #[test]
+    fn test_limited_cash_out_refinance() {
+        let mut data = refinance("refinance");
+        data.other_fees.lender_credit = 10_000.0;
+        let statement = calculate_cash_to_close(&data);
+        // The credit covers the costs but is not paid out
+        assert_eq!(statement.lender_credit, 4_200.0);
+        assert_eq!(statement.total_closing_costs, 0.0);
+        assert_eq!(statement.mortgage_payoffs, 190_000.0);
+        assert_eq!(statement.cash_back(), 10_000.0);
+        assert_eq!(statement.max_cash_back, Some(2_000.0));
+        assert!(!statement.within_purpose);
+
+        // The same loan as a cash-out refinance has no limit
+        let statement = calculate_cash_to_close(&refinance("cashOut"));
+        assert_eq!(statement.cash_to_close, -5_800.0);
+        assert_eq!(statement.max_cash_back, None);
+        assert!(statement.within_purpose);
+    }
+
+    #[test]
+    fn test_debt_payoff_requires_cash_out() {
+        let mut data = refinance("refinance");
+        data.new_loan.total_loan_amount = 194_200.0;
+        data.consumer_debt.consumer_debts = vec![ConsumerDebtItemData {
+            balance: 4_000.0,
+            pay_off_at_closing: true,
+            ..Default::default()
+        }];
+        let statement = calculate_cash_to_close(&data);
+        assert_eq!(statement.cash_to_close, 4_000.0);
+        assert!(!statement.within_purpose);
+        assert_eq!(statement.warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_max_cash_back_by_purpose() {
+        assert_eq!(max_cash_back(Some(&LoanPurpose::Refinance), "cnv", 50_000.0), Some(1_000.0));
+        assert_eq!(max_cash_back(Some(&LoanPurpose::Refinance), "fha", 300_000.0), Some(500.0));
+        assert_eq!(max_cash_back(Some(&LoanPurpose::IRRRLStreamline), "va", 300_000.0), Some(500.0));
+        assert_eq!(max_cash_back(Some(&LoanPurpose::CashOut), "cnv", 300_000.0), None);
+    }
+}
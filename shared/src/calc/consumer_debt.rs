@@ -0,0 +1,238 @@
+// shared/src/calc/consumer_debt.rs
+// Agency rules for the payments consumer debts contribute to DTI. Every adjustment
+// carries a note so the options template can show why a payment differs from the
+// one entered.
+use crate::calc::amortization::round_cents;
+use crate::calc::dti::ratio;
+use crate::models::{ConsumerDebtItemData, CreditType, LoanType, StudentLoanStatus};
+
+/// Revolving accounts reporting no payment qualify at this percentage of the balance.
+pub const REVOLVING_PAYMENT_PERCENT: f64 = 5.0;
+/// Installment debts with this many payments or fewer left may be left out of DTI.
+pub const OMIT_MAX_REMAINING_PAYMENTS: u32 = 10;
+/// FHA only omits short installment debts whose combined payments stay within this
+/// percentage of gross monthly income.
+pub const FHA_OMIT_MAX_INCOME_PERCENT: f64 = 5.0;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DebtAdjustment {
+    pub qualifying_payment: f64,
+    pub omit_suggested: bool,
+    pub notes: Vec<String>,
+}
+
+fn agency(loan_type: Option<&LoanType>) -> &'static str {
+    match loan_type {
+        Some(LoanType::CNV) => "Conventional",
+        Some(LoanType::FHA) => "FHA",
+        Some(LoanType::VA) => "VA",
+        Some(LoanType::USDA) => "USDA",
+        Some(LoanType::NonQM) | None => "Default",
+    }
+}
+
+/// Monthly payment used for a deferred student loan, or an income-driven plan with no
+/// payment, as a percentage of the balance.
+pub fn student_loan_payment_percent(loan_type: Option<&LoanType>) -> f64 {
+    match loan_type {
+        Some(LoanType::FHA) | Some(LoanType::USDA) => 0.5,
+        // 5% of the balance spread over 12 months
+        Some(LoanType::VA) => 5.0 / 12.0,
+        Some(LoanType::CNV) | Some(LoanType::NonQM) | None => 1.0,
+    }
+}
+
+/// Payment the debt qualifies with, and the reason when it is not the payment entered.
+pub fn qualifying_payment(debt: &ConsumerDebtItemData, loan_type: Option<&LoanType>) -> (f64, Option<String>) {
+    let agency = agency(loan_type);
+
+    match StudentLoanStatus::from_key(&debt.student_loan_status) {
+        Some(StudentLoanStatus::Deferred) => {
+            let percent = student_loan_payment_percent(loan_type);
+            let payment = round_cents(debt.balance * percent / 100.0);
+            return (
+                payment,
+                Some(format!("{agency}: deferred student loan qualifies at {percent:.2}% of the balance (${payment:.2})")),
+            );
+        }
+        Some(StudentLoanStatus::IncomeDriven) if debt.monthly_payment <= 0.0 => {
+            let percent = student_loan_payment_percent(loan_type);
+            let payment = round_cents(debt.balance * percent / 100.0);
+            return (
+                payment,
+                Some(format!(
+                    "{agency}: income-driven plan with no payment qualifies at {percent:.2}% of the balance (${payment:.2})"
+                )),
+            );
+        }
+        _ => {}
+    }
+
+    if debt.credit_type == CreditType::Revolving.to_string() && debt.monthly_payment <= 0.0 && debt.balance > 0.0 {
+        let payment = round_cents(debt.balance * REVOLVING_PAYMENT_PERCENT / 100.0);
+        return (
+            payment,
+            Some(format!(
+                "{agency}: revolving account with no payment qualifies at {REVOLVING_PAYMENT_PERCENT:.0}% of the balance (${payment:.2})"
+            )),
+        );
+    }
+
+    (debt.monthly_payment, None)
+}
+
+fn short_remaining_term(debt: &ConsumerDebtItemData) -> bool {
+    debt.term_months.is_some_and(|months| months > 0 && months <= OMIT_MAX_REMAINING_PAYMENTS)
+}
+
+/// Agencies count leases however few payments are left, since a lease is usually renewed
+/// or replaced. Non-QM programs treat them like other installment debt.
+fn lease_counts_in_full(loan_type: Option<&LoanType>) -> bool {
+    matches!(
+        loan_type,
+        Some(LoanType::CNV) | Some(LoanType::FHA) | Some(LoanType::VA) | Some(LoanType::USDA)
+    )
+}
+
+/// Applies the rules to every debt, in order. Omission is only suggested; the user still
+/// decides with the omit checkbox. `qualifying_income` is the gross monthly income FHA
+/// measures short-term debts against.
+pub fn evaluate_debts(
+    debts: &[ConsumerDebtItemData],
+    loan_type: Option<&LoanType>,
+    qualifying_income: f64,
+) -> Vec<DebtAdjustment> {
+    let agency = agency(loan_type);
+    let mut adjustments: Vec<DebtAdjustment> = debts
+        .iter()
+        .map(|debt| {
+            let (qualifying_payment, note) = qualifying_payment(debt, loan_type);
+            DebtAdjustment {
+                qualifying_payment,
+                omit_suggested: false,
+                notes: note.into_iter().collect(),
+            }
+        })
+        .collect();
+
+    let is_lease = |debt: &ConsumerDebtItemData| debt.credit_type == CreditType::Lease.to_string();
+    let mut candidates = Vec::new();
+    for (index, debt) in debts.iter().enumerate() {
+        let counted = !debt.omit_from_dti && !debt.pay_off_at_closing;
+        if !counted || !short_remaining_term(debt) || adjustments[index].qualifying_payment <= 0.0 {
+            continue;
+        }
+
+        if is_lease(debt) && lease_counts_in_full(loan_type) {
+            adjustments[index]
+                .notes
+                .push(format!("{agency}: lease payments count regardless of the payments left"));
+        } else if is_lease(debt) || debt.credit_type == CreditType::Installment.to_string() {
+            candidates.push(index);
+        }
+    }
+
+    let short_term_payments: f64 = candidates.iter().map(|&index| adjustments[index].qualifying_payment).sum();
+    let fha_percent = ratio(short_term_payments, qualifying_income);
+    for index in candidates {
+        let months = debts[index].term_months.unwrap_or_default();
+        let adjustment = &mut adjustments[index];
+        match loan_type {
+            Some(LoanType::FHA) if qualifying_income <= 0.0 || fha_percent > FHA_OMIT_MAX_INCOME_PERCENT => {
+                adjustment.notes.push(format!(
+                    "FHA: {months} payments left, but short-term debts total {fha_percent:.2}% of income (over {FHA_OMIT_MAX_INCOME_PERCENT:.0}%), so it must be counted"
+                ));
+            }
+            Some(LoanType::VA) => {
+                adjustment.omit_suggested = true;
+                adjustment.notes.push(format!(
+                    "VA: {months} payments left, may be omitted unless the payment significantly affects the first months of the loan"
+                ));
+            }
+            _ => {
+                adjustment.omit_suggested = true;
+                adjustment
+                    .notes
+                    .push(format!("{agency}: {months} payments left, may be omitted from DTI"));
+            }
+        }
+    }
+
+    adjustments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn debt(credit_type: CreditType, balance: f64, monthly_payment: f64) -> ConsumerDebtItemData {
+        ConsumerDebtItemData {
+            credit_type: credit_type.to_string(),
+            balance,
+            monthly_payment,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_student_loan_payments_by_program() {
+        let mut loan = debt(CreditType::Installment, 30_000.0, 0.0);
+        loan.student_loan_status = "deferred".to_string();
+        assert_eq!(qualifying_payment(&loan, Some(&LoanType::CNV)).0, 300.0);
+        assert_eq!(qualifying_payment(&loan, Some(&LoanType::FHA)).0, 150.0);
+        assert_eq!(qualifying_payment(&loan, Some(&LoanType::VA)).0, 125.0);
+
+        // Income-driven plans use the documented payment when there is one
+        loan.student_loan_status = "idr".to_string();
+        loan.monthly_payment = 85.0;
+        assert_eq!(qualifying_payment(&loan, Some(&LoanType::FHA)), (85.0, None));
+        loan.monthly_payment = 0.0;
+        assert_eq!(qualifying_payment(&loan, Some(&LoanType::FHA)).0, 150.0);
+    }
+
+    #[test]
+    fn test_revolving_without_payment() {
+        let card = debt(CreditType::Revolving, 2_400.0, 0.0);
+        let (payment, note) = qualifying_payment(&card, None);
+        assert_eq!(payment, 120.0);
+        assert!(note.unwrap().contains("5% of the balance"));
+
+        let paying = debt(CreditType::Revolving, 2_400.0, 60.0);
+        assert_eq!(qualifying_payment(&paying, None), (60.0, None));
+    }
+
+    #[test]
+    fn test_short_installment_and_lease_omission() {
+        let mut car = debt(CreditType::Installment, 3_000.0, 400.0);
+        car.term_months = Some(8);
+        let mut lease = debt(CreditType::Lease, 2_000.0, 350.0);
+        lease.term_months = Some(6);
+        let mut long = debt(CreditType::Installment, 20_000.0, 500.0);
+        long.term_months = Some(40);
+        let debts = vec![car, lease, long];
+
+        let cnv = evaluate_debts(&debts, Some(&LoanType::CNV), 10_000.0);
+        assert!(cnv[0].omit_suggested);
+        assert!(!cnv[1].omit_suggested);
+        assert!(cnv[1].notes[0].contains("lease payments count"));
+        assert!(!cnv[2].omit_suggested);
+        assert!(cnv[2].notes.is_empty());
+
+        // Non-QM treats the lease like any other short installment debt
+        let non_qm = evaluate_debts(&debts, Some(&LoanType::NonQM), 10_000.0);
+        assert!(non_qm[0].omit_suggested && non_qm[1].omit_suggested);
+    }
+
+    #[test]
+    fn test_fha_short_term_income_test() {
+        let mut car = debt(CreditType::Installment, 3_000.0, 400.0);
+        car.term_months = Some(8);
+        let debts = vec![car];
+
+        // 400 is 4% of 10,000 but 8% of 5,000
+        assert!(evaluate_debts(&debts, Some(&LoanType::FHA), 10_000.0)[0].omit_suggested);
+        let tight = evaluate_debts(&debts, Some(&LoanType::FHA), 5_000.0);
+        assert!(!tight[0].omit_suggested);
+        assert!(tight[0].notes[0].contains("8.00% of income"));
+    }
+}
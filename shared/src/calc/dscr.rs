@@ -0,0 +1,93 @@
+// shared/src/calc/dscr.rs
+// Debt service coverage for investor loans: expected rent divided by the property's
+// full monthly payment. Borrower income plays no part.
+use serde::{Deserialize, Serialize};
+
+use crate::calc::amortization::{monthly_payment, round_cents};
+use crate::models::DSCRCalculator;
+
+/// Minimum ratio used when no minimum is configured.
+pub const DEFAULT_MIN_DSCR: f64 = 1.0;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DscrResult {
+    pub loan_amount: f64,
+    pub principal_interest: f64,
+    pub monthly_taxes: f64,
+    pub monthly_insurance: f64,
+    pub monthly_hoa: f64,
+    pub pitia: f64,
+    pub ratio: f64,
+    pub min_ratio: f64,
+    pub meets_minimum: bool,
+}
+
+/// PITIA on the price less the down payment, and the ratio of expected rent to it.
+pub fn calculate_dscr(calculator: &DSCRCalculator, min_ratio: f64) -> DscrResult {
+    let loan_amount = (calculator.purchase_price - calculator.down_payment).max(0.0);
+    let principal_interest = monthly_payment(loan_amount, calculator.rate, calculator.term.months());
+    let monthly_taxes = round_cents(calculator.annual_property_tax / 12.0);
+    let monthly_insurance = round_cents(calculator.annual_insurance / 12.0);
+    let pitia = principal_interest + monthly_taxes + monthly_insurance + calculator.monthly_hoa;
+    let ratio = if pitia > 0.0 { calculator.e_x_rent / pitia } else { 0.0 };
+
+    DscrResult {
+        loan_amount,
+        principal_interest,
+        monthly_taxes,
+        monthly_insurance,
+        monthly_hoa: calculator.monthly_hoa,
+        pitia,
+        ratio,
+        min_ratio,
+        meets_minimum: pitia > 0.0 && ratio >= min_ratio,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Term;
+
+    fn calculator() -> DSCRCalculator {
+        DSCRCalculator {
+            purchase_price: 250_000.0,
+            down_payment: 50_000.0,
+            rate: 7.5,
+            term: Term::Thirty,
+            annual_property_tax: 3_600.0,
+            annual_insurance: 1_200.0,
+            monthly_hoa: 50.0,
+            e_x_rent: 2_200.0,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_term_months() {
+        assert_eq!(Term::TwentyFive.months(), 300);
+        for term in Term::ALL {
+            assert_eq!(Term::from_months(term.months()), term);
+        }
+        assert_eq!(Term::from_months(0), Term::Thirty);
+    }
+
+    #[test]
+    fn test_pitia_and_ratio() {
+        let result = calculate_dscr(&calculator(), 1.0);
+        assert_eq!(result.loan_amount, 200_000.0);
+        assert_eq!(result.principal_interest, 1_398.43);
+        assert_eq!(round_cents(result.pitia), 1_848.43);
+        assert_eq!(round_cents(result.ratio), 1.19);
+        assert!(result.meets_minimum);
+
+        assert!(!calculate_dscr(&calculator(), 1.25).meets_minimum);
+    }
+
+    #[test]
+    fn test_no_payment_never_meets_minimum() {
+        let result = calculate_dscr(&DSCRCalculator::default(), 0.0);
+        assert_eq!(result.ratio, 0.0);
+        assert!(!result.meets_minimum);
+    }
+}
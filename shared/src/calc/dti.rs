@@ -0,0 +1,471 @@
+// shared/src/calc/dti.rs
+// Debt-to-income ratios checked against program limits. Ratios are percentages of
+// gross monthly qualifying income.
+use crate::calc::bank_statement;
+use crate::calc::consumer_debt::qualifying_payment;
+use crate::calc::income::parse_amount;
+use crate::calc::rental;
+use crate::calc::rule_set::IncomeRuleSet;
+use crate::calc::self_employment;
+use crate::models::{ConsumerDebtItemData, DebtToIncomeData, IncomeInformationData, LoanType, OptionsTemplateData, W2Job};
+
+/// Program DTI limits. A `None` limit means the program does not cap that ratio.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DtiLimits {
+    pub front_end: Option<f64>,
+    pub back_end: Option<f64>,
+    // Highest ratios allowed once compensating factors are documented
+    pub front_end_with_factors: Option<f64>,
+    pub back_end_with_factors: Option<f64>,
+}
+
+/// Limits for the loan type. Non-QM, and templates without a loan type, use the
+/// configured default back-end limit.
+pub fn dti_limits(loan_type: Option<&LoanType>, default_back_end: f64) -> DtiLimits {
+    match loan_type {
+        Some(LoanType::FHA) => DtiLimits {
+            front_end: Some(31.0),
+            back_end: Some(43.0),
+            front_end_with_factors: Some(46.9),
+            back_end_with_factors: Some(56.9),
+        },
+        Some(LoanType::CNV) => DtiLimits {
+            front_end: None,
+            back_end: Some(45.0),
+            front_end_with_factors: None,
+            back_end_with_factors: Some(50.0),
+        },
+        // Above 41% VA leans on residual income rather than a hard ratio cap
+        Some(LoanType::VA) => DtiLimits {
+            front_end: None,
+            back_end: Some(41.0),
+            front_end_with_factors: None,
+            back_end_with_factors: None,
+        },
+        // HB-1-3555, chapter 11: 29/41, and 32/44 with compensating factors
+        Some(LoanType::USDA) => DtiLimits {
+            front_end: Some(29.0),
+            back_end: Some(41.0),
+            front_end_with_factors: Some(32.0),
+            back_end_with_factors: Some(44.0),
+        },
+        Some(LoanType::NonQM) | None => DtiLimits {
+            front_end: None,
+            back_end: Some(default_back_end),
+            front_end_with_factors: None,
+            back_end_with_factors: Some(default_back_end),
+        },
+    }
+}
+
+pub fn ratio(payment: f64, monthly_income: f64) -> f64 {
+    if monthly_income > 0.0 {
+        payment / monthly_income * 100.0
+    } else {
+        0.0
+    }
+}
+
+/// Monthly income from a W-2 job: salary, or hourly pay when no salary is entered,
+/// plus commission, bonus and overtime.
+pub fn w2_job_monthly_income(job: &W2Job) -> f64 {
+    let annual_salary = parse_amount(&job.annual_salary);
+    let base = if annual_salary > 0.0 {
+        annual_salary / 12.0
+    } else {
+        parse_amount(&job.hourly_rate) * parse_amount(&job.hours_per_week) * 52.0 / 12.0
+    };
+
+    base + parse_amount(&job.commission_monthly)
+        + parse_amount(&job.bonus_monthly)
+        + parse_amount(&job.overtime_monthly)
+}
+
+/// Qualifying monthly income by source, as it feeds DTI.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct QualifyingIncome {
+    // The W-2 worksheet sections' selected figures, or the W-2 jobs when no section
+    // has been saved
+    pub w2: f64,
+    // General income entries, including the military, retirement, investment and
+    // boarder/ADU results saved into them
+    pub general: f64,
+    pub self_employment: f64,
+    pub rental: f64,
+    pub bank_statement: f64,
+    // Borrower and co-borrower income entered directly on the options template, counted
+    // only when no worksheet has income
+    pub entered: f64,
+}
+
+impl QualifyingIncome {
+    pub fn total(&self) -> f64 {
+        self.w2 + self.general + self.self_employment + self.rental + self.bank_statement + self.entered
+    }
+}
+
+/// Income from the saved W-2, general income, self-employment, rental and bank-statement
+/// worksheets. W-2 income is the figure from each section's selected calculation method.
+/// Income entered directly on the options template stands in when the worksheets have
+/// none. Rental income uses the vacancy factor of `rules`.
+pub fn qualifying_income(income: &IncomeInformationData, rules: &IncomeRuleSet) -> QualifyingIncome {
+    let w2 = if income.w2_section_results.is_empty() {
+        income
+            .w2_jobs_data
+            .iter()
+            .flat_map(|data| data.jobs.iter())
+            .map(w2_job_monthly_income)
+            .sum()
+    } else {
+        income.w2_section_results.iter().map(|section| section.qualifying_monthly).sum()
+    };
+
+    let mut qualifying = QualifyingIncome {
+        w2,
+        general: income
+            .general_income_data
+            .iter()
+            .flat_map(|data| data.entries.iter())
+            .map(|entry| parse_amount(&entry.monthly_amount))
+            .sum(),
+        self_employment: income
+            .self_employment_data
+            .as_ref()
+            .map_or(0.0, self_employment::total_monthly_income),
+        rental: income
+            .rental_income_data
+            .as_ref()
+            .map_or(0.0, |data| rental::total_monthly_income(data, rules)),
+        bank_statement: income
+            .bank_statement_data
+            .as_ref()
+            .map_or(0.0, bank_statement::total_monthly_income),
+        entered: 0.0,
+    };
+    if qualifying.total() <= 0.0 {
+        qualifying.entered = income.borrower_monthly_income + income.coborrower_monthly_income;
+    }
+    qualifying
+}
+
+/// Gross monthly qualifying income: the total of [`qualifying_income`].
+pub fn qualifying_monthly_income(income: &IncomeInformationData, rules: &IncomeRuleSet) -> f64 {
+    qualifying_income(income, rules).total()
+}
+
+/// Warnings for W-2 worksheet sections whose trend shows a significant decline but
+/// whose selected figure is above the figure the trend recommends.
+pub fn declining_income_warnings(income: &IncomeInformationData) -> Vec<String> {
+    income
+        .w2_section_results
+        .iter()
+        .filter(|section| section.trend.significant_decline)
+        .filter_map(|section| {
+            let recommended = section.trend.recommended_monthly?;
+            (section.qualifying_monthly > recommended).then(|| {
+                let label = match section.section.as_str() {
+                    "hourly" => "Hourly",
+                    "salary" => "Salary",
+                    "ot_bonus" => "OT/Bonus",
+                    "commission" => "Commission",
+                    other => other,
+                };
+                format!(
+                    "{label} income qualifies at ${:.2}/mo despite a significant decline; the trend recommends ${recommended:.2}/mo.",
+                    section.qualifying_monthly
+                )
+            })
+        })
+        .collect()
+}
+
+/// Consumer debt payments that count toward DTI, using the program's qualifying payment
+/// for each debt. Debts paid off at closing drop out.
+pub fn monthly_debts(debts: &[ConsumerDebtItemData], loan_type: Option<&LoanType>) -> f64 {
+    debts
+        .iter()
+        .filter(|debt| !debt.omit_from_dti && !debt.pay_off_at_closing)
+        .map(|debt| qualifying_payment(debt, loan_type).0)
+        .sum()
+}
+
+/// DTI for the options template. Housing is every proposed housing obligation from the
+/// benefit to borrower section, escrowed or not, and debts include net losses on rental
+/// properties; `compensating_factors` is carried over from the current result. Income
+/// follows the rule set for the loan type and agency, with `custom_rules` for the
+/// custom profile.
+pub fn calculate_dti(data: &OptionsTemplateData, default_max_dti: f64, custom_rules: &IncomeRuleSet) -> DebtToIncomeData {
+    let loan_type = LoanType::from_key(&data.loan_information.loan_type);
+    let rules = IncomeRuleSet::for_loan_type(loan_type.as_ref(), &data.loan_information.agency, custom_rules);
+    let qualifying_income = qualifying_monthly_income(&data.income_information, &rules);
+    let housing_payment = data.benefit_to_borrower.proposed_total_obligations;
+    let rental_losses = data
+        .income_information
+        .rental_income_data
+        .as_ref()
+        .map_or(0.0, |data| rental::total_monthly_loss(data, &rules));
+    let debts = monthly_debts(&data.consumer_debt.consumer_debts, loan_type.as_ref()) + rental_losses;
+    let compensating_factors = data.debt_to_income.compensating_factors;
+
+    let limits = dti_limits(loan_type.as_ref(), default_max_dti);
+    let (max_front_end_ratio, max_back_end_ratio) = if compensating_factors {
+        (limits.front_end_with_factors, limits.back_end_with_factors)
+    } else {
+        (limits.front_end, limits.back_end)
+    };
+
+    let front_end_ratio = ratio(housing_payment, qualifying_income);
+    let back_end_ratio = ratio(housing_payment + debts, qualifying_income);
+    let within = |actual: f64, max: Option<f64>| max.is_none_or(|max| actual <= max);
+
+    DebtToIncomeData {
+        front_end_ratio,
+        back_end_ratio,
+        qualifying_income,
+        housing_payment,
+        monthly_debts: debts,
+        compensating_factors,
+        max_front_end_ratio,
+        max_back_end_ratio,
+        within_limits: qualifying_income > 0.0
+            && within(front_end_ratio, max_front_end_ratio)
+            && within(back_end_ratio, max_back_end_ratio),
+        income_warnings: declining_income_warnings(&data.income_information),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        BankStatementAccount, BankStatementData, BankStatementMonth, GeneralIncomeData, GeneralIncomeEntry, RentalIncomeData,
+        RentalProperty, SelfEmploymentBusiness, SelfEmploymentData, SelfEmploymentYear, W2JobsData,
+    };
+    use crate::calc::income_trend::{W2SectionResult, analyze_trend};
+
+    fn fha_template() -> OptionsTemplateData {
+        let mut data = OptionsTemplateData::default();
+        data.loan_information.loan_type = "fha".to_string();
+        data.income_information.borrower_monthly_income = 10_000.0;
+        data.benefit_to_borrower.proposed_total_obligations = 3_500.0;
+        data.consumer_debt.consumer_debts = vec![
+            ConsumerDebtItemData {
+                monthly_payment: 800.0,
+                ..Default::default()
+            },
+            ConsumerDebtItemData {
+                monthly_payment: 400.0,
+                omit_from_dti: true,
+                ..Default::default()
+            },
+            ConsumerDebtItemData {
+                monthly_payment: 300.0,
+                pay_off_at_closing: true,
+                ..Default::default()
+            },
+        ];
+        data
+    }
+
+    #[test]
+    fn test_qualifying_income_from_worksheets() {
+        let income = IncomeInformationData {
+            coborrower_monthly_income: 500.0,
+            w2_jobs_data: Some(W2JobsData {
+                jobs: vec![
+                    W2Job {
+                        annual_salary: "$60,000".to_string(),
+                        bonus_monthly: "250".to_string(),
+                        ..Default::default()
+                    },
+                    W2Job {
+                        hourly_rate: "20".to_string(),
+                        hours_per_week: "30".to_string(),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            }),
+            general_income_data: Some(GeneralIncomeData {
+                entries: vec![GeneralIncomeEntry {
+                    monthly_amount: "1,200".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            self_employment_data: Some(SelfEmploymentData {
+                businesses: vec![SelfEmploymentBusiness {
+                    recent_year: SelfEmploymentYear {
+                        net_income: 36_000.0,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }],
+            }),
+            bank_statement_data: Some(BankStatementData {
+                accounts: vec![BankStatementAccount {
+                    account_type: "personal".to_string(),
+                    months: vec![
+                        BankStatementMonth {
+                            total_deposits: 4_000.0,
+                            ..Default::default()
+                        };
+                        12
+                    ],
+                    ..Default::default()
+                }],
+            }),
+            ..Default::default()
+        };
+
+        // Income typed on the template is not added on top of the worksheets
+        assert_eq!(qualifying_monthly_income(&income, &IncomeRuleSet::default()), 5_000.0 + 250.0 + 2_600.0 + 1_200.0 + 3_000.0 + 4_000.0);
+        let by_source = qualifying_income(&income, &IncomeRuleSet::default());
+        assert_eq!(by_source.w2, 5_000.0 + 250.0 + 2_600.0);
+        assert_eq!(by_source.general, 1_200.0);
+        assert_eq!(by_source.self_employment, 3_000.0);
+        assert_eq!(by_source.bank_statement, 4_000.0);
+        assert_eq!(by_source.entered, 0.0);
+    }
+
+    #[test]
+    fn test_w2_income_uses_selected_section_figures() {
+        let mut income = IncomeInformationData {
+            borrower_monthly_income: 9_000.0,
+            w2_jobs_data: Some(W2JobsData {
+                jobs: vec![W2Job {
+                    annual_salary: "60,000".to_string(),
+                    overtime_monthly: "800".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            w2_section_results: vec![
+                W2SectionResult {
+                    section: "salary".to_string(),
+                    qualifying_monthly: 5_000.0,
+                    ..Default::default()
+                },
+                // The underwriter averaged overtime down to $450
+                W2SectionResult {
+                    section: "ot_bonus".to_string(),
+                    qualifying_monthly: 450.0,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        let by_source = qualifying_income(&income, &IncomeRuleSet::default());
+        assert_eq!(by_source.w2, 5_450.0);
+        assert_eq!(by_source.entered, 0.0);
+
+        // Without any worksheet income the template figure is used
+        income.w2_section_results.clear();
+        income.w2_jobs_data = None;
+        assert_eq!(qualifying_monthly_income(&income, &IncomeRuleSet::default()), 9_000.0);
+    }
+
+    #[test]
+    fn test_declining_income_is_flagged() {
+        // Salary fell 20% and the underwriter kept the higher average
+        let mut data = fha_template();
+        data.income_information.w2_section_results = vec![
+            W2SectionResult {
+                section: "salary".to_string(),
+                qualifying_monthly: 4_600.0,
+                trend: analyze_trend(4_000.0, 5_000.0, 4_800.0, 10.0, 4_000.0),
+            },
+            W2SectionResult {
+                section: "ot_bonus".to_string(),
+                qualifying_monthly: 500.0,
+                trend: analyze_trend(500.0, 480.0, 450.0, 10.0, 476.67),
+            },
+        ];
+        let result = calculate_dti(&data, 43.0, &IncomeRuleSet::default());
+        assert_eq!(result.qualifying_income, 5_100.0);
+        assert_eq!(
+            result.income_warnings,
+            vec!["Salary income qualifies at $4600.00/mo despite a significant decline; the trend recommends $4000.00/mo.".to_string()]
+        );
+
+        // Qualifying on the recommended figure clears the warning
+        data.income_information.w2_section_results[0].qualifying_monthly = 4_000.0;
+        assert!(calculate_dti(&data, 43.0, &IncomeRuleSet::default()).income_warnings.is_empty());
+    }
+
+    #[test]
+    fn test_fha_limits_and_compensating_factors() {
+        let mut data = fha_template();
+        let result = calculate_dti(&data, 43.0, &IncomeRuleSet::default());
+        assert_eq!(result.monthly_debts, 800.0);
+        assert_eq!(result.front_end_ratio, 35.0);
+        assert_eq!(result.back_end_ratio, 43.0);
+        assert_eq!(result.max_front_end_ratio, Some(31.0));
+        assert!(!result.within_limits);
+
+        data.debt_to_income.compensating_factors = true;
+        let result = calculate_dti(&data, 43.0, &IncomeRuleSet::default());
+        assert_eq!(result.max_front_end_ratio, Some(46.9));
+        assert_eq!(result.max_back_end_ratio, Some(56.9));
+        assert!(result.within_limits);
+    }
+
+    #[test]
+    fn test_program_limits() {
+        let cnv = dti_limits(Some(&LoanType::CNV), 43.0);
+        assert_eq!((cnv.front_end, cnv.back_end, cnv.back_end_with_factors), (None, Some(45.0), Some(50.0)));
+
+        let va = dti_limits(Some(&LoanType::VA), 43.0);
+        assert_eq!(va.back_end_with_factors, None);
+
+        let usda = dti_limits(Some(&LoanType::USDA), 43.0);
+        assert_eq!((usda.front_end, usda.back_end), (Some(29.0), Some(41.0)));
+
+        assert_eq!(dti_limits(Some(&LoanType::NonQM), 48.0).back_end, Some(48.0));
+        assert_eq!(dti_limits(None, 43.0).back_end, Some(43.0));
+    }
+
+    #[test]
+    fn test_debts_use_qualifying_payments() {
+        let mut data = fha_template();
+        data.consumer_debt.consumer_debts.push(ConsumerDebtItemData {
+            balance: 40_000.0,
+            student_loan_status: "deferred".to_string(),
+            ..Default::default()
+        });
+        // 800 plus 0.5% of the deferred balance
+        assert_eq!(calculate_dti(&data, 43.0, &IncomeRuleSet::default()).monthly_debts, 1_000.0);
+    }
+
+    #[test]
+    fn test_rental_income_and_losses() {
+        let mut data = fha_template();
+        let lease = |gross_monthly_rent: f64, monthly_pitia: f64| RentalProperty {
+            method: "lease".to_string(),
+            gross_monthly_rent,
+            monthly_pitia,
+            ..Default::default()
+        };
+        data.income_information.rental_income_data = Some(RentalIncomeData {
+            properties: vec![lease(2_000.0, 1_000.0), lease(1_000.0, 1_250.0)],
+        });
+        // With worksheet income, the template figure gives way to the W-2 worksheet
+        data.income_information.w2_section_results = vec![W2SectionResult {
+            section: "salary".to_string(),
+            qualifying_monthly: 10_000.0,
+            ..Default::default()
+        }];
+        let result = calculate_dti(&data, 43.0, &IncomeRuleSet::default());
+        assert_eq!(result.qualifying_income, 10_500.0);
+        assert_eq!(result.monthly_debts, 1_300.0);
+    }
+
+    #[test]
+    fn test_no_income_is_never_within_limits() {
+        let mut data = fha_template();
+        data.income_information.borrower_monthly_income = 0.0;
+        let result = calculate_dti(&data, 43.0, &IncomeRuleSet::default());
+        assert_eq!(result.back_end_ratio, 0.0);
+        assert!(!result.within_limits);
+    }
+}
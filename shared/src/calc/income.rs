@@ -0,0 +1,1055 @@
+// shared/src/calc/income.rs
+// Qualifying income calculations for the income worksheet.
+//
+// Every `*_monthly` figure returned from this module is a monthly amount so the
+// worksheet sections can be summed together without unit conversion.
+use serde::{Deserialize, Serialize};
+
+use crate::calc::income_trend::{analyze_trend, IncomeTrend, DEFAULT_DECLINE_THRESHOLD_PERCENT};
+use crate::calc::rule_set::IncomeRuleSet;
+
+// Guideline factors. These are the Fannie Mae values; the worksheet sections read
+// them from the borrower's `IncomeRuleSet`.
+pub const NON_TAXABLE_GROSS_UP: f64 = 1.25;
+pub const SOCIAL_SECURITY_TAXABLE_SHARE: f64 = 0.85;
+pub const PENSION_FIRST_TIER_LIMIT: f64 = 1000.0;
+pub const PENSION_FIRST_TIER_FACTOR: f64 = 0.70;
+pub const PENSION_SECOND_TIER_FACTOR: f64 = 0.85;
+pub const ALIMONY_FACTOR: f64 = 0.75;
+
+/// Parses a user-entered amount, ignoring `$`, `,` and surrounding whitespace.
+/// Anything that does not parse is treated as zero.
+pub fn parse_amount(s: &str) -> f64 {
+    parse_or(s, 0.0)
+}
+
+/// Like [`parse_amount`] but falls back to `default` when the field is empty or invalid.
+pub fn parse_or(s: &str, default: f64) -> f64 {
+    let cleaned: String = s.chars().filter(|c| *c != '$' && *c != ',').collect();
+    cleaned.trim().parse::<f64>().ok().filter(|v| v.is_finite()).unwrap_or(default)
+}
+
+// Pay Frequency
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum PayFrequency {
+    Weekly,
+    BiWeekly,
+    SemiMonthly,
+    #[default]
+    Monthly,
+}
+
+impl PayFrequency {
+    pub fn from_key(key: &str) -> Self {
+        match key {
+            "weekly" => PayFrequency::Weekly,
+            "bi-weekly" => PayFrequency::BiWeekly,
+            "semi-monthly" => PayFrequency::SemiMonthly,
+            _ => PayFrequency::Monthly,
+        }
+    }
+
+    pub fn as_key(&self) -> &'static str {
+        match self {
+            PayFrequency::Weekly => "weekly",
+            PayFrequency::BiWeekly => "bi-weekly",
+            PayFrequency::SemiMonthly => "semi-monthly",
+            PayFrequency::Monthly => "monthly",
+        }
+    }
+
+    pub fn periods_per_year(&self) -> f64 {
+        match self {
+            PayFrequency::Weekly => 52.0,
+            PayFrequency::BiWeekly => 26.0,
+            PayFrequency::SemiMonthly => 24.0,
+            PayFrequency::Monthly => 12.0,
+        }
+    }
+
+    /// Factor that converts one pay period's earnings to a monthly amount.
+    pub fn monthly_factor(&self) -> f64 {
+        self.periods_per_year() / 12.0
+    }
+}
+
+// Averaging method selected on a worksheet section
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum IncomeMethod {
+    #[default]
+    None,
+    Current,
+    Ytd,
+    PriorYear,
+    SecondPriorYear,
+    YtdPlusOneYear,
+    YtdPlusTwoYears,
+    Lowest,
+}
+
+impl IncomeMethod {
+    pub fn from_key(key: &str) -> Self {
+        match key {
+            "current" => IncomeMethod::Current,
+            "ytd" => IncomeMethod::Ytd,
+            "w2_year1" => IncomeMethod::PriorYear,
+            "w2_year2" => IncomeMethod::SecondPriorYear,
+            "ytd_1w2" => IncomeMethod::YtdPlusOneYear,
+            "ytd_2w2" => IncomeMethod::YtdPlusTwoYears,
+            "lowest" => IncomeMethod::Lowest,
+            _ => IncomeMethod::None,
+        }
+    }
+
+    pub fn as_key(&self) -> &'static str {
+        match self {
+            IncomeMethod::None => "none",
+            IncomeMethod::Current => "current",
+            IncomeMethod::Ytd => "ytd",
+            IncomeMethod::PriorYear => "w2_year1",
+            IncomeMethod::SecondPriorYear => "w2_year2",
+            IncomeMethod::YtdPlusOneYear => "ytd_1w2",
+            IncomeMethod::YtdPlusTwoYears => "ytd_2w2",
+            IncomeMethod::Lowest => "lowest",
+        }
+    }
+}
+
+// Earnings over a number of months (YTD paystub or a W2 year)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct PeriodEarnings {
+    pub amount: f64,
+    pub months: f64,
+}
+
+impl PeriodEarnings {
+    pub fn new(amount: f64, months: f64) -> Self {
+        Self { amount, months }
+    }
+
+    pub fn monthly(&self) -> f64 {
+        if self.months > 0.0 {
+            self.amount / self.months
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Default for PeriodEarnings {
+    fn default() -> Self {
+        Self {
+            amount: 0.0,
+            months: 12.0,
+        }
+    }
+}
+
+// YTD / two-year history averages shared by the hourly, salary, OT/bonus and commission sections
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub struct HistoricalAverages {
+    pub ytd: f64,
+    pub ytd_plus_one_year: f64,
+    pub ytd_plus_two_years: f64,
+    pub lowest: f64,
+}
+
+impl HistoricalAverages {
+    pub fn from_monthly(ytd: f64, prior_year: f64, second_prior_year: f64) -> Self {
+        let ytd_plus_one_year = (ytd + prior_year) / 2.0;
+        let ytd_plus_two_years = (ytd + prior_year + second_prior_year) / 3.0;
+        let lowest = [ytd, ytd_plus_one_year, ytd_plus_two_years]
+            .into_iter()
+            .filter(|v| *v > 0.0)
+            .fold(None, |acc: Option<f64>, v| Some(acc.map_or(v, |a| a.min(v))))
+            .unwrap_or(0.0);
+
+        Self {
+            ytd,
+            ytd_plus_one_year,
+            ytd_plus_two_years,
+            lowest,
+        }
+    }
+}
+
+fn select_income(
+    method: IncomeMethod,
+    current: f64,
+    prior_year: f64,
+    second_prior_year: f64,
+    averages: &HistoricalAverages,
+) -> f64 {
+    match method {
+        IncomeMethod::None => 0.0,
+        IncomeMethod::Current => current,
+        IncomeMethod::Ytd => averages.ytd,
+        IncomeMethod::PriorYear => prior_year,
+        IncomeMethod::SecondPriorYear => second_prior_year,
+        IncomeMethod::YtdPlusOneYear => averages.ytd_plus_one_year,
+        IncomeMethod::YtdPlusTwoYears => averages.ytd_plus_two_years,
+        IncomeMethod::Lowest => averages.lowest,
+    }
+}
+
+// Hourly
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HourlyInput {
+    pub hourly_rate: f64,
+    pub hours_per_week: f64,
+    pub ytd: PeriodEarnings,
+    pub w2_year1: PeriodEarnings,
+    pub w2_year2: PeriodEarnings,
+    pub method: IncomeMethod,
+    pub decline_threshold_percent: f64,
+}
+
+impl Default for HourlyInput {
+    fn default() -> Self {
+        Self {
+            hourly_rate: 0.0,
+            hours_per_week: 40.0,
+            ytd: PeriodEarnings::default(),
+            w2_year1: PeriodEarnings::default(),
+            w2_year2: PeriodEarnings::default(),
+            method: IncomeMethod::None,
+            decline_threshold_percent: DEFAULT_DECLINE_THRESHOLD_PERCENT,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct HourlyResult {
+    pub current_monthly: f64,
+    pub ytd_monthly: f64,
+    pub w2_year1_monthly: f64,
+    pub w2_year2_monthly: f64,
+    pub averages: HistoricalAverages,
+    pub trend: IncomeTrend,
+    pub qualifying_monthly: f64,
+}
+
+pub fn calculate_hourly(input: &HourlyInput) -> HourlyResult {
+    let current_monthly = input.hourly_rate * input.hours_per_week * 52.0 / 12.0;
+    let ytd_monthly = input.ytd.monthly();
+    let w2_year1_monthly = input.w2_year1.monthly();
+    let w2_year2_monthly = input.w2_year2.monthly();
+    let averages = HistoricalAverages::from_monthly(ytd_monthly, w2_year1_monthly, w2_year2_monthly);
+    let trend = analyze_trend(
+        ytd_monthly,
+        w2_year1_monthly,
+        w2_year2_monthly,
+        input.decline_threshold_percent,
+        averages.lowest,
+    );
+    let qualifying_monthly = select_income(
+        input.method,
+        current_monthly,
+        w2_year1_monthly,
+        w2_year2_monthly,
+        &averages,
+    );
+
+    HourlyResult {
+        current_monthly,
+        ytd_monthly,
+        w2_year1_monthly,
+        w2_year2_monthly,
+        averages,
+        trend,
+        qualifying_monthly,
+    }
+}
+
+// Salary
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SalaryInput {
+    pub salary_amount: f64,
+    pub pay_frequency: PayFrequency,
+    pub ytd: PeriodEarnings,
+    pub w2_year1: PeriodEarnings,
+    pub w2_year2: PeriodEarnings,
+    pub method: IncomeMethod,
+    pub decline_threshold_percent: f64,
+}
+
+impl Default for SalaryInput {
+    fn default() -> Self {
+        Self {
+            salary_amount: 0.0,
+            pay_frequency: PayFrequency::default(),
+            ytd: PeriodEarnings::default(),
+            w2_year1: PeriodEarnings::default(),
+            w2_year2: PeriodEarnings::default(),
+            method: IncomeMethod::None,
+            decline_threshold_percent: DEFAULT_DECLINE_THRESHOLD_PERCENT,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct SalaryResult {
+    pub current_monthly: f64,
+    pub ytd_monthly: f64,
+    pub w2_year1_monthly: f64,
+    pub w2_year2_monthly: f64,
+    pub averages: HistoricalAverages,
+    pub trend: IncomeTrend,
+    pub qualifying_monthly: f64,
+}
+
+pub fn calculate_salary(input: &SalaryInput) -> SalaryResult {
+    let current_monthly = input.salary_amount * input.pay_frequency.monthly_factor();
+    let ytd_monthly = input.ytd.monthly();
+    let w2_year1_monthly = input.w2_year1.monthly();
+    let w2_year2_monthly = input.w2_year2.monthly();
+    let averages = HistoricalAverages::from_monthly(ytd_monthly, w2_year1_monthly, w2_year2_monthly);
+    let trend = analyze_trend(
+        ytd_monthly,
+        w2_year1_monthly,
+        w2_year2_monthly,
+        input.decline_threshold_percent,
+        averages.lowest,
+    );
+    let qualifying_monthly = select_income(
+        input.method,
+        current_monthly,
+        w2_year1_monthly,
+        w2_year2_monthly,
+        &averages,
+    );
+
+    SalaryResult {
+        current_monthly,
+        ytd_monthly,
+        w2_year1_monthly,
+        w2_year2_monthly,
+        averages,
+        trend,
+        qualifying_monthly,
+    }
+}
+
+// Overtime / Bonus
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OtBonusInput {
+    pub ytd: PeriodEarnings,
+    pub past_year: PeriodEarnings,
+    pub additional_year: PeriodEarnings,
+    pub method: IncomeMethod,
+    pub decline_threshold_percent: f64,
+}
+
+impl Default for OtBonusInput {
+    fn default() -> Self {
+        Self {
+            ytd: PeriodEarnings::default(),
+            past_year: PeriodEarnings::default(),
+            additional_year: PeriodEarnings::default(),
+            method: IncomeMethod::None,
+            decline_threshold_percent: DEFAULT_DECLINE_THRESHOLD_PERCENT,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct OtBonusResult {
+    pub ytd_monthly: f64,
+    pub past_year_monthly: f64,
+    pub additional_year_monthly: f64,
+    pub averages: HistoricalAverages,
+    pub trend: IncomeTrend,
+    pub qualifying_monthly: f64,
+}
+
+pub fn calculate_ot_bonus(input: &OtBonusInput) -> OtBonusResult {
+    let ytd_monthly = input.ytd.monthly();
+    let past_year_monthly = input.past_year.monthly();
+    let additional_year_monthly = input.additional_year.monthly();
+    let averages =
+        HistoricalAverages::from_monthly(ytd_monthly, past_year_monthly, additional_year_monthly);
+    let trend = analyze_trend(
+        ytd_monthly,
+        past_year_monthly,
+        additional_year_monthly,
+        input.decline_threshold_percent,
+        averages.lowest,
+    );
+    // There is no "current" rate for variable pay
+    let qualifying_monthly = match input.method {
+        IncomeMethod::Current => 0.0,
+        method => select_income(
+            method,
+            0.0,
+            past_year_monthly,
+            additional_year_monthly,
+            &averages,
+        ),
+    };
+
+    OtBonusResult {
+        ytd_monthly,
+        past_year_monthly,
+        additional_year_monthly,
+        averages,
+        trend,
+        qualifying_monthly,
+    }
+}
+
+// Commission
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct CommissionPeriod {
+    pub commission: f64,
+    pub expenses: f64,
+    pub months: f64,
+}
+
+impl CommissionPeriod {
+    pub fn net(&self) -> f64 {
+        self.commission - self.expenses
+    }
+
+    pub fn monthly(&self) -> f64 {
+        PeriodEarnings::new(self.net(), self.months).monthly()
+    }
+}
+
+impl Default for CommissionPeriod {
+    fn default() -> Self {
+        Self {
+            commission: 0.0,
+            expenses: 0.0,
+            months: 12.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CommissionInput {
+    pub ytd: CommissionPeriod,
+    pub past_year: CommissionPeriod,
+    pub additional_year: CommissionPeriod,
+    pub method: IncomeMethod,
+    pub decline_threshold_percent: f64,
+}
+
+impl Default for CommissionInput {
+    fn default() -> Self {
+        Self {
+            ytd: CommissionPeriod::default(),
+            past_year: CommissionPeriod::default(),
+            additional_year: CommissionPeriod::default(),
+            method: IncomeMethod::Ytd,
+            decline_threshold_percent: DEFAULT_DECLINE_THRESHOLD_PERCENT,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommissionResult {
+    pub ytd_net: f64,
+    pub ytd_monthly: f64,
+    pub past_year_net: f64,
+    pub past_year_monthly: f64,
+    pub additional_year_net: f64,
+    pub additional_year_monthly: f64,
+    pub averages: HistoricalAverages,
+    pub trend: IncomeTrend,
+    pub qualifying_monthly: f64,
+}
+
+pub fn calculate_commission(input: &CommissionInput) -> CommissionResult {
+    let ytd_monthly = input.ytd.monthly();
+    let past_year_monthly = input.past_year.monthly();
+    let additional_year_monthly = input.additional_year.monthly();
+    let averages =
+        HistoricalAverages::from_monthly(ytd_monthly, past_year_monthly, additional_year_monthly);
+    let trend = analyze_trend(
+        ytd_monthly,
+        past_year_monthly,
+        additional_year_monthly,
+        input.decline_threshold_percent,
+        averages.lowest,
+    );
+    let qualifying_monthly = match input.method {
+        IncomeMethod::Current => 0.0,
+        method => select_income(
+            method,
+            0.0,
+            past_year_monthly,
+            additional_year_monthly,
+            &averages,
+        ),
+    };
+
+    CommissionResult {
+        ytd_net: input.ytd.net(),
+        ytd_monthly,
+        past_year_net: input.past_year.net(),
+        past_year_monthly,
+        additional_year_net: input.additional_year.net(),
+        additional_year_monthly,
+        averages,
+        trend,
+        qualifying_monthly,
+    }
+}
+
+// Other W2 earnings (all monthly amounts)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct OtherW2Input {
+    pub tips: f64,
+    pub shift_differential: f64,
+    pub on_call_pay: f64,
+    pub call_back_pay: f64,
+    pub hazard_pay: f64,
+    pub profit_sharing: f64,
+    pub sick_pay: f64,
+    pub holiday_vacation_pay: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct OtherW2Result {
+    pub tips: f64,
+    pub shift_differential: f64,
+    pub on_call_pay: f64,
+    pub call_back_pay: f64,
+    pub hazard_pay: f64,
+    pub profit_sharing: f64,
+    pub sick_pay: f64,
+    pub holiday_vacation_pay: f64,
+    pub qualifying_monthly: f64,
+}
+
+pub fn calculate_other_w2(input: &OtherW2Input) -> OtherW2Result {
+    let mut result = OtherW2Result {
+        tips: input.tips,
+        shift_differential: input.shift_differential,
+        on_call_pay: input.on_call_pay,
+        call_back_pay: input.call_back_pay,
+        hazard_pay: input.hazard_pay,
+        profit_sharing: input.profit_sharing,
+        // Sick pay is generally not considered qualifying income
+        sick_pay: 0.0,
+        holiday_vacation_pay: input.holiday_vacation_pay,
+        qualifying_monthly: 0.0,
+    };
+    result.qualifying_monthly = result.tips
+        + result.shift_differential
+        + result.on_call_pay
+        + result.call_back_pay
+        + result.hazard_pay
+        + result.profit_sharing
+        + result.sick_pay
+        + result.holiday_vacation_pay;
+    result
+}
+
+// Social Security
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SocialSecurityInput {
+    // With documentation of the taxable/non-taxable split
+    pub taxable_portion: f64,
+    pub non_taxable_portion: f64,
+    pub months_with_doc: f64,
+    // Without taxation documentation
+    pub total_annual_benefit: f64,
+    pub months_without_doc: f64,
+}
+
+impl Default for SocialSecurityInput {
+    fn default() -> Self {
+        Self {
+            taxable_portion: 0.0,
+            non_taxable_portion: 0.0,
+            months_with_doc: 12.0,
+            total_annual_benefit: 0.0,
+            months_without_doc: 12.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct SocialSecurityResult {
+    pub taxable_income: f64,
+    pub non_taxable_grossed_up: f64,
+    pub total_with_doc: f64,
+    pub monthly_with_doc: f64,
+    pub taxable_share: f64,
+    pub non_taxable_share: f64,
+    pub non_taxable_share_grossed_up: f64,
+    pub total_without_doc: f64,
+    pub monthly_without_doc: f64,
+}
+
+pub fn calculate_social_security(input: &SocialSecurityInput, rules: &IncomeRuleSet) -> SocialSecurityResult {
+    let taxable_income = input.taxable_portion;
+    let non_taxable_grossed_up = input.non_taxable_portion * rules.non_taxable_gross_up;
+    let total_with_doc = taxable_income + non_taxable_grossed_up;
+
+    let taxable_share = input.total_annual_benefit * rules.social_security_taxable_share;
+    let non_taxable_share = input.total_annual_benefit * (1.0 - rules.social_security_taxable_share);
+    let non_taxable_share_grossed_up = non_taxable_share * rules.non_taxable_gross_up;
+    let total_without_doc = taxable_share + non_taxable_share_grossed_up;
+
+    SocialSecurityResult {
+        taxable_income,
+        non_taxable_grossed_up,
+        total_with_doc,
+        monthly_with_doc: PeriodEarnings::new(total_with_doc, input.months_with_doc).monthly(),
+        taxable_share,
+        non_taxable_share,
+        non_taxable_share_grossed_up,
+        total_without_doc,
+        monthly_without_doc: PeriodEarnings::new(total_without_doc, input.months_without_doc)
+            .monthly(),
+    }
+}
+
+// Pension
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PensionInput {
+    pub gross_monthly: f64,
+    pub taxable_percent: f64,
+    pub vesting_percent: f64,
+}
+
+impl Default for PensionInput {
+    fn default() -> Self {
+        Self {
+            gross_monthly: 0.0,
+            taxable_percent: 100.0,
+            vesting_percent: 100.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct PensionResult {
+    pub gross_monthly: f64,
+    pub taxable_monthly: f64,
+    pub non_taxable_monthly: f64,
+    pub annualized: f64,
+    // After the rule set's tiered haircut
+    pub tiered_monthly: f64,
+    pub vested_monthly: f64,
+    pub qualifying_monthly: f64,
+}
+
+pub fn calculate_pension(input: &PensionInput, rules: &IncomeRuleSet) -> PensionResult {
+    let gross = input.gross_monthly;
+    let taxable_pct = input.taxable_percent / 100.0;
+
+    // 70% of the first $1,000, 85% of anything above it with the default rules
+    let first_tier_limit = rules.pension_first_tier_limit;
+    let tiered_monthly = if gross <= first_tier_limit {
+        gross * rules.pension_first_tier_factor
+    } else {
+        first_tier_limit * rules.pension_first_tier_factor + (gross - first_tier_limit) * rules.pension_second_tier_factor
+    };
+    let vested_monthly = gross * input.vesting_percent / 100.0;
+
+    PensionResult {
+        gross_monthly: gross,
+        taxable_monthly: gross * taxable_pct,
+        non_taxable_monthly: gross * (1.0 - taxable_pct),
+        annualized: gross * 12.0,
+        tiered_monthly,
+        vested_monthly,
+        qualifying_monthly: tiered_monthly.min(vested_monthly),
+    }
+}
+
+// Retirement account distributions, used per account by `calc::retirement`
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum DistributionFrequency {
+    #[default]
+    Monthly,
+    Quarterly,
+    SemiAnnually,
+    Annually,
+}
+
+impl DistributionFrequency {
+    pub fn from_key(key: &str) -> Self {
+        match key {
+            "quarterly" => DistributionFrequency::Quarterly,
+            "semi-annually" => DistributionFrequency::SemiAnnually,
+            "annually" => DistributionFrequency::Annually,
+            _ => DistributionFrequency::Monthly,
+        }
+    }
+
+    pub fn per_year(&self) -> f64 {
+        match self {
+            DistributionFrequency::Monthly => 12.0,
+            DistributionFrequency::Quarterly => 4.0,
+            DistributionFrequency::SemiAnnually => 2.0,
+            DistributionFrequency::Annually => 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum IraType {
+    Qualified,
+    NonQualified,
+}
+
+impl IraType {
+    pub const ALL: [IraType; 2] = [IraType::Qualified, IraType::NonQualified];
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "qualified" => Some(IraType::Qualified),
+            "non-qualified" => Some(IraType::NonQualified),
+            _ => None,
+        }
+    }
+
+    pub fn as_key(&self) -> &'static str {
+        match self {
+            IraType::Qualified => "qualified",
+            IraType::NonQualified => "non-qualified",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            IraType::Qualified => "Qualified (Traditional, SEP, SIMPLE, 401k)",
+            IraType::NonQualified => "Non-Qualified (Roth)",
+        }
+    }
+}
+
+// Other income (all monthly amounts)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct OtherIncomeInput {
+    pub alimony: f64,
+    pub child_support: f64,
+    pub interest_dividends: f64,
+    pub disability: f64,
+    pub workers_comp: f64,
+    pub other: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct OtherIncomeResult {
+    pub alimony: f64,
+    pub child_support: f64,
+    pub interest_dividends: f64,
+    pub disability: f64,
+    pub workers_comp: f64,
+    pub other: f64,
+    pub qualifying_monthly: f64,
+}
+
+pub fn calculate_other_income(input: &OtherIncomeInput, rules: &IncomeRuleSet) -> OtherIncomeResult {
+    let mut result = OtherIncomeResult {
+        alimony: input.alimony * rules.alimony_factor,
+        // Child support is generally not considered qualifying income
+        child_support: 0.0,
+        interest_dividends: input.interest_dividends,
+        disability: input.disability,
+        workers_comp: input.workers_comp,
+        other: input.other,
+        qualifying_monthly: 0.0,
+    };
+    result.qualifying_monthly = result.alimony
+        + result.child_support
+        + result.interest_dividends
+        + result.disability
+        + result.workers_comp
+        + result.other;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calc::rule_set::RuleSetProfile;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 0.005,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn test_parse_amount() {
+        assert_eq!(parse_amount("1,250.50"), 1250.5);
+        assert_eq!(parse_amount(" $300 "), 300.0);
+        assert_eq!(parse_amount("abc"), 0.0);
+        assert_eq!(parse_amount(""), 0.0);
+        assert_eq!(parse_or("", 12.0), 12.0);
+    }
+
+    #[test]
+    fn test_income_method_keys_round_trip() {
+        for method in [
+            IncomeMethod::None,
+            IncomeMethod::Current,
+            IncomeMethod::Ytd,
+            IncomeMethod::PriorYear,
+            IncomeMethod::SecondPriorYear,
+            IncomeMethod::YtdPlusOneYear,
+            IncomeMethod::YtdPlusTwoYears,
+            IncomeMethod::Lowest,
+        ] {
+            assert_eq!(IncomeMethod::from_key(method.as_key()), method);
+        }
+        assert_eq!(IncomeMethod::from_key("bogus"), IncomeMethod::None);
+    }
+
+    #[test]
+    fn test_historical_averages_lowest_ignores_empty_years() {
+        let averages = HistoricalAverages::from_monthly(5000.0, 4000.0, 0.0);
+        assert_close(averages.ytd_plus_one_year, 4500.0);
+        assert_close(averages.ytd_plus_two_years, 3000.0);
+        assert_close(averages.lowest, 3000.0);
+
+        let empty = HistoricalAverages::from_monthly(0.0, 0.0, 0.0);
+        assert_eq!(empty.lowest, 0.0);
+    }
+
+    #[test]
+    fn test_hourly_methods() {
+        let mut input = HourlyInput {
+            hourly_rate: 25.0,
+            hours_per_week: 40.0,
+            ytd: PeriodEarnings::new(26_000.0, 6.0),
+            w2_year1: PeriodEarnings::new(48_000.0, 12.0),
+            w2_year2: PeriodEarnings::new(42_000.0, 12.0),
+            method: IncomeMethod::Current,
+            decline_threshold_percent: DEFAULT_DECLINE_THRESHOLD_PERCENT,
+        };
+        let result = calculate_hourly(&input);
+        assert_close(result.current_monthly, 25.0 * 40.0 * 52.0 / 12.0);
+        assert_close(result.ytd_monthly, 26_000.0 / 6.0);
+        assert_close(result.qualifying_monthly, result.current_monthly);
+
+        input.method = IncomeMethod::Ytd;
+        assert_close(calculate_hourly(&input).qualifying_monthly, 4333.33);
+
+        input.method = IncomeMethod::YtdPlusOneYear;
+        assert_close(calculate_hourly(&input).qualifying_monthly, (4333.333 + 4000.0) / 2.0);
+
+        input.method = IncomeMethod::YtdPlusTwoYears;
+        assert_close(
+            calculate_hourly(&input).qualifying_monthly,
+            (4333.333 + 4000.0 + 3500.0) / 3.0,
+        );
+
+        input.method = IncomeMethod::Lowest;
+        assert_close(calculate_hourly(&input).qualifying_monthly, 3944.44);
+
+        input.method = IncomeMethod::None;
+        assert_eq!(calculate_hourly(&input).qualifying_monthly, 0.0);
+    }
+
+    #[test]
+    fn test_salary_pay_frequencies() {
+        let cases = [
+            (PayFrequency::Monthly, 5000.0),
+            (PayFrequency::SemiMonthly, 2500.0 * 24.0 / 12.0),
+            (PayFrequency::BiWeekly, 2000.0 * 26.0 / 12.0),
+            (PayFrequency::Weekly, 1000.0 * 52.0 / 12.0),
+        ];
+        let amounts = [5000.0, 2500.0, 2000.0, 1000.0];
+        for ((frequency, expected), amount) in cases.into_iter().zip(amounts) {
+            let input = SalaryInput {
+                salary_amount: amount,
+                pay_frequency: frequency,
+                method: IncomeMethod::Current,
+                ..Default::default()
+            };
+            assert_close(calculate_salary(&input).qualifying_monthly, expected);
+            assert_eq!(PayFrequency::from_key(frequency.as_key()), frequency);
+        }
+    }
+
+    #[test]
+    fn test_salary_history_methods() {
+        let mut input = SalaryInput {
+            ytd: PeriodEarnings::new(30_000.0, 6.0),
+            w2_year1: PeriodEarnings::new(57_600.0, 12.0),
+            w2_year2: PeriodEarnings::new(54_000.0, 12.0),
+            method: IncomeMethod::Ytd,
+            ..Default::default()
+        };
+        assert_close(calculate_salary(&input).qualifying_monthly, 5000.0);
+        input.method = IncomeMethod::PriorYear;
+        assert_close(calculate_salary(&input).qualifying_monthly, 4800.0);
+        input.method = IncomeMethod::SecondPriorYear;
+        assert_close(calculate_salary(&input).qualifying_monthly, 4500.0);
+    }
+
+    #[test]
+    fn test_salary_zero_months_is_zero() {
+        let input = SalaryInput {
+            ytd: PeriodEarnings::new(30_000.0, 0.0),
+            method: IncomeMethod::Ytd,
+            ..Default::default()
+        };
+        assert_eq!(calculate_salary(&input).qualifying_monthly, 0.0);
+    }
+
+    #[test]
+    fn test_ot_bonus_methods() {
+        let mut input = OtBonusInput {
+            ytd: PeriodEarnings::new(6_000.0, 6.0),
+            past_year: PeriodEarnings::new(14_400.0, 12.0),
+            additional_year: PeriodEarnings::new(9_600.0, 12.0),
+            method: IncomeMethod::Ytd,
+            decline_threshold_percent: DEFAULT_DECLINE_THRESHOLD_PERCENT,
+        };
+        assert_close(calculate_ot_bonus(&input).qualifying_monthly, 1000.0);
+        input.method = IncomeMethod::YtdPlusOneYear;
+        assert_close(calculate_ot_bonus(&input).qualifying_monthly, 1100.0);
+        // YTD is down 16.7% from the past year; the declining YTD figure is recommended
+        // but the selected average stands
+        let trend = calculate_ot_bonus(&input).trend;
+        assert!(trend.significant_decline);
+        assert_eq!(trend.recommended_monthly, Some(1000.0));
+        input.method = IncomeMethod::YtdPlusTwoYears;
+        assert_close(calculate_ot_bonus(&input).qualifying_monthly, 1000.0);
+        input.method = IncomeMethod::Lowest;
+        assert_close(calculate_ot_bonus(&input).qualifying_monthly, 1000.0);
+        input.method = IncomeMethod::Current;
+        assert_eq!(calculate_ot_bonus(&input).qualifying_monthly, 0.0);
+    }
+
+    #[test]
+    fn test_commission_nets_expenses() {
+        let mut input = CommissionInput {
+            ytd: CommissionPeriod {
+                commission: 40_000.0,
+                expenses: 4_000.0,
+                months: 6.0,
+            },
+            past_year: CommissionPeriod {
+                commission: 70_000.0,
+                expenses: 10_000.0,
+                months: 12.0,
+            },
+            additional_year: CommissionPeriod {
+                commission: 60_000.0,
+                expenses: 12_000.0,
+                months: 12.0,
+            },
+            method: IncomeMethod::Ytd,
+            decline_threshold_percent: DEFAULT_DECLINE_THRESHOLD_PERCENT,
+        };
+        let result = calculate_commission(&input);
+        assert_close(result.ytd_net, 36_000.0);
+        assert_close(result.qualifying_monthly, 6000.0);
+
+        input.method = IncomeMethod::PriorYear;
+        assert_close(calculate_commission(&input).qualifying_monthly, 5000.0);
+        input.method = IncomeMethod::SecondPriorYear;
+        assert_close(calculate_commission(&input).qualifying_monthly, 4000.0);
+        input.method = IncomeMethod::YtdPlusOneYear;
+        assert_close(calculate_commission(&input).qualifying_monthly, 5500.0);
+        input.method = IncomeMethod::YtdPlusTwoYears;
+        assert_close(calculate_commission(&input).qualifying_monthly, 5000.0);
+        input.method = IncomeMethod::Lowest;
+        assert_close(calculate_commission(&input).qualifying_monthly, 5000.0);
+    }
+
+    #[test]
+    fn test_other_w2_excludes_sick_pay() {
+        let input = OtherW2Input {
+            tips: 500.0,
+            shift_differential: 200.0,
+            on_call_pay: 100.0,
+            call_back_pay: 50.0,
+            hazard_pay: 150.0,
+            profit_sharing: 300.0,
+            sick_pay: 400.0,
+            holiday_vacation_pay: 75.0,
+        };
+        let result = calculate_other_w2(&input);
+        assert_eq!(result.sick_pay, 0.0);
+        assert_close(result.qualifying_monthly, 1375.0);
+    }
+
+    #[test]
+    fn test_social_security_with_documentation() {
+        let input = SocialSecurityInput {
+            taxable_portion: 18_000.0,
+            non_taxable_portion: 6_000.0,
+            months_with_doc: 12.0,
+            ..Default::default()
+        };
+        let result = calculate_social_security(&input, &IncomeRuleSet::default());
+        assert_close(result.non_taxable_grossed_up, 7_500.0);
+        assert_close(result.total_with_doc, 25_500.0);
+        assert_close(result.monthly_with_doc, 2_125.0);
+    }
+
+    #[test]
+    fn test_social_security_without_documentation() {
+        let input = SocialSecurityInput {
+            total_annual_benefit: 24_000.0,
+            months_without_doc: 12.0,
+            ..Default::default()
+        };
+        let result = calculate_social_security(&input, &IncomeRuleSet::default());
+        assert_close(result.taxable_share, 20_400.0);
+        assert_close(result.non_taxable_share, 3_600.0);
+        assert_close(result.non_taxable_share_grossed_up, 4_500.0);
+        assert_close(result.monthly_without_doc, 2_075.0);
+
+        // FHA grosses up by 15%
+        let fha = IncomeRuleSet::for_profile(RuleSetProfile::FHA, &IncomeRuleSet::default());
+        let result = calculate_social_security(&input, &fha);
+        assert_close(result.non_taxable_share_grossed_up, 4_140.0);
+    }
+
+    #[test]
+    fn test_pension_tiers_and_vesting() {
+        let rules = IncomeRuleSet::default();
+        let small = calculate_pension(&PensionInput {
+            gross_monthly: 800.0,
+            ..Default::default()
+        }, &rules);
+        assert_close(small.tiered_monthly, 560.0);
+        assert_close(small.qualifying_monthly, 560.0);
+
+        let large = calculate_pension(&PensionInput {
+            gross_monthly: 3000.0,
+            taxable_percent: 80.0,
+            vesting_percent: 100.0,
+        }, &rules);
+        assert_close(large.tiered_monthly, 700.0 + 1700.0);
+        assert_close(large.taxable_monthly, 2400.0);
+        assert_close(large.non_taxable_monthly, 600.0);
+        assert_close(large.annualized, 36_000.0);
+
+        let partially_vested = calculate_pension(&PensionInput {
+            gross_monthly: 3000.0,
+            taxable_percent: 100.0,
+            vesting_percent: 50.0,
+        }, &rules);
+        assert_close(partially_vested.vested_monthly, 1500.0);
+        assert_close(partially_vested.qualifying_monthly, 1500.0);
+    }
+
+    #[test]
+    fn test_other_income_factors() {
+        let input = OtherIncomeInput {
+            alimony: 1_000.0,
+            child_support: 800.0,
+            interest_dividends: 100.0,
+            disability: 500.0,
+            workers_comp: 0.0,
+            other: 50.0,
+        };
+        let result = calculate_other_income(&input, &IncomeRuleSet::default());
+        assert_close(result.alimony, 750.0);
+        assert_eq!(result.child_support, 0.0);
+        assert_close(result.qualifying_monthly, 750.0 + 100.0 + 500.0 + 50.0);
+    }
+}
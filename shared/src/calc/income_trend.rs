@@ -0,0 +1,149 @@
+// shared/src/calc/income_trend.rs
+// Year-over-year trend across the YTD and W-2 figures of a worksheet section. When
+// income is falling, underwriters qualify on the lower recent figure rather than an
+// average that hides the decline.
+use serde::{Deserialize, Serialize};
+
+/// Declines larger than this percentage are significant unless configured otherwise.
+pub const DEFAULT_DECLINE_THRESHOLD_PERCENT: f64 = 10.0;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IncomeTrend {
+    pub ytd_monthly: f64,
+    pub prior_year_monthly: f64,
+    pub second_prior_year_monthly: f64,
+    // Second prior year to prior year, and prior year to YTD. `None` without both figures.
+    pub prior_year_change_percent: Option<f64>,
+    pub ytd_change_percent: Option<f64>,
+    pub decline_threshold_percent: f64,
+    pub declining: bool,
+    pub significant_decline: bool,
+    // Figure the trend recommends qualifying with; shown beside the selected method's figure
+    pub recommended_monthly: Option<f64>,
+    pub reasoning: String,
+}
+
+/// A W-2 worksheet section as saved: the figure from the calculation method the
+/// underwriter selected, and the section's trend analysis.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct W2SectionResult {
+    pub section: String,
+    pub qualifying_monthly: f64,
+    pub trend: IncomeTrend,
+}
+
+impl IncomeTrend {
+    /// Whether the section has any YTD or W-2 income to analyze.
+    pub fn has_income(&self) -> bool {
+        self.ytd_monthly > 0.0 || self.prior_year_monthly > 0.0 || self.second_prior_year_monthly > 0.0
+    }
+}
+
+/// Percentage change between two monthly figures, when both are known.
+pub fn percent_change(from: f64, to: f64) -> Option<f64> {
+    (from > 0.0 && to > 0.0).then(|| (to - from) / from * 100.0)
+}
+
+/// Compares each period with the one before it. A decline beyond the threshold means
+/// the declining period is used alone, or the YTD figure if that is lower still; a
+/// smaller decline falls back to `lowest_average` with a warning.
+pub fn analyze_trend(
+    ytd_monthly: f64,
+    prior_year_monthly: f64,
+    second_prior_year_monthly: f64,
+    decline_threshold_percent: f64,
+    lowest_average: f64,
+) -> IncomeTrend {
+    let prior_year_change_percent = percent_change(second_prior_year_monthly, prior_year_monthly);
+    let ytd_change_percent = percent_change(prior_year_monthly, ytd_monthly);
+    let mut trend = IncomeTrend {
+        ytd_monthly,
+        prior_year_monthly,
+        second_prior_year_monthly,
+        prior_year_change_percent,
+        ytd_change_percent,
+        decline_threshold_percent,
+        ..Default::default()
+    };
+
+    if prior_year_change_percent.is_none() && ytd_change_percent.is_none() {
+        trend.reasoning = "Not enough history to measure a trend; the selected method applies.".to_string();
+        return trend;
+    }
+
+    let decline = |change: Option<f64>| change.filter(|change| *change < 0.0).map(f64::abs);
+    let ytd_decline = decline(ytd_change_percent);
+    let prior_year_decline = decline(prior_year_change_percent);
+    let significant = |decline: Option<f64>| decline.filter(|decline| *decline > decline_threshold_percent);
+
+    trend.declining = ytd_decline.is_some() || prior_year_decline.is_some();
+    trend.significant_decline = significant(ytd_decline).is_some() || significant(prior_year_decline).is_some();
+
+    if let Some(decline) = significant(ytd_decline) {
+        trend.recommended_monthly = Some(ytd_monthly);
+        trend.reasoning = format!(
+            "YTD income is down {decline:.1}% from the prior year, beyond the {decline_threshold_percent:.1}% threshold. The declining YTD figure should be used alone (${ytd_monthly:.2}/mo)."
+        );
+    } else if let Some(decline) = significant(prior_year_decline) {
+        let monthly = if ytd_monthly > 0.0 {
+            prior_year_monthly.min(ytd_monthly)
+        } else {
+            prior_year_monthly
+        };
+        trend.recommended_monthly = Some(monthly);
+        trend.reasoning = format!(
+            "Prior-year income is down {decline:.1}% from the year before, beyond the {decline_threshold_percent:.1}% threshold. The declining year should be used alone, or YTD when lower (${monthly:.2}/mo)."
+        );
+    } else if let Some(decline) = ytd_decline.into_iter().chain(prior_year_decline).reduce(f64::max) {
+        trend.recommended_monthly = Some(lowest_average);
+        trend.reasoning = format!(
+            "Income is down {decline:.1}%, within the {decline_threshold_percent:.1}% threshold. The lowest of the averages should be used (${lowest_average:.2}/mo); document that the income has stabilized."
+        );
+    } else {
+        trend.reasoning = "Income is stable or increasing; the selected method applies.".to_string();
+    }
+
+    trend
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percent_change() {
+        assert_eq!(percent_change(4_000.0, 5_000.0), Some(25.0));
+        assert_eq!(percent_change(5_000.0, 4_000.0), Some(-20.0));
+        assert_eq!(percent_change(0.0, 4_000.0), None);
+    }
+
+    #[test]
+    fn test_increasing_income_keeps_method() {
+        let trend = analyze_trend(5_000.0, 4_800.0, 4_500.0, 10.0, 4_766.67);
+        assert!(!trend.declining);
+        assert_eq!(trend.recommended_monthly, None);
+        assert!(trend.reasoning.contains("stable or increasing"));
+    }
+
+    #[test]
+    fn test_significant_decline_uses_declining_period() {
+        // YTD fell 20% from the prior year
+        let trend = analyze_trend(4_000.0, 5_000.0, 4_800.0, 10.0, 4_000.0);
+        assert!(trend.significant_decline);
+        assert_eq!(trend.recommended_monthly, Some(4_000.0));
+
+        // The prior year fell 25%; YTD recovered, so the prior year is used alone
+        let trend = analyze_trend(3_800.0, 3_600.0, 4_800.0, 10.0, 3_700.0);
+        assert!(trend.significant_decline);
+        assert_eq!(trend.recommended_monthly, Some(3_600.0));
+        assert!(trend.reasoning.contains("25.0%"));
+    }
+
+    #[test]
+    fn test_small_decline_uses_lowest_with_warning() {
+        let trend = analyze_trend(4_750.0, 5_000.0, 4_900.0, 10.0, 4_875.0);
+        assert!(trend.declining && !trend.significant_decline);
+        assert_eq!(trend.recommended_monthly, Some(4_875.0));
+        assert!(trend.reasoning.contains("document that the income has stabilized"));
+    }
+}
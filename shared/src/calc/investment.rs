@@ -0,0 +1,252 @@
+// shared/src/calc/investment.rs
+// Interest, dividends, capital gains and trust distributions averaged over two years of
+// 1099s, Schedule D and K-1s. Assets spent on closing or held back as reserves no longer
+// produce income, so asset-backed sources are reduced in proportion, and what remains
+// has to support the income for the rule set's continuance period.
+use serde::{Deserialize, Serialize};
+
+use crate::calc::amortization::round_cents;
+use crate::calc::rule_set::IncomeRuleSet;
+use crate::models::enums::IncomeType;
+use crate::models::{GeneralIncomeData, GeneralIncomeEntry, InvestmentIncomeData, InvestmentIncomeSource};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum InvestmentSourceType {
+    #[default]
+    Interest,
+    Dividends,
+    CapitalGains,
+    Trust,
+}
+
+impl InvestmentSourceType {
+    pub const ALL: [InvestmentSourceType; 4] = [
+        InvestmentSourceType::Interest,
+        InvestmentSourceType::Dividends,
+        InvestmentSourceType::CapitalGains,
+        InvestmentSourceType::Trust,
+    ];
+
+    pub fn from_key(key: &str) -> Self {
+        match key {
+            "dividends" => InvestmentSourceType::Dividends,
+            "capital_gains" => InvestmentSourceType::CapitalGains,
+            "trust" => InvestmentSourceType::Trust,
+            _ => InvestmentSourceType::Interest,
+        }
+    }
+
+    pub fn as_key(&self) -> &'static str {
+        match self {
+            InvestmentSourceType::Interest => "interest",
+            InvestmentSourceType::Dividends => "dividends",
+            InvestmentSourceType::CapitalGains => "capital_gains",
+            InvestmentSourceType::Trust => "trust",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            InvestmentSourceType::Interest => "Interest (1099-INT)",
+            InvestmentSourceType::Dividends => "Dividends (1099-DIV)",
+            InvestmentSourceType::CapitalGains => "Capital Gains (Schedule D)",
+            InvestmentSourceType::Trust => "Trust / K-1 Distributions",
+        }
+    }
+}
+
+/// Monthly income from the two-year average, or from the recent year alone when the
+/// income is declining. Net losses count as zero.
+pub fn average_monthly(source: &InvestmentIncomeSource) -> f64 {
+    let annual = if source.recent_year_amount < source.prior_year_amount {
+        source.recent_year_amount
+    } else {
+        (source.prior_year_amount + source.recent_year_amount) / 2.0
+    };
+    round_cents((annual / 12.0).max(0.0))
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct InvestmentSourceResult {
+    pub average_monthly: f64,
+    pub qualifying_monthly: f64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct InvestmentResult {
+    // In the same order as the worksheet's sources
+    pub sources: Vec<InvestmentSourceResult>,
+    pub total_assets: f64,
+    pub remaining_assets: f64,
+    // Share of each asset-backed source left after funds to close and reserves
+    pub remaining_share: f64,
+    pub asset_backed_monthly: f64,
+    // How long the remaining assets cover the asset-backed income
+    pub months_supported: Option<f64>,
+    pub meets_continuance: bool,
+    pub qualifying_monthly: f64,
+}
+
+pub fn calculate_investment_income(data: &InvestmentIncomeData, rules: &IncomeRuleSet) -> InvestmentResult {
+    let total_assets: f64 = data.sources.iter().map(|source| source.asset_balance.max(0.0)).sum();
+    let remaining_assets = (total_assets - data.funds_to_close - data.reserves).max(0.0);
+    let remaining_share = if total_assets > 0.0 { remaining_assets / total_assets } else { 1.0 };
+
+    let sources: Vec<InvestmentSourceResult> = data
+        .sources
+        .iter()
+        .map(|source| {
+            let average_monthly = average_monthly(source);
+            let qualifying_monthly = if source.asset_balance > 0.0 {
+                round_cents(average_monthly * remaining_share)
+            } else {
+                average_monthly
+            };
+            InvestmentSourceResult {
+                average_monthly,
+                qualifying_monthly,
+            }
+        })
+        .collect();
+
+    let asset_backed_monthly: f64 = data
+        .sources
+        .iter()
+        .zip(&sources)
+        .filter(|(source, _)| source.asset_balance > 0.0)
+        .map(|(_, result)| result.qualifying_monthly)
+        .sum();
+    let months_supported = (asset_backed_monthly > 0.0).then(|| remaining_assets / asset_backed_monthly);
+
+    InvestmentResult {
+        qualifying_monthly: sources.iter().map(|result| result.qualifying_monthly).sum(),
+        sources,
+        total_assets,
+        remaining_assets,
+        remaining_share,
+        asset_backed_monthly,
+        months_supported,
+        meets_continuance: months_supported.is_none_or(|months| months >= rules.continuance_months as f64),
+    }
+}
+
+/// Records each source as a general income entry with the source's id, and removes
+/// entries for sources that are no longer on the worksheet.
+pub fn apply_to_general_income(general: &mut GeneralIncomeData, data: &InvestmentIncomeData, result: &InvestmentResult) {
+    let income_type = IncomeType::InvestmentIncome.to_string();
+    general
+        .entries
+        .retain(|entry| entry.income_type != income_type || data.sources.iter().any(|source| source.id == entry.id));
+
+    for (source, source_result) in data.sources.iter().zip(&result.sources) {
+        let index = match general.entries.iter().position(|entry| entry.id == source.id) {
+            Some(index) => index,
+            None => {
+                general.entries.push(GeneralIncomeEntry {
+                    id: source.id.clone(),
+                    income_type: income_type.clone(),
+                    ..Default::default()
+                });
+                general.entries.len() - 1
+            }
+        };
+
+        let entry = &mut general.entries[index];
+        entry.source_name = source.name.clone();
+        entry.description = InvestmentSourceType::from_key(&source.source_type).label().to_string();
+        entry.monthly_amount = format!("{:.2}", source_result.qualifying_monthly);
+        entry.annual_amount = format!("{:.2}", source_result.qualifying_monthly * 12.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(source_type: &str, prior: f64, recent: f64, balance: f64) -> InvestmentIncomeSource {
+        InvestmentIncomeSource {
+            source_type: source_type.to_string(),
+            prior_year_amount: prior,
+            recent_year_amount: recent,
+            asset_balance: balance,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_two_year_average_and_declines() {
+        assert_eq!(average_monthly(&source("dividends", 2_400.0, 3_600.0, 0.0)), 250.0);
+        // Declining income uses the recent year
+        assert_eq!(average_monthly(&source("interest", 3_600.0, 2_400.0, 0.0)), 200.0);
+        // Capital losses do not count
+        assert_eq!(average_monthly(&source("capital_gains", -6_000.0, -1_200.0, 0.0)), 0.0);
+    }
+
+    #[test]
+    fn test_funds_to_close_reduce_asset_backed_income() {
+        let data = InvestmentIncomeData {
+            funds_to_close: 40_000.0,
+            reserves: 10_000.0,
+            sources: vec![
+                source("interest", 6_000.0, 6_000.0, 150_000.0),
+                source("dividends", 3_000.0, 3_000.0, 50_000.0),
+                source("trust", 12_000.0, 12_000.0, 0.0),
+            ],
+        };
+        let result = calculate_investment_income(&data, &IncomeRuleSet::default());
+        assert_eq!(result.remaining_assets, 150_000.0);
+        assert_eq!(result.remaining_share, 0.75);
+        assert_eq!(result.sources[0].qualifying_monthly, 375.0);
+        assert_eq!(result.sources[1].qualifying_monthly, 187.5);
+        // Trust distributions do not depend on the borrower's assets
+        assert_eq!(result.sources[2].qualifying_monthly, 1_000.0);
+        assert_eq!(result.qualifying_monthly, 1_562.5);
+        assert!(result.meets_continuance);
+    }
+
+    #[test]
+    fn test_assets_must_support_continuance() {
+        let data = InvestmentIncomeData {
+            funds_to_close: 60_000.0,
+            sources: vec![source("capital_gains", 30_000.0, 30_000.0, 100_000.0)],
+            ..Default::default()
+        };
+        // $40,000 left supports $1,000 a month for 40 months
+        let result = calculate_investment_income(&data, &IncomeRuleSet::default());
+        assert_eq!(result.sources[0].qualifying_monthly, 1_000.0);
+        assert_eq!(result.months_supported, Some(40.0));
+        assert!(result.meets_continuance);
+
+        let custom = IncomeRuleSet {
+            continuance_months: 48,
+            ..Default::default()
+        };
+        assert!(!calculate_investment_income(&data, &custom).meets_continuance);
+    }
+
+    #[test]
+    fn test_sources_recorded_as_general_income() {
+        let mut data = InvestmentIncomeData {
+            sources: vec![source("interest", 1_200.0, 1_200.0, 0.0), source("dividends", 2_400.0, 2_400.0, 0.0)],
+            ..Default::default()
+        };
+        let mut general = GeneralIncomeData {
+            entries: vec![GeneralIncomeEntry::default()],
+            ..Default::default()
+        };
+
+        let result = calculate_investment_income(&data, &IncomeRuleSet::default());
+        apply_to_general_income(&mut general, &data, &result);
+        assert_eq!(general.entries.len(), 3);
+        assert_eq!(general.entries[1].id, data.sources[0].id);
+        assert_eq!(general.entries[1].income_type, "Investment Income");
+        assert_eq!(general.entries[2].monthly_amount, "200.00");
+
+        // Removing a source removes its entry
+        data.sources.remove(0);
+        let result = calculate_investment_income(&data, &IncomeRuleSet::default());
+        apply_to_general_income(&mut general, &data, &result);
+        assert_eq!(general.entries.len(), 2);
+        assert_eq!(general.entries[1].id, data.sources[0].id);
+    }
+}
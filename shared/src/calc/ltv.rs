@@ -0,0 +1,283 @@
+// shared/src/calc/ltv.rs
+// Loan-to-value ratios and program maximums. Ratios are percentages of the property value.
+use crate::calc::options_template::property_value;
+use crate::models::{LoanPurpose, LoanToValueData, LoanType, OccupancyType, OptionsTemplateData, PropertyType};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LoanToValue {
+    pub ltv: f64,
+    pub cltv: f64,
+    pub hcltv: f64,
+}
+
+fn ratio(amount: f64, value: f64) -> f64 {
+    if value > 0.0 { amount / value * 100.0 } else { 0.0 }
+}
+
+/// LTV on the base loan, CLTV adding subordinate balances and HCLTV counting the full
+/// HELOC line instead of its drawn balance. Financed premiums are not part of the
+/// base loan, so they never count against the program maximums.
+pub fn calculate(
+    value: f64,
+    base_loan_amount: f64,
+    subordinated_amount: f64,
+    heloc_balance: f64,
+    heloc_credit_limit: f64,
+) -> LoanToValue {
+    let combined = base_loan_amount + subordinated_amount;
+    LoanToValue {
+        ltv: ratio(base_loan_amount, value),
+        cltv: ratio(combined + heloc_balance, value),
+        hcltv: ratio(combined + heloc_balance.max(heloc_credit_limit), value),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LtvLimits {
+    pub max_ltv: f64,
+    pub max_cltv: f64,
+    pub max_hcltv: f64,
+}
+
+impl LtvLimits {
+    const fn uniform(max: f64) -> Self {
+        LtvLimits {
+            max_ltv: max,
+            max_cltv: max,
+            max_hcltv: max,
+        }
+    }
+}
+
+fn conventional_limits(
+    purpose: &LoanPurpose,
+    occupancy: &OccupancyType,
+    property_type: &PropertyType,
+) -> Result<Option<LtvLimits>, String> {
+    use OccupancyType::*;
+
+    let multi_unit = *property_type == PropertyType::MultiUnit;
+    let manufactured = *property_type == PropertyType::Manufactured;
+
+    if multi_unit && *occupancy == Secondary {
+        return Err("Conventional second homes are limited to one unit".to_string());
+    }
+    if manufactured && *occupancy == Investment {
+        return Err("Manufactured homes are not eligible as conventional investment properties".to_string());
+    }
+
+    let max = match (purpose, occupancy) {
+        (LoanPurpose::CashOut, _) if manufactured => 65.0,
+        (LoanPurpose::CashOut, Primary) if multi_unit => 75.0,
+        (LoanPurpose::CashOut, Primary) => 80.0,
+        (LoanPurpose::CashOut, Secondary) => 75.0,
+        (LoanPurpose::CashOut, Investment) if multi_unit => 70.0,
+        (LoanPurpose::CashOut, Investment) => 75.0,
+        (LoanPurpose::Purchase, Primary) if multi_unit => 85.0,
+        (LoanPurpose::Purchase, Primary) if manufactured => 95.0,
+        (LoanPurpose::Purchase, Primary) => 97.0,
+        (LoanPurpose::Purchase, Investment) if multi_unit => 75.0,
+        (LoanPurpose::Purchase, Investment) => 85.0,
+        (_, Primary) if multi_unit => 75.0,
+        (_, Primary) => 95.0,
+        (_, Secondary) => 90.0,
+        (_, Investment) => 75.0,
+    };
+
+    Ok(Some(LtvLimits::uniform(max)))
+}
+
+/// Program maximums for the scenario. `Ok(None)` means the program sets no LTV limit
+/// (streamline refinances, Non-QM); `Err` explains why the scenario is not eligible.
+pub fn ltv_limits(
+    loan_type: &LoanType,
+    purpose: &LoanPurpose,
+    occupancy: &OccupancyType,
+    property_type: &PropertyType,
+) -> Result<Option<LtvLimits>, String> {
+    match loan_type {
+        LoanType::CNV => conventional_limits(purpose, occupancy, property_type),
+        LoanType::FHA => {
+            if *occupancy != OccupancyType::Primary {
+                return Err("FHA financing requires a primary residence".to_string());
+            }
+            Ok(match purpose {
+                // Secondary financing from an eligible provider can take a purchase to 100%
+                LoanPurpose::Purchase => Some(LtvLimits {
+                    max_ltv: 96.5,
+                    max_cltv: 100.0,
+                    max_hcltv: 100.0,
+                }),
+                LoanPurpose::Refinance => Some(LtvLimits::uniform(97.75)),
+                LoanPurpose::CashOut => Some(LtvLimits::uniform(80.0)),
+                LoanPurpose::IRRRLStreamline => None,
+            })
+        }
+        LoanType::VA => {
+            if *occupancy != OccupancyType::Primary {
+                return Err("VA financing requires the veteran to occupy the property".to_string());
+            }
+            Ok(match purpose {
+                LoanPurpose::IRRRLStreamline => None,
+                _ => Some(LtvLimits::uniform(100.0)),
+            })
+        }
+        LoanType::USDA => {
+            if *occupancy != OccupancyType::Primary {
+                return Err("USDA financing requires a primary residence".to_string());
+            }
+            match purpose {
+                LoanPurpose::CashOut => Err("USDA does not allow cash-out refinances".to_string()),
+                LoanPurpose::IRRRLStreamline => Ok(None),
+                _ => Ok(Some(LtvLimits::uniform(100.0))),
+            }
+        }
+        LoanType::NonQM => Ok(None),
+    }
+}
+
+fn limit_warning(label: &str, actual: f64, max: f64) -> Option<String> {
+    (actual > max).then(|| format!("{label} {actual:.2}% exceeds the {max:.2}% maximum"))
+}
+
+/// Ratios for the options template, checked against the program maximums. Property
+/// type and occupancy fall back to the first choice in their selects; no limits are
+/// checked until a loan type and purpose are picked.
+pub fn check_template(data: &OptionsTemplateData) -> LoanToValueData {
+    let loan = &data.new_loan;
+    let value = property_value(data);
+    let ratios = calculate(
+        value,
+        loan.base_loan_amount,
+        loan.subordinated_amount,
+        loan.heloc_balance,
+        loan.heloc_credit_limit,
+    );
+
+    let mut result = LoanToValueData {
+        ltv: ratios.ltv,
+        cltv: ratios.cltv,
+        hcltv: ratios.hcltv,
+        ..Default::default()
+    };
+
+    let info = &data.loan_information;
+    let (Some(loan_type), Some(purpose)) = (LoanType::from_key(&info.loan_type), LoanPurpose::from_key(&info.purpose))
+    else {
+        return result;
+    };
+    let occupancy = OccupancyType::from_key(&info.occupancy).unwrap_or(OccupancyType::Primary);
+    let property_type = PropertyType::from_key(&info.property_type).unwrap_or(PropertyType::SFR);
+
+    match ltv_limits(&loan_type, &purpose, &occupancy, &property_type) {
+        Ok(Some(limits)) => {
+            result.max_ltv = Some(limits.max_ltv);
+            result.max_cltv = Some(limits.max_cltv);
+            result.max_hcltv = Some(limits.max_hcltv);
+
+            if value > 0.0 {
+                result.warnings.extend(
+                    [
+                        limit_warning("LTV", ratios.ltv, limits.max_ltv),
+                        limit_warning("CLTV", ratios.cltv, limits.max_cltv),
+                        limit_warning("HCLTV", ratios.hcltv, limits.max_hcltv),
+                    ]
+                    .into_iter()
+                    .flatten(),
+                );
+            }
+        }
+        Ok(None) => {}
+        Err(reason) => result.warnings.push(reason),
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_ratios() {
+        let ratios = calculate(400_000.0, 300_000.0, 20_000.0, 10_000.0, 50_000.0);
+        assert_eq!(ratios.ltv, 75.0);
+        assert_eq!(ratios.cltv, 82.5);
+        assert_eq!(ratios.hcltv, 92.5);
+
+        assert_eq!(calculate(0.0, 300_000.0, 0.0, 0.0, 0.0), LoanToValue::default());
+    }
+
+    #[test]
+    fn test_conventional_limits() {
+        let max_ltv = |purpose, occupancy, property| {
+            ltv_limits(&LoanType::CNV, &purpose, &occupancy, &property).map(|limits| limits.unwrap().max_ltv)
+        };
+
+        assert_eq!(max_ltv(LoanPurpose::Purchase, OccupancyType::Primary, PropertyType::SFR), Ok(97.0));
+        assert_eq!(max_ltv(LoanPurpose::Purchase, OccupancyType::Primary, PropertyType::MultiUnit), Ok(85.0));
+        assert_eq!(max_ltv(LoanPurpose::Refinance, OccupancyType::Primary, PropertyType::Condo), Ok(95.0));
+        assert_eq!(max_ltv(LoanPurpose::Purchase, OccupancyType::Secondary, PropertyType::PUD), Ok(90.0));
+        assert_eq!(max_ltv(LoanPurpose::Purchase, OccupancyType::Investment, PropertyType::SFR), Ok(85.0));
+        assert_eq!(max_ltv(LoanPurpose::CashOut, OccupancyType::Primary, PropertyType::SFR), Ok(80.0));
+        assert_eq!(max_ltv(LoanPurpose::CashOut, OccupancyType::Investment, PropertyType::MultiUnit), Ok(70.0));
+        assert_eq!(max_ltv(LoanPurpose::CashOut, OccupancyType::Primary, PropertyType::Manufactured), Ok(65.0));
+        assert!(max_ltv(LoanPurpose::Purchase, OccupancyType::Secondary, PropertyType::MultiUnit).is_err());
+        assert!(max_ltv(LoanPurpose::Refinance, OccupancyType::Investment, PropertyType::Manufactured).is_err());
+    }
+
+    #[test]
+    fn test_government_limits() {
+        let fha_purchase =
+            ltv_limits(&LoanType::FHA, &LoanPurpose::Purchase, &OccupancyType::Primary, &PropertyType::SFR);
+        assert_eq!(fha_purchase.unwrap().unwrap().max_ltv, 96.5);
+        assert_eq!(
+            ltv_limits(&LoanType::FHA, &LoanPurpose::IRRRLStreamline, &OccupancyType::Primary, &PropertyType::SFR),
+            Ok(None)
+        );
+        assert!(ltv_limits(&LoanType::FHA, &LoanPurpose::Purchase, &OccupancyType::Investment, &PropertyType::SFR).is_err());
+        assert_eq!(
+            ltv_limits(&LoanType::VA, &LoanPurpose::CashOut, &OccupancyType::Primary, &PropertyType::SFR),
+            Ok(Some(LtvLimits::uniform(100.0)))
+        );
+        assert!(ltv_limits(&LoanType::VA, &LoanPurpose::Purchase, &OccupancyType::Secondary, &PropertyType::SFR).is_err());
+        assert!(ltv_limits(&LoanType::USDA, &LoanPurpose::CashOut, &OccupancyType::Primary, &PropertyType::SFR).is_err());
+        assert_eq!(
+            ltv_limits(&LoanType::NonQM, &LoanPurpose::CashOut, &OccupancyType::Investment, &PropertyType::SFR),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn test_check_template() {
+        let mut data = OptionsTemplateData::default();
+        data.loan_information.loan_type = "cnv".to_string();
+        data.loan_information.purpose = "purchase".to_string();
+        data.new_loan.sales_price = 400_000.0;
+        data.new_loan.market_value = 410_000.0;
+        data.new_loan.base_loan_amount = 380_000.0;
+        data.new_loan.subordinated_amount = 12_000.0;
+
+        // Purchases use the lower sales price
+        let result = check_template(&data);
+        assert_eq!(result.ltv, 95.0);
+        assert_eq!(result.cltv, 98.0);
+        assert_eq!(result.max_ltv, Some(97.0));
+        assert_eq!(result.warnings, vec![
+            "CLTV 98.00% exceeds the 97.00% maximum".to_string(),
+            "HCLTV 98.00% exceeds the 97.00% maximum".to_string(),
+        ]);
+
+        data.loan_information.occupancy = "secondary".to_string();
+        data.loan_information.property_type = "multiUnit".to_string();
+        assert_eq!(check_template(&data).warnings, vec![
+            "Conventional second homes are limited to one unit".to_string()
+        ]);
+
+        data.loan_information.loan_type.clear();
+        let unchecked = check_template(&data);
+        assert_eq!(unchecked.ltv, 95.0);
+        assert_eq!(unchecked.max_ltv, None);
+        assert!(unchecked.warnings.is_empty());
+    }
+}
@@ -0,0 +1,159 @@
+// shared/src/calc/military.rs
+// Military income from a Leave and Earnings Statement. Base, flight and hazard pay are
+// taxable; housing (BAH), subsistence (BAS) and clothing allowances are not, so they
+// are grossed up under the active rule set. An ETS date inside the continuance period
+// needs evidence of reenlistment or a new position.
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::calc::amortization::round_cents;
+use crate::calc::pay_period::{months_covered, parse_date};
+use crate::calc::rule_set::IncomeRuleSet;
+use crate::models::{GeneralIncomeData, GeneralIncomeEntry, MilitaryIncomeData};
+use crate::models::enums::IncomeType;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MilitaryResult {
+    pub taxable_monthly: f64,
+    pub non_taxable_monthly: f64,
+    pub grossed_up_non_taxable: f64,
+    pub qualifying_monthly: f64,
+    // None when no ETS date is entered
+    pub months_to_ets: Option<f64>,
+    pub meets_continuance: bool,
+}
+
+pub fn taxable_monthly(data: &MilitaryIncomeData) -> f64 {
+    let other = if data.other_allowances_taxable { data.other_allowances } else { 0.0 };
+    data.base_pay + data.flight_pay + data.hazard_pay + other
+}
+
+pub fn non_taxable_monthly(data: &MilitaryIncomeData) -> f64 {
+    let other = if data.other_allowances_taxable { 0.0 } else { data.other_allowances };
+    data.bah + data.bas + data.clothing_allowance + other
+}
+
+/// Qualifying military income as of `as_of`, normally the application date or today.
+pub fn calculate_military(data: &MilitaryIncomeData, rules: &IncomeRuleSet, as_of: NaiveDate) -> MilitaryResult {
+    let taxable_monthly = round_cents(taxable_monthly(data));
+    let non_taxable_monthly = round_cents(non_taxable_monthly(data));
+    let grossed_up_non_taxable = round_cents(non_taxable_monthly * rules.non_taxable_gross_up);
+
+    let months_to_ets = parse_date(&data.ets_date).map(|ets| months_covered(as_of, ets));
+
+    MilitaryResult {
+        taxable_monthly,
+        non_taxable_monthly,
+        grossed_up_non_taxable,
+        qualifying_monthly: taxable_monthly + grossed_up_non_taxable,
+        months_to_ets,
+        meets_continuance: months_to_ets.is_none_or(|months| months >= rules.continuance_months as f64),
+    }
+}
+
+/// Writes the qualifying military income into the borrower's general income entries,
+/// updating the existing military entry or adding one.
+pub fn apply_to_general_income(general: &mut GeneralIncomeData, data: &MilitaryIncomeData, result: &MilitaryResult) {
+    let income_type = IncomeType::Military.to_string();
+    let index = match general.entries.iter().position(|entry| entry.income_type == income_type) {
+        Some(index) => index,
+        None => {
+            general.entries.push(GeneralIncomeEntry {
+                income_type,
+                ..Default::default()
+            });
+            general.entries.len() - 1
+        }
+    };
+
+    let entry = &mut general.entries[index];
+    entry.source_name = data.branch.clone();
+    entry.description = "Military pay and allowances (LES)".to_string();
+    entry.monthly_amount = format!("{:.2}", result.qualifying_monthly);
+    entry.annual_amount = format!("{:.2}", result.qualifying_monthly * 12.0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calc::rule_set::RuleSetProfile;
+
+    fn les() -> MilitaryIncomeData {
+        MilitaryIncomeData {
+            branch: "US Army".to_string(),
+            base_pay: 4_000.0,
+            bah: 2_000.0,
+            bas: 400.0,
+            clothing_allowance: 50.0,
+            flight_pay: 250.0,
+            other_allowances: 100.0,
+            ..Default::default()
+        }
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_non_taxable_allowances_grossed_up() {
+        let rules = IncomeRuleSet::default();
+        let result = calculate_military(&les(), &rules, date(2026, 1, 1));
+        assert_eq!(result.taxable_monthly, 4_250.0);
+        assert_eq!(result.non_taxable_monthly, 2_550.0);
+        assert_eq!(result.grossed_up_non_taxable, 3_187.5);
+        assert_eq!(result.qualifying_monthly, 7_437.5);
+
+        let fha = IncomeRuleSet::for_profile(RuleSetProfile::FHA, &rules);
+        assert_eq!(calculate_military(&les(), &fha, date(2026, 1, 1)).grossed_up_non_taxable, 2_932.5);
+
+        let taxable_other = MilitaryIncomeData {
+            other_allowances_taxable: true,
+            ..les()
+        };
+        let result = calculate_military(&taxable_other, &rules, date(2026, 1, 1));
+        assert_eq!(result.taxable_monthly, 4_350.0);
+        assert_eq!(result.non_taxable_monthly, 2_450.0);
+    }
+
+    #[test]
+    fn test_ets_continuance() {
+        let rules = IncomeRuleSet::default();
+        assert!(calculate_military(&les(), &rules, date(2026, 1, 1)).meets_continuance);
+
+        let separating = MilitaryIncomeData {
+            ets_date: "2027-06-30".to_string(),
+            ..les()
+        };
+        let result = calculate_military(&separating, &rules, date(2026, 1, 1));
+        assert_eq!(result.months_to_ets, Some(18.0));
+        assert!(!result.meets_continuance);
+
+        let reenlisted = MilitaryIncomeData {
+            ets_date: "2030-12-31".to_string(),
+            ..les()
+        };
+        assert!(calculate_military(&reenlisted, &rules, date(2026, 1, 1)).meets_continuance);
+    }
+
+    #[test]
+    fn test_apply_to_general_income() {
+        let data = les();
+        let result = calculate_military(&data, &IncomeRuleSet::default(), date(2026, 1, 1));
+        let mut general = GeneralIncomeData {
+            entries: vec![GeneralIncomeEntry {
+                monthly_amount: "1500".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        apply_to_general_income(&mut general, &data, &result);
+        apply_to_general_income(&mut general, &data, &result);
+        assert_eq!(general.entries.len(), 2);
+        assert_eq!(general.entries[1].income_type, "Military");
+        assert_eq!(general.entries[1].source_name, "US Army");
+        assert_eq!(general.entries[1].monthly_amount, "7437.50");
+        assert_eq!(general.entries[0].monthly_amount, "1500");
+    }
+}
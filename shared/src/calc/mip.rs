@@ -0,0 +1,159 @@
+// shared/src/calc/mip.rs
+// FHA mortgage insurance premiums. Premium rates are percentages of the base loan amount.
+use crate::calc::amortization::round_cents;
+
+/// Upfront MIP charged on every FHA case.
+pub const UFMIP_RATE: f64 = 1.75;
+/// Base loan amount above which the higher annual MIP factors apply.
+pub const ANNUAL_MIP_LOAN_SIZE_LIMIT: f64 = 726_200.0;
+/// UFMIP refund percentage for months 1 through 36 after the prior endorsement.
+/// The refund drops two points a month and ends after month 36.
+pub const UFMIP_REFUND_TABLE: [f64; 36] = [
+    80.0, 78.0, 76.0, 74.0, 72.0, 70.0, 68.0, 66.0, 64.0, 62.0, 60.0, 58.0, //
+    56.0, 54.0, 52.0, 50.0, 48.0, 46.0, 44.0, 42.0, 40.0, 38.0, 36.0, 34.0, //
+    32.0, 30.0, 28.0, 26.0, 24.0, 22.0, 20.0, 18.0, 16.0, 14.0, 12.0, 10.0,
+];
+
+/// Upfront MIP on the base loan amount.
+pub fn upfront_mip(base_loan_amount: f64) -> f64 {
+    round_cents(base_loan_amount.max(0.0) * UFMIP_RATE / 100.0)
+}
+
+/// Annual MIP factor (percent) for the loan's term, base LTV and size.
+pub fn annual_mip_factor(base_loan_amount: f64, ltv: f64, term_months: u32) -> f64 {
+    let high_balance = base_loan_amount > ANNUAL_MIP_LOAN_SIZE_LIMIT;
+
+    if term_months > 180 {
+        match (high_balance, ltv > 95.0) {
+            (false, false) => 0.50,
+            (false, true) => 0.55,
+            (true, false) => 0.70,
+            (true, true) => 0.75,
+        }
+    } else if high_balance {
+        if ltv <= 78.0 {
+            0.15
+        } else if ltv <= 90.0 {
+            0.40
+        } else {
+            0.65
+        }
+    } else if ltv <= 90.0 {
+        0.15
+    } else {
+        0.40
+    }
+}
+
+/// First-year monthly annual MIP on the base loan amount.
+pub fn monthly_annual_mip(base_loan_amount: f64, factor: f64) -> f64 {
+    round_cents(base_loan_amount.max(0.0) * factor / 100.0 / 12.0)
+}
+
+/// Refund percentage of the prior UFMIP for a refinance `months` after endorsement.
+pub fn ufmip_refund_percentage(months_since_endorsement: u32) -> f64 {
+    match months_since_endorsement {
+        0 => 0.0,
+        m => UFMIP_REFUND_TABLE.get(m as usize - 1).copied().unwrap_or(0.0),
+    }
+}
+
+/// Portion of the prior UFMIP credited back when refinancing FHA to FHA.
+pub fn ufmip_refund(prior_ufmip: f64, months_since_endorsement: u32) -> f64 {
+    round_cents(prior_ufmip.max(0.0) * ufmip_refund_percentage(months_since_endorsement) / 100.0)
+}
+
+/// Months annual MIP is paid: 11 years when the base LTV is 90% or less, otherwise
+/// the full term.
+pub fn mip_duration_months(ltv: f64, term_months: u32) -> u32 {
+    if ltv <= 90.0 { term_months.min(132) } else { term_months }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FhaMip {
+    pub upfront_rate: f64,
+    pub upfront_mip: f64,
+    pub refund: f64,
+    // UFMIP added to the loan after the refund credit
+    pub financed_mip: f64,
+    pub annual_factor: f64,
+    pub monthly_mip: f64,
+}
+
+/// Full MIP picture for an FHA loan. `prior_ufmip` and `months_since_endorsement`
+/// only matter when the loan being paid off is FHA.
+pub fn calculate_fha_mip(
+    base_loan_amount: f64,
+    ltv: f64,
+    term_months: u32,
+    prior_ufmip: f64,
+    months_since_endorsement: u32,
+) -> FhaMip {
+    let upfront = upfront_mip(base_loan_amount);
+    let refund = ufmip_refund(prior_ufmip, months_since_endorsement).min(upfront);
+    let annual_factor = annual_mip_factor(base_loan_amount, ltv, term_months);
+
+    FhaMip {
+        upfront_rate: UFMIP_RATE,
+        upfront_mip: upfront,
+        refund,
+        financed_mip: round_cents(upfront - refund),
+        annual_factor,
+        monthly_mip: monthly_annual_mip(base_loan_amount, annual_factor),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upfront_mip() {
+        assert_eq!(upfront_mip(300_000.0), 5_250.0);
+        assert_eq!(upfront_mip(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_annual_mip_factor_long_term() {
+        assert_eq!(annual_mip_factor(300_000.0, 96.5, 360), 0.55);
+        assert_eq!(annual_mip_factor(300_000.0, 95.0, 360), 0.50);
+        assert_eq!(annual_mip_factor(800_000.0, 96.5, 360), 0.75);
+        assert_eq!(annual_mip_factor(800_000.0, 90.0, 360), 0.70);
+    }
+
+    #[test]
+    fn test_annual_mip_factor_short_term() {
+        assert_eq!(annual_mip_factor(300_000.0, 90.0, 180), 0.15);
+        assert_eq!(annual_mip_factor(300_000.0, 92.0, 180), 0.40);
+        assert_eq!(annual_mip_factor(800_000.0, 78.0, 180), 0.15);
+        assert_eq!(annual_mip_factor(800_000.0, 85.0, 180), 0.40);
+        assert_eq!(annual_mip_factor(800_000.0, 95.0, 120), 0.65);
+    }
+
+    #[test]
+    fn test_mip_duration() {
+        assert_eq!(mip_duration_months(90.0, 360), 132);
+        assert_eq!(mip_duration_months(96.5, 360), 360);
+        assert_eq!(mip_duration_months(80.0, 120), 120);
+    }
+
+    #[test]
+    fn test_refund_table() {
+        assert_eq!(ufmip_refund_percentage(0), 0.0);
+        assert_eq!(ufmip_refund_percentage(1), 80.0);
+        assert_eq!(ufmip_refund_percentage(12), 58.0);
+        assert_eq!(ufmip_refund_percentage(36), 10.0);
+        assert_eq!(ufmip_refund_percentage(37), 0.0);
+        assert_eq!(ufmip_refund(5_000.0, 12), 2_900.0);
+    }
+
+    #[test]
+    fn test_calculate_fha_mip() {
+        let mip = calculate_fha_mip(300_000.0, 96.5, 360, 4_000.0, 24);
+        assert_eq!(mip.upfront_mip, 5_250.0);
+        assert_eq!(mip.refund, 1_360.0);
+        assert_eq!(mip.financed_mip, 3_890.0);
+        assert_eq!(mip.annual_factor, 0.55);
+        assert_eq!(mip.monthly_mip, 137.5);
+    }
+}
@@ -0,0 +1,29 @@
+// shared/src/calc/mod.rs
+// Headless calculation engines shared by the frontend and repository crates.
+
+pub mod income;
+pub mod income_trend;
+pub mod pay_period;
+pub mod self_employment;
+pub mod bank_statement;
+pub mod rental;
+pub mod retirement;
+pub mod military;
+pub mod investment;
+pub mod accessory_income;
+pub mod rule_set;
+pub mod dscr;
+pub mod amortization;
+pub mod mip;
+pub mod pmi;
+pub mod ltv;
+pub mod consumer_debt;
+pub mod dti;
+pub mod residual_income;
+pub mod va_funding_fee;
+pub mod pricing;
+pub mod apr;
+pub mod cash_to_close;
+pub mod options_template;
+pub mod savings;
+pub mod net_tangible_benefit;
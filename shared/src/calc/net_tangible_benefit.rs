@@ -0,0 +1,332 @@
+// shared/src/calc/net_tangible_benefit.rs
+// Net tangible benefit tests for streamline refinances. Rates are annual percentages.
+use crate::calc::cash_to_close::calculate_cash_to_close;
+use crate::models::{NetTangibleBenefitData, NetTangibleBenefitFindingData, OptionsTemplateData};
+
+/// VA IRRRL closing costs must be recouped through the lower payment within this many months.
+pub const VA_MAX_RECOUPMENT_MONTHS: f64 = 36.0;
+/// Minimum note rate reduction for a fixed-to-fixed VA IRRRL.
+pub const VA_MIN_RATE_REDUCTION: f64 = 0.5;
+/// Minimum reduction of note rate plus annual MIP for a fixed-to-fixed FHA streamline.
+pub const FHA_MIN_COMBINED_RATE_REDUCTION: f64 = 0.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamlineProgram {
+    VaIrrrl,
+    FhaStreamline,
+}
+
+impl StreamlineProgram {
+    /// Picks the program from the options template loan type and purpose keys.
+    pub fn from_keys(loan_type: &str, purpose: &str) -> Option<Self> {
+        match (loan_type, purpose) {
+            ("va", "irrrl") => Some(StreamlineProgram::VaIrrrl),
+            ("fha", "irrrl") => Some(StreamlineProgram::FhaStreamline),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            StreamlineProgram::VaIrrrl => "VA IRRRL",
+            StreamlineProgram::FhaStreamline => "FHA Streamline",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NetTangibleBenefitInput {
+    pub existing_rate: f64,
+    pub proposed_rate: f64,
+    pub existing_annual_mip_rate: f64,
+    pub proposed_annual_mip_rate: f64,
+    // Monthly P&I; escrow and mortgage insurance are left out of recoupment
+    pub existing_pi: f64,
+    pub proposed_pi: f64,
+    // Closing costs the borrower has to recoup, excluding escrow, prepaids and the funding fee
+    pub recoupable_costs: f64,
+}
+
+fn va_recoupment(input: &NetTangibleBenefitInput) -> NetTangibleBenefitFindingData {
+    let reduction = input.existing_pi - input.proposed_pi;
+    let costs = input.recoupable_costs.max(0.0);
+
+    let (passed, actual, detail) = if costs <= 0.0 {
+        (true, 0.0, "No closing costs to recoup".to_string())
+    } else if reduction <= 0.0 {
+        (
+            false,
+            0.0,
+            format!("${costs:.2} in costs but the P&I does not decrease ({reduction:.2}/mo)"),
+        )
+    } else {
+        let months = costs / reduction;
+        (
+            months <= VA_MAX_RECOUPMENT_MONTHS,
+            months,
+            format!("${costs:.2} in costs / ${reduction:.2} monthly P&I reduction = {months:.1} months"),
+        )
+    };
+
+    NetTangibleBenefitFindingData {
+        rule: "36-month recoupment".to_string(),
+        passed,
+        threshold: VA_MAX_RECOUPMENT_MONTHS,
+        actual,
+        detail,
+    }
+}
+
+fn va_rate_reduction(input: &NetTangibleBenefitInput) -> NetTangibleBenefitFindingData {
+    let reduction = input.existing_rate - input.proposed_rate;
+    NetTangibleBenefitFindingData {
+        rule: "Rate reduction".to_string(),
+        passed: reduction >= VA_MIN_RATE_REDUCTION,
+        threshold: VA_MIN_RATE_REDUCTION,
+        actual: reduction,
+        detail: format!(
+            "{:.3}% existing - {:.3}% proposed = {reduction:.3}%",
+            input.existing_rate, input.proposed_rate
+        ),
+    }
+}
+
+fn fha_combined_rate_reduction(input: &NetTangibleBenefitInput) -> NetTangibleBenefitFindingData {
+    let existing = input.existing_rate + input.existing_annual_mip_rate;
+    let proposed = input.proposed_rate + input.proposed_annual_mip_rate;
+    let reduction = existing - proposed;
+    NetTangibleBenefitFindingData {
+        rule: "Combined rate reduction".to_string(),
+        passed: reduction >= FHA_MIN_COMBINED_RATE_REDUCTION,
+        threshold: FHA_MIN_COMBINED_RATE_REDUCTION,
+        actual: reduction,
+        detail: format!(
+            "{existing:.3}% existing (rate + MIP) - {proposed:.3}% proposed = {reduction:.3}%"
+        ),
+    }
+}
+
+pub fn evaluate(program: StreamlineProgram, input: &NetTangibleBenefitInput) -> NetTangibleBenefitData {
+    let findings = match program {
+        StreamlineProgram::VaIrrrl => vec![va_recoupment(input), va_rate_reduction(input)],
+        StreamlineProgram::FhaStreamline => vec![fha_combined_rate_reduction(input)],
+    };
+
+    NetTangibleBenefitData {
+        program: program.label().to_string(),
+        passed: findings.iter().all(|finding| finding.passed),
+        findings,
+    }
+}
+
+/// Annual MIP as a percentage of the balance, backed out of the monthly premium.
+fn annual_mip_rate(monthly_premium: f64, balance: f64) -> f64 {
+    if balance > 0.0 {
+        monthly_premium * 12.0 / balance * 100.0
+    } else {
+        0.0
+    }
+}
+
+/// Lender, third-party and title fees plus discount points, less the lender credit. The
+/// funding fee, padded escrow and other prepaids are not recouped.
+pub fn recoupable_costs(data: &OptionsTemplateData) -> f64 {
+    let statement = calculate_cash_to_close(data);
+    (statement.loan_fees + statement.title_fees + statement.discount_points - data.other_fees.lender_credit).max(0.0)
+}
+
+/// Builds the test inputs from the options template. The existing side is the first lien
+/// being refinanced.
+pub fn input_from_template(data: &OptionsTemplateData) -> NetTangibleBenefitInput {
+    let first_lien = data
+        .mortgage_payoffs
+        .existing_loans
+        .iter()
+        .filter(|loan| !loan.is_subordinate)
+        .min_by_key(|loan| loan.position);
+    let (existing_rate, existing_balance) =
+        first_lien.map_or((0.0, 0.0), |loan| (loan.interest_rate, loan.loan_balance));
+
+    let benefit = &data.benefit_to_borrower;
+
+    NetTangibleBenefitInput {
+        existing_rate,
+        proposed_rate: data.new_loan.note_rate,
+        existing_annual_mip_rate: annual_mip_rate(benefit.existing_pmi, existing_balance),
+        proposed_annual_mip_rate: annual_mip_rate(benefit.proposed_pmi, data.new_loan.total_loan_amount),
+        existing_pi: benefit.existing_pi,
+        proposed_pi: benefit.proposed_pi,
+        recoupable_costs: recoupable_costs(data),
+    }
+}
+
+/// Runs the tests that apply to the template, or returns an empty result when it is
+/// not a streamline refinance.
+pub fn evaluate_template(data: &OptionsTemplateData) -> NetTangibleBenefitData {
+    StreamlineProgram::from_keys(&data.loan_information.loan_type, &data.loan_information.purpose)
+        .map(|program| evaluate(program, &input_from_template(data)))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calc::options_template::recalculate;
+    use crate::calc::rule_set::IncomeRuleSet;
+    use crate::models::{ExistingLoanData, PricingOptionData};
+
+    fn va_input() -> NetTangibleBenefitInput {
+        NetTangibleBenefitInput {
+            existing_rate: 7.0,
+            proposed_rate: 6.25,
+            existing_pi: 2_400.0,
+            proposed_pi: 2_250.0,
+            recoupable_costs: 4_500.0,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_program_from_keys() {
+        assert_eq!(StreamlineProgram::from_keys("va", "irrrl"), Some(StreamlineProgram::VaIrrrl));
+        assert_eq!(StreamlineProgram::from_keys("fha", "irrrl"), Some(StreamlineProgram::FhaStreamline));
+        assert_eq!(StreamlineProgram::from_keys("va", "refinance"), None);
+        assert_eq!(StreamlineProgram::from_keys("cnv", "irrrl"), None);
+    }
+
+    #[test]
+    fn test_va_irrrl_passes() {
+        let result = evaluate(StreamlineProgram::VaIrrrl, &va_input());
+        assert!(result.passed);
+        assert_eq!(result.program, "VA IRRRL");
+        assert_eq!(result.findings.len(), 2);
+        assert_eq!(result.findings[0].actual, 30.0);
+        assert!((result.findings[1].actual - 0.75).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_va_irrrl_recoupment_too_long() {
+        let input = NetTangibleBenefitInput {
+            recoupable_costs: 6_000.0,
+            ..va_input()
+        };
+        let result = evaluate(StreamlineProgram::VaIrrrl, &input);
+        assert!(!result.passed);
+        assert!(!result.findings[0].passed);
+        assert_eq!(result.findings[0].actual, 40.0);
+        assert!(result.findings[1].passed);
+    }
+
+    #[test]
+    fn test_va_irrrl_payment_increase_fails_recoupment() {
+        let input = NetTangibleBenefitInput {
+            proposed_pi: 2_500.0,
+            ..va_input()
+        };
+        assert!(!evaluate(StreamlineProgram::VaIrrrl, &input).findings[0].passed);
+
+        let no_costs = NetTangibleBenefitInput {
+            recoupable_costs: 0.0,
+            ..input
+        };
+        assert!(evaluate(StreamlineProgram::VaIrrrl, &no_costs).findings[0].passed);
+    }
+
+    #[test]
+    fn test_va_irrrl_rate_reduction_too_small() {
+        let input = NetTangibleBenefitInput {
+            proposed_rate: 6.625,
+            ..va_input()
+        };
+        let result = evaluate(StreamlineProgram::VaIrrrl, &input);
+        assert!(!result.passed);
+        assert!(!result.findings[1].passed);
+    }
+
+    #[test]
+    fn test_fha_combined_rate() {
+        let input = NetTangibleBenefitInput {
+            existing_rate: 6.5,
+            existing_annual_mip_rate: 0.85,
+            proposed_rate: 6.25,
+            proposed_annual_mip_rate: 0.55,
+            ..Default::default()
+        };
+        let result = evaluate(StreamlineProgram::FhaStreamline, &input);
+        assert!(result.passed);
+        assert!((result.findings[0].actual - 0.55).abs() < 1e-9);
+
+        let too_small = NetTangibleBenefitInput {
+            proposed_annual_mip_rate: 0.85,
+            ..input
+        };
+        assert!(!evaluate(StreamlineProgram::FhaStreamline, &too_small).passed);
+    }
+
+    #[test]
+    fn test_evaluate_template() {
+        let mut data = OptionsTemplateData::default();
+        assert_eq!(evaluate_template(&data), NetTangibleBenefitData::default());
+
+        data.loan_information.loan_type = "fha".to_string();
+        data.loan_information.purpose = "irrrl".to_string();
+        data.mortgage_payoffs.existing_loans = vec![ExistingLoanData {
+            position: 1,
+            loan_balance: 240_000.0,
+            interest_rate: 6.5,
+            ..Default::default()
+        }];
+        data.benefit_to_borrower.existing_pmi = 170.0;
+        data.benefit_to_borrower.proposed_pmi = 110.0;
+        data.new_loan.total_loan_amount = 240_000.0;
+        data.new_loan.note_rate = 6.25;
+
+        let input = input_from_template(&data);
+        assert!((input.existing_annual_mip_rate - 0.85).abs() < 1e-9);
+        assert!((input.proposed_annual_mip_rate - 0.55).abs() < 1e-9);
+        assert!(evaluate_template(&data).passed);
+    }
+
+    #[test]
+    fn test_va_recoupment_through_recalculate() {
+        let mut data = OptionsTemplateData::default();
+        data.loan_information.loan_type = "va".to_string();
+        data.loan_information.purpose = "irrrl".to_string();
+        data.loan_information.term_months = 360;
+        data.mortgage_payoffs.existing_loans = vec![ExistingLoanData {
+            position: 1,
+            loan_balance: 300_000.0,
+            remaining_term_months: 360,
+            interest_rate: 7.0,
+            ..Default::default()
+        }];
+        data.new_loan.base_loan_amount = 300_000.0;
+        data.pricing.pricing_options = vec![PricingOptionData {
+            note_rate: 6.0,
+            bd_percentage: 0.5,
+            ysp_percentage: 0.2,
+            is_selected: true,
+            ..Default::default()
+        }];
+        data.other_fees.admin_fees = 1_000.0;
+        data.title_fees.title_insurance = 800.0;
+        data.other_fees.padded_taxes = 1_500.0;
+        data.benefit_to_borrower.proposed_taxes = 400.0;
+        data.benefit_to_borrower.escrow_taxes = true;
+
+        recalculate(&mut data, &[], 43.0, &IncomeRuleSet::default());
+        // The IRRRL funding fee is financed but not recouped
+        assert_eq!(data.new_loan.ff_umip_amount, 1_500.0);
+        let bd_dollar = data.pricing.pricing_options[0].bd_dollar;
+        let lender_credit = data.other_fees.lender_credit;
+        let costs = 1_000.0 + 800.0 + bd_dollar - lender_credit;
+        assert!((recoupable_costs(&data) - costs).abs() < 1e-9);
+        // Total closing costs count the padded taxes; recoupment does not
+        assert!((data.other_fees.total_closing_costs - (costs + 1_500.0)).abs() < 1e-9);
+
+        let reduction = data.benefit_to_borrower.existing_pi - data.benefit_to_borrower.proposed_pi;
+        let recoupment = &data.net_tangible_benefit.findings[0];
+        assert!((recoupment.actual - costs / reduction).abs() < 1e-9);
+        assert!(data.net_tangible_benefit.passed);
+    }
+}
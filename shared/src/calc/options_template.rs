@@ -0,0 +1,433 @@
+// shared/src/calc/options_template.rs
+// Derived values for the options template. `recalculate` is run whenever a
+// section changes so every section sees figures computed from the others.
+use crate::calc::amortization::monthly_payment;
+use crate::calc::cash_to_close::calculate_cash_to_close;
+use crate::calc::dti::calculate_dti;
+use crate::calc::mip::calculate_fha_mip;
+use crate::calc::net_tangible_benefit::evaluate_template;
+use crate::calc::pmi::estimate_pmi;
+use crate::calc::pricing::{derive_point_dollars, selected_pricing_option};
+use crate::calc::residual_income::evaluate_template as evaluate_residual_income;
+use crate::calc::rule_set::IncomeRuleSet;
+use crate::calc::savings::{calculate_savings, update_payment_totals};
+use crate::calc::va_funding_fee::{VaFundingFeePurpose, VaUsage, funding_fee, funding_fee_rate};
+use crate::models::{ExistingLoanData, MiRateCardEntry, OptionsTemplateData};
+
+/// Value used for LTV. Purchases use the lesser of price and appraised value.
+pub fn property_value(data: &OptionsTemplateData) -> f64 {
+    let loan = &data.new_loan;
+    if data.loan_information.purpose == "purchase" && loan.sales_price > 0.0 {
+        if loan.market_value > 0.0 {
+            loan.sales_price.min(loan.market_value)
+        } else {
+            loan.sales_price
+        }
+    } else {
+        loan.market_value
+    }
+}
+
+/// Base loan amount over the property value, as a percentage.
+pub fn base_ltv(data: &OptionsTemplateData) -> f64 {
+    let value = property_value(data);
+    if value > 0.0 {
+        data.new_loan.base_loan_amount / value * 100.0
+    } else {
+        0.0
+    }
+}
+
+/// P&I on an existing lien from its balance, rate and remaining term. Falls back to
+/// the payment that was typed in when the loan terms are incomplete.
+pub fn existing_loan_pi(loan: &ExistingLoanData) -> f64 {
+    if loan.loan_balance > 0.0 && loan.remaining_term_months > 0 {
+        monthly_payment(loan.loan_balance, loan.interest_rate, loan.remaining_term_months)
+    } else {
+        loan.monthly_payment
+    }
+}
+
+/// Prices conventional MI from the rate card. When the card has no matching row the
+/// typed-in PMI is left alone.
+fn apply_conventional_pmi(data: &mut OptionsTemplateData, mi_rate_card: &[MiRateCardEntry]) {
+    data.new_loan.mi_coverage = 0.0;
+    data.new_loan.mi_annual_rate = 0.0;
+    data.new_loan.pmi_termination_month = 0;
+
+    if let Some(estimate) = estimate_pmi(
+        mi_rate_card,
+        data.new_loan.total_loan_amount,
+        data.new_loan.note_rate,
+        data.loan_information.term_months,
+        property_value(data),
+        data.loan_information.credit_score,
+    ) {
+        data.new_loan.mi_coverage = estimate.coverage;
+        data.new_loan.mi_annual_rate = estimate.annual_rate;
+        data.new_loan.pmi_termination_month = estimate.termination_month;
+        data.benefit_to_borrower.proposed_pmi = estimate.monthly_pmi;
+    }
+}
+
+/// `default_max_dti` is the configured back-end limit for programs without their own, and
+/// `custom_rules` the configured custom income rule set.
+pub fn recalculate(
+    data: &mut OptionsTemplateData,
+    mi_rate_card: &[MiRateCardEntry],
+    default_max_dti: f64,
+    custom_rules: &IncomeRuleSet,
+) {
+    let is_purchase = data.loan_information.purpose == "purchase";
+
+    // The selected pricing option sets the note rate
+    if let Some(rate) = selected_pricing_option(data)
+        .map(|option| option.note_rate)
+        .filter(|rate| *rate > 0.0)
+    {
+        data.new_loan.note_rate = rate;
+    }
+
+    data.new_loan.down_payment_percent = if data.new_loan.sales_price > 0.0 {
+        data.new_loan.down_payment / data.new_loan.sales_price * 100.0
+    } else {
+        0.0
+    };
+
+    // Financed upfront premiums depend on the loan type
+    let financed_premium = match data.loan_information.loan_type.as_str() {
+        "fha" => {
+            let (prior_ufmip, months_since_endorsement) = if is_purchase {
+                (0.0, 0)
+            } else {
+                (data.new_loan.prior_ufmip, data.new_loan.months_since_endorsement)
+            };
+            let mip = calculate_fha_mip(
+                data.new_loan.base_loan_amount,
+                base_ltv(data),
+                data.loan_information.term_months,
+                prior_ufmip,
+                months_since_endorsement,
+            );
+            data.new_loan.ff_umip_percentage = mip.upfront_rate;
+            data.new_loan.ff_umip_amount = mip.upfront_mip;
+            data.new_loan.umip_refund = mip.refund;
+            data.benefit_to_borrower.proposed_pmi = mip.monthly_mip;
+            mip.financed_mip
+        }
+        "va" => {
+            let rate = funding_fee_rate(
+                VaFundingFeePurpose::from_key(&data.loan_information.purpose),
+                VaUsage::from_subsequent(data.new_loan.va_subsequent_use),
+                data.new_loan.down_payment_percent,
+                data.new_loan.va_funding_fee_exempt,
+            );
+            data.new_loan.ff_umip_percentage = rate;
+            data.new_loan.ff_umip_amount = funding_fee(data.new_loan.base_loan_amount, rate);
+            data.new_loan.umip_refund = 0.0;
+            data.new_loan.ff_umip_amount
+        }
+        _ => {
+            data.new_loan.ff_umip_percentage = 0.0;
+            data.new_loan.ff_umip_amount = 0.0;
+            data.new_loan.umip_refund = 0.0;
+            0.0
+        }
+    };
+
+    data.new_loan.total_loan_amount = data.new_loan.base_loan_amount + financed_premium;
+
+    if data.loan_information.loan_type == "cnv" {
+        apply_conventional_pmi(data, mi_rate_card);
+    }
+
+    // Points are priced on the total loan amount, and the selected option's YSP is the
+    // lender credit
+    derive_point_dollars(&mut data.pricing, data.new_loan.total_loan_amount);
+    if let Some(credit) = selected_pricing_option(data).map(|option| option.ysp_dollar) {
+        data.other_fees.lender_credit = credit;
+    }
+
+    // Closing costs and cash out come from the closing statement, not typed-in totals
+    let statement = calculate_cash_to_close(data);
+    data.other_fees.total_closing_costs = statement.total_closing_costs;
+    data.other_fees.cash_out_amount = statement.cash_back();
+
+    let existing_loans = if is_purchase {
+        &[][..]
+    } else {
+        &data.mortgage_payoffs.existing_loans[..]
+    };
+
+    if !data.benefit_to_borrower.existing_pi_override {
+        data.benefit_to_borrower.existing_pi = existing_loans.iter().map(existing_loan_pi).sum();
+    }
+
+    if !data.benefit_to_borrower.proposed_pi_override {
+        // Subordinated liens stay in place, so their payments carry over to the proposed side
+        let subordinate_pi: f64 = existing_loans
+            .iter()
+            .filter(|loan| loan.is_subordinate)
+            .map(existing_loan_pi)
+            .sum();
+        data.benefit_to_borrower.proposed_pi = monthly_payment(
+            data.new_loan.total_loan_amount,
+            data.new_loan.note_rate,
+            data.loan_information.term_months,
+        ) + subordinate_pi;
+    }
+
+    update_payment_totals(&mut data.benefit_to_borrower, is_purchase);
+    data.debt_to_income = calculate_dti(data, default_max_dti, custom_rules);
+    data.residual_income = evaluate_residual_income(data);
+
+    data.savings = if is_purchase {
+        Default::default()
+    } else {
+        calculate_savings(&data.benefit_to_borrower, &data.consumer_debt, &data.other_fees)
+    };
+
+    data.net_tangible_benefit = evaluate_template(data);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::PricingOptionData;
+
+    fn refinance_template() -> OptionsTemplateData {
+        let mut data = OptionsTemplateData::default();
+        data.loan_information.purpose = "refinance".to_string();
+        data.loan_information.term_months = 360;
+        data.new_loan.base_loan_amount = 200_000.0;
+        data.new_loan.note_rate = 6.0;
+        data.mortgage_payoffs.existing_loans = vec![ExistingLoanData {
+            position: 1,
+            loan_balance: 300_000.0,
+            monthly_payment: 2_500.0,
+            remaining_term_months: 360,
+            interest_rate: 7.0,
+            is_subordinate: false,
+        }];
+        data
+    }
+
+    #[test]
+    fn test_recalculate_fills_pi() {
+        let mut data = refinance_template();
+        recalculate(&mut data, &[], 43.0, &IncomeRuleSet::default());
+        assert_eq!(data.new_loan.total_loan_amount, 200_000.0);
+        assert_eq!(data.benefit_to_borrower.proposed_pi, 1199.10);
+        assert_eq!(data.benefit_to_borrower.existing_pi, 1995.91);
+    }
+
+    #[test]
+    fn test_recalculate_respects_override() {
+        let mut data = refinance_template();
+        data.benefit_to_borrower.proposed_pi_override = true;
+        data.benefit_to_borrower.proposed_pi = 1_000.0;
+        data.benefit_to_borrower.existing_pi_override = true;
+        data.benefit_to_borrower.existing_pi = 2_000.0;
+        recalculate(&mut data, &[], 43.0, &IncomeRuleSet::default());
+        assert_eq!(data.benefit_to_borrower.proposed_pi, 1_000.0);
+        assert_eq!(data.benefit_to_borrower.existing_pi, 2_000.0);
+    }
+
+    #[test]
+    fn test_recalculate_uses_selected_rate_and_subordinate_liens() {
+        let mut data = refinance_template();
+        data.pricing.pricing_options = vec![
+            PricingOptionData {
+                note_rate: 6.5,
+                ..Default::default()
+            },
+            PricingOptionData {
+                note_rate: 6.0,
+                is_selected: true,
+                ..Default::default()
+            },
+        ];
+        data.new_loan.note_rate = 0.0;
+        data.mortgage_payoffs.existing_loans.push(ExistingLoanData {
+            position: 2,
+            loan_balance: 0.0,
+            monthly_payment: 150.0,
+            remaining_term_months: 0,
+            interest_rate: 0.0,
+            is_subordinate: true,
+        });
+        recalculate(&mut data, &[], 43.0, &IncomeRuleSet::default());
+        assert_eq!(data.new_loan.note_rate, 6.0);
+        assert_eq!(data.benefit_to_borrower.existing_pi, 1995.91 + 150.0);
+        assert_eq!(data.benefit_to_borrower.proposed_pi, 1199.10 + 150.0);
+    }
+
+    #[test]
+    fn test_recalculate_prices_points_from_selected_option() {
+        let mut data = refinance_template();
+        data.other_fees.lender_credit = 500.0;
+        data.pricing.pricing_options = vec![
+            PricingOptionData {
+                note_rate: 6.25,
+                ysp_percentage: 0.75,
+                bd_percentage: 0.0,
+                is_selected: true,
+                ..Default::default()
+            },
+            PricingOptionData {
+                note_rate: 5.75,
+                bd_percentage: 1.25,
+                ..Default::default()
+            },
+        ];
+        recalculate(&mut data, &[], 43.0, &IncomeRuleSet::default());
+        assert_eq!(data.pricing.pricing_options[0].ysp_dollar, 1_500.0);
+        assert_eq!(data.pricing.pricing_options[1].bd_dollar, 2_500.0);
+        assert_eq!(data.other_fees.lender_credit, 1_500.0);
+
+        // Without a selected option the typed-in credit stays
+        data.pricing.pricing_options[0].is_selected = false;
+        data.other_fees.lender_credit = 500.0;
+        recalculate(&mut data, &[], 43.0, &IncomeRuleSet::default());
+        assert_eq!(data.other_fees.lender_credit, 500.0);
+    }
+
+    #[test]
+    fn test_recalculate_purchase_has_no_existing_pi() {
+        let mut data = refinance_template();
+        data.loan_information.purpose = "purchase".to_string();
+        recalculate(&mut data, &[], 43.0, &IncomeRuleSet::default());
+        assert_eq!(data.benefit_to_borrower.existing_pi, 0.0);
+        assert_eq!(data.benefit_to_borrower.proposed_pi, 1199.10);
+        assert_eq!(data.savings, Default::default());
+    }
+
+    #[test]
+    fn test_recalculate_fha_mip() {
+        let mut data = refinance_template();
+        data.loan_information.loan_type = "fha".to_string();
+        data.new_loan.market_value = 250_000.0;
+        data.new_loan.prior_ufmip = 4_000.0;
+        data.new_loan.months_since_endorsement = 24;
+        recalculate(&mut data, &[], 43.0, &IncomeRuleSet::default());
+        assert_eq!(base_ltv(&data), 80.0);
+        assert_eq!(data.new_loan.ff_umip_percentage, 1.75);
+        assert_eq!(data.new_loan.ff_umip_amount, 3_500.0);
+        assert_eq!(data.new_loan.umip_refund, 1_360.0);
+        assert_eq!(data.new_loan.total_loan_amount, 202_140.0);
+        assert_eq!(data.benefit_to_borrower.proposed_pmi, 83.33);
+    }
+
+    #[test]
+    fn test_recalculate_va_funding_fee() {
+        let mut data = refinance_template();
+        data.loan_information.loan_type = "va".to_string();
+        data.loan_information.purpose = "purchase".to_string();
+        data.new_loan.sales_price = 210_000.0;
+        data.new_loan.down_payment = 10_500.0;
+        recalculate(&mut data, &[], 43.0, &IncomeRuleSet::default());
+        assert_eq!(data.new_loan.down_payment_percent, 5.0);
+        assert_eq!(data.new_loan.ff_umip_percentage, 1.50);
+        assert_eq!(data.new_loan.total_loan_amount, 203_000.0);
+
+        data.new_loan.va_funding_fee_exempt = true;
+        recalculate(&mut data, &[], 43.0, &IncomeRuleSet::default());
+        assert_eq!(data.new_loan.ff_umip_amount, 0.0);
+        assert_eq!(data.new_loan.total_loan_amount, 200_000.0);
+    }
+
+    #[test]
+    fn test_recalculate_conventional_pmi() {
+        let rate_card = vec![MiRateCardEntry {
+            ltv_min: 90.0,
+            ltv_max: 95.0,
+            credit_score_min: 740,
+            credit_score_max: 850,
+            coverage: 30.0,
+            term_min_months: 241,
+            term_max_months: 480,
+            annual_rate: 0.41,
+            ..Default::default()
+        }];
+        let mut data = refinance_template();
+        data.loan_information.loan_type = "cnv".to_string();
+        data.loan_information.credit_score = 760;
+        data.new_loan.market_value = 212_000.0;
+        data.benefit_to_borrower.proposed_pmi = 55.0;
+
+        recalculate(&mut data, &rate_card, 43.0, &IncomeRuleSet::default());
+        assert_eq!(data.new_loan.mi_coverage, 30.0);
+        assert_eq!(data.benefit_to_borrower.proposed_pmi, 68.33);
+        assert!(data.new_loan.pmi_termination_month > 0);
+
+        // No matching row keeps the typed-in figure
+        data.loan_information.credit_score = 700;
+        data.benefit_to_borrower.proposed_pmi = 55.0;
+        recalculate(&mut data, &rate_card, 43.0, &IncomeRuleSet::default());
+        assert_eq!(data.benefit_to_borrower.proposed_pmi, 55.0);
+        assert_eq!(data.new_loan.mi_coverage, 0.0);
+    }
+
+    #[test]
+    fn test_base_ltv_purchase_uses_lesser_value() {
+        let mut data = OptionsTemplateData::default();
+        data.loan_information.purpose = "purchase".to_string();
+        data.new_loan.sales_price = 400_000.0;
+        data.new_loan.market_value = 380_000.0;
+        data.new_loan.base_loan_amount = 342_000.0;
+        assert_eq!(base_ltv(&data), 90.0);
+    }
+
+    #[test]
+    fn test_recalculate_derives_savings() {
+        let mut data = refinance_template();
+        data.other_fees.third_party_fees = 3_000.0;
+        recalculate(&mut data, &[], 43.0, &IncomeRuleSet::default());
+        assert_eq!(data.other_fees.total_closing_costs, 3_000.0);
+        let expected_savings = 1995.91 - 1199.10;
+        assert!((data.savings.payment_reduction - expected_savings).abs() < 0.001);
+        assert!((data.savings.recoup_period_months - 3_000.0 / expected_savings).abs() < 0.001);
+        assert_eq!(
+            data.benefit_to_borrower.existing_total_obligations,
+            data.benefit_to_borrower.existing_pi
+        );
+    }
+
+    #[test]
+    fn test_recalculate_derives_cash_out() {
+        let mut data = refinance_template();
+        data.loan_information.purpose = "cashOut".to_string();
+        data.new_loan.base_loan_amount = 320_000.0;
+        data.title_fees.title_insurance = 1_500.0;
+        data.other_fees.padded_taxes = 500.0;
+        recalculate(&mut data, &[], 43.0, &IncomeRuleSet::default());
+        assert_eq!(data.other_fees.total_closing_costs, 2_000.0);
+        assert_eq!(data.other_fees.cash_out_amount, 18_000.0);
+    }
+
+    #[test]
+    fn test_recalculate_purchase_has_no_cash_out() {
+        let mut data = refinance_template();
+        data.loan_information.purpose = "purchase".to_string();
+        data.new_loan.sales_price = 250_000.0;
+        data.title_fees.title_insurance = 1_500.0;
+        data.other_fees.padded_taxes = 500.0;
+        recalculate(&mut data, &[], 43.0, &IncomeRuleSet::default());
+        assert_eq!(data.other_fees.total_closing_costs, 2_000.0);
+        // The borrower brings $52,000, which is not cash out
+        assert_eq!(calculate_cash_to_close(&data).cash_to_close, 52_000.0);
+        assert_eq!(data.other_fees.cash_out_amount, 0.0);
+    }
+
+    #[test]
+    fn test_recalculate_fills_dti() {
+        let mut data = refinance_template();
+        data.loan_information.loan_type = "cnv".to_string();
+        data.income_information.borrower_monthly_income = 4_000.0;
+        recalculate(&mut data, &[], 43.0, &IncomeRuleSet::default());
+        assert_eq!(data.debt_to_income.housing_payment, 1199.10);
+        assert!((data.debt_to_income.back_end_ratio - 29.9775).abs() < 1e-9);
+        assert_eq!(data.debt_to_income.max_back_end_ratio, Some(45.0));
+        assert!(data.debt_to_income.within_limits);
+    }
+}
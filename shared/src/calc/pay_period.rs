@@ -0,0 +1,133 @@
+// shared/src/calc/pay_period.rs
+// Months and pay periods covered by a YTD pay stub, worked out from its period-end
+// date instead of a hand-typed month count. YTD runs from January 1, or from the hire
+// date when the borrower started during the year.
+use chrono::{Datelike, Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::calc::income::PayFrequency;
+
+/// Parses a date from an `<input type="date">` value. Empty or invalid input is `None`.
+pub fn parse_date(s: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(s.trim(), "%Y-%m-%d").ok()
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct YtdPeriod {
+    pub start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub months: f64,
+    pub pay_periods: f64,
+}
+
+/// Calendar months from `start` through `end` inclusive. Whole months are counted
+/// first; the days left over are a fraction of the month that follows.
+pub fn months_covered(start: NaiveDate, end: NaiveDate) -> f64 {
+    let Some(end_exclusive) = end.succ_opt() else {
+        return 0.0;
+    };
+    if end_exclusive <= start {
+        return 0.0;
+    }
+
+    let mut whole = 0;
+    while start
+        .checked_add_months(Months::new(whole + 1))
+        .is_some_and(|next| next <= end_exclusive)
+    {
+        whole += 1;
+    }
+
+    let month_start = start + Months::new(whole);
+    let month_end = month_start + Months::new(1);
+    let remaining = (end_exclusive - month_start).num_days() as f64;
+    let days_in_month = (month_end - month_start).num_days() as f64;
+    whole as f64 + remaining / days_in_month
+}
+
+/// The YTD period on a pay stub ending `period_end`. A hire date after the stub's
+/// period end leaves nothing covered.
+pub fn ytd_period(period_end: NaiveDate, frequency: PayFrequency, hire_date: Option<NaiveDate>) -> Option<YtdPeriod> {
+    let year_start = NaiveDate::from_ymd_opt(period_end.year(), 1, 1)?;
+    let start = hire_date.map_or(year_start, |hired| hired.max(year_start));
+    if start > period_end {
+        return None;
+    }
+
+    let months = months_covered(start, period_end);
+    let days = ((period_end - start).num_days() + 1) as f64;
+    let pay_periods = match frequency {
+        PayFrequency::Weekly => days / 7.0,
+        PayFrequency::BiWeekly => days / 14.0,
+        PayFrequency::SemiMonthly => months * 2.0,
+        PayFrequency::Monthly => months,
+    };
+
+    Some(YtdPeriod {
+        start,
+        period_end,
+        months,
+        pay_periods,
+    })
+}
+
+/// Months for the YTD averages from the worksheet's stub fields. `None` until a
+/// period-end date is entered, or when the hire date falls after it. The months do not
+/// depend on the pay frequency.
+pub fn ytd_months(period_end: &str, hire_date: &str) -> Option<f64> {
+    let end = parse_date(period_end)?;
+    ytd_period(end, PayFrequency::Monthly, parse_date(hire_date)).map(|period| period.months)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_months_covered() {
+        assert_eq!(months_covered(date(2026, 1, 1), date(2026, 6, 30)), 6.0);
+        assert_eq!(months_covered(date(2026, 1, 1), date(2026, 6, 15)), 5.5);
+        assert_eq!(months_covered(date(2026, 1, 1), date(2026, 12, 31)), 12.0);
+        assert_eq!(months_covered(date(2026, 3, 1), date(2026, 2, 28)), 0.0);
+    }
+
+    #[test]
+    fn test_ytd_period_by_frequency() {
+        let period = ytd_period(date(2026, 3, 31), PayFrequency::Monthly, None).unwrap();
+        assert_eq!(period.start, date(2026, 1, 1));
+        assert_eq!((period.months, period.pay_periods), (3.0, 3.0));
+
+        // 90 days through March 31
+        let weekly = ytd_period(date(2026, 3, 31), PayFrequency::Weekly, None).unwrap();
+        assert!((weekly.pay_periods - 90.0 / 7.0).abs() < 1e-9);
+        let bi_weekly = ytd_period(date(2026, 3, 31), PayFrequency::BiWeekly, None).unwrap();
+        assert!((bi_weekly.pay_periods - 90.0 / 14.0).abs() < 1e-9);
+        let semi_monthly = ytd_period(date(2026, 3, 31), PayFrequency::SemiMonthly, None).unwrap();
+        assert_eq!(semi_monthly.pay_periods, 6.0);
+    }
+
+    #[test]
+    fn test_hire_date_during_year() {
+        // Hired March 16: March 16 to June 15 is three months, then half of the next
+        let period = ytd_period(date(2026, 6, 30), PayFrequency::Monthly, Some(date(2026, 3, 16))).unwrap();
+        assert_eq!(period.start, date(2026, 3, 16));
+        assert!((period.months - (3.0 + 15.0 / 30.0)).abs() < 1e-9);
+
+        // Hired in a prior year, so YTD still starts January 1
+        let period = ytd_period(date(2026, 6, 30), PayFrequency::Monthly, Some(date(2019, 5, 1))).unwrap();
+        assert_eq!(period.months, 6.0);
+
+        assert_eq!(ytd_period(date(2026, 6, 30), PayFrequency::Monthly, Some(date(2026, 7, 1))), None);
+    }
+
+    #[test]
+    fn test_ytd_months_from_fields() {
+        assert_eq!(ytd_months("2026-06-30", ""), Some(6.0));
+        assert_eq!(ytd_months("2026-06-30", "2026-03-01"), Some(4.0));
+        assert_eq!(ytd_months("", ""), None);
+    }
+}
@@ -0,0 +1,242 @@
+// shared/src/calc/pmi.rs
+// Conventional private mortgage insurance priced from a configurable rate card.
+use crate::calc::amortization::{build_schedule, round_cents};
+use crate::models::MiRateCardEntry;
+
+/// LTV at which borrower-paid MI terminates automatically.
+pub const AUTOMATIC_TERMINATION_LTV: f64 = 78.0;
+/// Loans at or below this LTV carry no MI.
+pub const MI_REQUIRED_ABOVE_LTV: f64 = 80.0;
+
+/// Column order for rate card CSV import and export.
+pub const RATE_CARD_CSV_HEADER: &str =
+    "ltv_min,ltv_max,credit_score_min,credit_score_max,coverage,term_min_months,term_max_months,annual_rate";
+
+/// Standard agency coverage for the LTV and term, as a percentage.
+pub fn standard_coverage(ltv: f64, term_months: u32) -> f64 {
+    let long_term = term_months > 240;
+    if ltv <= MI_REQUIRED_ABOVE_LTV {
+        0.0
+    } else if ltv <= 85.0 {
+        if long_term { 12.0 } else { 6.0 }
+    } else if ltv <= 90.0 {
+        if long_term { 25.0 } else { 12.0 }
+    } else if ltv <= 95.0 {
+        if long_term { 30.0 } else { 25.0 }
+    } else {
+        35.0
+    }
+}
+
+/// Annual MI rate from the first matching rate card row.
+pub fn lookup_rate(
+    rate_card: &[MiRateCardEntry],
+    ltv: f64,
+    credit_score: u32,
+    coverage: f64,
+    term_months: u32,
+) -> Option<f64> {
+    rate_card
+        .iter()
+        .find(|entry| {
+            ltv > entry.ltv_min
+                && ltv <= entry.ltv_max
+                && (entry.credit_score_min..=entry.credit_score_max).contains(&credit_score)
+                && (entry.coverage - coverage).abs() < 0.001
+                && (entry.term_min_months..=entry.term_max_months).contains(&term_months)
+        })
+        .map(|entry| entry.annual_rate)
+}
+
+pub fn monthly_pmi(loan_amount: f64, annual_rate: f64) -> f64 {
+    round_cents(loan_amount.max(0.0) * annual_rate / 100.0 / 12.0)
+}
+
+/// Month in which MI drops off automatically: the scheduled balance reaching 78% of
+/// the original value, or the midpoint of the term if that comes first.
+pub fn termination_month(loan_amount: f64, note_rate: f64, term_months: u32, original_value: f64) -> u32 {
+    let midpoint = term_months.div_ceil(2);
+    if original_value <= 0.0 {
+        return midpoint;
+    }
+
+    let target = original_value * AUTOMATIC_TERMINATION_LTV / 100.0;
+    build_schedule(loan_amount, note_rate, term_months)
+        .rows
+        .iter()
+        .find(|row| row.balance <= target)
+        .map_or(midpoint, |row| row.month.min(midpoint))
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PmiEstimate {
+    pub coverage: f64,
+    pub annual_rate: f64,
+    pub monthly_pmi: f64,
+    pub termination_month: u32,
+}
+
+/// Prices MI for a conventional loan. Returns a zero estimate when the LTV does not
+/// require MI and `None` when the rate card has no matching row.
+pub fn estimate_pmi(
+    rate_card: &[MiRateCardEntry],
+    loan_amount: f64,
+    note_rate: f64,
+    term_months: u32,
+    original_value: f64,
+    credit_score: u32,
+) -> Option<PmiEstimate> {
+    if original_value <= 0.0 {
+        return None;
+    }
+
+    let ltv = loan_amount / original_value * 100.0;
+    if ltv <= MI_REQUIRED_ABOVE_LTV {
+        return Some(PmiEstimate::default());
+    }
+
+    let coverage = standard_coverage(ltv, term_months);
+    let annual_rate = lookup_rate(rate_card, ltv, credit_score, coverage, term_months)?;
+
+    Some(PmiEstimate {
+        coverage,
+        annual_rate,
+        monthly_pmi: monthly_pmi(loan_amount, annual_rate),
+        termination_month: termination_month(loan_amount, note_rate, term_months, original_value),
+    })
+}
+
+/// Parses rate card rows from CSV. A header row matching `RATE_CARD_CSV_HEADER` and
+/// blank lines are skipped; errors name the offending line.
+pub fn parse_rate_card_csv(csv: &str) -> Result<Vec<MiRateCardEntry>, String> {
+    let mut entries = Vec::new();
+
+    for (index, line) in csv.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.replace(' ', "") == RATE_CARD_CSV_HEADER {
+            continue;
+        }
+
+        let line_number = index + 1;
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        if fields.len() != 8 {
+            return Err(format!("Line {line_number}: expected 8 columns, found {}", fields.len()));
+        }
+
+        let number = |position: usize| {
+            fields[position]
+                .parse::<f64>()
+                .map_err(|_| format!("Line {line_number}: '{}' is not a number", fields[position]))
+        };
+        let whole = |position: usize| {
+            fields[position]
+                .parse::<u32>()
+                .map_err(|_| format!("Line {line_number}: '{}' is not a whole number", fields[position]))
+        };
+
+        entries.push(MiRateCardEntry {
+            id: 0,
+            ltv_min: number(0)?,
+            ltv_max: number(1)?,
+            credit_score_min: whole(2)?,
+            credit_score_max: whole(3)?,
+            coverage: number(4)?,
+            term_min_months: whole(5)?,
+            term_max_months: whole(6)?,
+            annual_rate: number(7)?,
+        });
+    }
+
+    Ok(entries)
+}
+
+pub fn rate_card_to_csv(rate_card: &[MiRateCardEntry]) -> String {
+    let mut csv = String::from(RATE_CARD_CSV_HEADER);
+    for entry in rate_card {
+        csv.push_str(&format!(
+            "\n{},{},{},{},{},{},{},{}",
+            entry.ltv_min,
+            entry.ltv_max,
+            entry.credit_score_min,
+            entry.credit_score_max,
+            entry.coverage,
+            entry.term_min_months,
+            entry.term_max_months,
+            entry.annual_rate
+        ));
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rate_card() -> Vec<MiRateCardEntry> {
+        parse_rate_card_csv(
+            "ltv_min,ltv_max,credit_score_min,credit_score_max,coverage,term_min_months,term_max_months,annual_rate
+             90,95,740,850,30,241,480,0.41
+             90,95,680,739,30,241,480,0.62
+             85,90,740,850,25,241,480,0.30",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_standard_coverage() {
+        assert_eq!(standard_coverage(80.0, 360), 0.0);
+        assert_eq!(standard_coverage(83.0, 360), 12.0);
+        assert_eq!(standard_coverage(83.0, 180), 6.0);
+        assert_eq!(standard_coverage(90.0, 360), 25.0);
+        assert_eq!(standard_coverage(95.0, 360), 30.0);
+        assert_eq!(standard_coverage(97.0, 360), 35.0);
+    }
+
+    #[test]
+    fn test_lookup_rate() {
+        let card = rate_card();
+        assert_eq!(lookup_rate(&card, 95.0, 760, 30.0, 360), Some(0.41));
+        assert_eq!(lookup_rate(&card, 92.0, 700, 30.0, 360), Some(0.62));
+        assert_eq!(lookup_rate(&card, 90.0, 760, 25.0, 360), Some(0.30));
+        assert_eq!(lookup_rate(&card, 92.0, 650, 30.0, 360), None);
+        assert_eq!(lookup_rate(&card, 92.0, 760, 30.0, 180), None);
+    }
+
+    #[test]
+    fn test_estimate_pmi() {
+        let estimate = estimate_pmi(&rate_card(), 285_000.0, 6.5, 360, 300_000.0, 760).unwrap();
+        assert_eq!(estimate.coverage, 30.0);
+        assert_eq!(estimate.annual_rate, 0.41);
+        assert_eq!(estimate.monthly_pmi, 97.38);
+
+        let schedule = build_schedule(285_000.0, 6.5, 360);
+        let month = estimate.termination_month;
+        assert!(schedule.balance_after(month) <= 234_000.0);
+        assert!(schedule.balance_after(month - 1) > 234_000.0);
+
+        assert_eq!(
+            estimate_pmi(&rate_card(), 240_000.0, 6.5, 360, 300_000.0, 760),
+            Some(PmiEstimate::default())
+        );
+        assert_eq!(estimate_pmi(&rate_card(), 285_000.0, 6.5, 360, 300_000.0, 600), None);
+    }
+
+    #[test]
+    fn test_termination_capped_at_midpoint() {
+        // $808.33/month of principal reaches $234,000 in month 71
+        assert_eq!(termination_month(291_000.0, 0.0, 360, 300_000.0), 71);
+        // At 12% the balance is still above 78% of value at the midpoint
+        assert_eq!(termination_month(291_000.0, 12.0, 360, 300_000.0), 180);
+    }
+
+    #[test]
+    fn test_csv_round_trip_and_errors() {
+        let card = rate_card();
+        assert_eq!(parse_rate_card_csv(&rate_card_to_csv(&card)).unwrap(), card);
+        assert_eq!(
+            parse_rate_card_csv("90,95,740,850,30,241,480").unwrap_err(),
+            "Line 1: expected 8 columns, found 7"
+        );
+        assert!(parse_rate_card_csv("\n90,95,abc,850,30,241,480,0.4").unwrap_err().starts_with("Line 2:"));
+    }
+}
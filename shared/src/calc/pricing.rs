@@ -0,0 +1,140 @@
+// shared/src/calc/pricing.rs
+// Rate options on the options template. YSP is the lender credit an option pays and BD
+// the discount points it costs, both as a percentage of the total loan amount. Each
+// option is compared with the selected one: the P&I difference, the difference in
+// net cost, and how long the cheaper payment takes to repay the extra points.
+use serde::{Deserialize, Serialize};
+
+use crate::calc::amortization::{monthly_payment, round_cents};
+use crate::models::{OptionsTemplateData, PricingData, PricingOptionData};
+
+/// The pricing option marked as selected, if any.
+pub fn selected_pricing_option(data: &OptionsTemplateData) -> Option<&PricingOptionData> {
+    data.pricing.pricing_options.iter().find(|option| option.is_selected)
+}
+
+/// Dollar amount of `percentage` points on `loan_amount`.
+pub fn point_dollars(percentage: f64, loan_amount: f64) -> f64 {
+    round_cents(percentage / 100.0 * loan_amount)
+}
+
+/// Fills in each option's YSP and BD dollars from its percentages.
+pub fn derive_point_dollars(pricing: &mut PricingData, loan_amount: f64) {
+    for option in &mut pricing.pricing_options {
+        option.ysp_dollar = point_dollars(option.ysp_percentage, loan_amount);
+        option.bd_dollar = point_dollars(option.bd_percentage, loan_amount);
+    }
+}
+
+/// Points paid less lender credit. Negative when the option is a net credit.
+pub fn net_cost(option: &PricingOptionData) -> f64 {
+    round_cents(option.bd_dollar - option.ysp_dollar)
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PricingComparison {
+    pub monthly_pi: f64,
+    pub net_cost: f64,
+    // Against the selected option; `None` when no option is selected
+    pub payment_difference: Option<f64>,
+    pub cost_difference: Option<f64>,
+    // Months for the lower payment to repay the higher cost. `None` when one option is
+    // both cheaper and lower, or when the two are the same.
+    pub break_even_months: Option<f64>,
+}
+
+/// Compares every option with the selected one, in the same order as the options.
+/// Dollar amounts are taken as already derived.
+pub fn compare_options(pricing: &PricingData, loan_amount: f64, term_months: u32) -> Vec<PricingComparison> {
+    let pi = |option: &PricingOptionData| monthly_payment(loan_amount, option.note_rate, term_months);
+    let selected = pricing
+        .pricing_options
+        .iter()
+        .find(|option| option.is_selected)
+        .map(|option| (pi(option), net_cost(option)));
+
+    pricing
+        .pricing_options
+        .iter()
+        .map(|option| {
+            let monthly_pi = pi(option);
+            let net_cost = net_cost(option);
+            let differences = selected.map(|(selected_pi, selected_cost)| {
+                (round_cents(monthly_pi - selected_pi), round_cents(net_cost - selected_cost))
+            });
+            let break_even_months = differences
+                .filter(|(payment, cost)| payment * cost < 0.0)
+                .map(|(payment, cost)| (cost / payment).abs());
+
+            PricingComparison {
+                monthly_pi,
+                net_cost,
+                payment_difference: differences.map(|(payment, _)| payment),
+                cost_difference: differences.map(|(_, cost)| cost),
+                break_even_months,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn option(note_rate: f64, ysp_percentage: f64, bd_percentage: f64, is_selected: bool) -> PricingOptionData {
+        PricingOptionData {
+            note_rate,
+            ysp_percentage,
+            bd_percentage,
+            is_selected,
+            ..Default::default()
+        }
+    }
+
+    fn pricing() -> PricingData {
+        let mut pricing = PricingData {
+            pricing_options: vec![
+                option(6.0, 0.0, 1.0, false),
+                option(6.5, 0.0, 0.0, true),
+                option(7.0, 0.5, 0.0, false),
+            ],
+        };
+        derive_point_dollars(&mut pricing, 300_000.0);
+        pricing
+    }
+
+    #[test]
+    fn test_point_dollars_from_percentages() {
+        let pricing = pricing();
+        assert_eq!(pricing.pricing_options[0].bd_dollar, 3_000.0);
+        assert_eq!(pricing.pricing_options[2].ysp_dollar, 1_500.0);
+        assert_eq!(net_cost(&pricing.pricing_options[0]), 3_000.0);
+        assert_eq!(net_cost(&pricing.pricing_options[2]), -1_500.0);
+    }
+
+    #[test]
+    fn test_break_even_against_selected() {
+        let comparisons = compare_options(&pricing(), 300_000.0, 360);
+        assert_eq!(comparisons[1].monthly_pi, 1_896.2);
+        assert_eq!(comparisons[1].payment_difference, Some(0.0));
+        assert_eq!(comparisons[1].break_even_months, None);
+
+        // Buying down to 6%: $3,000 for $97.55 a month
+        assert_eq!(comparisons[0].payment_difference, Some(-97.55));
+        assert_eq!(comparisons[0].cost_difference, Some(3_000.0));
+        assert!((comparisons[0].break_even_months.unwrap() - 30.75).abs() < 0.01);
+
+        // Taking the credit at 7%: the selected option repays its extra cost
+        assert_eq!(comparisons[2].cost_difference, Some(-1_500.0));
+        assert!(comparisons[2].break_even_months.unwrap() > 0.0);
+    }
+
+    #[test]
+    fn test_no_selection_has_no_differences() {
+        let mut pricing = pricing();
+        pricing.pricing_options[1].is_selected = false;
+        let comparisons = compare_options(&pricing, 300_000.0, 360);
+        assert!(comparisons.iter().all(|comparison| comparison.payment_difference.is_none()));
+        assert_eq!(comparisons[0].net_cost, 3_000.0);
+    }
+}
@@ -0,0 +1,185 @@
+// shared/src/calc/rental.rs
+// Rental income by the Schedule E or lease method, in the style of Fannie Mae Form
+// 1038. The subject property's rent is income against its own housing payment; a
+// property already owned nets its rent against its PITIA, and the result is income
+// when positive and a monthly debt when negative.
+use serde::{Deserialize, Serialize};
+
+use crate::calc::amortization::round_cents;
+use crate::calc::rule_set::IncomeRuleSet;
+use crate::models::{RentalIncomeData, RentalProperty};
+
+/// Share of gross lease rent counted, leaving 25% for vacancy and maintenance. Rule sets
+/// may use their own vacancy factor.
+pub const LEASE_GROSS_RENT_FACTOR: f64 = 0.75;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum RentalMethod {
+    #[default]
+    ScheduleE,
+    Lease,
+}
+
+impl RentalMethod {
+    pub const ALL: [RentalMethod; 2] = [RentalMethod::ScheduleE, RentalMethod::Lease];
+
+    pub fn from_key(key: &str) -> Self {
+        match key {
+            "lease" => RentalMethod::Lease,
+            _ => RentalMethod::ScheduleE,
+        }
+    }
+
+    pub fn as_key(&self) -> &'static str {
+        match self {
+            RentalMethod::ScheduleE => "scheduleE",
+            RentalMethod::Lease => "lease",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            RentalMethod::ScheduleE => "Schedule E",
+            RentalMethod::Lease => "Lease (gross rent less vacancy)",
+        }
+    }
+}
+
+/// Schedule E rents less expenses, with depreciation and the housing costs already in
+/// PITIA added back, spread over the months the property was in service.
+pub fn schedule_e_monthly(property: &RentalProperty) -> f64 {
+    if property.months_in_service == 0 {
+        return 0.0;
+    }
+    let annual = property.rents_received - property.total_expenses
+        + property.depreciation
+        + property.mortgage_interest
+        + property.taxes
+        + property.insurance
+        + property.hoa_dues;
+    annual / property.months_in_service.min(12) as f64
+}
+
+pub fn lease_monthly(property: &RentalProperty, rules: &IncomeRuleSet) -> f64 {
+    property.gross_monthly_rent * rules.rental_vacancy_factor
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RentalResult {
+    // Rental income before the property's own housing payment
+    pub gross_monthly: f64,
+    // After PITIA for a property already owned; the same as gross for the subject
+    pub net_monthly: f64,
+    pub monthly_income: f64,
+    pub monthly_loss: f64,
+}
+
+pub fn analyze_property(property: &RentalProperty, rules: &IncomeRuleSet) -> RentalResult {
+    let gross_monthly = round_cents(match RentalMethod::from_key(&property.method) {
+        RentalMethod::ScheduleE => schedule_e_monthly(property),
+        RentalMethod::Lease => lease_monthly(property, rules),
+    });
+    // The subject property's PITIA is already the proposed housing payment
+    let net_monthly = if property.is_subject_property {
+        gross_monthly
+    } else {
+        round_cents(gross_monthly - property.monthly_pitia)
+    };
+
+    RentalResult {
+        gross_monthly,
+        net_monthly,
+        monthly_income: net_monthly.max(0.0),
+        monthly_loss: (-net_monthly).max(0.0),
+    }
+}
+
+/// Net rental income added to qualifying income.
+pub fn total_monthly_income(data: &RentalIncomeData, rules: &IncomeRuleSet) -> f64 {
+    data.properties.iter().map(|property| analyze_property(property, rules).monthly_income).sum()
+}
+
+/// Net rental losses counted as monthly debts.
+pub fn total_monthly_loss(data: &RentalIncomeData, rules: &IncomeRuleSet) -> f64 {
+    data.properties.iter().map(|property| analyze_property(property, rules).monthly_loss).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule_e() -> RentalProperty {
+        RentalProperty {
+            rents_received: 24_000.0,
+            total_expenses: 20_000.0,
+            depreciation: 3_000.0,
+            mortgage_interest: 6_000.0,
+            taxes: 2_400.0,
+            insurance: 1_200.0,
+            hoa_dues: 600.0,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_method_keys_round_trip() {
+        for method in RentalMethod::ALL {
+            assert_eq!(RentalMethod::from_key(method.as_key()), method);
+        }
+    }
+
+    #[test]
+    fn test_schedule_e_add_backs_and_proration() {
+        // 24,000 - 20,000 + 13,200 of add-backs = 17,200 a year
+        let mut property = schedule_e();
+        assert_eq!(round_cents(schedule_e_monthly(&property)), 1_433.33);
+
+        // Bought during the year, so spread over the months it was rented
+        property.months_in_service = 8;
+        assert_eq!(schedule_e_monthly(&property), 2_150.0);
+
+        property.months_in_service = 0;
+        assert_eq!(schedule_e_monthly(&property), 0.0);
+    }
+
+    #[test]
+    fn test_reo_net_against_pitia() {
+        let mut property = RentalProperty {
+            method: "lease".to_string(),
+            gross_monthly_rent: 2_000.0,
+            monthly_pitia: 1_200.0,
+            ..Default::default()
+        };
+        let result = analyze_property(&property, &IncomeRuleSet::default());
+        assert_eq!(result.gross_monthly, 1_500.0);
+        assert_eq!((result.monthly_income, result.monthly_loss), (300.0, 0.0));
+
+        property.monthly_pitia = 1_800.0;
+        let result = analyze_property(&property, &IncomeRuleSet::default());
+        assert_eq!(result.net_monthly, -300.0);
+        assert_eq!((result.monthly_income, result.monthly_loss), (0.0, 300.0));
+    }
+
+    #[test]
+    fn test_subject_property_and_totals() {
+        // The subject's PITIA is the housing payment, so it is not netted here
+        let subject = RentalProperty {
+            is_subject_property: true,
+            method: "lease".to_string(),
+            gross_monthly_rent: 1_600.0,
+            monthly_pitia: 2_500.0,
+            ..Default::default()
+        };
+        assert_eq!(analyze_property(&subject, &IncomeRuleSet::default()).monthly_income, 1_200.0);
+
+        let losing = RentalProperty {
+            monthly_pitia: 2_000.0,
+            ..schedule_e()
+        };
+        let data = RentalIncomeData {
+            properties: vec![subject, losing],
+        };
+        assert_eq!(total_monthly_income(&data, &IncomeRuleSet::default()), 1_200.0);
+        assert_eq!(total_monthly_loss(&data, &IncomeRuleSet::default()), 566.67);
+    }
+}
@@ -0,0 +1,251 @@
+// shared/src/calc/residual_income.rs
+// VA residual income: what is left of gross monthly income after taxes, shelter costs
+// and debts, compared against the VA regional table.
+use crate::calc::amortization::round_cents;
+use crate::models::{OptionsTemplateData, ResidualIncomeData};
+
+/// Loan amounts at or above this use the higher residual income table.
+pub const UPPER_TABLE_LOAN_AMOUNT: f64 = 80_000.0;
+/// Maintenance and utilities charged per square foot of living area.
+pub const MAINTENANCE_UTILITIES_PER_SQ_FT: f64 = 0.14;
+/// Above this DTI the borrower needs residual income of at least 120% of the guideline.
+pub const CUSHION_DTI_THRESHOLD: f64 = 41.0;
+pub const CUSHION_FACTOR: f64 = 1.20;
+/// Family size beyond which no further per-member amount is added.
+pub const MAX_FAMILY_SIZE: u32 = 7;
+
+// Residual income by family size (1 through 5) and region, in the order of `VaRegion`
+const LOWER_LOAN_AMOUNT_TABLE: [[f64; 4]; 5] = [
+    [390.0, 382.0, 382.0, 425.0],
+    [654.0, 641.0, 641.0, 713.0],
+    [788.0, 772.0, 772.0, 859.0],
+    [888.0, 868.0, 868.0, 967.0],
+    [921.0, 902.0, 902.0, 1004.0],
+];
+const UPPER_LOAN_AMOUNT_TABLE: [[f64; 4]; 5] = [
+    [450.0, 441.0, 441.0, 491.0],
+    [755.0, 738.0, 738.0, 823.0],
+    [909.0, 889.0, 889.0, 990.0],
+    [1025.0, 1003.0, 1003.0, 1117.0],
+    [1062.0, 1039.0, 1039.0, 1158.0],
+];
+// Added for each family member over five
+const LOWER_LOAN_AMOUNT_ADDITIONAL_MEMBER: f64 = 75.0;
+const UPPER_LOAN_AMOUNT_ADDITIONAL_MEMBER: f64 = 80.0;
+
+const NORTHEAST_STATES: [&str; 9] = ["CT", "ME", "MA", "NH", "NJ", "NY", "PA", "RI", "VT"];
+const MIDWEST_STATES: [&str; 12] = ["IL", "IN", "IA", "KS", "MI", "MN", "MO", "NE", "ND", "OH", "SD", "WI"];
+const SOUTH_STATES: [&str; 18] = [
+    "AL", "AR", "DE", "DC", "FL", "GA", "KY", "LA", "MD", "MS", "NC", "OK", "PR", "SC", "TN", "TX", "VA", "WV",
+];
+const WEST_STATES: [&str; 13] = ["AK", "AZ", "CA", "CO", "HI", "ID", "MT", "NV", "NM", "OR", "UT", "WA", "WY"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VaRegion {
+    Northeast,
+    Midwest,
+    South,
+    West,
+}
+
+impl VaRegion {
+    /// Region for a two-letter state or territory code.
+    pub fn from_state(state: &str) -> Option<Self> {
+        let state = state.trim().to_uppercase();
+        let state = state.as_str();
+        if NORTHEAST_STATES.contains(&state) {
+            Some(VaRegion::Northeast)
+        } else if MIDWEST_STATES.contains(&state) {
+            Some(VaRegion::Midwest)
+        } else if SOUTH_STATES.contains(&state) {
+            Some(VaRegion::South)
+        } else if WEST_STATES.contains(&state) {
+            Some(VaRegion::West)
+        } else {
+            None
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            VaRegion::Northeast => "Northeast",
+            VaRegion::Midwest => "Midwest",
+            VaRegion::South => "South",
+            VaRegion::West => "West",
+        }
+    }
+
+    fn column(&self) -> usize {
+        match self {
+            VaRegion::Northeast => 0,
+            VaRegion::Midwest => 1,
+            VaRegion::South => 2,
+            VaRegion::West => 3,
+        }
+    }
+}
+
+/// Guideline residual income for the region, family size and loan amount.
+pub fn required_residual_income(region: VaRegion, family_size: u32, loan_amount: f64) -> f64 {
+    let (table, additional_member) = if loan_amount >= UPPER_TABLE_LOAN_AMOUNT {
+        (&UPPER_LOAN_AMOUNT_TABLE, UPPER_LOAN_AMOUNT_ADDITIONAL_MEMBER)
+    } else {
+        (&LOWER_LOAN_AMOUNT_TABLE, LOWER_LOAN_AMOUNT_ADDITIONAL_MEMBER)
+    };
+
+    let family_size = family_size.clamp(1, MAX_FAMILY_SIZE);
+    let row = (family_size.min(5) - 1) as usize;
+    let extra_members = family_size.saturating_sub(5) as f64;
+
+    table[row][region.column()] + extra_members * additional_member
+}
+
+pub fn maintenance_and_utilities(square_feet: f64) -> f64 {
+    round_cents(square_feet.max(0.0) * MAINTENANCE_UTILITIES_PER_SQ_FT)
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResidualIncomeInput {
+    pub gross_monthly_income: f64,
+    // Federal, state and local income tax plus Social Security and Medicare
+    pub monthly_taxes: f64,
+    pub housing_payment: f64,
+    pub monthly_debts: f64,
+    pub square_feet: f64,
+}
+
+pub fn residual_income(input: &ResidualIncomeInput) -> f64 {
+    input.gross_monthly_income
+        - input.monthly_taxes
+        - input.housing_payment
+        - input.monthly_debts
+        - maintenance_and_utilities(input.square_feet)
+}
+
+/// Fills the residual income results for a VA template from the DTI figures and the
+/// inputs already on `data.residual_income`. Results are cleared for other loan types
+/// or when the state is not recognised.
+pub fn evaluate_template(data: &OptionsTemplateData) -> ResidualIncomeData {
+    let inputs = &data.residual_income;
+    let mut result = ResidualIncomeData {
+        state: inputs.state.clone(),
+        family_size: inputs.family_size,
+        square_feet: inputs.square_feet,
+        monthly_taxes: inputs.monthly_taxes,
+        ..Default::default()
+    };
+
+    if data.loan_information.loan_type != "va" {
+        return result;
+    }
+    let Some(region) = VaRegion::from_state(&inputs.state) else {
+        return result;
+    };
+
+    let dti = &data.debt_to_income;
+    let input = ResidualIncomeInput {
+        gross_monthly_income: dti.qualifying_income,
+        monthly_taxes: inputs.monthly_taxes,
+        housing_payment: dti.housing_payment,
+        monthly_debts: dti.monthly_debts,
+        square_feet: inputs.square_feet,
+    };
+
+    let guideline = required_residual_income(region, inputs.family_size, data.new_loan.total_loan_amount);
+    let cushion_applied = dti.back_end_ratio > CUSHION_DTI_THRESHOLD;
+    let required = if cushion_applied {
+        round_cents(guideline * CUSHION_FACTOR)
+    } else {
+        guideline
+    };
+    let residual = round_cents(residual_income(&input));
+
+    result.region = region.label().to_string();
+    result.maintenance_utilities = maintenance_and_utilities(inputs.square_feet);
+    result.residual_income = residual;
+    result.guideline_residual_income = guideline;
+    result.required_residual_income = required;
+    result.cushion_applied = cushion_applied;
+    result.meets_guideline = dti.qualifying_income > 0.0 && residual >= required;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_region_from_state() {
+        assert_eq!(VaRegion::from_state("ny"), Some(VaRegion::Northeast));
+        assert_eq!(VaRegion::from_state("OH"), Some(VaRegion::Midwest));
+        assert_eq!(VaRegion::from_state(" tx "), Some(VaRegion::South));
+        assert_eq!(VaRegion::from_state("CA"), Some(VaRegion::West));
+        assert_eq!(VaRegion::from_state("XX"), None);
+    }
+
+    #[test]
+    fn test_required_residual_income() {
+        assert_eq!(required_residual_income(VaRegion::South, 4, 250_000.0), 1003.0);
+        assert_eq!(required_residual_income(VaRegion::West, 1, 79_999.0), 425.0);
+        assert_eq!(required_residual_income(VaRegion::Northeast, 2, 80_000.0), 755.0);
+        // Six and seven members add the per-member amount; larger families are capped
+        assert_eq!(required_residual_income(VaRegion::Midwest, 6, 300_000.0), 1119.0);
+        assert_eq!(required_residual_income(VaRegion::Midwest, 9, 300_000.0), 1199.0);
+        assert_eq!(required_residual_income(VaRegion::West, 7, 70_000.0), 1154.0);
+    }
+
+    #[test]
+    fn test_residual_income() {
+        let input = ResidualIncomeInput {
+            gross_monthly_income: 7_000.0,
+            monthly_taxes: 1_200.0,
+            housing_payment: 2_100.0,
+            monthly_debts: 600.0,
+            square_feet: 2_000.0,
+        };
+        assert_eq!(maintenance_and_utilities(2_000.0), 280.0);
+        assert_eq!(residual_income(&input), 2_820.0);
+    }
+
+    fn va_template(back_end_ratio: f64) -> OptionsTemplateData {
+        let mut data = OptionsTemplateData::default();
+        data.loan_information.loan_type = "va".to_string();
+        data.new_loan.total_loan_amount = 300_000.0;
+        data.debt_to_income.qualifying_income = 5_000.0;
+        data.debt_to_income.housing_payment = 1_900.0;
+        data.debt_to_income.monthly_debts = 300.0;
+        data.debt_to_income.back_end_ratio = back_end_ratio;
+        data.residual_income.state = "TX".to_string();
+        data.residual_income.family_size = 4;
+        data.residual_income.square_feet = 1_800.0;
+        data.residual_income.monthly_taxes = 1_400.0;
+        data
+    }
+
+    #[test]
+    fn test_evaluate_template_cushion() {
+        // 5,000 - 1,400 - 1,900 - 300 - 252 = 1,148
+        let result = evaluate_template(&va_template(40.0));
+        assert_eq!(result.region, "South");
+        assert_eq!(result.residual_income, 1_148.0);
+        assert_eq!(result.required_residual_income, 1_003.0);
+        assert!(!result.cushion_applied);
+        assert!(result.meets_guideline);
+
+        // Above 41% DTI the guideline rises 20% to 1,203.60
+        let result = evaluate_template(&va_template(44.0));
+        assert!(result.cushion_applied);
+        assert_eq!(result.required_residual_income, 1_203.6);
+        assert!(!result.meets_guideline);
+    }
+
+    #[test]
+    fn test_evaluate_template_skips_other_programs() {
+        let mut data = va_template(40.0);
+        data.loan_information.loan_type = "fha".to_string();
+        let result = evaluate_template(&data);
+        assert_eq!(result.state, "TX");
+        assert_eq!(result.residual_income, 0.0);
+        assert!(!result.meets_guideline);
+    }
+}
@@ -0,0 +1,196 @@
+// shared/src/calc/retirement.rs
+// Retirement assets on the IRA worksheet, one record per account. An account the
+// borrower already draws on qualifies on its distribution when the balance keeps it
+// going for the continuance period; otherwise the balance is depleted over the loan
+// term. Balances that are not vested, or that the borrower cannot reach without an
+// early-withdrawal penalty, count only at the rule set's retirement asset factor.
+use serde::{Deserialize, Serialize};
+
+use crate::calc::amortization::round_cents;
+use crate::calc::income::DistributionFrequency;
+use crate::calc::rule_set::IncomeRuleSet;
+use crate::models::{RetirementAccount, RetirementAssetsData};
+
+/// Share of a restricted retirement balance counted toward depletion income. Rule sets
+/// may use their own factor.
+pub const RETIREMENT_ASSET_FACTOR: f64 = 0.70;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum RetirementMethod {
+    #[default]
+    None,
+    Distribution,
+    Depletion,
+}
+
+impl RetirementMethod {
+    pub fn label(&self) -> &'static str {
+        match self {
+            RetirementMethod::None => "Not eligible",
+            RetirementMethod::Distribution => "Current distribution",
+            RetirementMethod::Depletion => "Asset depletion",
+        }
+    }
+}
+
+/// Balance counted for the account after the haircut on restricted funds.
+pub fn counted_balance(account: &RetirementAccount, rules: &IncomeRuleSet) -> f64 {
+    if account.is_vested && account.penalty_free {
+        account.balance
+    } else {
+        account.balance * rules.retirement_asset_factor
+    }
+}
+
+/// Months the counted balance is spread over: the loan term, capped by the program's
+/// divisor. A zero divisor means the program does not allow asset depletion.
+pub fn depletion_months(loan_term_months: u32, rules: &IncomeRuleSet) -> u32 {
+    match loan_term_months {
+        0 => rules.asset_depletion_months,
+        term => term.min(rules.asset_depletion_months),
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RetirementResult {
+    pub counted_balance: f64,
+    pub monthly_distribution: f64,
+    // How long the counted balance supports the current distribution
+    pub months_remaining: Option<f64>,
+    pub meets_continuance: bool,
+    pub depletion_months: u32,
+    pub depletion_monthly: f64,
+    pub method: RetirementMethod,
+    pub qualifying_monthly: f64,
+}
+
+pub fn analyze_account(account: &RetirementAccount, loan_term_months: u32, rules: &IncomeRuleSet) -> RetirementResult {
+    let counted_balance = round_cents(counted_balance(account, rules));
+    let frequency = DistributionFrequency::from_key(&account.distribution_frequency);
+    let monthly_distribution = round_cents(account.distribution_amount * frequency.per_year() / 12.0);
+
+    let months_remaining = (monthly_distribution > 0.0).then(|| counted_balance / monthly_distribution);
+    let meets_continuance = months_remaining.is_some_and(|months| months >= rules.continuance_months as f64);
+
+    let depletion_months = depletion_months(loan_term_months, rules);
+    let depletion_monthly = if depletion_months > 0 {
+        round_cents(counted_balance / depletion_months as f64)
+    } else {
+        0.0
+    };
+
+    // A distribution that will not last falls back to depleting what is left
+    let (method, qualifying_monthly) = if meets_continuance {
+        (RetirementMethod::Distribution, monthly_distribution)
+    } else if depletion_monthly > 0.0 {
+        (RetirementMethod::Depletion, depletion_monthly)
+    } else {
+        (RetirementMethod::None, 0.0)
+    };
+
+    RetirementResult {
+        counted_balance,
+        monthly_distribution,
+        months_remaining,
+        meets_continuance,
+        depletion_months,
+        depletion_monthly,
+        method,
+        qualifying_monthly,
+    }
+}
+
+/// Qualifying retirement income across every account.
+pub fn total_monthly_income(data: &RetirementAssetsData, loan_term_months: u32, rules: &IncomeRuleSet) -> f64 {
+    data.accounts
+        .iter()
+        .map(|account| analyze_account(account, loan_term_months, rules).qualifying_monthly)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calc::rule_set::RuleSetProfile;
+
+    fn account(balance: f64) -> RetirementAccount {
+        RetirementAccount {
+            balance,
+            penalty_free: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_restricted_balance_haircut() {
+        let rules = IncomeRuleSet::default();
+        assert_eq!(counted_balance(&account(100_000.0), &rules), 100_000.0);
+
+        let early = RetirementAccount {
+            penalty_free: false,
+            ..account(100_000.0)
+        };
+        assert_eq!(counted_balance(&early, &rules), 70_000.0);
+
+        let unvested = RetirementAccount {
+            is_vested: false,
+            ..account(100_000.0)
+        };
+        let custom = IncomeRuleSet {
+            retirement_asset_factor: 0.6,
+            ..Default::default()
+        };
+        assert_eq!(counted_balance(&unvested, &custom), 60_000.0);
+    }
+
+    #[test]
+    fn test_depletion_over_loan_term() {
+        let rules = IncomeRuleSet::default();
+        let result = analyze_account(&account(360_000.0), 360, &rules);
+        assert_eq!(result.method, RetirementMethod::Depletion);
+        assert_eq!(result.qualifying_monthly, 1_000.0);
+
+        // A 15-year term spreads the balance over 180 months
+        assert_eq!(analyze_account(&account(360_000.0), 180, &rules).qualifying_monthly, 2_000.0);
+
+        // VA does not allow asset depletion
+        let va = IncomeRuleSet::for_profile(RuleSetProfile::VA, &rules);
+        let result = analyze_account(&account(360_000.0), 360, &va);
+        assert_eq!(result.method, RetirementMethod::None);
+        assert_eq!(result.qualifying_monthly, 0.0);
+    }
+
+    #[test]
+    fn test_distribution_continuance() {
+        let rules = IncomeRuleSet::default();
+        // $6,000 a quarter from $90,000 lasts 45 months
+        let drawing = RetirementAccount {
+            distribution_amount: 6_000.0,
+            distribution_frequency: "quarterly".to_string(),
+            ..account(90_000.0)
+        };
+        let result = analyze_account(&drawing, 360, &rules);
+        assert_eq!(result.monthly_distribution, 2_000.0);
+        assert_eq!(result.months_remaining, Some(45.0));
+        assert_eq!(result.method, RetirementMethod::Distribution);
+        assert_eq!(result.qualifying_monthly, 2_000.0);
+
+        // After the haircut only $63,000 counts, about 31 months of distributions
+        let restricted = RetirementAccount {
+            penalty_free: false,
+            ..drawing
+        };
+        let result = analyze_account(&restricted, 360, &rules);
+        assert!(!result.meets_continuance);
+        assert_eq!(result.method, RetirementMethod::Depletion);
+        assert_eq!(result.qualifying_monthly, 175.0);
+    }
+
+    #[test]
+    fn test_accounts_combined() {
+        let data = RetirementAssetsData {
+            accounts: vec![account(360_000.0), account(180_000.0)],
+        };
+        assert_eq!(total_monthly_income(&data, 360, &IncomeRuleSet::default()), 1_500.0);
+    }
+}
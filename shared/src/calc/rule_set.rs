@@ -0,0 +1,220 @@
+// shared/src/calc/rule_set.rs
+// Agency rule sets for the income worksheet. Each profile carries the factors that
+// differ between programs: the gross-up for non-taxable income, the haircuts on
+// pension, alimony and rental income, how long income must continue and the divisor
+// for asset depletion. The custom profile's values come from settings.
+use serde::{Deserialize, Serialize};
+
+use crate::calc::income::{
+    ALIMONY_FACTOR, NON_TAXABLE_GROSS_UP, PENSION_FIRST_TIER_FACTOR, PENSION_FIRST_TIER_LIMIT,
+    PENSION_SECOND_TIER_FACTOR, SOCIAL_SECURITY_TAXABLE_SHARE,
+};
+use crate::calc::rental::LEASE_GROSS_RENT_FACTOR;
+use crate::calc::retirement::RETIREMENT_ASSET_FACTOR;
+use crate::models::LoanType;
+
+/// Months income must be expected to continue after closing.
+pub const DEFAULT_CONTINUANCE_MONTHS: u32 = 36;
+/// FHA grosses up non-taxable income by 15% unless the borrower's tax rate is higher.
+pub const FHA_NON_TAXABLE_GROSS_UP: f64 = 1.15;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum RuleSetProfile {
+    #[default]
+    Fannie,
+    Freddie,
+    FHA,
+    VA,
+    USDA,
+    Custom,
+}
+
+impl RuleSetProfile {
+    pub const ALL: [RuleSetProfile; 6] = [
+        RuleSetProfile::Fannie,
+        RuleSetProfile::Freddie,
+        RuleSetProfile::FHA,
+        RuleSetProfile::VA,
+        RuleSetProfile::USDA,
+        RuleSetProfile::Custom,
+    ];
+
+    pub fn from_key(key: &str) -> Self {
+        match key {
+            "freddie" => RuleSetProfile::Freddie,
+            "fha" => RuleSetProfile::FHA,
+            "va" => RuleSetProfile::VA,
+            "usda" => RuleSetProfile::USDA,
+            "custom" => RuleSetProfile::Custom,
+            _ => RuleSetProfile::Fannie,
+        }
+    }
+
+    pub fn as_key(&self) -> &'static str {
+        match self {
+            RuleSetProfile::Fannie => "fannie",
+            RuleSetProfile::Freddie => "freddie",
+            RuleSetProfile::FHA => "fha",
+            RuleSetProfile::VA => "va",
+            RuleSetProfile::USDA => "usda",
+            RuleSetProfile::Custom => "custom",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            RuleSetProfile::Fannie => "Fannie Mae",
+            RuleSetProfile::Freddie => "Freddie Mac",
+            RuleSetProfile::FHA => "FHA",
+            RuleSetProfile::VA => "VA",
+            RuleSetProfile::USDA => "USDA",
+            RuleSetProfile::Custom => "Custom",
+        }
+    }
+
+    /// Conventional loans follow the agency chosen on the loan, `"freddie"` for Freddie
+    /// Mac and Fannie Mae otherwise; Non-QM and loans without a type use the custom
+    /// profile.
+    pub fn for_loan_type(loan_type: Option<&LoanType>, agency: &str) -> Self {
+        match loan_type {
+            Some(LoanType::CNV) if agency == "freddie" => RuleSetProfile::Freddie,
+            Some(LoanType::CNV) => RuleSetProfile::Fannie,
+            Some(LoanType::FHA) => RuleSetProfile::FHA,
+            Some(LoanType::VA) => RuleSetProfile::VA,
+            Some(LoanType::USDA) => RuleSetProfile::USDA,
+            Some(LoanType::NonQM) | None => RuleSetProfile::Custom,
+        }
+    }
+}
+
+// Missing fields fall back to the Fannie Mae values, so a partly saved custom
+// profile still loads
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IncomeRuleSet {
+    pub profile: RuleSetProfile,
+    // Gross-up factors
+    pub non_taxable_gross_up: f64,
+    pub social_security_taxable_share: f64,
+    // Haircuts
+    pub pension_first_tier_limit: f64,
+    pub pension_first_tier_factor: f64,
+    pub pension_second_tier_factor: f64,
+    pub alimony_factor: f64,
+    // Share of gross lease rent counted after vacancy and maintenance
+    pub rental_vacancy_factor: f64,
+    // Continuance and asset depletion
+    pub continuance_months: u32,
+    // Share of a retirement balance counted when it is not vested or not yet penalty-free
+    pub retirement_asset_factor: f64,
+    // Months eligible assets are spread over; zero when the program does not allow it.
+    // Fannie Mae B3-3.1-09 divides by the loan term in months, 360 for a 30-year loan
+    pub asset_depletion_months: u32,
+}
+
+impl Default for IncomeRuleSet {
+    fn default() -> Self {
+        Self {
+            profile: RuleSetProfile::Fannie,
+            non_taxable_gross_up: NON_TAXABLE_GROSS_UP,
+            social_security_taxable_share: SOCIAL_SECURITY_TAXABLE_SHARE,
+            pension_first_tier_limit: PENSION_FIRST_TIER_LIMIT,
+            pension_first_tier_factor: PENSION_FIRST_TIER_FACTOR,
+            pension_second_tier_factor: PENSION_SECOND_TIER_FACTOR,
+            alimony_factor: ALIMONY_FACTOR,
+            rental_vacancy_factor: LEASE_GROSS_RENT_FACTOR,
+            continuance_months: DEFAULT_CONTINUANCE_MONTHS,
+            retirement_asset_factor: RETIREMENT_ASSET_FACTOR,
+            asset_depletion_months: 360,
+        }
+    }
+}
+
+impl IncomeRuleSet {
+    /// The rule set for `profile`. `custom` supplies the values of the custom profile.
+    pub fn for_profile(profile: RuleSetProfile, custom: &IncomeRuleSet) -> Self {
+        let fannie = Self::default();
+        match profile {
+            RuleSetProfile::Fannie => fannie,
+            // Freddie Mac Guide 5307.1 divides eligible assets by 240
+            RuleSetProfile::Freddie => Self {
+                profile,
+                asset_depletion_months: 240,
+                ..fannie
+            },
+            RuleSetProfile::FHA => Self {
+                profile,
+                non_taxable_gross_up: FHA_NON_TAXABLE_GROSS_UP,
+                ..fannie
+            },
+            // The VA Lender's Handbook (M26-7, chapter 4) has no asset depletion method
+            RuleSetProfile::VA => Self {
+                profile,
+                asset_depletion_months: 0,
+                ..fannie
+            },
+            // HB-1-3555, chapter 9: non-taxable income grossed up 25%, 75% of lease rent
+            // and three years' continuance, as Fannie Mae; assets spread over 36 months
+            RuleSetProfile::USDA => Self {
+                profile,
+                asset_depletion_months: 36,
+                ..fannie
+            },
+            RuleSetProfile::Custom => Self {
+                profile,
+                ..custom.clone()
+            },
+        }
+    }
+
+    pub fn for_loan_type(loan_type: Option<&LoanType>, agency: &str, custom: &IncomeRuleSet) -> Self {
+        Self::for_profile(RuleSetProfile::for_loan_type(loan_type, agency), custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_keys_round_trip() {
+        for profile in RuleSetProfile::ALL {
+            assert_eq!(RuleSetProfile::from_key(profile.as_key()), profile);
+        }
+    }
+
+    #[test]
+    fn test_profiles_by_loan_type() {
+        let custom = IncomeRuleSet {
+            alimony_factor: 1.0,
+            ..Default::default()
+        };
+
+        let fha = IncomeRuleSet::for_loan_type(Some(&LoanType::FHA), "", &custom);
+        assert_eq!(fha.profile, RuleSetProfile::FHA);
+        assert_eq!(fha.non_taxable_gross_up, 1.15);
+
+        let cnv = IncomeRuleSet::for_loan_type(Some(&LoanType::CNV), "fannie", &custom);
+        assert_eq!(cnv, IncomeRuleSet::default());
+
+        let freddie = IncomeRuleSet::for_loan_type(Some(&LoanType::CNV), "freddie", &custom);
+        assert_eq!(freddie.profile, RuleSetProfile::Freddie);
+        assert_eq!(freddie.asset_depletion_months, 240);
+
+        let usda = IncomeRuleSet::for_loan_type(Some(&LoanType::USDA), "", &custom);
+        assert_eq!(usda.profile, RuleSetProfile::USDA);
+        assert_eq!(usda.non_taxable_gross_up, NON_TAXABLE_GROSS_UP);
+        assert_eq!(usda.asset_depletion_months, 36);
+
+        let non_qm = IncomeRuleSet::for_loan_type(Some(&LoanType::NonQM), "", &custom);
+        assert_eq!(non_qm.profile, RuleSetProfile::Custom);
+        assert_eq!(non_qm.alimony_factor, 1.0);
+    }
+
+    #[test]
+    fn test_partial_custom_profile_uses_defaults() {
+        let rules: IncomeRuleSet = serde_json::from_str(r#"{"alimony_factor": 1.0}"#).unwrap();
+        assert_eq!(rules.alimony_factor, 1.0);
+        assert_eq!(rules.non_taxable_gross_up, NON_TAXABLE_GROSS_UP);
+    }
+}
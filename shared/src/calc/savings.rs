@@ -0,0 +1,174 @@
+// shared/src/calc/savings.rs
+// Housing payment totals for the benefit-to-borrower table and the savings they produce.
+use crate::models::{BenefitToBorrowerData, ConsumerDebtData, OtherFeesData, SavingsData};
+
+/// Existing monthly payment: P&I plus escrowed items, PMI and HOA.
+pub fn existing_mortgage_payment(data: &BenefitToBorrowerData) -> f64 {
+    let mut total = data.existing_pi;
+    if data.escrow_taxes {
+        total += data.existing_taxes;
+    }
+    if data.escrow_insurance {
+        total += data.existing_insurance;
+    }
+    if data.escrow_flood_insurance {
+        total += data.existing_flood_insurance;
+    }
+    total + data.existing_pmi + data.existing_hoa
+}
+
+/// Proposed monthly payment: P&I plus escrowed items, PMI and HOA.
+pub fn proposed_mortgage_payment(data: &BenefitToBorrowerData) -> f64 {
+    let mut total = data.proposed_pi;
+    if data.escrow_taxes {
+        total += data.proposed_taxes;
+    }
+    if data.escrow_insurance {
+        total += data.proposed_insurance;
+    }
+    if data.escrow_flood_insurance {
+        total += data.proposed_flood_insurance;
+    }
+    total + data.proposed_pmi + data.proposed_hoa
+}
+
+/// All existing housing obligations, escrowed or not.
+pub fn existing_total_obligations(data: &BenefitToBorrowerData) -> f64 {
+    data.existing_pi
+        + data.existing_taxes
+        + data.existing_insurance
+        + data.existing_flood_insurance
+        + data.existing_pmi
+        + data.existing_hoa
+}
+
+/// All proposed housing obligations, escrowed or not.
+pub fn proposed_total_obligations(data: &BenefitToBorrowerData) -> f64 {
+    data.proposed_pi
+        + data.proposed_taxes
+        + data.proposed_insurance
+        + data.proposed_flood_insurance
+        + data.proposed_pmi
+        + data.proposed_hoa
+}
+
+/// Fills the derived payment and obligation fields. A purchase has no existing side.
+pub fn update_payment_totals(data: &mut BenefitToBorrowerData, is_purchase: bool) {
+    data.existing_mortgage_payment = existing_mortgage_payment(data);
+    data.proposed_mortgage_payment = proposed_mortgage_payment(data);
+    data.existing_total_obligations = existing_total_obligations(data);
+    data.proposed_total_obligations = proposed_total_obligations(data);
+    data.overage_shortage = if is_purchase {
+        0.0
+    } else {
+        data.existing_mortgage_payment - data.proposed_mortgage_payment
+    };
+}
+
+/// Monthly payments that go away because the debt is paid off at closing.
+pub fn paid_off_debt_payments(debts: &ConsumerDebtData) -> f64 {
+    debts
+        .consumer_debts
+        .iter()
+        .filter(|debt| debt.pay_off_at_closing)
+        .map(|debt| debt.monthly_payment)
+        .sum()
+}
+
+/// Months of savings needed to recover the closing costs. Zero when there are no savings.
+pub fn recoup_period_months(closing_costs: f64, monthly_savings: f64) -> f64 {
+    if monthly_savings > 0.0 && closing_costs > 0.0 {
+        closing_costs / monthly_savings
+    } else {
+        0.0
+    }
+}
+
+pub fn calculate_savings(
+    benefit: &BenefitToBorrowerData,
+    debts: &ConsumerDebtData,
+    fees: &OtherFeesData,
+) -> SavingsData {
+    let payment_reduction =
+        existing_total_obligations(benefit) - proposed_total_obligations(benefit);
+    let debt_paid = paid_off_debt_payments(debts);
+    let monthly_savings = payment_reduction + debt_paid;
+
+    SavingsData {
+        monthly_savings,
+        annual_savings: monthly_savings * 12.0,
+        debt_paid,
+        payment_reduction,
+        recoup_period_months: recoup_period_months(fees.total_closing_costs, monthly_savings),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ConsumerDebtItemData;
+
+    fn benefit() -> BenefitToBorrowerData {
+        BenefitToBorrowerData {
+            existing_pi: 2_000.0,
+            existing_taxes: 300.0,
+            existing_insurance: 100.0,
+            existing_pmi: 150.0,
+            proposed_pi: 1_600.0,
+            proposed_taxes: 300.0,
+            proposed_insurance: 100.0,
+            escrow_taxes: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_payment_totals_honor_escrow_flags() {
+        let mut data = benefit();
+        update_payment_totals(&mut data, false);
+        assert_eq!(data.existing_mortgage_payment, 2_450.0);
+        assert_eq!(data.proposed_mortgage_payment, 1_900.0);
+        assert_eq!(data.existing_total_obligations, 2_550.0);
+        assert_eq!(data.proposed_total_obligations, 2_000.0);
+        assert_eq!(data.overage_shortage, 550.0);
+
+        update_payment_totals(&mut data, true);
+        assert_eq!(data.overage_shortage, 0.0);
+    }
+
+    #[test]
+    fn test_calculate_savings_with_paid_off_debts() {
+        let debts = ConsumerDebtData {
+            consumer_debts: vec![
+                ConsumerDebtItemData {
+                    monthly_payment: 250.0,
+                    pay_off_at_closing: true,
+                    ..Default::default()
+                },
+                ConsumerDebtItemData {
+                    monthly_payment: 400.0,
+                    pay_off_at_closing: false,
+                    ..Default::default()
+                },
+            ],
+        };
+        let fees = OtherFeesData {
+            total_closing_costs: 6_000.0,
+            ..Default::default()
+        };
+
+        let savings = calculate_savings(&benefit(), &debts, &fees);
+        assert_eq!(savings.payment_reduction, 550.0);
+        assert_eq!(savings.debt_paid, 250.0);
+        assert_eq!(savings.monthly_savings, 800.0);
+        assert_eq!(savings.annual_savings, 9_600.0);
+        assert_eq!(savings.recoup_period_months, 7.5);
+    }
+
+    #[test]
+    fn test_recoup_period_without_savings() {
+        assert_eq!(recoup_period_months(5_000.0, 0.0), 0.0);
+        assert_eq!(recoup_period_months(5_000.0, -100.0), 0.0);
+        assert_eq!(recoup_period_months(0.0, 100.0), 0.0);
+    }
+}
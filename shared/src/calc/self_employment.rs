@@ -0,0 +1,325 @@
+// shared/src/calc/self_employment.rs
+// Self-employment cash flow in the style of Fannie Mae Form 1084. Each business is
+// analysed over two tax years; figures on the year structs are annual and results
+// are reported monthly alongside the other worksheet sections.
+use serde::{Deserialize, Serialize};
+
+use crate::models::{SelfEmploymentBusiness, SelfEmploymentData, SelfEmploymentYear};
+
+// Tax return the business files
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum BusinessType {
+    #[default]
+    ScheduleC,
+    Partnership,
+    SCorporation,
+    Corporation,
+}
+
+impl BusinessType {
+    pub const ALL: [BusinessType; 4] = [
+        BusinessType::ScheduleC,
+        BusinessType::Partnership,
+        BusinessType::SCorporation,
+        BusinessType::Corporation,
+    ];
+
+    pub fn from_key(key: &str) -> Self {
+        match key {
+            "form1065" => BusinessType::Partnership,
+            "form1120S" => BusinessType::SCorporation,
+            "form1120" => BusinessType::Corporation,
+            _ => BusinessType::ScheduleC,
+        }
+    }
+
+    pub fn as_key(&self) -> &'static str {
+        match self {
+            BusinessType::ScheduleC => "scheduleC",
+            BusinessType::Partnership => "form1065",
+            BusinessType::SCorporation => "form1120S",
+            BusinessType::Corporation => "form1120",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            BusinessType::ScheduleC => "Schedule C (Sole Proprietor)",
+            BusinessType::Partnership => "Form 1065 / K-1 (Partnership)",
+            BusinessType::SCorporation => "Form 1120S / K-1 (S Corporation)",
+            BusinessType::Corporation => "Form 1120 (Corporation)",
+        }
+    }
+
+    /// Lines of the cash-flow analysis that apply to this return, in worksheet order.
+    pub fn line_items(&self) -> Vec<LineItem> {
+        use LineItem::*;
+        match self {
+            BusinessType::ScheduleC => vec![
+                NetIncome,
+                NonrecurringIncome,
+                Depreciation,
+                Depletion,
+                Amortization,
+                BusinessUseOfHome,
+                MealsExclusion,
+            ],
+            BusinessType::Partnership => vec![
+                NetIncome,
+                GuaranteedPayments,
+                NonrecurringIncome,
+                Depreciation,
+                Depletion,
+                Amortization,
+                NotesPayableShortTerm,
+                MealsExclusion,
+            ],
+            BusinessType::SCorporation => vec![
+                W2Wages,
+                NetIncome,
+                NonrecurringIncome,
+                Depreciation,
+                Depletion,
+                Amortization,
+                NotesPayableShortTerm,
+                MealsExclusion,
+            ],
+            BusinessType::Corporation => vec![
+                W2Wages,
+                NetIncome,
+                TotalTax,
+                NonrecurringIncome,
+                Depreciation,
+                Depletion,
+                Amortization,
+                NotesPayableShortTerm,
+                MealsExclusion,
+            ],
+        }
+    }
+
+    /// Whether the business-level lines are scaled by the borrower's ownership share.
+    /// Schedule C income belongs entirely to the borrower.
+    pub fn uses_ownership(&self) -> bool {
+        *self != BusinessType::ScheduleC
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineItem {
+    NetIncome,
+    GuaranteedPayments,
+    W2Wages,
+    NonrecurringIncome,
+    Depreciation,
+    Depletion,
+    Amortization,
+    BusinessUseOfHome,
+    NotesPayableShortTerm,
+    MealsExclusion,
+    TotalTax,
+}
+
+impl LineItem {
+    pub fn label(&self, business_type: BusinessType) -> &'static str {
+        match (self, business_type) {
+            (LineItem::NetIncome, BusinessType::ScheduleC) => "Net profit or loss",
+            (LineItem::NetIncome, BusinessType::Corporation) => "Taxable income",
+            (LineItem::NetIncome, _) => "K-1 ordinary income (loss)",
+            (LineItem::GuaranteedPayments, _) => "K-1 guaranteed payments",
+            (LineItem::W2Wages, _) => "W-2 wages from the business",
+            (LineItem::NonrecurringIncome, _) => "Nonrecurring income (loss)",
+            (LineItem::Depreciation, _) => "Depreciation",
+            (LineItem::Depletion, _) => "Depletion",
+            (LineItem::Amortization, _) => "Amortization / casualty loss",
+            (LineItem::BusinessUseOfHome, _) => "Business use of home",
+            (LineItem::NotesPayableShortTerm, _) => "Notes payable in less than 1 year",
+            (LineItem::MealsExclusion, _) => "Meals and entertainment exclusion",
+            (LineItem::TotalTax, _) => "Total tax",
+        }
+    }
+
+    /// Lines that reduce cash flow. The user enters every line as a positive amount.
+    pub fn is_deduction(&self) -> bool {
+        matches!(
+            self,
+            LineItem::NonrecurringIncome | LineItem::NotesPayableShortTerm | LineItem::MealsExclusion | LineItem::TotalTax
+        )
+    }
+
+    pub fn value(&self, year: &SelfEmploymentYear) -> f64 {
+        match self {
+            LineItem::NetIncome => year.net_income,
+            LineItem::GuaranteedPayments => year.guaranteed_payments,
+            LineItem::W2Wages => year.w2_wages,
+            LineItem::NonrecurringIncome => year.nonrecurring_income,
+            LineItem::Depreciation => year.depreciation,
+            LineItem::Depletion => year.depletion,
+            LineItem::Amortization => year.amortization,
+            LineItem::BusinessUseOfHome => year.business_use_of_home,
+            LineItem::NotesPayableShortTerm => year.notes_payable_short_term,
+            LineItem::MealsExclusion => year.meals_exclusion,
+            LineItem::TotalTax => year.total_tax,
+        }
+    }
+
+    pub fn set_value(&self, year: &mut SelfEmploymentYear, value: f64) {
+        let field = match self {
+            LineItem::NetIncome => &mut year.net_income,
+            LineItem::GuaranteedPayments => &mut year.guaranteed_payments,
+            LineItem::W2Wages => &mut year.w2_wages,
+            LineItem::NonrecurringIncome => &mut year.nonrecurring_income,
+            LineItem::Depreciation => &mut year.depreciation,
+            LineItem::Depletion => &mut year.depletion,
+            LineItem::Amortization => &mut year.amortization,
+            LineItem::BusinessUseOfHome => &mut year.business_use_of_home,
+            LineItem::NotesPayableShortTerm => &mut year.notes_payable_short_term,
+            LineItem::MealsExclusion => &mut year.meals_exclusion,
+            LineItem::TotalTax => &mut year.total_tax,
+        };
+        *field = value;
+    }
+}
+
+/// Annual cash flow for one tax year. K-1 income, guaranteed payments and W-2 wages
+/// are already the borrower's own; everything reported for the business as a whole
+/// is scaled by the ownership share. Lines that do not apply to the return are ignored.
+pub fn annual_cash_flow(business_type: BusinessType, ownership_percent: f64, year: &SelfEmploymentYear) -> f64 {
+    let share = if business_type.uses_ownership() {
+        ownership_percent.clamp(0.0, 100.0) / 100.0
+    } else {
+        1.0
+    };
+
+    let mut borrower = 0.0;
+    let mut business = 0.0;
+    for item in business_type.line_items() {
+        let amount = if item.is_deduction() { -item.value(year) } else { item.value(year) };
+        match item {
+            LineItem::GuaranteedPayments | LineItem::W2Wages => borrower += amount,
+            LineItem::NetIncome if business_type != BusinessType::Corporation => borrower += amount,
+            _ => business += amount,
+        }
+    }
+
+    borrower + business * share
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BusinessResult {
+    pub prior_year_annual: f64,
+    pub recent_year_annual: f64,
+    // Decline from the prior year as a percentage of the prior year
+    pub decline_percent: f64,
+    pub declining: bool,
+    pub qualifying_monthly: f64,
+}
+
+/// Two-year average, unless cash flow fell in the most recent year, in which case only
+/// the lower recent year is used and the business is flagged for review.
+pub fn analyze_business(business: &SelfEmploymentBusiness) -> BusinessResult {
+    let business_type = BusinessType::from_key(&business.business_type);
+    let prior = annual_cash_flow(business_type, business.ownership_percent, &business.prior_year);
+    let recent = annual_cash_flow(business_type, business.ownership_percent, &business.recent_year);
+
+    let declining = prior > 0.0 && recent < prior;
+    let decline_percent = if declining { (prior - recent) / prior * 100.0 } else { 0.0 };
+    let qualifying_monthly = if declining || prior == 0.0 {
+        recent / 12.0
+    } else {
+        (prior + recent) / 24.0
+    };
+
+    BusinessResult {
+        prior_year_annual: prior,
+        recent_year_annual: recent,
+        decline_percent,
+        declining,
+        qualifying_monthly,
+    }
+}
+
+/// Monthly self-employment income across all businesses. Losses reduce the total.
+pub fn total_monthly_income(data: &SelfEmploymentData) -> f64 {
+    data.businesses
+        .iter()
+        .map(|business| analyze_business(business).qualifying_monthly)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn year(net_income: f64, depreciation: f64) -> SelfEmploymentYear {
+        SelfEmploymentYear {
+            net_income,
+            depreciation,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_business_type_keys_round_trip() {
+        for business_type in BusinessType::ALL {
+            assert_eq!(BusinessType::from_key(business_type.as_key()), business_type);
+        }
+    }
+
+    #[test]
+    fn test_schedule_c_add_backs() {
+        let year = SelfEmploymentYear {
+            net_income: 60_000.0,
+            depreciation: 5_000.0,
+            business_use_of_home: 2_400.0,
+            meals_exclusion: 600.0,
+            nonrecurring_income: 1_800.0,
+            // Not a Schedule C line, so ignored
+            total_tax: 9_000.0,
+            ..Default::default()
+        };
+        // Ownership does not apply to a sole proprietor
+        assert_eq!(annual_cash_flow(BusinessType::ScheduleC, 50.0, &year), 65_000.0);
+    }
+
+    #[test]
+    fn test_entity_ownership_share() {
+        let year = SelfEmploymentYear {
+            net_income: 40_000.0,
+            w2_wages: 50_000.0,
+            guaranteed_payments: 12_000.0,
+            depreciation: 20_000.0,
+            notes_payable_short_term: 4_000.0,
+            total_tax: 6_000.0,
+            ..Default::default()
+        };
+        // K-1 income and W-2 wages are the borrower's; 16,000 of net add-backs at 50%
+        assert_eq!(annual_cash_flow(BusinessType::SCorporation, 50.0, &year), 98_000.0);
+        assert_eq!(annual_cash_flow(BusinessType::Partnership, 50.0, &year), 60_000.0);
+        // A corporation's taxable income less tax is scaled too: (40,000 - 6,000 + 16,000) at 50%
+        assert_eq!(annual_cash_flow(BusinessType::Corporation, 50.0, &year), 75_000.0);
+    }
+
+    #[test]
+    fn test_declining_income_uses_recent_year() {
+        let mut business = SelfEmploymentBusiness {
+            prior_year: year(70_000.0, 2_000.0),
+            recent_year: year(80_000.0, 4_000.0),
+            ..Default::default()
+        };
+        let result = analyze_business(&business);
+        assert!(!result.declining);
+        assert_eq!(result.qualifying_monthly, 6_500.0);
+
+        business.recent_year = year(54_000.0, 0.0);
+        let result = analyze_business(&business);
+        assert!(result.declining);
+        assert_eq!(result.decline_percent, 25.0);
+        assert_eq!(result.qualifying_monthly, 4_500.0);
+
+        let data = SelfEmploymentData {
+            businesses: vec![business.clone(), business],
+        };
+        assert_eq!(total_monthly_income(&data), 9_000.0);
+    }
+}
@@ -0,0 +1,127 @@
+// shared/src/calc/va_funding_fee.rs
+// VA funding fee. Rates are percentages of the base loan amount.
+use crate::calc::amortization::round_cents;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VaFundingFeePurpose {
+    Purchase,
+    // VA charges every refinance other than an IRRRL at the cash-out rates
+    CashOut,
+    Irrrl,
+}
+
+impl VaFundingFeePurpose {
+    /// Maps the options template purpose key.
+    pub fn from_key(key: &str) -> Self {
+        match key {
+            "purchase" => VaFundingFeePurpose::Purchase,
+            "irrrl" => VaFundingFeePurpose::Irrrl,
+            _ => VaFundingFeePurpose::CashOut,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VaUsage {
+    First,
+    Subsequent,
+}
+
+impl VaUsage {
+    pub fn from_subsequent(subsequent_use: bool) -> Self {
+        if subsequent_use {
+            VaUsage::Subsequent
+        } else {
+            VaUsage::First
+        }
+    }
+}
+
+/// Funding fee rate (percent). Veterans exempt for a service-connected disability pay none.
+pub fn funding_fee_rate(
+    purpose: VaFundingFeePurpose,
+    usage: VaUsage,
+    down_payment_percent: f64,
+    exempt: bool,
+) -> f64 {
+    if exempt {
+        return 0.0;
+    }
+
+    match purpose {
+        VaFundingFeePurpose::Irrrl => 0.50,
+        VaFundingFeePurpose::CashOut => match usage {
+            VaUsage::First => 2.15,
+            VaUsage::Subsequent => 3.30,
+        },
+        VaFundingFeePurpose::Purchase => {
+            if down_payment_percent >= 10.0 {
+                1.25
+            } else if down_payment_percent >= 5.0 {
+                1.50
+            } else {
+                match usage {
+                    VaUsage::First => 2.15,
+                    VaUsage::Subsequent => 3.30,
+                }
+            }
+        }
+    }
+}
+
+/// Funding fee in dollars on the base loan amount.
+pub fn funding_fee(base_loan_amount: f64, rate: f64) -> f64 {
+    round_cents(base_loan_amount.max(0.0) * rate / 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use VaFundingFeePurpose::*;
+    use VaUsage::*;
+
+    #[test]
+    fn test_funding_fee_tiers() {
+        let cases = [
+            // (purpose, usage, down payment %, exempt, expected rate)
+            (Purchase, First, 0.0, false, 2.15),
+            (Purchase, Subsequent, 0.0, false, 3.30),
+            (Purchase, First, 4.99, false, 2.15),
+            (Purchase, First, 5.0, false, 1.50),
+            (Purchase, Subsequent, 5.0, false, 1.50),
+            (Purchase, First, 9.99, false, 1.50),
+            (Purchase, First, 10.0, false, 1.25),
+            (Purchase, Subsequent, 20.0, false, 1.25),
+            (CashOut, First, 0.0, false, 2.15),
+            (CashOut, Subsequent, 0.0, false, 3.30),
+            (Irrrl, First, 0.0, false, 0.50),
+            (Irrrl, Subsequent, 0.0, false, 0.50),
+            (Purchase, First, 0.0, true, 0.0),
+            (CashOut, Subsequent, 0.0, true, 0.0),
+            (Irrrl, First, 0.0, true, 0.0),
+        ];
+
+        for (purpose, usage, down_payment, exempt, expected) in cases {
+            assert_eq!(
+                funding_fee_rate(purpose, usage, down_payment, exempt),
+                expected,
+                "{purpose:?} {usage:?} {down_payment}% exempt={exempt}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_purpose_from_key() {
+        assert_eq!(VaFundingFeePurpose::from_key("purchase"), Purchase);
+        assert_eq!(VaFundingFeePurpose::from_key("cashOut"), CashOut);
+        assert_eq!(VaFundingFeePurpose::from_key("refinance"), CashOut);
+        assert_eq!(VaFundingFeePurpose::from_key("irrrl"), Irrrl);
+    }
+
+    #[test]
+    fn test_funding_fee_amount() {
+        assert_eq!(funding_fee(300_000.0, 2.15), 6_450.0);
+        assert_eq!(funding_fee(250_000.0, 0.50), 1_250.0);
+        assert_eq!(funding_fee(250_000.0, 0.0), 0.0);
+    }
+}
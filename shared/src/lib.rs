@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 
 
 pub mod models;
+pub mod calc;
 
 // API Error response for consistent error handling
 #[derive(Debug, Clone, Serialize, Deserialize)]
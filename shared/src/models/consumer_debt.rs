@@ -3,7 +3,7 @@ use validator::Validate;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
-use super::enums::CreditType;
+use super::enums::{CreditType, StudentLoanStatus};
 
 // Consumer Debt Model
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Validate)]
@@ -21,6 +21,7 @@ pub struct ConsumerDebt {
     pub interest_rate: Option<f64>,
     pub omit_from_dti: bool,
     pub pay_off_at_closing: bool,
+    pub student_loan_status: Option<StudentLoanStatus>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -38,6 +39,7 @@ impl Default for ConsumerDebt {
             interest_rate: None,
             omit_from_dti: false,
             pay_off_at_closing: false,
+            student_loan_status: None,
             created_at: now,
             updated_at: now,
         }
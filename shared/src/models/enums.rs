@@ -45,6 +45,7 @@ pub enum LoanType {
     CNV,
     FHA,
     VA,
+    USDA,
     NonQM,
 }
 
@@ -54,6 +55,7 @@ impl fmt::Display for LoanType {
             LoanType::CNV => write!(f, "CNV"),
             LoanType::FHA => write!(f, "FHA"),
             LoanType::VA => write!(f, "VA"),
+            LoanType::USDA => write!(f, "USDA"),
             LoanType::NonQM => write!(f, "NonQM"),
         }
     }
@@ -97,6 +99,23 @@ impl std::fmt::Display for CreditType {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum StudentLoanStatus {
+    Repayment,
+    Deferred,
+    IncomeDriven,
+}
+
+impl fmt::Display for StudentLoanStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StudentLoanStatus::Repayment => write!(f, "Repayment"),
+            StudentLoanStatus::Deferred => write!(f, "Deferred"),
+            StudentLoanStatus::IncomeDriven => write!(f, "IncomeDriven"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum IncomeType {
     W2Employment,
@@ -108,6 +127,9 @@ pub enum IncomeType {
     Disability,
     Alimony,
     ChildSupport,
+    Military,
+    BoarderIncome,
+    AccessoryUnit,
     Other,
 }
 
@@ -123,6 +145,9 @@ impl fmt::Display for IncomeType {
             IncomeType::Disability => write!(f, "Disability"),
             IncomeType::Alimony => write!(f, "Alimony"),
             IncomeType::ChildSupport => write!(f, "Child Support"),
+            IncomeType::Military => write!(f, "Military"),
+            IncomeType::BoarderIncome => write!(f, "Boarder Income"),
+            IncomeType::AccessoryUnit => write!(f, "ADU Rental Income"),
             IncomeType::Other => write!(f, "Other"),
         }
     }
@@ -151,6 +176,76 @@ impl fmt::Display for Status {
     }
 }
 
+// Options template keys. These are the values used by the loan information selects,
+// which differ from the stored display names.
+impl PropertyType {
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "sfr" => Some(PropertyType::SFR),
+            "manufactured" => Some(PropertyType::Manufactured),
+            "multiUnit" => Some(PropertyType::MultiUnit),
+            "condo" => Some(PropertyType::Condo),
+            "pud" => Some(PropertyType::PUD),
+            _ => None,
+        }
+    }
+}
+
+impl OccupancyType {
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "primary" => Some(OccupancyType::Primary),
+            "secondary" => Some(OccupancyType::Secondary),
+            "investment" => Some(OccupancyType::Investment),
+            _ => None,
+        }
+    }
+}
+
+impl LoanType {
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "cnv" => Some(LoanType::CNV),
+            "fha" => Some(LoanType::FHA),
+            "va" => Some(LoanType::VA),
+            "usda" => Some(LoanType::USDA),
+            "nonQM" => Some(LoanType::NonQM),
+            _ => None,
+        }
+    }
+}
+
+impl StudentLoanStatus {
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "repayment" => Some(StudentLoanStatus::Repayment),
+            "deferred" => Some(StudentLoanStatus::Deferred),
+            "idr" => Some(StudentLoanStatus::IncomeDriven),
+            _ => None,
+        }
+    }
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            StudentLoanStatus::Repayment => "repayment",
+            StudentLoanStatus::Deferred => "deferred",
+            StudentLoanStatus::IncomeDriven => "idr",
+        }
+    }
+}
+
+impl LoanPurpose {
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "purchase" => Some(LoanPurpose::Purchase),
+            "cashOut" => Some(LoanPurpose::CashOut),
+            "refinance" => Some(LoanPurpose::Refinance),
+            "irrrl" => Some(LoanPurpose::IRRRLStreamline),
+            _ => None,
+        }
+    }
+}
+
 // Parsing functions for converting strings to enums
 pub fn parse_property_type(s: &str) -> PropertyType {
     match s {
@@ -174,6 +269,7 @@ pub fn parse_loan_type(s: &str) -> LoanType {
     match s {
         "FHA" => LoanType::FHA,
         "VA" => LoanType::VA,
+        "USDA" => LoanType::USDA,
         "NonQM" => LoanType::NonQM,
         _ => LoanType::CNV,
     }
@@ -209,6 +305,9 @@ pub fn parse_income_type(s: &str) -> IncomeType {
         "Disability" => IncomeType::Disability,
         "Alimony" => IncomeType::Alimony,
         "Child Support" => IncomeType::ChildSupport,
+        "Military" => IncomeType::Military,
+        "Boarder Income" => IncomeType::BoarderIncome,
+        "ADU Rental Income" => IncomeType::AccessoryUnit,
         "Other" => IncomeType::Other,
         _ => IncomeType::W2Employment,
     }
@@ -54,6 +54,10 @@ pub struct BenefitToBorrower {
     #[validate(range(min = 0.0))]
     pub proposed_total_obligations: f64,
 
+    // Manual P&I overrides
+    pub existing_pi_override: bool,
+    pub proposed_pi_override: bool,
+
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -84,6 +88,8 @@ impl Default for BenefitToBorrower {
             debt_paydown: 0.0,
             existing_total_obligations: 0.0,
             proposed_total_obligations: 0.0,
+            existing_pi_override: false,
+            proposed_pi_override: false,
             created_at: now,
             updated_at: now,
         }
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::calc::income_trend::W2SectionResult;
+
 // Data structures for the options template
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct OptionsTemplateData {
@@ -11,9 +13,11 @@ pub struct OptionsTemplateData {
     pub pricing: PricingData,
     pub consumer_debt: ConsumerDebtData,
     pub debt_to_income: DebtToIncomeData,
+    pub residual_income: ResidualIncomeData,
     pub title_fees: TitleFeesData,
     pub income_information: IncomeInformationData,
     pub savings: SavingsData,
+    pub net_tangible_benefit: NetTangibleBenefitData,
 }
 
 // Placeholder data structures - these would need to be defined based on actual form fields
@@ -25,6 +29,9 @@ pub struct LoanInformationData {
     pub term_months: u32,
     pub purpose: String,
     pub appraisal_waiver: bool,
+    pub credit_score: u32,
+    // Agency a conventional loan is underwritten to, "fannie" or "freddie"
+    pub agency: String,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -50,6 +57,23 @@ pub struct NewLoanData {
     pub down_payment_percent: f64,
     pub base_loan_amount: f64,
     pub subordinated_amount: f64,
+    // Subordinate HELOC staying in place; kept out of subordinated_amount
+    pub heloc_balance: f64,
+    pub heloc_credit_limit: f64,
+    // Financed funding fee / upfront MIP, calculated from the loan type
+    pub ff_umip_percentage: f64,
+    pub ff_umip_amount: f64,
+    pub umip_refund: f64,
+    // Prior FHA loan, used for the UFMIP refund on FHA-to-FHA refinances
+    pub prior_ufmip: f64,
+    pub months_since_endorsement: u32,
+    // VA funding fee inputs
+    pub va_subsequent_use: bool,
+    pub va_funding_fee_exempt: bool,
+    // Conventional MI from the rate card; zero when no MI applies
+    pub mi_coverage: f64,
+    pub mi_annual_rate: f64,
+    pub pmi_termination_month: u32,
     pub total_loan_amount: f64,
     pub note_rate: f64,
     pub appraisal_waiver: bool,
@@ -85,6 +109,10 @@ pub struct BenefitToBorrowerData {
     pub debt_paydown: f64,
     pub existing_total_obligations: f64,
     pub proposed_total_obligations: f64,
+
+    // When set, the P&I was typed in and is not recalculated from the loan terms
+    pub existing_pi_override: bool,
+    pub proposed_pi_override: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -100,6 +128,7 @@ pub struct OtherFeesData {
     pub admin_fees: f64,
     pub tax_service: f64,
     pub flood_certification: f64,
+    // Derived from the closing statement; cash out is negative when the borrower brings funds
     pub total_closing_costs: f64,
     pub cash_out_amount: f64,
 }
@@ -161,12 +190,44 @@ pub struct ConsumerDebtItemData {
     pub interest_rate: Option<f64>,
     pub omit_from_dti: bool,
     pub pay_off_at_closing: bool,
+    // Empty when the debt is not a student loan, otherwise "repayment", "deferred" or "idr"
+    pub student_loan_status: String,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct DebtToIncomeData {
     pub front_end_ratio: f64,
     pub back_end_ratio: f64,
+    // Monthly figures the ratios were computed from
+    pub qualifying_income: f64,
+    pub housing_payment: f64,
+    pub monthly_debts: f64,
+    // Set by the user when compensating factors allow the higher program limits
+    pub compensating_factors: bool,
+    pub max_front_end_ratio: Option<f64>,
+    pub max_back_end_ratio: Option<f64>,
+    pub within_limits: bool,
+    // W-2 sections qualifying above their trend's recommendation despite a significant
+    // decline; not saved, rebuilt from the worksheets on load
+    pub income_warnings: Vec<String>,
+}
+
+// VA residual income. The first four fields are entered by the user; the rest are
+// filled by `shared::calc::residual_income` for VA loans.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct ResidualIncomeData {
+    pub state: String,
+    pub family_size: u32,
+    pub square_feet: f64,
+    pub monthly_taxes: f64,
+    pub region: String,
+    pub maintenance_utilities: f64,
+    pub residual_income: f64,
+    // Table amount before the 20% cushion
+    pub guideline_residual_income: f64,
+    pub required_residual_income: f64,
+    pub cushion_applied: bool,
+    pub meets_guideline: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -242,6 +303,273 @@ pub struct IncomeInformationData {
     pub back_end_ratio: f64,
     pub general_income_data: Option<GeneralIncomeData>,
     pub w2_jobs_data: Option<W2JobsData>, // Keep for backward compatibility
+    // Hourly, salary, OT/bonus and commission sections of the W-2 worksheet
+    pub w2_section_results: Vec<W2SectionResult>,
+    pub self_employment_data: Option<SelfEmploymentData>,
+    pub rental_income_data: Option<RentalIncomeData>,
+    pub bank_statement_data: Option<BankStatementData>,
+}
+
+// Self-employment cash-flow worksheet, two tax years per business
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct SelfEmploymentData {
+    pub businesses: Vec<SelfEmploymentBusiness>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SelfEmploymentBusiness {
+    pub id: String,
+    pub business_name: String,
+    // "scheduleC", "form1065", "form1120S" or "form1120"
+    pub business_type: String,
+    pub ownership_percent: f64,
+    pub prior_year: SelfEmploymentYear,
+    pub recent_year: SelfEmploymentYear,
+}
+
+impl Default for SelfEmploymentBusiness {
+    fn default() -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            business_name: String::new(),
+            business_type: "scheduleC".to_string(),
+            ownership_percent: 100.0,
+            prior_year: SelfEmploymentYear::default(),
+            recent_year: SelfEmploymentYear::default(),
+        }
+    }
+}
+
+// Annual figures from one tax year's returns. Which lines apply depends on the business type.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct SelfEmploymentYear {
+    pub tax_year: i32,
+    pub net_income: f64,
+    pub guaranteed_payments: f64,
+    pub w2_wages: f64,
+    pub nonrecurring_income: f64,
+    pub depreciation: f64,
+    pub depletion: f64,
+    pub amortization: f64,
+    pub business_use_of_home: f64,
+    pub notes_payable_short_term: f64,
+    pub meals_exclusion: f64,
+    pub total_tax: f64,
+}
+
+// Bank-statement worksheet for Non-QM loans, 12 or 24 months of deposits per account
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct BankStatementData {
+    pub accounts: Vec<BankStatementAccount>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BankStatementAccount {
+    pub id: String,
+    pub account_name: String,
+    // "business" or "personal"
+    pub account_type: String,
+    // "fixed" or "cpa"; business accounts only
+    pub expense_method: String,
+    pub expense_percent: f64,
+    pub ownership_percent: f64,
+    pub months: Vec<BankStatementMonth>,
+}
+
+impl Default for BankStatementAccount {
+    fn default() -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            account_name: String::new(),
+            account_type: "business".to_string(),
+            expense_method: "fixed".to_string(),
+            expense_percent: 50.0,
+            ownership_percent: 100.0,
+            months: vec![BankStatementMonth::default(); 12],
+        }
+    }
+}
+
+// One statement's deposits. Excluded deposits are transfers between the borrower's
+// accounts and anything that is not business income.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct BankStatementMonth {
+    // "YYYY-MM"
+    pub month: String,
+    pub total_deposits: f64,
+    pub excluded_deposits: f64,
+    pub nsf_count: u32,
+}
+
+// Rental income worksheet, one entry per property the borrower rents out
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct RentalIncomeData {
+    pub properties: Vec<RentalProperty>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RentalProperty {
+    pub id: String,
+    pub address: String,
+    // The property being financed, as opposed to real estate already owned
+    pub is_subject_property: bool,
+    // "scheduleE" or "lease"
+    pub method: String,
+    // Schedule E figures are annual, from the most recent return
+    pub rents_received: f64,
+    pub total_expenses: f64,
+    pub depreciation: f64,
+    pub mortgage_interest: f64,
+    pub taxes: f64,
+    pub insurance: f64,
+    pub hoa_dues: f64,
+    pub months_in_service: u32,
+    // Lease method
+    pub gross_monthly_rent: f64,
+    // Principal, interest, taxes, insurance and HOA on a property already owned
+    pub monthly_pitia: f64,
+}
+
+impl Default for RentalProperty {
+    fn default() -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            address: String::new(),
+            is_subject_property: false,
+            method: "scheduleE".to_string(),
+            rents_received: 0.0,
+            total_expenses: 0.0,
+            depreciation: 0.0,
+            mortgage_interest: 0.0,
+            taxes: 0.0,
+            insurance: 0.0,
+            hoa_dues: 0.0,
+            months_in_service: 12,
+            gross_monthly_rent: 0.0,
+            monthly_pitia: 0.0,
+        }
+    }
+}
+
+// Military pay from the borrower's Leave and Earnings Statement, monthly amounts
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct MilitaryIncomeData {
+    pub branch: String,
+    // Expiration of term of service, empty for career service with no ETS on the LES
+    pub ets_date: String,
+    pub base_pay: f64,
+    pub bah: f64,
+    pub bas: f64,
+    pub clothing_allowance: f64,
+    pub flight_pay: f64,
+    pub hazard_pay: f64,
+    pub other_allowances: f64,
+    pub other_allowances_taxable: bool,
+}
+
+// Investment income worksheet. Funds to close and reserves come out of the sources'
+// assets before continuance is checked.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct InvestmentIncomeData {
+    pub funds_to_close: f64,
+    pub reserves: f64,
+    pub sources: Vec<InvestmentIncomeSource>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InvestmentIncomeSource {
+    // Also the id of the general income entry the source is recorded as
+    pub id: String,
+    pub name: String,
+    // "interest", "dividends", "capital_gains" or "trust"
+    pub source_type: String,
+    // Annual amounts from the 1099-INT/DIV, Schedule D or K-1
+    pub prior_year_amount: f64,
+    pub recent_year_amount: f64,
+    // Current value of the assets producing the income; zero for trust distributions
+    pub asset_balance: f64,
+}
+
+impl Default for InvestmentIncomeSource {
+    fn default() -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: String::new(),
+            source_type: "interest".to_string(),
+            prior_year_amount: 0.0,
+            recent_year_amount: 0.0,
+            asset_balance: 0.0,
+        }
+    }
+}
+
+// Boarder rent and accessory dwelling unit (ADU) income for affordable programs
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AccessoryIncomeData {
+    // "homeready", "home_possible" or "standard"
+    pub program: String,
+    pub boarder_name: String,
+    // Rent received from the boarder over the last twelve months
+    pub boarder_months: Vec<BoarderRentMonth>,
+    // Monthly rent on the ADU lease
+    pub adu_monthly_rent: f64,
+    pub adu_lease_signed: bool,
+}
+
+impl Default for AccessoryIncomeData {
+    fn default() -> Self {
+        Self {
+            program: "homeready".to_string(),
+            boarder_name: String::new(),
+            boarder_months: vec![BoarderRentMonth::default(); 12],
+            adu_monthly_rent: 0.0,
+            adu_lease_signed: false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct BoarderRentMonth {
+    // "YYYY-MM"
+    pub month: String,
+    pub amount: f64,
+}
+
+// Retirement assets on the IRA worksheet, one entry per account
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct RetirementAssetsData {
+    pub accounts: Vec<RetirementAccount>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RetirementAccount {
+    pub id: String,
+    pub description: String,
+    // "qualified" or "non-qualified"
+    pub account_type: String,
+    pub balance: f64,
+    pub is_vested: bool,
+    // The borrower is 59½ or older, or can otherwise withdraw without penalty
+    pub penalty_free: bool,
+    // Current distribution, if the borrower is already drawing on the account
+    pub distribution_amount: f64,
+    // "monthly", "quarterly", "semi-annually" or "annually"
+    pub distribution_frequency: String,
+}
+
+impl Default for RetirementAccount {
+    fn default() -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            description: String::new(),
+            account_type: "qualified".to_string(),
+            balance: 0.0,
+            is_vested: true,
+            penalty_free: false,
+            distribution_amount: 0.0,
+            distribution_frequency: "monthly".to_string(),
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -299,4 +627,36 @@ pub struct SavingsData {
     pub debt_paid: f64,
     pub payment_reduction: f64,
     pub recoup_period_months: f64,
-}
\ No newline at end of file
+}
+
+// Result of the streamline refinance benefit tests. `program` is empty when the
+// scenario is not a VA IRRRL or FHA streamline.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct NetTangibleBenefitData {
+    pub program: String,
+    pub passed: bool,
+    pub findings: Vec<NetTangibleBenefitFindingData>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct NetTangibleBenefitFindingData {
+    pub rule: String,
+    pub passed: bool,
+    // The limit the rule checks against and the scenario's figure for it
+    pub threshold: f64,
+    pub actual: f64,
+    pub detail: String,
+}
+
+// Loan-to-value ratios with the program maximums they were checked against. The
+// maximums are `None` when the program sets no limit for the scenario.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct LoanToValueData {
+    pub ltv: f64,
+    pub cltv: f64,
+    pub hcltv: f64,
+    pub max_ltv: Option<f64>,
+    pub max_cltv: Option<f64>,
+    pub max_hcltv: Option<f64>,
+    pub warnings: Vec<String>,
+}
@@ -12,10 +12,36 @@ pub enum Term{
 
 }
 
+impl Term {
+    pub const ALL: [Term; 4] = [Term::Fifteen, Term::Twenty, Term::TwentyFive, Term::Thirty];
+
+    pub fn years(&self) -> u32 {
+        match self {
+            Term::Fifteen => 15,
+            Term::Twenty => 20,
+            Term::TwentyFive => 25,
+            Term::Thirty => 30,
+        }
+    }
+
+    pub fn months(&self) -> u32 {
+        self.years() * 12
+    }
+
+    /// Term for a number of months, defaulting to thirty years for anything unlisted.
+    pub fn from_months(months: u32) -> Self {
+        Term::ALL
+            .into_iter()
+            .find(|term| term.months() == months)
+            .unwrap_or(Term::Thirty)
+    }
+}
+
 // This struct represents a DSCRCalculator in the database and in the app
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct DSCRCalculator {
     pub id: Uuid,
+    pub property_address: String,
     pub purchase_price: f64,
     pub down_payment: f64,
     pub rate: f64,
@@ -33,6 +59,7 @@ impl Default for DSCRCalculator {
 
         Self {
             id: Uuid::new_v4(),
+            property_address: String::new(),
             purchase_price: 0.0,
             down_payment: 0.0,
             rate: 0.0,
@@ -52,6 +79,7 @@ impl Default for DSCRCalculator {
 
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
 pub struct CreateDSCRCalculator {
+    pub property_address: String,
     #[validate(range(min = 0.0, message = "Purchase price must be a positive number"))]
     pub purchase_price: f64,
     #[validate(range(min = 0.0, message = "Down payment must be a positive number"))]
@@ -73,6 +101,7 @@ pub struct CreateDSCRCalculator {
 
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
 pub struct UpdateDSCRCalculator {
+    pub property_address: Option<String>,
     #[validate(range(min = 0.0, message = "Purchase price must be a positive number"))]
     pub purchase_price: Option<f64>,
     #[validate(range(min = 0.0, message = "Down payment must be a positive number"))]
@@ -54,11 +54,27 @@ pub struct NewLoanDetails {
     pub base_loan_amount: f64,
     #[validate(range(min = 0.0))]
     pub subordinated_amount: f64,
+    #[validate(range(min = 0.0))]
+    pub heloc_balance: f64,
+    #[validate(range(min = 0.0))]
+    pub heloc_credit_limit: f64,
     #[validate(range(min = 0.0, max = 100.0))]
     pub ff_umip_percentage: f64,
     #[validate(range(min = 0.0))]
+    pub ff_umip_amount: f64,
+    #[validate(range(min = 0.0))]
     pub umip_refund: f64,
     #[validate(range(min = 0.0))]
+    pub prior_ufmip: f64,
+    pub months_since_endorsement: u32,
+    pub va_subsequent_use: bool,
+    pub va_funding_fee_exempt: bool,
+    #[validate(range(min = 0.0, max = 100.0))]
+    pub mi_coverage: f64,
+    #[validate(range(min = 0.0, max = 100.0))]
+    pub mi_annual_rate: f64,
+    pub pmi_termination_month: u32,
+    #[validate(range(min = 0.0))]
     pub total_loan_amount: f64,
     #[validate(range(min = 0.0, max = 100.0))]
     pub note_rate: f64,
@@ -77,8 +93,18 @@ impl Default for NewLoanDetails {
             down_payment: 0.0,
             base_loan_amount: 0.0,
             subordinated_amount: 0.0,
+            heloc_balance: 0.0,
+            heloc_credit_limit: 0.0,
             ff_umip_percentage: 0.0,
+            ff_umip_amount: 0.0,
             umip_refund: 0.0,
+            prior_ufmip: 0.0,
+            months_since_endorsement: 0,
+            va_subsequent_use: false,
+            va_funding_fee_exempt: false,
+            mi_coverage: 0.0,
+            mi_annual_rate: 0.0,
+            pmi_termination_month: 0,
             total_loan_amount: 0.0,
             note_rate: 0.0,
             appraisal_waiver: false,
@@ -98,6 +124,8 @@ pub struct LoanInformation {
     pub new_term_months: u32,
     pub loan_purpose: LoanPurpose,
     pub appraisal_waiver: bool,
+    pub credit_score: u32,
+    pub agency: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -113,6 +141,8 @@ impl Default for LoanInformation {
             new_term_months: 360,
             loan_purpose: LoanPurpose::Refinance,
             appraisal_waiver: false,
+            credit_score: 0,
+            agency: "fannie".to_string(),
             created_at: now,
             updated_at: now,
         }
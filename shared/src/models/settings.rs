@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::calc::rule_set::IncomeRuleSet;
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AppSettings {
     pub id: i32,
@@ -7,6 +9,12 @@ pub struct AppSettings {
     pub currency: String,
     pub default_loan_term: i32,
     pub dti_threshold: f64,
+    // Lowest debt service coverage ratio accepted on investor loans
+    pub min_dscr: f64,
+    // Year-over-year income decline, in percent, beyond which the declining figure is recommended alone
+    pub income_decline_threshold: f64,
+    // Income rule set for the custom profile, used by Non-QM loans
+    pub custom_rule_set: IncomeRuleSet,
     pub auto_backup: bool,
 }
 
@@ -18,6 +26,9 @@ impl Default for AppSettings {
             currency: "USD ($)".to_string(),
             default_loan_term: 30,
             dti_threshold: 43.0,
+            min_dscr: crate::calc::dscr::DEFAULT_MIN_DSCR,
+            income_decline_threshold: crate::calc::income_trend::DEFAULT_DECLINE_THRESHOLD_PERCENT,
+            custom_rule_set: IncomeRuleSet::default(),
             auto_backup: false,
         }
     }
@@ -36,4 +47,19 @@ impl AppSettings {
     pub fn format_currency(&self, amount: f64) -> String {
         format!("{}{:.2}", self.currency_symbol(), amount)
     }
-}
\ No newline at end of file
+}
+// One row of the conventional mortgage insurance rate card. A row applies when
+// `ltv_min < ltv <= ltv_max`, the credit score and term fall inside their inclusive
+// bands and the coverage matches. `annual_rate` is a percentage of the loan amount.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct MiRateCardEntry {
+    pub id: i32,
+    pub ltv_min: f64,
+    pub ltv_max: f64,
+    pub credit_score_min: u32,
+    pub credit_score_max: u32,
+    pub coverage: f64,
+    pub term_min_months: u32,
+    pub term_max_months: u32,
+    pub annual_rate: f64,
+}